@@ -0,0 +1,99 @@
+//! Failures injected into matching requests
+
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FaultKind {
+    /// Wait before answering normally (or with another fault)
+    Latency(Duration),
+    /// Answer with this status and an error body
+    Status(u16),
+    /// Answer 429 with a `Retry-After` header
+    RateLimited { retry_after_secs: u32 },
+    /// Answer 200 with a truncated JSON body
+    MalformedJson,
+    /// Close the connection without answering
+    Disconnect,
+}
+
+/// A fault that fires on requests matching a method and path prefix.
+///
+/// By default it matches every request and never runs out; narrow it with
+/// [`on`](Self::on), [`after`](Self::after) and [`times`](Self::times).
+#[derive(Debug, Clone)]
+pub struct Fault {
+    pub(crate) kind: FaultKind,
+    method: Option<String>,
+    path_prefix: String,
+    skip: usize,
+    remaining: Option<usize>,
+}
+
+impl Fault {
+    pub fn new(kind: FaultKind) -> Self {
+        Self {
+            kind,
+            method: None,
+            path_prefix: "/".to_string(),
+            skip: 0,
+            remaining: None,
+        }
+    }
+
+    pub fn latency(delay: Duration) -> Self {
+        Self::new(FaultKind::Latency(delay))
+    }
+
+    pub fn status(status: u16) -> Self {
+        Self::new(FaultKind::Status(status))
+    }
+
+    pub fn rate_limited(retry_after_secs: u32) -> Self {
+        Self::new(FaultKind::RateLimited { retry_after_secs })
+    }
+
+    pub fn malformed_json() -> Self {
+        Self::new(FaultKind::MalformedJson)
+    }
+
+    pub fn disconnect() -> Self {
+        Self::new(FaultKind::Disconnect)
+    }
+
+    /// Only match `method` requests whose path (without the query) starts with `path_prefix`
+    pub fn on(mut self, method: &str, path_prefix: &str) -> Self {
+        self.method = Some(method.to_ascii_uppercase());
+        self.path_prefix = path_prefix.to_string();
+        self
+    }
+
+    /// Let the first `count` matching requests through untouched
+    pub fn after(mut self, count: usize) -> Self {
+        self.skip = count;
+        self
+    }
+
+    /// Stop firing after `count` requests
+    pub fn times(mut self, count: usize) -> Self {
+        self.remaining = Some(count);
+        self
+    }
+
+    /// Whether the fault fires for this request, consuming one use if it does
+    pub(crate) fn fire(&mut self, method: &str, path: &str) -> bool {
+        if self.remaining == Some(0)
+            || self.method.as_deref().is_some_and(|m| m != method)
+            || !path.starts_with(&self.path_prefix)
+        {
+            return false;
+        }
+        if self.skip > 0 {
+            self.skip -= 1;
+            return false;
+        }
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        true
+    }
+}
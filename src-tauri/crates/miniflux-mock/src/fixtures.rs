@@ -0,0 +1,286 @@
+//! Data served by the fake server, and its Miniflux JSON shape
+
+use chrono::{SecondsFormat, TimeZone, Utc};
+use serde_json::{json, Value};
+
+/// 2026-01-01T00:00:00Z, the publish time of entry 0 unless overridden
+pub const BASE_TIMESTAMP: i64 = 1_767_225_600;
+
+/// The account the server authenticates as
+#[derive(Debug, Clone)]
+pub struct MockUser {
+    pub id: i64,
+    pub username: String,
+}
+
+impl MockUser {
+    pub fn new(id: i64, username: impl Into<String>) -> Self {
+        Self {
+            id,
+            username: username.into(),
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "username": self.username,
+            "is_admin": false,
+            "theme": "system_serif",
+            "language": "en_US",
+            "timezone": "UTC",
+            "entry_sorting_direction": "desc",
+            "entry_sorting_order": "published_at",
+            "entries_per_page": 100,
+            "keyboard_shortcuts": true,
+            "display_mode": "standalone",
+            "show_reading_time": true,
+            "entry_swipe": true,
+            "stylesheet": "",
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MockCategory {
+    pub id: i64,
+    pub title: String,
+    pub hide_globally: bool,
+}
+
+impl MockCategory {
+    pub fn new(id: i64, title: impl Into<String>) -> Self {
+        Self {
+            id,
+            title: title.into(),
+            hide_globally: false,
+        }
+    }
+
+    pub(crate) fn to_json(&self, user_id: i64) -> Value {
+        json!({
+            "id": self.id,
+            "user_id": user_id,
+            "title": self.title,
+            "hide_globally": self.hide_globally,
+        })
+    }
+}
+
+/// A feed icon; `data` is the base64 payload without the data-URL prefix
+#[derive(Debug, Clone)]
+pub struct MockIcon {
+    pub id: i64,
+    pub mime_type: String,
+    pub data: String,
+}
+
+impl MockIcon {
+    pub fn new(id: i64, mime_type: impl Into<String>, data: impl Into<String>) -> Self {
+        Self {
+            id,
+            mime_type: mime_type.into(),
+            data: data.into(),
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "mime_type": self.mime_type,
+            "data": format!("{};base64,{}", self.mime_type, self.data),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MockFeed {
+    pub id: i64,
+    pub category_id: i64,
+    pub title: String,
+    pub site_url: String,
+    pub feed_url: String,
+    pub icon: Option<MockIcon>,
+}
+
+impl MockFeed {
+    pub fn new(id: i64, category_id: i64) -> Self {
+        Self {
+            id,
+            category_id,
+            title: format!("Feed {id}"),
+            site_url: format!("https://feed-{id}.example.com"),
+            feed_url: format!("https://feed-{id}.example.com/rss.xml"),
+            icon: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn icon(mut self, icon: MockIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub(crate) fn to_json(&self, user_id: i64, category: Option<&MockCategory>) -> Value {
+        json!({
+            "id": self.id,
+            "user_id": user_id,
+            "title": self.title,
+            "site_url": self.site_url,
+            "feed_url": self.feed_url,
+            "category": category.map(|category| category.to_json(user_id)),
+            "icon": self.icon.as_ref().map(|icon| json!({ "feed_id": self.id, "icon_id": icon.id })),
+            "checked_at": rfc3339(BASE_TIMESTAMP),
+            "parsing_error_message": "",
+            "parsing_error_count": 0,
+            "crawler": false,
+            "disabled": false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MockEnclosure {
+    pub id: i64,
+    pub url: String,
+    pub mime_type: String,
+    /// Size in bytes
+    pub size: i64,
+    pub media_progression: i64,
+}
+
+impl MockEnclosure {
+    pub fn new(id: i64) -> Self {
+        Self {
+            id,
+            url: format!("https://media.example.com/{id}.mp3"),
+            mime_type: "audio/mpeg".to_string(),
+            size: 0,
+            media_progression: 0,
+        }
+    }
+
+    pub fn media_progression(mut self, seconds: i64) -> Self {
+        self.media_progression = seconds;
+        self
+    }
+
+    pub(crate) fn to_json(&self, user_id: i64, entry_id: i64) -> Value {
+        json!({
+            "id": self.id,
+            "user_id": user_id,
+            "entry_id": entry_id,
+            "url": self.url,
+            "mime_type": self.mime_type,
+            "size": self.size,
+            "media_progression": self.media_progression,
+        })
+    }
+}
+
+/// An entry; timestamps are Unix seconds
+#[derive(Debug, Clone)]
+pub struct MockEntry {
+    pub id: i64,
+    pub feed_id: i64,
+    pub title: String,
+    pub url: String,
+    pub content: String,
+    /// `unread`, `read` or `removed`
+    pub status: String,
+    pub starred: bool,
+    pub published_at: i64,
+    pub changed_at: i64,
+    pub tags: Vec<String>,
+    pub enclosures: Vec<MockEnclosure>,
+}
+
+impl MockEntry {
+    /// An unread entry published `id` minutes after [`BASE_TIMESTAMP`] and
+    /// last changed at the same time
+    pub fn new(id: i64, feed_id: i64) -> Self {
+        let published_at = BASE_TIMESTAMP + id * 60;
+        Self {
+            id,
+            feed_id,
+            title: format!("Entry {id}"),
+            url: format!("https://feed-{feed_id}.example.com/entries/{id}"),
+            content: format!("<p>Content of entry {id}</p>"),
+            status: "unread".to_string(),
+            starred: false,
+            published_at,
+            changed_at: published_at,
+            tags: Vec::new(),
+            enclosures: Vec::new(),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    pub fn starred(mut self, starred: bool) -> Self {
+        self.starred = starred;
+        self
+    }
+
+    pub fn published_at(mut self, timestamp: i64) -> Self {
+        self.published_at = timestamp;
+        self
+    }
+
+    pub fn changed_at(mut self, timestamp: i64) -> Self {
+        self.changed_at = timestamp;
+        self
+    }
+
+    pub fn enclosure(mut self, enclosure: MockEnclosure) -> Self {
+        self.enclosures.push(enclosure);
+        self
+    }
+
+    pub(crate) fn to_json(&self, user_id: i64, feed: Value) -> Value {
+        json!({
+            "id": self.id,
+            "user_id": user_id,
+            "feed_id": self.feed_id,
+            "title": self.title,
+            "url": self.url,
+            "comments_url": "",
+            "author": "",
+            "content": self.content,
+            "hash": format!("hash-{}", self.id),
+            "published_at": rfc3339(self.published_at),
+            "created_at": rfc3339(self.published_at),
+            "changed_at": rfc3339(self.changed_at),
+            "status": self.status,
+            "share_code": "",
+            "starred": self.starred,
+            "reading_time": 1,
+            "enclosures": self
+                .enclosures
+                .iter()
+                .map(|enclosure| enclosure.to_json(user_id, self.id))
+                .collect::<Vec<_>>(),
+            "feed": feed,
+            "tags": self.tags,
+        })
+    }
+}
+
+pub(crate) fn rfc3339(timestamp: i64) -> String {
+    Utc.timestamp_opt(timestamp, 0)
+        .single()
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
@@ -0,0 +1,232 @@
+//! A fake Miniflux server for integration tests.
+//!
+//! [`MockMiniflux`] listens on a random local port and serves the parts of
+//! the Miniflux v1 API the app syncs against: the current user, categories,
+//! feeds, entries (with Miniflux's filters, ordering and `limit`/`offset`
+//! pagination, including `changed_after`), feed counters, icons and
+//! enclosures. Writes such as status changes and bookmarks update the data
+//! set and stamp `changed_at`, so an incremental sync sees them.
+//!
+//! Faults (latency, error statuses, 429 with `Retry-After`, malformed JSON,
+//! dropped connections) can be injected per method and path.
+//!
+//! ```no_run
+//! # async fn example() {
+//! use miniflux_mock::{Fault, MockCategory, MockEntry, MockFeed, MockMiniflux, MockUser};
+//!
+//! let server = MockMiniflux::start(MockUser::new(1, "reader")).await;
+//! server.add_category(MockCategory::new(1, "News"));
+//! server.add_feed(MockFeed::new(10, 1));
+//! server.add_entries((1..=120).map(|id| MockEntry::new(id, 10)));
+//! server.inject(Fault::status(503).on("GET", "/v1/entries").after(1).times(1));
+//!
+//! // Point a client at server.url() with server.token()
+//! # }
+//! ```
+
+mod fault;
+mod fixtures;
+mod http;
+mod routes;
+mod state;
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+pub use fault::{Fault, FaultKind};
+pub use fixtures::{
+    MockCategory, MockEnclosure, MockEntry, MockFeed, MockIcon, MockUser, BASE_TIMESTAMP,
+};
+pub use state::RecordedRequest;
+
+use http::Response;
+use state::ServerState;
+
+/// A running fake server; it stops when dropped
+pub struct MockMiniflux {
+    address: SocketAddr,
+    state: Arc<Mutex<ServerState>>,
+    task: JoinHandle<()>,
+}
+
+impl MockMiniflux {
+    /// Starts a server authenticating as `user` with a token derived from its id
+    pub async fn start(user: MockUser) -> Self {
+        let token = format!("mock-token-{}", user.id);
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind mock Miniflux listener");
+        let address = listener
+            .local_addr()
+            .expect("Failed to read mock Miniflux address");
+        let state = Arc::new(Mutex::new(ServerState::new(user, token)));
+
+        let task = tokio::spawn(serve(listener, state.clone()));
+
+        Self {
+            address,
+            state,
+            task,
+        }
+    }
+
+    /// Base URL to configure the client with
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// API token expected in `X-Auth-Token`
+    pub fn token(&self) -> String {
+        self.state().token.clone()
+    }
+
+    pub fn add_category(&self, category: MockCategory) {
+        self.state().categories.insert(category.id, category);
+    }
+
+    pub fn add_feed(&self, feed: MockFeed) {
+        self.state().feeds.insert(feed.id, feed);
+    }
+
+    pub fn add_entries(&self, entries: impl IntoIterator<Item = MockEntry>) {
+        let mut state = self.state();
+        for entry in entries {
+            state.entries.insert(entry.id, entry);
+        }
+    }
+
+    pub fn remove_category(&self, id: i64) {
+        self.state().categories.remove(&id);
+    }
+
+    pub fn remove_feed(&self, id: i64) {
+        let mut state = self.state();
+        state.feeds.remove(&id);
+        state.entries.retain(|_, entry| entry.feed_id != id);
+    }
+
+    /// Changes an entry the way the server would, stamping `changed_at` with
+    /// the current time unless `update` moved it later. Returns `false` if
+    /// there is no such entry.
+    pub fn update_entry(&self, id: i64, update: impl FnOnce(&mut MockEntry)) -> bool {
+        let mut update = Some(update);
+        let changed = self.state().update_entries(
+            |entry| {
+                if entry.id != id {
+                    return false;
+                }
+                if let Some(update) = update.take() {
+                    update(entry);
+                }
+                true
+            },
+            now(),
+        );
+        changed > 0
+    }
+
+    pub fn entry(&self, id: i64) -> Option<MockEntry> {
+        self.state().entries.get(&id).cloned()
+    }
+
+    /// Adds a fault; faults are checked in the order they were injected
+    pub fn inject(&self, fault: Fault) {
+        self.state().faults.push(fault);
+    }
+
+    pub fn clear_faults(&self) {
+        self.state().faults.clear();
+    }
+
+    /// Every request received so far, faulted ones included
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state().requests.clone()
+    }
+
+    /// How many requests were made to exactly this method and path
+    pub fn request_count(&self, method: &str, path: &str) -> usize {
+        self.state()
+            .requests
+            .iter()
+            .filter(|request| request.method == method && request.path == path)
+            .count()
+    }
+
+    /// The most requests the server was handling at the same time
+    pub fn peak_concurrency(&self) -> usize {
+        self.state().peak_in_flight
+    }
+
+    fn state(&self) -> MutexGuard<'_, ServerState> {
+        lock(&self.state)
+    }
+}
+
+impl Drop for MockMiniflux {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn lock(state: &Mutex<ServerState>) -> MutexGuard<'_, ServerState> {
+    // A panicking test thread shouldn't take every later request down with it
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+async fn serve(listener: TcpListener, state: Arc<Mutex<ServerState>>) {
+    loop {
+        let Ok((mut socket, _)) = listener.accept().await else {
+            return;
+        };
+        let state = state.clone();
+
+        tokio::spawn(async move {
+            let Some(request) = http::read_request(&mut socket).await else {
+                return;
+            };
+
+            let faults = lock(&state).admit(&request);
+
+            let mut response = None;
+            for fault in faults {
+                match fault {
+                    FaultKind::Latency(delay) => tokio::time::sleep(delay).await,
+                    _ if response.is_some() => {}
+                    FaultKind::Disconnect => {
+                        lock(&state).finish();
+                        return;
+                    }
+                    FaultKind::Status(status) => {
+                        response = Some(Response::error(status, "Injected failure"));
+                    }
+                    FaultKind::RateLimited { retry_after_secs } => {
+                        response = Some(
+                            Response::error(429, "Too Many Requests")
+                                .header("Retry-After", retry_after_secs.to_string()),
+                        );
+                    }
+                    FaultKind::MalformedJson => {
+                        response = Some(Response::raw(
+                            200,
+                            br#"{"total": 1, "entries": [{"id": 1, "title": "#.to_vec(),
+                        ));
+                    }
+                }
+            }
+
+            let response =
+                response.unwrap_or_else(|| routes::handle(&mut lock(&state), &request, now()));
+            http::write_response(&mut socket, &response).await;
+            lock(&state).finish();
+        });
+    }
+}
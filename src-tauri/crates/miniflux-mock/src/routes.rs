@@ -0,0 +1,279 @@
+//! `/v1` endpoints of the fake server
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::http::{Request, Response};
+use crate::state::{EntryQuery, ServerState};
+
+#[derive(Deserialize)]
+struct EntriesStatusUpdate {
+    entry_ids: Vec<i64>,
+    status: String,
+}
+
+#[derive(Deserialize)]
+struct EnclosureUpdate {
+    media_progression: i64,
+}
+
+/// Answers an authenticated, fault-free request
+pub(crate) fn handle(state: &mut ServerState, request: &Request, now: i64) -> Response {
+    if request.headers.get("x-auth-token") != Some(&state.token) {
+        return Response::error(401, "Access Unauthorized");
+    }
+
+    let Some(path) = request.path.strip_prefix("/v1/") else {
+        return not_found();
+    };
+    let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["me"]) => Response::json(200, &state.user.to_json()),
+        ("GET", ["version"]) => Response::json(
+            200,
+            &json!({
+                "version": "2.2.0",
+                "commit": "mock",
+                "build_date": "",
+                "go_version": "",
+                "compiler": "",
+                "arch": "",
+                "os": "",
+            }),
+        ),
+
+        ("GET", ["categories"]) => {
+            let categories: Vec<Value> = state
+                .categories
+                .values()
+                .map(|category| category.to_json(state.user.id))
+                .collect();
+            Response::json(200, &Value::Array(categories))
+        }
+        ("GET", ["categories", id, "feeds"]) => with_id(id, |id| {
+            if !state.categories.contains_key(&id) {
+                return not_found();
+            }
+            let feeds: Vec<Value> = state
+                .feeds
+                .values()
+                .filter(|feed| feed.category_id == id)
+                .map(|feed| state.feed_json(feed))
+                .collect();
+            Response::json(200, &Value::Array(feeds))
+        }),
+        ("GET", ["categories", id, "entries"]) => with_id(id, |id| {
+            if !state.categories.contains_key(&id) {
+                return not_found();
+            }
+            list_entries(state, request, |query| query.category_id = Some(id))
+        }),
+        ("PUT", ["categories", id, "refresh"]) => with_id(id, |id| {
+            if state.categories.contains_key(&id) {
+                Response::no_content()
+            } else {
+                not_found()
+            }
+        }),
+        ("PUT", ["categories", id, "mark-all-as-read"]) => with_id(id, |id| {
+            let feed_ids: Vec<i64> = state
+                .feeds
+                .values()
+                .filter(|feed| feed.category_id == id)
+                .map(|feed| feed.id)
+                .collect();
+            mark_read(state, now, |feed_id| feed_ids.contains(&feed_id))
+        }),
+
+        ("GET", ["feeds"]) => {
+            let feeds: Vec<Value> = state
+                .feeds
+                .values()
+                .map(|feed| state.feed_json(feed))
+                .collect();
+            Response::json(200, &Value::Array(feeds))
+        }
+        ("GET", ["feeds", "counters"]) => {
+            let mut reads = Map::new();
+            let mut unreads = Map::new();
+            for entry in state.entries.values() {
+                let counter = match entry.status.as_str() {
+                    "read" => &mut reads,
+                    "unread" => &mut unreads,
+                    _ => continue,
+                };
+                let count = counter.entry(entry.feed_id.to_string()).or_insert(json!(0));
+                *count = json!(count.as_i64().unwrap_or(0) + 1);
+            }
+            Response::json(200, &json!({ "reads": reads, "unreads": unreads }))
+        }
+        ("GET", ["feeds", id]) => with_id(id, |id| match state.feeds.get(&id) {
+            Some(feed) => Response::json(200, &state.feed_json(feed)),
+            None => not_found(),
+        }),
+        ("GET", ["feeds", id, "icon"]) => with_id(id, |id| {
+            match state.feeds.get(&id).and_then(|feed| feed.icon.as_ref()) {
+                Some(icon) => Response::json(200, &icon.to_json()),
+                None => not_found(),
+            }
+        }),
+        ("GET", ["feeds", id, "entries"]) => with_id(id, |id| {
+            if !state.feeds.contains_key(&id) {
+                return not_found();
+            }
+            list_entries(state, request, |query| query.feed_id = Some(id))
+        }),
+        ("PUT", ["feeds", id, "refresh"]) => with_id(id, |id| {
+            if state.feeds.contains_key(&id) {
+                Response::no_content()
+            } else {
+                not_found()
+            }
+        }),
+        ("PUT", ["feeds", id, "mark-all-as-read"]) => {
+            with_id(id, |id| mark_read(state, now, |feed_id| feed_id == id))
+        }
+
+        ("GET", ["icons", id]) => with_id(id, |id| {
+            let icon = state
+                .feeds
+                .values()
+                .filter_map(|feed| feed.icon.as_ref())
+                .find(|icon| icon.id == id);
+            match icon {
+                Some(icon) => Response::json(200, &icon.to_json()),
+                None => not_found(),
+            }
+        }),
+
+        ("GET", ["entries"]) => list_entries(state, request, |_| {}),
+        ("PUT", ["entries"]) => {
+            let update: EntriesStatusUpdate = match serde_json::from_slice(&request.body) {
+                Ok(update) => update,
+                Err(e) => return Response::error(400, &e.to_string()),
+            };
+            if !matches!(update.status.as_str(), "read" | "unread" | "removed") {
+                return Response::error(400, "Invalid entry status");
+            }
+            state.update_entries(
+                |entry| {
+                    if !update.entry_ids.contains(&entry.id) {
+                        return false;
+                    }
+                    entry.status = update.status.clone();
+                    true
+                },
+                now,
+            );
+            Response::no_content()
+        }
+        ("GET", ["entries", id]) => with_id(id, |id| match state.entries.get(&id) {
+            Some(entry) => Response::json(200, &state.entry_json(entry)),
+            None => not_found(),
+        }),
+        ("PUT", ["entries", id, "bookmark"]) => with_id(id, |id| {
+            let toggled = state.update_entries(
+                |entry| {
+                    if entry.id != id {
+                        return false;
+                    }
+                    entry.starred = !entry.starred;
+                    true
+                },
+                now,
+            );
+            if toggled == 0 {
+                not_found()
+            } else {
+                Response::no_content()
+            }
+        }),
+
+        ("GET", ["enclosures", id]) => with_id(id, |id| {
+            let enclosure = state.entries.values().find_map(|entry| {
+                entry
+                    .enclosures
+                    .iter()
+                    .find(|enclosure| enclosure.id == id)
+                    .map(|enclosure| enclosure.to_json(state.user.id, entry.id))
+            });
+            match enclosure {
+                Some(enclosure) => Response::json(200, &enclosure),
+                None => not_found(),
+            }
+        }),
+        ("PUT", ["enclosures", id]) => with_id(id, |id| {
+            let update: EnclosureUpdate = match serde_json::from_slice(&request.body) {
+                Ok(update) => update,
+                Err(e) => return Response::error(400, &e.to_string()),
+            };
+            let enclosure = state
+                .entries
+                .values_mut()
+                .flat_map(|entry| entry.enclosures.iter_mut())
+                .find(|enclosure| enclosure.id == id);
+            match enclosure {
+                Some(enclosure) => {
+                    enclosure.media_progression = update.media_progression;
+                    Response::no_content()
+                }
+                None => not_found(),
+            }
+        }),
+
+        ("PUT", ["users", id, "mark-all-as-read"]) => with_id(id, |id| {
+            if id != state.user.id {
+                return Response::error(403, "Access Forbidden");
+            }
+            mark_read(state, now, |_| true)
+        }),
+
+        _ => not_found(),
+    }
+}
+
+fn not_found() -> Response {
+    Response::error(404, "Resource Not Found")
+}
+
+fn with_id(id: &str, respond: impl FnOnce(i64) -> Response) -> Response {
+    match id.parse::<i64>() {
+        Ok(id) => respond(id),
+        Err(_) => Response::error(400, &format!("Invalid ID: {id}")),
+    }
+}
+
+fn list_entries(
+    state: &ServerState,
+    request: &Request,
+    scope: impl FnOnce(&mut EntryQuery),
+) -> Response {
+    let mut query = match EntryQuery::from_request(request) {
+        Ok(query) => query,
+        Err(message) => return Response::error(400, &message),
+    };
+    scope(&mut query);
+
+    let (total, entries) = state.select_entries(&query);
+    let entries: Vec<Value> = entries
+        .into_iter()
+        .map(|entry| state.entry_json(entry))
+        .collect();
+
+    Response::json(200, &json!({ "total": total, "entries": entries }))
+}
+
+fn mark_read(state: &mut ServerState, now: i64, in_scope: impl Fn(i64) -> bool) -> Response {
+    state.update_entries(
+        |entry| {
+            if entry.status != "unread" || !in_scope(entry.feed_id) {
+                return false;
+            }
+            entry.status = "read".to_string();
+            true
+        },
+        now,
+    );
+    Response::no_content()
+}
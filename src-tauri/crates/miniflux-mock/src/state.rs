@@ -0,0 +1,344 @@
+//! The server's data set and the entry query semantics of `/v1/entries`
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::fault::{Fault, FaultKind};
+use crate::fixtures::{MockCategory, MockEntry, MockFeed, MockUser};
+use crate::http::Request;
+
+/// A request as the server saw it, for assertions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    /// The raw query parameters, in order
+    pub query: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ServerState {
+    pub user: MockUser,
+    pub token: String,
+    pub categories: BTreeMap<i64, MockCategory>,
+    pub feeds: BTreeMap<i64, MockFeed>,
+    pub entries: BTreeMap<i64, MockEntry>,
+    pub faults: Vec<Fault>,
+    pub requests: Vec<RecordedRequest>,
+    /// Requests admitted but not answered yet, and the most seen at once
+    pub in_flight: usize,
+    pub peak_in_flight: usize,
+}
+
+impl ServerState {
+    pub fn new(user: MockUser, token: String) -> Self {
+        Self {
+            user,
+            token,
+            categories: BTreeMap::new(),
+            feeds: BTreeMap::new(),
+            entries: BTreeMap::new(),
+            faults: Vec::new(),
+            requests: Vec::new(),
+            in_flight: 0,
+            peak_in_flight: 0,
+        }
+    }
+
+    /// Records the request and returns the faults that fire for it
+    pub fn admit(&mut self, request: &Request) -> Vec<FaultKind> {
+        self.requests.push(RecordedRequest {
+            method: request.method.clone(),
+            path: request.path.clone(),
+            query: request.query.clone(),
+        });
+        self.in_flight += 1;
+        self.peak_in_flight = self.peak_in_flight.max(self.in_flight);
+
+        self.faults
+            .iter_mut()
+            .filter_map(|fault| {
+                fault
+                    .fire(&request.method, &request.path)
+                    .then(|| fault.kind.clone())
+            })
+            .collect()
+    }
+
+    /// Marks an admitted request as answered (or dropped)
+    pub fn finish(&mut self) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+    }
+
+    pub fn feed_json(&self, feed: &MockFeed) -> Value {
+        feed.to_json(self.user.id, self.categories.get(&feed.category_id))
+    }
+
+    pub fn entry_json(&self, entry: &MockEntry) -> Value {
+        let feed = match self.feeds.get(&entry.feed_id) {
+            Some(feed) => self.feed_json(feed),
+            None => MockFeed::new(entry.feed_id, 0).to_json(self.user.id, None),
+        };
+        entry.to_json(self.user.id, feed)
+    }
+
+    fn category_of(&self, entry: &MockEntry) -> Option<i64> {
+        self.feeds.get(&entry.feed_id).map(|feed| feed.category_id)
+    }
+
+    /// Entries matching the query, paginated, plus the total before pagination
+    pub fn select_entries(&self, query: &EntryQuery) -> (usize, Vec<&MockEntry>) {
+        let mut matching: Vec<&MockEntry> = self
+            .entries
+            .values()
+            .filter(|entry| query.matches(entry, self.category_of(entry)))
+            .collect();
+
+        matching.sort_by(|a, b| {
+            let ordering = match query.order.as_str() {
+                "id" => a.id.cmp(&b.id),
+                "status" => a.status.cmp(&b.status),
+                "title" => a.title.cmp(&b.title),
+                "changed_at" => a.changed_at.cmp(&b.changed_at),
+                _ => a.published_at.cmp(&b.published_at),
+            }
+            .then(a.id.cmp(&b.id));
+            if query.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        let total = matching.len();
+        let page = matching
+            .into_iter()
+            .skip(query.offset)
+            .take(query.limit)
+            .collect();
+
+        (total, page)
+    }
+
+    /// Applies `update` to every entry it returns `true` for, stamping `changed_at`
+    pub fn update_entries(
+        &mut self,
+        mut update: impl FnMut(&mut MockEntry) -> bool,
+        now: i64,
+    ) -> usize {
+        let mut changed = 0;
+        for entry in self.entries.values_mut() {
+            if update(entry) {
+                entry.changed_at = entry.changed_at.max(now);
+                changed += 1;
+            }
+        }
+        changed
+    }
+}
+
+/// The filters, order and page of an entry listing request
+#[derive(Debug, Clone)]
+pub(crate) struct EntryQuery {
+    pub statuses: Vec<String>,
+    pub starred: Option<bool>,
+    pub feed_id: Option<i64>,
+    pub category_id: Option<i64>,
+    pub search: Option<String>,
+    pub published_after: Option<i64>,
+    pub published_before: Option<i64>,
+    pub changed_after: Option<i64>,
+    pub changed_before: Option<i64>,
+    pub after_entry_id: Option<i64>,
+    pub before_entry_id: Option<i64>,
+    pub order: String,
+    pub descending: bool,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+impl Default for EntryQuery {
+    fn default() -> Self {
+        Self {
+            statuses: Vec::new(),
+            starred: None,
+            feed_id: None,
+            category_id: None,
+            search: None,
+            published_after: None,
+            published_before: None,
+            changed_after: None,
+            changed_before: None,
+            after_entry_id: None,
+            before_entry_id: None,
+            order: "published_at".to_string(),
+            descending: false,
+            limit: 100,
+            offset: 0,
+        }
+    }
+}
+
+impl EntryQuery {
+    /// Parses the query string the way Miniflux does, rejecting bad numbers
+    pub fn from_request(request: &Request) -> Result<Self, String> {
+        fn number<T: std::str::FromStr>(
+            request: &Request,
+            name: &str,
+        ) -> Result<Option<T>, String> {
+            request
+                .query_param(name)
+                .map(|value| {
+                    value
+                        .parse::<T>()
+                        .map_err(|_| format!("Invalid value for {name}: {value}"))
+                })
+                .transpose()
+        }
+
+        let defaults = Self::default();
+        Ok(Self {
+            statuses: request
+                .query_all("status")
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            starred: request
+                .query_param("starred")
+                .map(|value| matches!(value, "true" | "1" | "")),
+            feed_id: number(request, "feed_id")?,
+            category_id: number(request, "category_id")?,
+            search: request
+                .query_param("search")
+                .map(str::to_lowercase)
+                .filter(|search| !search.is_empty()),
+            // `after`/`before` are the older names for the published_at bounds
+            published_after: number(request, "published_after")?.or(number(request, "after")?),
+            published_before: number(request, "published_before")?.or(number(request, "before")?),
+            changed_after: number(request, "changed_after")?,
+            changed_before: number(request, "changed_before")?,
+            after_entry_id: number(request, "after_entry_id")?,
+            before_entry_id: number(request, "before_entry_id")?,
+            order: request
+                .query_param("order")
+                .map(str::to_string)
+                .unwrap_or(defaults.order),
+            descending: request.query_param("direction") == Some("desc"),
+            limit: number(request, "limit")?.unwrap_or(defaults.limit),
+            offset: number(request, "offset")?.unwrap_or(defaults.offset),
+        })
+    }
+
+    fn matches(&self, entry: &MockEntry, category_id: Option<i64>) -> bool {
+        // Removed entries only show up when asked for by status
+        let status_matches = if self.statuses.is_empty() {
+            entry.status != "removed"
+        } else {
+            self.statuses.contains(&entry.status)
+        };
+
+        status_matches
+            && self.starred.is_none_or(|starred| entry.starred == starred)
+            && self.feed_id.is_none_or(|id| entry.feed_id == id)
+            && self.category_id.is_none_or(|id| category_id == Some(id))
+            && self.search.as_ref().is_none_or(|search| {
+                entry.title.to_lowercase().contains(search)
+                    || entry.content.to_lowercase().contains(search)
+            })
+            && self
+                .published_after
+                .is_none_or(|time| entry.published_at > time)
+            && self
+                .published_before
+                .is_none_or(|time| entry.published_at < time)
+            && self
+                .changed_after
+                .is_none_or(|time| entry.changed_at > time)
+            && self
+                .changed_before
+                .is_none_or(|time| entry.changed_at < time)
+            && self.after_entry_id.is_none_or(|id| entry.id > id)
+            && self.before_entry_id.is_none_or(|id| entry.id < id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::BASE_TIMESTAMP;
+
+    fn state_with_entries(count: i64) -> ServerState {
+        let mut state = ServerState::new(MockUser::new(1, "reader"), "token".to_string());
+        state.categories.insert(1, MockCategory::new(1, "News"));
+        state.categories.insert(2, MockCategory::new(2, "Podcasts"));
+        state.feeds.insert(10, MockFeed::new(10, 1));
+        state.feeds.insert(20, MockFeed::new(20, 2));
+        for id in 1..=count {
+            let feed_id = if id % 2 == 0 { 20 } else { 10 };
+            state.entries.insert(id, MockEntry::new(id, feed_id));
+        }
+        state
+    }
+
+    fn ids(entries: &[&MockEntry]) -> Vec<i64> {
+        entries.iter().map(|entry| entry.id).collect()
+    }
+
+    #[test]
+    fn test_paginates_in_requested_order() {
+        let state = state_with_entries(10);
+        let query = EntryQuery {
+            descending: true,
+            limit: 3,
+            offset: 3,
+            ..EntryQuery::default()
+        };
+
+        let (total, page) = state.select_entries(&query);
+
+        assert_eq!(total, 10);
+        assert_eq!(ids(&page), vec![7, 6, 5]);
+    }
+
+    #[test]
+    fn test_changed_after_is_exclusive() {
+        let mut state = state_with_entries(5);
+        let cutoff = BASE_TIMESTAMP + 3 * 60;
+        let query = EntryQuery {
+            changed_after: Some(cutoff),
+            ..EntryQuery::default()
+        };
+
+        assert_eq!(ids(&state.select_entries(&query).1), vec![4, 5]);
+
+        state.update_entries(|entry| entry.id == 1, cutoff + 1);
+        assert_eq!(ids(&state.select_entries(&query).1), vec![1, 4, 5]);
+    }
+
+    #[test]
+    fn test_removed_entries_need_explicit_status() {
+        let mut state = state_with_entries(3);
+        state.entries.get_mut(&2).unwrap().status = "removed".to_string();
+
+        let (total, _) = state.select_entries(&EntryQuery::default());
+        assert_eq!(total, 2);
+
+        let query = EntryQuery {
+            statuses: vec!["removed".to_string()],
+            ..EntryQuery::default()
+        };
+        assert_eq!(ids(&state.select_entries(&query).1), vec![2]);
+    }
+
+    #[test]
+    fn test_filters_by_category_through_feed() {
+        let state = state_with_entries(6);
+        let query = EntryQuery {
+            category_id: Some(2),
+            ..EntryQuery::default()
+        };
+
+        assert_eq!(ids(&state.select_entries(&query).1), vec![2, 4, 6]);
+    }
+}
@@ -2,9 +2,10 @@ use tauri_specta::{collect_commands, Builder};
 
 pub fn generate_bindings() -> Builder<tauri::Wry> {
     use crate::commands::{
-        accounts, cloud_sync, counters, data, downloads, in_app_browser, miniflux, notifications,
-        player_window, podcast, preferences, quick_pane, reading_state, recovery, summarize, sync,
-        translation, translation_cache, tray,
+        accounts, cloud_snapshots, cloud_sync, counters, data, downloads, filter_rules,
+        in_app_browser, library_sync, miniflux, notifications, player_window, podcast, preferences,
+        quick_pane, reading_state, recovery, retention, search, smart_folders, summarize, sync,
+        sync_history, tags, translation, translation_cache, tray,
     };
 
     Builder::<tauri::Wry>::new().commands(collect_commands![
@@ -21,6 +22,7 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         data::clear_local_data,
         data::get_local_data_size,
         data::factory_reset,
+        data::run_database_maintenance,
         preferences::greet,
         preferences::load_preferences,
         preferences::save_preferences,
@@ -64,15 +66,23 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         miniflux::get_entries,
         miniflux::get_entries_list,
         miniflux::get_entry,
+        miniflux::fetch_feed_entries,
+        miniflux::fetch_category_entries,
         miniflux::mark_entry_read,
         miniflux::mark_entries_read,
         miniflux::mark_feed_as_read,
         miniflux::mark_category_as_read,
+        miniflux::mark_all_as_read,
+        miniflux::mark_entries_read_before,
         miniflux::toggle_entry_read,
         miniflux::toggle_entry_star,
         miniflux::update_entry,
         miniflux::refresh_feed,
         miniflux::refresh_all_feeds,
+        miniflux::refresh_category,
+        miniflux::fetch_category_feeds,
+        miniflux::get_feed_counters,
+        miniflux::get_icon,
         miniflux::create_feed,
         miniflux::update_feed,
         miniflux::delete_feed,
@@ -81,6 +91,9 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         miniflux::create_user,
         miniflux::update_user,
         miniflux::delete_user,
+        miniflux::update_user_preferences,
+        miniflux::get_enclosure,
+        miniflux::update_enclosure_progression,
         miniflux::get_api_keys,
         miniflux::create_api_key,
         miniflux::delete_api_key,
@@ -89,6 +102,8 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         miniflux::discover_subscriptions,
         miniflux::export_opml,
         miniflux::import_opml,
+        miniflux::export_opml_to_file,
+        miniflux::import_opml_from_file,
         miniflux::get_miniflux_version,
         miniflux::get_integrations,
         miniflux::fetch_entry_content,
@@ -96,8 +111,35 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         miniflux::get_feed_icon_data,
         sync::sync_miniflux,
         sync::force_full_sync,
+        sync::sync_all_accounts,
+        sync::sync_feed,
+        sync::sync_category,
         sync::get_sync_status,
+        sync::get_sync_conflicts,
+        sync::clear_sync_conflicts,
+        sync_history::get_sync_history,
+        sync::set_network_status,
         counters::get_unread_counts,
+        search::search_entries,
+        tags::list_tags,
+        tags::add_entry_tags,
+        tags::remove_entry_tags,
+        tags::rename_tag,
+        tags::delete_tag,
+        smart_folders::list_smart_folders,
+        smart_folders::create_smart_folder,
+        smart_folders::update_smart_folder,
+        smart_folders::delete_smart_folder,
+        filter_rules::list_filter_rules,
+        filter_rules::create_filter_rule,
+        filter_rules::update_filter_rule,
+        filter_rules::delete_filter_rule,
+        filter_rules::dry_run_filter_rule,
+        filter_rules::unhide_filtered_entries,
+        retention::get_retention_policies,
+        retention::set_retention_policy,
+        retention::delete_retention_policy,
+        retention::apply_retention_policies,
         in_app_browser::open_in_app_browser,
         in_app_browser::close_in_app_browser,
         in_app_browser::resize_browser_webview,
@@ -139,10 +181,18 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         cloud_sync::cloud_sync_delete_credentials,
         cloud_sync::cloud_sync_has_credentials,
         cloud_sync::cloud_sync_has_webdav_credentials,
+        cloud_sync::cloud_sync_save_encryption_passphrase,
+        cloud_sync::cloud_sync_has_encryption_passphrase,
         cloud_sync::cloud_sync_test_connection,
         cloud_sync::cloud_sync_test_webdav_connection,
         cloud_sync::cloud_sync_push,
         cloud_sync::cloud_sync_pull,
+        cloud_sync::cloud_sync_get_conflicts,
+        cloud_sync::cloud_sync_clear_conflicts,
+        cloud_snapshots::cloud_sync_list_snapshots,
+        cloud_snapshots::cloud_sync_preview_snapshot,
+        cloud_snapshots::cloud_sync_restore_snapshot,
+        library_sync::cloud_sync_library,
     ])
 }
 
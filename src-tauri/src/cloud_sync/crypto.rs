@@ -0,0 +1,187 @@
+//! Passphrase encryption for cloud sync payloads.
+//!
+//! An encrypted payload is stored as a JSON envelope instead of the plain
+//! sync JSON. The key is derived from the passphrase with Argon2id and the
+//! payload sealed with XChaCha20-Poly1305. The KDF parameters travel in the
+//! envelope so they can be raised later without breaking older uploads, and
+//! `version` is bumped for any change older builds can't read.
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+
+/// Marks a sync file as an encrypted envelope
+const ENVELOPE_FORMAT: &str = "minikyu-encrypted";
+const ENVELOPE_VERSION: u32 = 1;
+const KDF_ALGORITHM: &str = "argon2id";
+const CIPHER: &str = "xchacha20poly1305";
+
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+/// Refuse envelopes asking for more KDF memory than this, since anyone with
+/// write access to the bucket can upload one
+const MAX_KDF_MEMORY_KIB: u32 = 1024 * 1024;
+const MAX_KDF_ITERATIONS: u32 = 16;
+
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KdfParams {
+    algorithm: String,
+    salt: String,
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    format: String,
+    version: u32,
+    kdf: KdfParams,
+    cipher: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Whether `data` is an encrypted envelope rather than plain sync JSON
+pub fn is_encrypted(data: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(data)
+        .ok()
+        .and_then(|value| {
+            value
+                .get("format")
+                .and_then(|format| format.as_str())
+                .map(|format| format == ENVELOPE_FORMAT)
+        })
+        .unwrap_or(false)
+}
+
+pub fn validate_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(format!(
+            "Passphrase must be at least {MIN_PASSPHRASE_LEN} characters"
+        ));
+    }
+    Ok(())
+}
+
+/// Binds the envelope header to the ciphertext
+fn associated_data(version: u32) -> Vec<u8> {
+    format!("{ENVELOPE_FORMAT}:{version}").into_bytes()
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], kdf: &KdfParams) -> Result<[u8; KEY_LEN], String> {
+    let params = Params::new(
+        kdf.memory_kib,
+        kdf.iterations,
+        kdf.parallelism,
+        Some(KEY_LEN),
+    )
+    .map_err(|e| format!("Invalid key derivation parameters: {e}"))?;
+
+    let mut key = [0u8; KEY_LEN];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Failed to derive encryption key: {e}"))?;
+    Ok(key)
+}
+
+/// Encrypts `plaintext` and returns the envelope as JSON
+pub fn encrypt(plaintext: &str, passphrase: &str) -> Result<String, String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+
+    let defaults = Params::default();
+    let kdf = KdfParams {
+        algorithm: KDF_ALGORITHM.to_string(),
+        salt: BASE64_STANDARD.encode(salt),
+        memory_kib: defaults.m_cost(),
+        iterations: defaults.t_cost(),
+        parallelism: defaults.p_cost(),
+    };
+    let key = derive_key(passphrase, &salt, &kdf)?;
+
+    let cipher = XChaCha20Poly1305::new(&key.into());
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let aad = associated_data(ENVELOPE_VERSION);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext.as_bytes(),
+                aad: &aad,
+            },
+        )
+        .map_err(|e| format!("Failed to encrypt sync data: {e}"))?;
+
+    let envelope = Envelope {
+        format: ENVELOPE_FORMAT.to_string(),
+        version: ENVELOPE_VERSION,
+        kdf,
+        cipher: CIPHER.to_string(),
+        nonce: BASE64_STANDARD.encode(nonce),
+        ciphertext: BASE64_STANDARD.encode(ciphertext),
+    };
+    serde_json::to_string_pretty(&envelope)
+        .map_err(|e| format!("Failed to serialize encrypted sync data: {e}"))
+}
+
+/// Opens an envelope made by [`encrypt`] and returns the plain sync JSON
+pub fn decrypt(data: &str, passphrase: &str) -> Result<String, String> {
+    let envelope: Envelope = serde_json::from_str(data)
+        .map_err(|e| format!("Failed to parse encrypted sync data: {e}"))?;
+
+    if envelope.version > ENVELOPE_VERSION {
+        return Err(format!(
+            "Sync data uses encryption format version {}, which this version of the app can't read. Update the app to pull it.",
+            envelope.version
+        ));
+    }
+    if envelope.kdf.algorithm != KDF_ALGORITHM || envelope.cipher != CIPHER {
+        return Err(format!(
+            "Unsupported sync encryption: {} with {}",
+            envelope.kdf.algorithm, envelope.cipher
+        ));
+    }
+    if envelope.kdf.memory_kib > MAX_KDF_MEMORY_KIB || envelope.kdf.iterations > MAX_KDF_ITERATIONS
+    {
+        return Err("Sync data asks for unreasonable key derivation parameters".to_string());
+    }
+
+    let decode = |field: &str, value: &str| {
+        BASE64_STANDARD
+            .decode(value)
+            .map_err(|e| format!("Invalid {field} in encrypted sync data: {e}"))
+    };
+    let salt = decode("salt", &envelope.kdf.salt)?;
+    let nonce = decode("nonce", &envelope.nonce)?;
+    let ciphertext = decode("ciphertext", &envelope.ciphertext)?;
+    if nonce.len() != XNonce::default().len() {
+        return Err("Invalid nonce in encrypted sync data".to_string());
+    }
+
+    let key = derive_key(passphrase, &salt, &envelope.kdf)?;
+    let cipher = XChaCha20Poly1305::new(&key.into());
+    let aad = associated_data(envelope.version);
+    let plaintext = cipher
+        .decrypt(
+            XNonce::from_slice(&nonce),
+            Payload {
+                msg: &ciphertext,
+                aad: &aad,
+            },
+        )
+        .map_err(|_| {
+            "Failed to decrypt sync data: wrong passphrase or corrupted file".to_string()
+        })?;
+
+    String::from_utf8(plaintext).map_err(|e| format!("Decrypted sync data is not UTF-8: {e}"))
+}
+
+#[cfg(test)]
+#[path = "crypto.test.rs"]
+mod tests;
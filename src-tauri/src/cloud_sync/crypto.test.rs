@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use crate::cloud_sync::crypto::{decrypt, encrypt, is_encrypted, validate_passphrase};
+
+    const PAYLOAD: &str = r#"{"preferences":{"theme":"dark"},"server_urls":["https://rss.example.com"],"synced_at":"2026-10-01T00:00:00Z"}"#;
+
+    #[test]
+    fn test_round_trip_hides_payload() {
+        let sealed = encrypt(PAYLOAD, "correct horse").unwrap();
+
+        assert!(is_encrypted(&sealed));
+        assert!(!sealed.contains("rss.example.com"));
+        assert_eq!(decrypt(&sealed, "correct horse").unwrap(), PAYLOAD);
+    }
+
+    #[test]
+    fn test_plain_payload_is_not_encrypted() {
+        assert!(!is_encrypted(PAYLOAD));
+        assert!(!is_encrypted("not json"));
+    }
+
+    #[test]
+    fn test_wrong_passphrase_fails() {
+        let sealed = encrypt(PAYLOAD, "correct horse").unwrap();
+
+        let error = decrypt(&sealed, "battery staple").unwrap_err();
+        assert!(
+            error.contains("wrong passphrase"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn test_tampered_ciphertext_fails() {
+        let sealed = encrypt(PAYLOAD, "correct horse").unwrap();
+        let mut envelope: serde_json::Value = serde_json::from_str(&sealed).unwrap();
+        let ciphertext = envelope["ciphertext"].as_str().unwrap().to_string();
+        let flipped = if ciphertext.starts_with('A') {
+            "B"
+        } else {
+            "A"
+        };
+        envelope["ciphertext"] = format!("{flipped}{}", &ciphertext[1..]).into();
+
+        assert!(decrypt(&envelope.to_string(), "correct horse").is_err());
+    }
+
+    #[test]
+    fn test_newer_format_is_rejected() {
+        let sealed = encrypt(PAYLOAD, "correct horse").unwrap();
+        let mut envelope: serde_json::Value = serde_json::from_str(&sealed).unwrap();
+        envelope["version"] = 2.into();
+
+        let error = decrypt(&envelope.to_string(), "correct horse").unwrap_err();
+        assert!(error.contains("version 2"), "unexpected error: {error}");
+    }
+
+    #[test]
+    fn test_short_passphrase_is_rejected() {
+        assert!(validate_passphrase("short").is_err());
+        assert!(validate_passphrase("long enough").is_ok());
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Waits 5 seconds after the last notification before pushing. Each new
 //! notification resets the timer, so rapid preference changes are batched.
+//! The library sync runs after each push when it is enabled.
 
 use std::time::Duration;
 
@@ -46,5 +47,6 @@ pub async fn run_debounce_worker(app: AppHandle) {
                 warn!("Cloud sync debounced push failed: {e}");
             }
         }
+        crate::commands::library_sync::sync_library_in_background(&app).await;
     }
 }
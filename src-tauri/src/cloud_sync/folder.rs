@@ -0,0 +1,191 @@
+//! Sync folder storage operations for cloud sync.
+//!
+//! Sync files are written into a local folder that another tool keeps in
+//! sync between devices: Syncthing, the Nextcloud or Dropbox desktop clients,
+//! or a network share. Each file is written to a temporary file next to it
+//! and renamed into place, so neither the sync tool nor another device ever
+//! sees a half-written file. A file's version tag is its modification time
+//! and size, which changes whenever either this app or the sync tool
+//! replaces it.
+
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use futures_util::future::BoxFuture;
+use log::{debug, info};
+
+use super::{PushOutcome, RemoteFile, SyncBackend, WriteCondition};
+use crate::types::AppPreferences;
+
+/// Name of the preferences sync file inside the sync folder
+pub const SYNC_FILE_NAME: &str = "preferences-sync.json";
+
+/// A file in the sync folder.
+pub struct FolderRemote {
+    folder: PathBuf,
+    path: String,
+}
+
+impl FolderRemote {
+    pub fn from_preferences(prefs: &AppPreferences) -> Result<Self, String> {
+        let folder = prefs
+            .cloud_sync_folder_path
+            .clone()
+            .ok_or("Sync folder not configured")?;
+        Ok(Self {
+            folder: PathBuf::from(folder),
+            path: SYNC_FILE_NAME.to_string(),
+        })
+    }
+
+    /// Where the file is on disk.
+    ///
+    /// The folder itself is never created: if it is missing (say, an
+    /// unmounted share), writing into an empty folder in its place would
+    /// start a new sync history nobody else sees.
+    pub fn file_path(&self) -> Result<PathBuf, String> {
+        let relative = Path::new(&self.path);
+        let inside_folder = !self.path.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !inside_folder {
+            return Err(format!("Invalid sync file path: {}", self.path));
+        }
+        if !self.folder.is_dir() {
+            return Err(format!("Sync folder {} not found", self.folder.display()));
+        }
+        Ok(self.folder.join(relative))
+    }
+}
+
+/// Version tag of the file on disk, or `None` if there is no file.
+pub async fn version_of(path: &Path) -> Result<Option<String>, String> {
+    let metadata = match tokio::fs::metadata(path).await {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to read sync file: {e}")),
+    };
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |since_epoch| since_epoch.as_nanos());
+    Ok(Some(format!("{modified}-{}", metadata.len())))
+}
+
+/// Temporary file the new contents are written to before the rename
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    path.with_file_name(format!(".{name}.{}.tmp", &suffix[..8]))
+}
+
+/// Write data into the sync folder, provided the file still meets `condition`.
+pub async fn push(
+    remote: &FolderRemote,
+    json_data: &str,
+    condition: &WriteCondition,
+) -> Result<PushOutcome, String> {
+    let path = remote.file_path()?;
+    debug!("Cloud sync folder push to {}", path.display());
+
+    let current = version_of(&path).await?;
+    let stale = match condition {
+        WriteCondition::Unconditional => false,
+        WriteCondition::Matches(version) => current.as_deref() != Some(version.as_str()),
+        WriteCondition::Absent => current.is_some(),
+    };
+    if stale {
+        info!("Sync file changed since it was read");
+        return Ok(PushOutcome::Stale);
+    }
+
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create sync folder: {e}"))?;
+    }
+    let temp_path = temp_path(&path);
+    tokio::fs::write(&temp_path, json_data)
+        .await
+        .map_err(|e| format!("Failed to write sync file: {e}"))?;
+    if let Err(e) = tokio::fs::rename(&temp_path, &path).await {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(format!("Failed to finalize sync file: {e}"));
+    }
+
+    info!("Cloud sync folder push successful");
+    Ok(PushOutcome::Stored {
+        etag: version_of(&path).await?,
+    })
+}
+
+/// Read data from the sync folder. Returns `None` when there is no sync data yet.
+pub async fn pull(remote: &FolderRemote) -> Result<Option<RemoteFile>, String> {
+    let path = remote.file_path()?;
+    debug!("Cloud sync folder pull from {}", path.display());
+
+    // Tag the version before reading: if the file is replaced in between,
+    // the next conditional push sees the change instead of overwriting it
+    let Some(etag) = version_of(&path).await? else {
+        return Ok(None);
+    };
+    match tokio::fs::read_to_string(&path).await {
+        Ok(data) => Ok(Some(RemoteFile {
+            data,
+            etag: Some(etag),
+        })),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Failed to read sync file: {e}")),
+    }
+}
+
+/// Delete a file from the sync folder. Deleting a missing file succeeds.
+pub async fn delete(remote: &FolderRemote) -> Result<(), String> {
+    let path = remote.file_path()?;
+    debug!("Cloud sync folder delete {}", path.display());
+
+    match tokio::fs::remove_file(&path).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Delete failed: {e}")),
+    }
+}
+
+impl SyncBackend for FolderRemote {
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn with_path(&self, path: String) -> Box<dyn SyncBackend> {
+        Box::new(Self {
+            folder: self.folder.clone(),
+            path,
+        })
+    }
+
+    fn fetch(&self) -> BoxFuture<'_, Result<Option<RemoteFile>, String>> {
+        Box::pin(pull(self))
+    }
+
+    fn store<'a>(
+        &'a self,
+        data: &'a str,
+        condition: &'a WriteCondition,
+    ) -> BoxFuture<'a, Result<PushOutcome, String>> {
+        Box::pin(push(self, data, condition))
+    }
+
+    fn delete(&self) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(delete(self))
+    }
+}
+
+#[cfg(test)]
+#[path = "folder.test.rs"]
+mod tests;
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use crate::cloud_sync::folder::SYNC_FILE_NAME;
+    use crate::cloud_sync::{PushOutcome, Remote, WriteCondition};
+    use crate::types::AppPreferences;
+
+    fn temp_folder(name: &str) -> PathBuf {
+        let suffix = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time ok")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("minikyu-{name}-{suffix}"));
+        std::fs::create_dir_all(&root).expect("create temp dir");
+        root
+    }
+
+    fn folder_remote(folder: &std::path::Path) -> Remote {
+        let prefs = AppPreferences {
+            cloud_sync_protocol: "folder".to_string(),
+            cloud_sync_folder_path: Some(folder.display().to_string()),
+            ..AppPreferences::default()
+        };
+        Remote::from_preferences(&prefs).expect("folder remote")
+    }
+
+    #[tokio::test]
+    async fn test_writes_are_conditional_on_the_version_read() {
+        let root = temp_folder("sync-folder");
+        let remote = folder_remote(&root);
+
+        assert!(remote.fetch().await.unwrap().is_none());
+        let PushOutcome::Stored { etag: first } =
+            remote.store("{}", &WriteCondition::Absent).await.unwrap()
+        else {
+            panic!("first push should be stored");
+        };
+        assert!(matches!(
+            remote.store("{}", &WriteCondition::Absent).await.unwrap(),
+            PushOutcome::Stale
+        ));
+
+        let file = remote.fetch().await.unwrap().expect("sync file");
+        assert_eq!(file.data, "{}");
+        assert_eq!(file.etag, first);
+
+        let first = first.expect("version tag");
+        assert!(matches!(
+            remote
+                .store(r#"{"a":1}"#, &WriteCondition::Matches(first.clone()))
+                .await
+                .unwrap(),
+            PushOutcome::Stored { .. }
+        ));
+        assert!(matches!(
+            remote
+                .store(r#"{"a":2}"#, &WriteCondition::Matches(first))
+                .await
+                .unwrap(),
+            PushOutcome::Stale
+        ));
+        assert_eq!(
+            std::fs::read_to_string(root.join(SYNC_FILE_NAME)).unwrap(),
+            r#"{"a":1}"#
+        );
+
+        // Only the sync file is left behind, no temporary files
+        let names: Vec<String> = std::fs::read_dir(&root)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, vec![SYNC_FILE_NAME.to_string()]);
+
+        remote.delete().await.unwrap();
+        remote.delete().await.unwrap();
+        assert!(remote.fetch().await.unwrap().is_none());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn test_siblings_stay_inside_the_folder() {
+        let root = temp_folder("sync-folder-siblings");
+        let remote = folder_remote(&root);
+
+        let index = remote.sibling("snapshots/index.json");
+        index
+            .store("[]", &WriteCondition::Unconditional)
+            .await
+            .unwrap();
+        assert!(root.join("snapshots").join("index.json").is_file());
+
+        let outside = remote.sibling("../escaped.json");
+        assert!(outside
+            .store("{}", &WriteCondition::Unconditional)
+            .await
+            .is_err());
+        assert!(!root.with_file_name("escaped.json").exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn test_missing_folder_is_not_created() {
+        let root = temp_folder("sync-folder-missing").join("unmounted");
+        let remote = folder_remote(&root);
+
+        assert!(remote
+            .store("{}", &WriteCondition::Unconditional)
+            .await
+            .is_err());
+        assert!(!root.exists());
+
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+}
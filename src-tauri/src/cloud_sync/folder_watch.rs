@@ -0,0 +1,83 @@
+//! Sync folder watcher.
+//!
+//! When the "folder" protocol is in use, checks the sync files every 5
+//! seconds and pulls as soon as another device's changes arrive through the
+//! folder. Checking version tags instead of subscribing to file system
+//! events also works on network shares, and with sync tools that replace
+//! files by renaming a temporary file over them.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use tauri::{AppHandle, Emitter};
+use tokio::time::sleep;
+
+use super::folder::{version_of, FolderRemote};
+use super::library::LIBRARY_FILE_NAME;
+use super::state::CloudSyncState;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The watched sync files, if sync through a folder is enabled
+fn watched_files(app: &AppHandle) -> Option<(PathBuf, PathBuf)> {
+    let prefs = crate::commands::preferences::load_preferences_sync(app)?;
+    if !prefs.cloud_sync_enabled || prefs.cloud_sync_protocol != "folder" {
+        return None;
+    }
+    let remote = FolderRemote::from_preferences(&prefs).ok()?;
+    let preferences = remote.file_path().ok()?;
+    let library = preferences.with_file_name(LIBRARY_FILE_NAME);
+    Some((preferences, library))
+}
+
+/// Background task: pulls whenever a sync file in the folder changes.
+pub async fn run_folder_watcher(app: AppHandle) {
+    // Versions already acted on, so a file that fails to pull isn't retried
+    // until it changes again
+    let mut seen_preferences: Option<String> = None;
+    let mut seen_library: Option<String> = None;
+
+    loop {
+        sleep(POLL_INTERVAL).await;
+
+        let Some((preferences_path, library_path)) = watched_files(&app) else {
+            seen_preferences = None;
+            seen_library = None;
+            continue;
+        };
+
+        let preferences = version_of(&preferences_path).await.unwrap_or_else(|e| {
+            debug!("Sync folder watcher: {e}");
+            None
+        });
+        if preferences.is_some() && preferences != seen_preferences {
+            seen_preferences.clone_from(&preferences);
+            // Our own pushes and pulls are already merged
+            if preferences != CloudSyncState::load(&app).etag {
+                info!("Sync folder: preferences changed, pulling");
+                match crate::commands::cloud_sync::cloud_sync_pull(app.clone()).await {
+                    Ok(merged) => {
+                        if let Err(e) = app.emit("cloud-sync-preferences-updated", &merged) {
+                            log::error!("Failed to emit cloud-sync-preferences-updated event: {e}");
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Sync folder pull failed: {e}");
+                    }
+                }
+            }
+        }
+
+        let library = version_of(&library_path).await.unwrap_or_else(|e| {
+            debug!("Sync folder watcher: {e}");
+            None
+        });
+        if library.is_some() && library != seen_library {
+            debug!("Sync folder: library changed, syncing");
+            crate::commands::library_sync::sync_library_in_background(&app).await;
+            // The sync may have uploaded; that version needs no sync of its own
+            seen_library = version_of(&library_path).await.ok().flatten();
+        }
+    }
+}
@@ -1,4 +1,4 @@
-//! Keyring helpers for cloud sync credentials (S3 and WebDAV).
+//! Keyring helpers for cloud sync credentials (S3, WebDAV and the encryption passphrase).
 
 use ::keyring::Entry;
 use log::debug;
@@ -62,9 +62,29 @@ pub fn get_webdav_password() -> Result<String, String> {
     })
 }
 
+pub fn save_encryption_passphrase(passphrase: &str) -> Result<(), String> {
+    debug!("Saving cloud sync encryption passphrase to keyring");
+    create_entry("encryption-passphrase")
+        .set_password(passphrase)
+        .map_err(|e| {
+            debug!("Failed to save encryption passphrase: {e}");
+            format!("Failed to save encryption passphrase: {e}")
+        })
+}
+
+pub fn get_encryption_passphrase() -> Result<String, String> {
+    create_entry("encryption-passphrase")
+        .get_password()
+        .map_err(|e| {
+            debug!("Failed to get encryption passphrase: {e}");
+            format!("Failed to get encryption passphrase: {e}")
+        })
+}
+
 pub fn delete_credentials() -> Result<(), String> {
     let _ = create_entry("access-key").delete_credential();
     let _ = create_entry("secret-key").delete_credential();
     let _ = create_entry("webdav-password").delete_credential();
+    let _ = create_entry("encryption-passphrase").delete_credential();
     Ok(())
 }
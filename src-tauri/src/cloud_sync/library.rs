@@ -0,0 +1,255 @@
+//! Mergeable sync document for local-only reading data.
+//!
+//! The library document rides alongside the preferences file and carries the
+//! reading position, podcast progress, article summaries and entry tags. Each
+//! dataset maps a record key to the latest version of that record, either a
+//! value or a tombstone, stamped with when it was made. Merging keeps the
+//! newer version of every key, so documents merge the same way in any order
+//! and a device only has to apply the records that changed.
+//!
+//! Documents stay bounded: tombstones expire after [`TOMBSTONE_TTL_DAYS`] and
+//! each dataset keeps only its most recently changed records.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const LIBRARY_VERSION: u32 = 1;
+pub const LIBRARY_FILE_NAME: &str = "library-sync.json";
+
+pub const READING_POSITION: &str = "reading_position";
+pub const PODCAST_PROGRESS: &str = "podcast_progress";
+pub const ARTICLE_SUMMARIES: &str = "article_summaries";
+pub const TAGS: &str = "tags";
+
+/// Records each dataset keeps, most recently changed first
+const MAX_RECORDS: &[(&str, usize)] = &[
+    (READING_POSITION, 1),
+    (PODCAST_PROGRESS, 2_000),
+    (ARTICLE_SUMMARIES, 500),
+    (TAGS, 10_000),
+];
+const DEFAULT_MAX_RECORDS: usize = 1_000;
+
+/// Long enough for every device that syncs now and then to see a deletion
+pub const TOMBSTONE_TTL_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// RFC 3339 time this version was made
+    pub updated_at: String,
+    /// `None` marks a deleted record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+}
+
+impl Record {
+    pub fn live(value: Value, updated_at: impl Into<String>) -> Self {
+        Self {
+            updated_at: updated_at.into(),
+            value: Some(value),
+        }
+    }
+
+    pub fn tombstone(updated_at: impl Into<String>) -> Self {
+        Self {
+            updated_at: updated_at.into(),
+            value: None,
+        }
+    }
+
+    pub fn is_tombstone(&self) -> bool {
+        self.value.is_none()
+    }
+}
+
+pub type Dataset = BTreeMap<String, Record>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LibraryDocument {
+    pub version: u32,
+    #[serde(default)]
+    pub datasets: BTreeMap<String, Dataset>,
+}
+
+impl Default for LibraryDocument {
+    fn default() -> Self {
+        Self {
+            version: LIBRARY_VERSION,
+            datasets: BTreeMap::new(),
+        }
+    }
+}
+
+impl LibraryDocument {
+    /// Parses a downloaded document, refusing versions this build can't read
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let document: Self = serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse library sync data: {e}"))?;
+        if document.version > LIBRARY_VERSION {
+            return Err(format!(
+                "Library sync data uses format version {}, which this version of the app can't read. Update the app to sync it.",
+                document.version
+            ));
+        }
+        Ok(document)
+    }
+
+    pub fn dataset(&self, name: &str) -> Option<&Dataset> {
+        self.datasets.get(name)
+    }
+
+    pub fn dataset_mut(&mut self, name: &str) -> &mut Dataset {
+        self.datasets.entry(name.to_string()).or_default()
+    }
+
+    /// Keys of the records that aren't tombstones, per dataset
+    pub fn live_keys(&self) -> BTreeMap<String, BTreeSet<String>> {
+        self.datasets
+            .iter()
+            .map(|(name, dataset)| {
+                let keys = dataset
+                    .iter()
+                    .filter(|(_, record)| !record.is_tombstone())
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                (name.clone(), keys)
+            })
+            .collect()
+    }
+}
+
+fn parse_time(time: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(time).ok()
+}
+
+/// Orders two versions of a record. The later one wins; on a tie a
+/// tombstone wins, then the larger value, so every device picks the same one.
+fn compare(a: &Record, b: &Record) -> Ordering {
+    let by_time = match (parse_time(&a.updated_at), parse_time(&b.updated_at)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        _ => a.updated_at.cmp(&b.updated_at),
+    };
+    by_time.then_with(|| match (&a.value, &b.value) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => a.to_string().cmp(&b.to_string()),
+    })
+}
+
+/// Merges two documents, keeping the winning version of every record
+pub fn merge_documents(local: &LibraryDocument, remote: &LibraryDocument) -> LibraryDocument {
+    let mut merged = local.clone();
+    merged.version = LIBRARY_VERSION;
+    for (name, remote_dataset) in &remote.datasets {
+        let dataset = merged.dataset_mut(name);
+        for (key, remote_record) in remote_dataset {
+            match dataset.get(key) {
+                Some(local_record) if compare(local_record, remote_record) != Ordering::Less => {}
+                _ => {
+                    dataset.insert(key.clone(), remote_record.clone());
+                }
+            }
+        }
+    }
+    merged
+}
+
+/// Records of `merged` that have to be written to the local data in
+/// `local`: newer versions and deletions of records it has
+pub fn local_changes(
+    local: &LibraryDocument,
+    merged: &LibraryDocument,
+) -> Vec<(String, String, Record)> {
+    let mut changes = Vec::new();
+    for (name, dataset) in &merged.datasets {
+        let local_dataset = local.dataset(name);
+        for (key, record) in dataset {
+            let local_record = local_dataset.and_then(|dataset| dataset.get(key));
+            let changed = match local_record {
+                Some(local_record) => local_record != record,
+                None => !record.is_tombstone(),
+            };
+            if changed {
+                changes.push((name.clone(), key.clone(), record.clone()));
+            }
+        }
+    }
+    changes
+}
+
+/// Keys that were live locally after the last sync but are gone now, the
+/// candidates for tombstones
+pub fn deleted_since(
+    local: &LibraryDocument,
+    previously_live: &BTreeMap<String, BTreeSet<String>>,
+) -> Vec<(String, String)> {
+    let mut deleted = Vec::new();
+    for (name, keys) in previously_live {
+        let dataset = local.dataset(name);
+        for key in keys {
+            if !dataset.is_some_and(|dataset| dataset.contains_key(key)) {
+                deleted.push((name.clone(), key.clone()));
+            }
+        }
+    }
+    deleted
+}
+
+/// Drops expired tombstones and the oldest records beyond each dataset's cap
+pub fn bound(document: &mut LibraryDocument, now: DateTime<Utc>) {
+    let expiry = now - Duration::days(TOMBSTONE_TTL_DAYS);
+    for (name, dataset) in document.datasets.iter_mut() {
+        dataset.retain(|_, record| {
+            !record.is_tombstone()
+                || parse_time(&record.updated_at).is_some_and(|time| time > expiry)
+        });
+
+        let max_records = MAX_RECORDS
+            .iter()
+            .find(|(dataset_name, _)| dataset_name == name)
+            .map_or(DEFAULT_MAX_RECORDS, |(_, max)| *max);
+        if dataset.len() <= max_records {
+            continue;
+        }
+        let mut newest: Vec<(&String, &Record)> = dataset.iter().collect();
+        newest.sort_by(|(_, a), (_, b)| compare(b, a));
+        let keep: BTreeSet<String> = newest
+            .into_iter()
+            .take(max_records)
+            .map(|(key, _)| key.clone())
+            .collect();
+        dataset.retain(|key, _| keep.contains(key));
+    }
+    document.datasets.retain(|_, dataset| !dataset.is_empty());
+}
+
+/// Key of a record about one entry on one Miniflux server
+pub fn entry_key(server_url: &str, entry_id: i64) -> String {
+    format!("{}|{entry_id}", server_url.trim_end_matches('/'))
+}
+
+/// Key of one tag on one entry
+pub fn tag_key(server_url: &str, entry_id: i64, tag: &str) -> String {
+    format!("{}|{tag}", entry_key(server_url, entry_id))
+}
+
+/// Splits a key made by [`entry_key`] or [`tag_key`] into the server URL,
+/// entry ID and the rest (the tag), if any. Server URLs never contain a
+/// bare `|`, so the first two separators are unambiguous.
+pub fn split_key(key: &str) -> Option<(&str, i64, Option<&str>)> {
+    let (server_url, rest) = key.split_once('|')?;
+    let (entry_id, rest) = match rest.split_once('|') {
+        Some((entry_id, rest)) => (entry_id, Some(rest)),
+        None => (rest, None),
+    };
+    Some((server_url, entry_id.parse().ok()?, rest))
+}
+
+#[cfg(test)]
+#[path = "library.test.rs"]
+mod tests;
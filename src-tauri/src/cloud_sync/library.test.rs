@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+
+    use crate::cloud_sync::library::{
+        bound, deleted_since, local_changes, merge_documents, split_key, tag_key, LibraryDocument,
+        Record, ARTICLE_SUMMARIES, PODCAST_PROGRESS, TAGS,
+    };
+
+    fn document(datasets: &[(&str, &[(&str, Record)])]) -> LibraryDocument {
+        let mut document = LibraryDocument::default();
+        for (name, records) in datasets {
+            let dataset = document.dataset_mut(name);
+            for (key, record) in records.iter() {
+                dataset.insert(key.to_string(), record.clone());
+            }
+        }
+        document
+    }
+
+    fn now() -> DateTime<Utc> {
+        "2026-10-16T12:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn test_newer_record_wins_in_either_order() {
+        let local = document(&[(
+            PODCAST_PROGRESS,
+            &[(
+                "https://rss.example.com|1",
+                Record::live(json!({ "current_time": 30 }), "2026-10-16T10:00:00+00:00"),
+            )],
+        )]);
+        let remote = document(&[(
+            PODCAST_PROGRESS,
+            &[(
+                "https://rss.example.com|1",
+                Record::live(json!({ "current_time": 90 }), "2026-10-16T11:00:00+00:00"),
+            )],
+        )]);
+
+        let merged = merge_documents(&local, &remote);
+
+        assert_eq!(merged, merge_documents(&remote, &local));
+        assert_eq!(
+            merged.dataset(PODCAST_PROGRESS).unwrap()["https://rss.example.com|1"].value,
+            Some(json!({ "current_time": 90 }))
+        );
+    }
+
+    #[test]
+    fn test_tombstone_wins_a_tie() {
+        let local = document(&[(TAGS, &[("k", Record::tombstone("2026-10-16T10:00:00Z"))])]);
+        let remote = document(&[(
+            TAGS,
+            &[("k", Record::live(json!(true), "2026-10-16T10:00:00Z"))],
+        )]);
+
+        assert!(merge_documents(&local, &remote).dataset(TAGS).unwrap()["k"].is_tombstone());
+        assert!(merge_documents(&remote, &local).dataset(TAGS).unwrap()["k"].is_tombstone());
+    }
+
+    #[test]
+    fn test_local_changes_are_remote_wins_and_deletions_of_local_records() {
+        let local = document(&[(
+            TAGS,
+            &[
+                ("kept", Record::live(json!(true), "2026-10-16T10:00:00Z")),
+                ("removed", Record::live(json!(true), "2026-10-16T10:00:00Z")),
+            ],
+        )]);
+        let remote = document(&[(
+            TAGS,
+            &[
+                ("added", Record::live(json!(true), "2026-10-16T11:00:00Z")),
+                ("removed", Record::tombstone("2026-10-16T11:00:00Z")),
+                ("never_here", Record::tombstone("2026-10-16T11:00:00Z")),
+            ],
+        )]);
+
+        let merged = merge_documents(&local, &remote);
+        let changes: Vec<(String, bool)> = local_changes(&local, &merged)
+            .into_iter()
+            .map(|(_, key, record)| (key, record.is_tombstone()))
+            .collect();
+
+        assert_eq!(
+            changes,
+            vec![("added".to_string(), false), ("removed".to_string(), true)]
+        );
+    }
+
+    #[test]
+    fn test_deleted_since_finds_keys_gone_from_local() {
+        let local = document(&[(
+            TAGS,
+            &[("kept", Record::live(json!(true), "2026-10-16T10:00:00Z"))],
+        )]);
+        let previously_live = BTreeMap::from([(
+            TAGS.to_string(),
+            BTreeSet::from(["kept".to_string(), "removed".to_string()]),
+        )]);
+
+        assert_eq!(
+            deleted_since(&local, &previously_live),
+            vec![(TAGS.to_string(), "removed".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_bound_expires_tombstones_and_caps_datasets() {
+        let summaries: Vec<(String, Record)> = (0..510)
+            .map(|i| {
+                let time = format!("2026-10-01T{:02}:{:02}:00Z", i / 60, i % 60);
+                (format!("s{i}"), Record::live(json!("summary"), time))
+            })
+            .collect();
+        let summaries: Vec<(&str, Record)> = summaries
+            .iter()
+            .map(|(key, record)| (key.as_str(), record.clone()))
+            .collect();
+        let mut document = document(&[
+            (ARTICLE_SUMMARIES, &summaries),
+            (
+                TAGS,
+                &[
+                    ("old", Record::tombstone("2026-09-01T00:00:00Z")),
+                    ("recent", Record::tombstone("2026-10-10T00:00:00Z")),
+                ],
+            ),
+        ]);
+
+        bound(&mut document, now());
+
+        let kept = document.dataset(ARTICLE_SUMMARIES).unwrap();
+        assert_eq!(kept.len(), 500);
+        assert!(!kept.contains_key("s0"));
+        assert!(kept.contains_key("s509"));
+        let tags = document.dataset(TAGS).unwrap();
+        assert!(!tags.contains_key("old"));
+        assert!(tags.contains_key("recent"));
+    }
+
+    #[test]
+    fn test_keys_round_trip() {
+        let key = tag_key("https://rss.example.com/", 42, "to|do");
+
+        assert_eq!(key, "https://rss.example.com|42|to|do");
+        assert_eq!(
+            split_key(&key),
+            Some(("https://rss.example.com", 42, Some("to|do")))
+        );
+        assert_eq!(
+            split_key("https://rss.example.com|42"),
+            Some(("https://rss.example.com", 42, None))
+        );
+        assert_eq!(split_key("no-separator"), None);
+    }
+
+    #[test]
+    fn test_newer_format_is_rejected() {
+        assert!(LibraryDocument::parse(r#"{"version":1,"datasets":{}}"#).is_ok());
+        let error = LibraryDocument::parse(r#"{"version":2,"datasets":{}}"#).unwrap_err();
+        assert!(error.contains("version 2"), "unexpected error: {error}");
+    }
+}
@@ -0,0 +1,131 @@
+//! Three-way merge of synced preferences.
+//!
+//! Preferences are merged key by key against the base snapshot both sides
+//! last agreed on: a key only one side changed takes that side's value, and
+//! a key both sides changed to different values is a conflict, settled by
+//! whichever side changed it last and reported back.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use specta::Type;
+
+/// Preference key → RFC 3339 time it was last changed
+pub type ModifiedAt = BTreeMap<String, String>;
+
+/// A preference both devices changed since they last synced
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct PreferenceConflict {
+    pub key: String,
+    pub local_value: Value,
+    pub remote_value: Value,
+    /// "local" or "remote"
+    pub resolution: String,
+    pub detected_at: String,
+}
+
+#[derive(Debug)]
+pub struct MergeOutcome {
+    pub merged: Map<String, Value>,
+    pub modified_at: ModifiedAt,
+    pub conflicts: Vec<PreferenceConflict>,
+}
+
+fn parse_time(time: Option<&String>) -> Option<DateTime<FixedOffset>> {
+    time.and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+}
+
+/// Whether the local change to `key` is newer than the remote one; ties and
+/// missing times go to the remote side
+fn local_is_newer(key: &str, local_modified: &ModifiedAt, remote_modified: &ModifiedAt) -> bool {
+    match (
+        parse_time(local_modified.get(key)),
+        parse_time(remote_modified.get(key)),
+    ) {
+        (Some(local), Some(remote)) => local > remote,
+        (Some(_), None) => true,
+        _ => false,
+    }
+}
+
+/// Merges `local` and `remote` against `base`. Without a base (the first
+/// sync on this device) nothing can be told apart from a conflict, so keys
+/// that differ go to the newer side without being reported.
+pub fn three_way_merge(
+    base: Option<&Map<String, Value>>,
+    local: &Map<String, Value>,
+    local_modified: &ModifiedAt,
+    remote: &Map<String, Value>,
+    remote_modified: &ModifiedAt,
+    now: &str,
+) -> MergeOutcome {
+    let mut merged = Map::new();
+    let mut modified_at = ModifiedAt::new();
+    let mut conflicts = Vec::new();
+
+    let keys: BTreeSet<&String> = local.keys().chain(remote.keys()).collect();
+    for key in keys {
+        let local_value = local.get(key);
+        let remote_value = remote.get(key);
+
+        let take_local = if local_value == remote_value {
+            // Keep whichever time is newer for a value both sides agree on
+            local_is_newer(key, local_modified, remote_modified)
+        } else {
+            match base {
+                Some(base) if local_value == base.get(key) => false,
+                Some(base) if remote_value == base.get(key) => true,
+                Some(_) => {
+                    let take_local = local_is_newer(key, local_modified, remote_modified);
+                    conflicts.push(PreferenceConflict {
+                        key: key.clone(),
+                        local_value: local_value.cloned().unwrap_or(Value::Null),
+                        remote_value: remote_value.cloned().unwrap_or(Value::Null),
+                        resolution: if take_local { "local" } else { "remote" }.to_string(),
+                        detected_at: now.to_string(),
+                    });
+                    take_local
+                }
+                None => local_is_newer(key, local_modified, remote_modified),
+            }
+        };
+
+        let (value, modified) = if take_local {
+            (local_value, local_modified.get(key))
+        } else {
+            (remote_value, remote_modified.get(key))
+        };
+        if let Some(value) = value {
+            merged.insert(key.clone(), value.clone());
+        }
+        if let Some(modified) = modified {
+            modified_at.insert(key.clone(), modified.clone());
+        }
+    }
+
+    MergeOutcome {
+        merged,
+        modified_at,
+        conflicts,
+    }
+}
+
+/// Stamps every key whose value differs between `before` and `after`
+pub fn record_changes(
+    before: &Map<String, Value>,
+    after: &Map<String, Value>,
+    modified_at: &mut ModifiedAt,
+    now: &str,
+) {
+    for key in before.keys().chain(after.keys()) {
+        if before.get(key) != after.get(key) {
+            modified_at.insert(key.clone(), now.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "merge.test.rs"]
+mod tests;
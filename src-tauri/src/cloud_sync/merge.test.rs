@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Map, Value};
+
+    use crate::cloud_sync::merge::{record_changes, three_way_merge, ModifiedAt};
+
+    const NOW: &str = "2026-10-16T12:00:00+00:00";
+
+    fn map(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    fn stamps(pairs: &[(&str, &str)]) -> ModifiedAt {
+        pairs
+            .iter()
+            .map(|(key, time)| (key.to_string(), time.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_changes_to_different_keys_both_survive() {
+        let base = map(json!({ "theme": "light", "language": "en", "font": 16 }));
+        let local = map(json!({ "theme": "dark", "language": "en", "font": 16 }));
+        let remote = map(json!({ "theme": "light", "language": "ja", "font": 16 }));
+
+        let outcome = three_way_merge(
+            Some(&base),
+            &local,
+            &stamps(&[("theme", "2026-10-16T10:00:00+00:00")]),
+            &remote,
+            &stamps(&[("language", "2026-10-16T11:00:00+00:00")]),
+            NOW,
+        );
+
+        assert_eq!(
+            outcome.merged,
+            map(json!({ "theme": "dark", "language": "ja", "font": 16 }))
+        );
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(
+            outcome.modified_at,
+            stamps(&[
+                ("language", "2026-10-16T11:00:00+00:00"),
+                ("theme", "2026-10-16T10:00:00+00:00"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_conflict_goes_to_newer_change_and_is_reported() {
+        let base = map(json!({ "theme": "light" }));
+        let local = map(json!({ "theme": "dark" }));
+        let remote = map(json!({ "theme": "sepia" }));
+
+        let outcome = three_way_merge(
+            Some(&base),
+            &local,
+            &stamps(&[("theme", "2026-10-16T11:00:00+00:00")]),
+            &remote,
+            &stamps(&[("theme", "2026-10-16T10:00:00+00:00")]),
+            NOW,
+        );
+
+        assert_eq!(outcome.merged["theme"], json!("dark"));
+        assert_eq!(outcome.conflicts.len(), 1);
+        let conflict = &outcome.conflicts[0];
+        assert_eq!(conflict.key, "theme");
+        assert_eq!(conflict.local_value, json!("dark"));
+        assert_eq!(conflict.remote_value, json!("sepia"));
+        assert_eq!(conflict.resolution, "local");
+    }
+
+    #[test]
+    fn test_unstamped_conflict_goes_to_remote() {
+        let base = map(json!({ "theme": "light" }));
+        let local = map(json!({ "theme": "dark" }));
+        let remote = map(json!({ "theme": "sepia" }));
+
+        let outcome = three_way_merge(
+            Some(&base),
+            &local,
+            &ModifiedAt::new(),
+            &remote,
+            &ModifiedAt::new(),
+            NOW,
+        );
+
+        assert_eq!(outcome.merged["theme"], json!("sepia"));
+        assert_eq!(outcome.conflicts[0].resolution, "remote");
+    }
+
+    #[test]
+    fn test_first_sync_prefers_newer_side_silently() {
+        let local = map(json!({ "theme": "dark", "language": "en" }));
+        let remote = map(json!({ "theme": "light", "language": "ja" }));
+
+        let outcome = three_way_merge(
+            None,
+            &local,
+            &stamps(&[("theme", "2026-10-16T11:00:00+00:00")]),
+            &remote,
+            &ModifiedAt::new(),
+            NOW,
+        );
+
+        assert_eq!(
+            outcome.merged,
+            map(json!({ "theme": "dark", "language": "ja" }))
+        );
+        assert!(outcome.conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_key_removed_on_one_side_follows_that_side() {
+        let base = map(json!({ "theme": "light", "legacy": true }));
+        let local = map(json!({ "theme": "light", "legacy": true }));
+        let remote = map(json!({ "theme": "light" }));
+
+        let outcome = three_way_merge(
+            Some(&base),
+            &local,
+            &ModifiedAt::new(),
+            &remote,
+            &ModifiedAt::new(),
+            NOW,
+        );
+
+        assert_eq!(outcome.merged, map(json!({ "theme": "light" })));
+    }
+
+    #[test]
+    fn test_record_changes_stamps_only_changed_keys() {
+        let before = map(json!({ "theme": "light", "language": "en" }));
+        let after = map(json!({ "theme": "dark", "language": "en", "font": 16 }));
+        let mut modified_at = stamps(&[("language", "2026-10-01T00:00:00+00:00")]);
+
+        record_changes(&before, &after, &mut modified_at, NOW);
+
+        assert_eq!(
+            modified_at,
+            stamps(&[
+                ("font", NOW),
+                ("language", "2026-10-01T00:00:00+00:00"),
+                ("theme", NOW),
+            ])
+        );
+    }
+}
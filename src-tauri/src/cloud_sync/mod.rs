@@ -1,4 +1,119 @@
+pub mod crypto;
 pub mod debounce;
+pub mod folder;
+pub mod folder_watch;
 pub mod keyring;
+pub mod library;
+pub mod merge;
 pub mod s3;
+pub mod snapshots;
+pub mod state;
 pub mod webdav;
+
+use futures_util::future::BoxFuture;
+
+use crate::types::AppPreferences;
+
+/// A sync file as downloaded
+pub struct RemoteFile {
+    pub data: String,
+    /// Version tag (ETag) to make the next upload conditional on
+    pub etag: Option<String>,
+}
+
+/// What an upload requires of the file it replaces
+pub enum WriteCondition {
+    Unconditional,
+    /// The file is still at this version
+    Matches(String),
+    /// There is no file yet
+    Absent,
+}
+
+pub enum PushOutcome {
+    Stored {
+        etag: Option<String>,
+    },
+    /// The file changed since it was read; merge again and retry
+    Stale,
+}
+
+/// A storage service sync files are kept on, pointed at one file.
+///
+/// Implemented once per protocol; everything else goes through [`Remote`],
+/// so adding a backend only means implementing this and registering it in
+/// [`Remote::from_preferences`].
+pub trait SyncBackend: Send + Sync {
+    /// Path of the file within the backend, `/`-separated
+    fn path(&self) -> &str;
+
+    /// The same backend pointed at another file
+    fn with_path(&self, path: String) -> Box<dyn SyncBackend>;
+
+    /// Download the file, or `None` if it doesn't exist
+    fn fetch(&self) -> BoxFuture<'_, Result<Option<RemoteFile>, String>>;
+
+    /// Upload the file, provided it still meets `condition`
+    fn store<'a>(
+        &'a self,
+        data: &'a str,
+        condition: &'a WriteCondition,
+    ) -> BoxFuture<'a, Result<PushOutcome, String>>;
+
+    /// Delete the file; a file that is already gone counts as deleted
+    fn delete(&self) -> BoxFuture<'_, Result<(), String>>;
+}
+
+/// Where a sync file lives, as configured in preferences
+pub struct Remote(Box<dyn SyncBackend>);
+
+impl Remote {
+    /// The preferences sync file
+    pub fn from_preferences(prefs: &AppPreferences) -> Result<Self, String> {
+        let backend: Box<dyn SyncBackend> = match prefs.cloud_sync_protocol.as_str() {
+            "webdav" => Box::new(webdav::WebDavRemote::from_preferences(prefs)?),
+            "folder" => Box::new(folder::FolderRemote::from_preferences(prefs)?),
+            _ => Box::new(s3::S3Remote::from_preferences(prefs)?),
+        };
+        Ok(Self(backend))
+    }
+
+    /// Another file in the same folder on the same backend; `file_name` may
+    /// include subfolders
+    pub fn sibling(&self, file_name: &str) -> Self {
+        let path = match self.0.path().rsplit_once('/') {
+            Some((folder, _)) => format!("{folder}/{file_name}"),
+            None => file_name.to_string(),
+        };
+        Self(self.0.with_path(path))
+    }
+
+    pub async fn fetch(&self) -> Result<Option<RemoteFile>, String> {
+        self.0.fetch().await
+    }
+
+    pub async fn store(
+        &self,
+        data: &str,
+        condition: &WriteCondition,
+    ) -> Result<PushOutcome, String> {
+        self.0.store(data, condition).await
+    }
+
+    pub async fn delete(&self) -> Result<(), String> {
+        self.0.delete().await
+    }
+}
+
+impl RemoteFile {
+    /// Condition for replacing the file as it was when read
+    pub fn replace_condition(file: Option<&RemoteFile>) -> WriteCondition {
+        match file {
+            None => WriteCondition::Absent,
+            Some(RemoteFile {
+                etag: Some(etag), ..
+            }) => WriteCondition::Matches(etag.clone()),
+            Some(_) => WriteCondition::Unconditional,
+        }
+    }
+}
@@ -1,11 +1,15 @@
 //! S3-compatible storage operations for cloud sync.
 
 use log::{debug, error, info};
+use reqwest::header::{self, HeaderMap, HeaderValue};
 use s3::bucket::Bucket;
 use s3::creds::Credentials;
 use s3::Region;
 
-use super::keyring;
+use futures_util::future::BoxFuture;
+
+use super::{keyring, PushOutcome, RemoteFile, SyncBackend, WriteCondition};
+use crate::types::AppPreferences;
 
 /// Build an S3 bucket client from preferences config.
 fn build_bucket(
@@ -37,25 +41,49 @@ fn get_bucket(endpoint: &str, bucket_name: &str, region: &str) -> Result<Box<Buc
     build_bucket(endpoint, bucket_name, region, &access_key, &secret_key)
 }
 
-/// Upload JSON data to S3.
+/// Upload JSON data to S3, provided the object still meets `condition`.
 pub async fn push(
     endpoint: &str,
     bucket_name: &str,
     region: &str,
     object_key: &str,
     json_data: &str,
-) -> Result<(), String> {
+    condition: &WriteCondition,
+) -> Result<PushOutcome, String> {
     debug!("Cloud sync push to {endpoint}/{bucket_name}/{object_key}");
     let bucket = get_bucket(endpoint, bucket_name, region)?;
 
+    let mut headers = HeaderMap::new();
+    match condition {
+        WriteCondition::Unconditional => {}
+        WriteCondition::Matches(etag) => {
+            let etag = HeaderValue::from_str(etag).map_err(|e| format!("Invalid ETag: {e}"))?;
+            headers.insert(header::IF_MATCH, etag);
+        }
+        WriteCondition::Absent => {
+            headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static("*"));
+        }
+    }
+
     let response = bucket
-        .put_object_with_content_type(object_key, json_data.as_bytes(), "application/json")
+        .put_object_with_content_type_and_headers(
+            object_key,
+            json_data.as_bytes(),
+            "application/json",
+            Some(headers),
+        )
         .await
         .map_err(|e| {
             error!("Cloud sync push failed: {e}");
             format!("Upload failed: {e}")
         })?;
 
+    // 409 is what S3 answers when a concurrent conditional write won the race
+    if matches!(response.status_code(), 409 | 412) {
+        info!("Cloud sync push rejected: remote data changed since it was read");
+        return Ok(PushOutcome::Stale);
+    }
+
     if response.status_code() >= 300 {
         return Err(format!(
             "Upload failed with status {}",
@@ -64,16 +92,18 @@ pub async fn push(
     }
 
     info!("Cloud sync push successful");
-    Ok(())
+    Ok(PushOutcome::Stored {
+        etag: response.headers().get("etag").cloned(),
+    })
 }
 
-/// Download JSON data from S3.
+/// Download JSON data from S3. Returns `None` when there is no sync data yet.
 pub async fn pull(
     endpoint: &str,
     bucket_name: &str,
     region: &str,
     object_key: &str,
-) -> Result<String, String> {
+) -> Result<Option<RemoteFile>, String> {
     debug!("Cloud sync pull from {endpoint}/{bucket_name}/{object_key}");
     let bucket = get_bucket(endpoint, bucket_name, region)?;
 
@@ -83,7 +113,7 @@ pub async fn pull(
     })?;
 
     if response.status_code() == 404 {
-        return Err("No sync data found on remote".to_string());
+        return Ok(None);
     }
 
     if response.status_code() >= 300 {
@@ -93,7 +123,109 @@ pub async fn pull(
         ));
     }
 
-    String::from_utf8(response.to_vec()).map_err(|e| format!("Invalid UTF-8 in sync data: {e}"))
+    let data = String::from_utf8(response.to_vec())
+        .map_err(|e| format!("Invalid UTF-8 in sync data: {e}"))?;
+    Ok(Some(RemoteFile {
+        data,
+        etag: response.headers().get("etag").cloned(),
+    }))
+}
+
+/// Delete an object from S3. Deleting a missing object succeeds.
+pub async fn delete(
+    endpoint: &str,
+    bucket_name: &str,
+    region: &str,
+    object_key: &str,
+) -> Result<(), String> {
+    debug!("Cloud sync delete {endpoint}/{bucket_name}/{object_key}");
+    let bucket = get_bucket(endpoint, bucket_name, region)?;
+
+    let response = bucket.delete_object(object_key).await.map_err(|e| {
+        error!("Cloud sync delete failed: {e}");
+        format!("Delete failed: {e}")
+    })?;
+
+    if response.status_code() >= 300 && response.status_code() != 404 {
+        return Err(format!(
+            "Delete failed with status {}",
+            response.status_code()
+        ));
+    }
+    Ok(())
+}
+
+/// An object in an S3-compatible bucket.
+pub struct S3Remote {
+    endpoint: String,
+    bucket: String,
+    region: String,
+    object_key: String,
+}
+
+impl S3Remote {
+    pub fn from_preferences(prefs: &AppPreferences) -> Result<Self, String> {
+        Ok(Self {
+            endpoint: prefs
+                .cloud_sync_endpoint
+                .clone()
+                .ok_or("Cloud sync endpoint not configured")?,
+            bucket: prefs
+                .cloud_sync_bucket
+                .clone()
+                .ok_or("Cloud sync bucket not configured")?,
+            region: prefs.cloud_sync_region.clone(),
+            object_key: prefs.cloud_sync_object_key.clone(),
+        })
+    }
+}
+
+impl SyncBackend for S3Remote {
+    fn path(&self) -> &str {
+        &self.object_key
+    }
+
+    fn with_path(&self, path: String) -> Box<dyn SyncBackend> {
+        Box::new(Self {
+            endpoint: self.endpoint.clone(),
+            bucket: self.bucket.clone(),
+            region: self.region.clone(),
+            object_key: path,
+        })
+    }
+
+    fn fetch(&self) -> BoxFuture<'_, Result<Option<RemoteFile>, String>> {
+        Box::pin(pull(
+            &self.endpoint,
+            &self.bucket,
+            &self.region,
+            &self.object_key,
+        ))
+    }
+
+    fn store<'a>(
+        &'a self,
+        data: &'a str,
+        condition: &'a WriteCondition,
+    ) -> BoxFuture<'a, Result<PushOutcome, String>> {
+        Box::pin(push(
+            &self.endpoint,
+            &self.bucket,
+            &self.region,
+            &self.object_key,
+            data,
+            condition,
+        ))
+    }
+
+    fn delete(&self) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(delete(
+            &self.endpoint,
+            &self.bucket,
+            &self.region,
+            &self.object_key,
+        ))
+    }
 }
 
 /// Test S3 connection by listing bucket objects (limited to 1).
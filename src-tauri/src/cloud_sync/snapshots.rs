@@ -0,0 +1,114 @@
+//! Versioned snapshots of the preferences sync file.
+//!
+//! Every push that changes the synced preferences also stores a copy of the
+//! uploaded file under `snapshots/` next to it. A small index file lists the
+//! snapshots with when and from which device they were taken, so listing
+//! them needs neither bucket versioning nor directory listings, and the
+//! oldest are deleted once there are more than the retention count.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use specta::Type;
+
+pub const SNAPSHOT_INDEX_FILE_NAME: &str = "snapshots/index.json";
+const SNAPSHOT_INDEX_VERSION: u32 = 1;
+
+pub const DEFAULT_SNAPSHOT_RETENTION: u32 = 20;
+pub const MAX_SNAPSHOT_RETENTION: u32 = 200;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct SnapshotInfo {
+    pub id: String,
+    pub created_at: String,
+    pub device_name: String,
+}
+
+/// A preference that differs between a snapshot and the local preferences
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct PreferenceDiff {
+    pub key: String,
+    /// `null` when the local preferences don't have the key
+    pub local_value: Value,
+    /// `null` when the snapshot doesn't have the key
+    pub snapshot_value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotIndex {
+    pub version: u32,
+    /// Oldest first
+    #[serde(default)]
+    pub snapshots: Vec<SnapshotInfo>,
+}
+
+impl Default for SnapshotIndex {
+    fn default() -> Self {
+        Self {
+            version: SNAPSHOT_INDEX_VERSION,
+            snapshots: Vec::new(),
+        }
+    }
+}
+
+impl SnapshotIndex {
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let index: Self = serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse snapshot index: {e}"))?;
+        if index.version > SNAPSHOT_INDEX_VERSION {
+            return Err(format!(
+                "Snapshot index uses format version {}, which this version of the app can't read. Update the app to use snapshots.",
+                index.version
+            ));
+        }
+        Ok(index)
+    }
+
+    /// Adds a snapshot and returns the ones that fall out of `retention`
+    pub fn add(&mut self, snapshot: SnapshotInfo, retention: u32) -> Vec<SnapshotInfo> {
+        self.snapshots.push(snapshot);
+        let excess = self
+            .snapshots
+            .len()
+            .saturating_sub(retention.max(1) as usize);
+        self.snapshots.drain(..excess).collect()
+    }
+
+    pub fn find(&self, id: &str) -> Option<&SnapshotInfo> {
+        self.snapshots.iter().find(|snapshot| snapshot.id == id)
+    }
+}
+
+/// A new snapshot ID: the time it was taken, sortable, plus a random suffix
+/// so two devices pushing in the same second don't collide
+pub fn new_snapshot_id(now: DateTime<Utc>) -> String {
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    format!("{}-{}", now.format("%Y%m%dT%H%M%SZ"), &suffix[..8])
+}
+
+/// File a snapshot is stored in, next to the preferences sync file
+pub fn snapshot_file_name(id: &str) -> String {
+    format!("snapshots/{id}.json")
+}
+
+/// Keys whose values differ between the local and snapshot preferences
+pub fn diff_preferences(
+    local: &Map<String, Value>,
+    snapshot: &Map<String, Value>,
+) -> Vec<PreferenceDiff> {
+    let mut keys: Vec<&String> = local.keys().chain(snapshot.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter(|key| local.get(*key) != snapshot.get(*key))
+        .map(|key| PreferenceDiff {
+            key: key.clone(),
+            local_value: local.get(key).cloned().unwrap_or(Value::Null),
+            snapshot_value: snapshot.get(key).cloned().unwrap_or(Value::Null),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "snapshots.test.rs"]
+mod tests;
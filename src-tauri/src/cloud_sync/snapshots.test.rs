@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Map, Value};
+
+    use crate::cloud_sync::snapshots::{
+        diff_preferences, new_snapshot_id, snapshot_file_name, SnapshotIndex, SnapshotInfo,
+    };
+
+    fn snapshot(id: &str) -> SnapshotInfo {
+        SnapshotInfo {
+            id: id.to_string(),
+            created_at: "2026-10-16T12:00:00+00:00".to_string(),
+            device_name: "laptop".to_string(),
+        }
+    }
+
+    fn map(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_add_evicts_oldest_beyond_retention() {
+        let mut index = SnapshotIndex::default();
+        for id in ["a", "b", "c"] {
+            assert!(index.add(snapshot(id), 3).is_empty());
+        }
+
+        let evicted = index.add(snapshot("d"), 3);
+
+        assert_eq!(evicted, vec![snapshot("a")]);
+        let ids: Vec<&str> = index.snapshots.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c", "d"]);
+        assert!(index.find("a").is_none());
+        assert!(index.find("d").is_some());
+    }
+
+    #[test]
+    fn test_ids_sort_by_time_and_name_safe_files() {
+        let earlier = new_snapshot_id("2026-10-16T09:00:00Z".parse().unwrap());
+        let later = new_snapshot_id("2026-10-16T10:00:00Z".parse().unwrap());
+
+        assert!(earlier < later);
+        assert!(earlier.starts_with("20261016T090000Z-"));
+        assert!(later.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'));
+        assert_eq!(
+            snapshot_file_name(&earlier),
+            format!("snapshots/{earlier}.json")
+        );
+    }
+
+    #[test]
+    fn test_diff_lists_changed_added_and_removed_keys() {
+        let local = map(json!({ "theme": "dark", "language": "en", "font": 16 }));
+        let snapshot = map(json!({ "theme": "light", "language": "en", "legacy": true }));
+
+        let keys: Vec<(String, Value, Value)> = diff_preferences(&local, &snapshot)
+            .into_iter()
+            .map(|diff| (diff.key, diff.local_value, diff.snapshot_value))
+            .collect();
+
+        assert_eq!(
+            keys,
+            vec![
+                ("font".to_string(), json!(16), Value::Null),
+                ("legacy".to_string(), Value::Null, json!(true)),
+                ("theme".to_string(), json!("dark"), json!("light")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_newer_index_format_is_rejected() {
+        assert!(SnapshotIndex::parse(r#"{"version":1,"snapshots":[]}"#).is_ok());
+        assert!(SnapshotIndex::parse(r#"{"version":2,"snapshots":[]}"#).is_err());
+    }
+}
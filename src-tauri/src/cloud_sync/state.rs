@@ -0,0 +1,131 @@
+//! Device-local bookkeeping for preference sync.
+//!
+//! Kept in `cloud-sync-state.json` next to the preferences file: the synced
+//! preferences as of the last push or pull (the merge base), the remote
+//! file's version tag, when each preference last changed, and the conflicts
+//! merges have settled since they were last cleared.
+//!
+//! Library sync keeps its own state in `library-sync-state.json`, so the two
+//! syncs can run side by side without overwriting each other's state.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tauri::{AppHandle, Runtime};
+
+use super::merge::{record_changes, ModifiedAt, PreferenceConflict};
+use crate::commands::preferences::get_preferences_path;
+use crate::types::AppPreferences;
+
+/// Conflicts kept for the frontend; older ones are dropped
+const MAX_CONFLICTS: usize = 50;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CloudSyncState {
+    #[serde(default)]
+    pub base: Option<Map<String, Value>>,
+    /// ETag of the remote file `base` was read from or written as
+    #[serde(default)]
+    pub etag: Option<String>,
+    #[serde(default)]
+    pub modified_at: ModifiedAt,
+    #[serde(default)]
+    pub conflicts: Vec<PreferenceConflict>,
+}
+
+fn state_path<R: Runtime>(app: &AppHandle<R>, file_name: &str) -> Result<PathBuf, String> {
+    Ok(get_preferences_path(app)?.with_file_name(file_name))
+}
+
+/// Reads a state file, starting afresh if it is missing or unreadable
+fn load_state<T: DeserializeOwned + Default>(path: Result<PathBuf, String>) -> T {
+    let Ok(path) = path else {
+        return T::default();
+    };
+    std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| {
+            serde_json::from_str(&contents)
+                .inspect_err(|e| log::warn!("Failed to parse cloud sync state: {e}"))
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
+/// Writes a state file atomically
+fn save_state<T: Serialize>(state: &T, path: &Path) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(state)
+        .map_err(|e| format!("Failed to serialize cloud sync state: {e}"))?;
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, json)
+        .map_err(|e| format!("Failed to write cloud sync state: {e}"))?;
+    std::fs::rename(&temp_path, path)
+        .map_err(|e| format!("Failed to finalize cloud sync state: {e}"))
+}
+
+impl CloudSyncState {
+    const FILE_NAME: &'static str = "cloud-sync-state.json";
+
+    pub fn load<R: Runtime>(app: &AppHandle<R>) -> Self {
+        load_state(state_path(app, Self::FILE_NAME))
+    }
+
+    pub fn save<R: Runtime>(&self, app: &AppHandle<R>) -> Result<(), String> {
+        save_state(self, &state_path(app, Self::FILE_NAME)?)
+    }
+
+    pub fn add_conflicts(&mut self, conflicts: &[PreferenceConflict]) {
+        self.conflicts.extend_from_slice(conflicts);
+        let excess = self.conflicts.len().saturating_sub(MAX_CONFLICTS);
+        self.conflicts.drain(..excess);
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LibrarySyncState {
+    /// Dataset → keys of the records present locally after the last library
+    /// sync, to tell local deletions apart from records never synced
+    #[serde(default)]
+    pub live_keys: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl LibrarySyncState {
+    const FILE_NAME: &'static str = "library-sync-state.json";
+
+    pub fn load<R: Runtime>(app: &AppHandle<R>) -> Self {
+        load_state(state_path(app, Self::FILE_NAME))
+    }
+
+    pub fn save<R: Runtime>(&self, app: &AppHandle<R>) -> Result<(), String> {
+        save_state(self, &state_path(app, Self::FILE_NAME)?)
+    }
+}
+
+/// Stamps the synced preferences that changed between `before` and `after`
+pub fn record_local_changes<R: Runtime>(
+    app: &AppHandle<R>,
+    before: &AppPreferences,
+    after: &AppPreferences,
+) -> Result<(), String> {
+    let as_map = |preferences: &AppPreferences| {
+        preferences
+            .to_sync_json()
+            .map(|value| value.as_object().cloned().unwrap_or_default())
+    };
+    let (before, after) = (as_map(before)?, as_map(after)?);
+    if before == after {
+        return Ok(());
+    }
+
+    let mut state = CloudSyncState::load(app);
+    record_changes(
+        &before,
+        &after,
+        &mut state.modified_at,
+        &chrono::Utc::now().to_rfc3339(),
+    );
+    state.save(app)
+}
@@ -1,23 +1,40 @@
 //! WebDAV storage operations for cloud sync.
 
 use log::{debug, error, info};
+use reqwest::{header, StatusCode};
 use reqwest_dav::{Auth, ClientBuilder, Depth};
 
-use super::keyring;
+use futures_util::future::BoxFuture;
 
-/// Upload JSON data to WebDAV.
+use super::{keyring, PushOutcome, RemoteFile, SyncBackend, WriteCondition};
+use crate::types::AppPreferences;
+
+/// URL of a file on the WebDAV server, joined the way `reqwest_dav` joins paths
+fn file_url(url: &str, file_path: &str) -> String {
+    format!(
+        "{}/{}",
+        url.trim_end_matches('/'),
+        file_path.trim_start_matches('/')
+    )
+}
+
+/// Upload JSON data to WebDAV, provided the file still meets `condition`.
+///
+/// Uploads and downloads go through plain `reqwest` because `reqwest_dav`
+/// doesn't expose the ETag and conditional headers they need.
 pub async fn push(
     url: &str,
     username: &str,
     file_path: &str,
     json_data: &str,
-) -> Result<(), String> {
+    condition: &WriteCondition,
+) -> Result<PushOutcome, String> {
     debug!("Cloud sync WebDAV push to {url}{file_path}");
     let password = keyring::get_webdav_password()?;
 
     let client = ClientBuilder::new()
         .set_host(url.to_string())
-        .set_auth(Auth::Basic(username.to_string(), password))
+        .set_auth(Auth::Basic(username.to_string(), password.clone()))
         .build()
         .map_err(|e| format!("Failed to create WebDAV client: {e}"))?;
 
@@ -28,38 +45,154 @@ pub async fn push(
         }
     }
 
-    client
-        .put(file_path, json_data.as_bytes().to_vec())
-        .await
-        .map_err(|e| {
-            error!("Cloud sync WebDAV push failed: {e}");
-            format!("Upload failed: {e}")
-        })?;
+    let mut request = reqwest::Client::new()
+        .put(file_url(url, file_path))
+        .basic_auth(username, Some(password))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(json_data.to_string());
+    match condition {
+        WriteCondition::Unconditional => {}
+        WriteCondition::Matches(etag) => request = request.header(header::IF_MATCH, etag),
+        WriteCondition::Absent => request = request.header(header::IF_NONE_MATCH, "*"),
+    }
+
+    let response = request.send().await.map_err(|e| {
+        error!("Cloud sync WebDAV push failed: {e}");
+        format!("Upload failed: {e}")
+    })?;
+
+    if response.status() == StatusCode::PRECONDITION_FAILED {
+        info!("Cloud sync WebDAV push rejected: remote data changed since it was read");
+        return Ok(PushOutcome::Stale);
+    }
+
+    if !response.status().is_success() {
+        error!("Cloud sync WebDAV push failed: {}", response.status());
+        return Err(format!("Upload failed with status {}", response.status()));
+    }
 
     info!("Cloud sync WebDAV push successful");
-    Ok(())
+    Ok(PushOutcome::Stored {
+        etag: etag_of(&response),
+    })
 }
 
-/// Download JSON data from WebDAV.
-pub async fn pull(url: &str, username: &str, file_path: &str) -> Result<String, String> {
+/// Download JSON data from WebDAV. Returns `None` when there is no sync data yet.
+pub async fn pull(
+    url: &str,
+    username: &str,
+    file_path: &str,
+) -> Result<Option<RemoteFile>, String> {
     debug!("Cloud sync WebDAV pull from {url}{file_path}");
     let password = keyring::get_webdav_password()?;
 
-    let client = ClientBuilder::new()
-        .set_host(url.to_string())
-        .set_auth(Auth::Basic(username.to_string(), password))
-        .build()
-        .map_err(|e| format!("Failed to create WebDAV client: {e}"))?;
+    let response = reqwest::Client::new()
+        .get(file_url(url, file_path))
+        .basic_auth(username, Some(password))
+        .send()
+        .await
+        .map_err(|e| {
+            error!("Cloud sync WebDAV pull failed: {e}");
+            format!("Download failed: {e}")
+        })?;
 
-    let response = client.get(file_path).await.map_err(|e| {
-        error!("Cloud sync WebDAV pull failed: {e}");
-        format!("Download failed: {e}")
-    })?;
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
 
-    response
+    if !response.status().is_success() {
+        error!("Cloud sync WebDAV pull failed: {}", response.status());
+        return Err(format!("Download failed with status {}", response.status()));
+    }
+
+    let etag = etag_of(&response);
+    let data = response
         .text()
         .await
-        .map_err(|e| format!("Failed to read response: {e}"))
+        .map_err(|e| format!("Failed to read response: {e}"))?;
+    Ok(Some(RemoteFile { data, etag }))
+}
+
+/// Delete a file from WebDAV. Deleting a missing file succeeds.
+pub async fn delete(url: &str, username: &str, file_path: &str) -> Result<(), String> {
+    debug!("Cloud sync WebDAV delete {url}{file_path}");
+    let password = keyring::get_webdav_password()?;
+
+    let response = reqwest::Client::new()
+        .delete(file_url(url, file_path))
+        .basic_auth(username, Some(password))
+        .send()
+        .await
+        .map_err(|e| {
+            error!("Cloud sync WebDAV delete failed: {e}");
+            format!("Delete failed: {e}")
+        })?;
+
+    if !response.status().is_success() && response.status() != StatusCode::NOT_FOUND {
+        return Err(format!("Delete failed with status {}", response.status()));
+    }
+    Ok(())
+}
+
+fn etag_of(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(header::ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .map(str::to_string)
+}
+
+/// A file on a WebDAV server.
+pub struct WebDavRemote {
+    url: String,
+    username: String,
+    path: String,
+}
+
+impl WebDavRemote {
+    pub fn from_preferences(prefs: &AppPreferences) -> Result<Self, String> {
+        Ok(Self {
+            url: prefs
+                .cloud_sync_webdav_url
+                .clone()
+                .ok_or("WebDAV URL not configured")?,
+            username: prefs
+                .cloud_sync_webdav_username
+                .clone()
+                .ok_or("WebDAV username not configured")?,
+            path: prefs.cloud_sync_webdav_path.clone(),
+        })
+    }
+}
+
+impl SyncBackend for WebDavRemote {
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn with_path(&self, path: String) -> Box<dyn SyncBackend> {
+        Box::new(Self {
+            url: self.url.clone(),
+            username: self.username.clone(),
+            path,
+        })
+    }
+
+    fn fetch(&self) -> BoxFuture<'_, Result<Option<RemoteFile>, String>> {
+        Box::pin(pull(&self.url, &self.username, &self.path))
+    }
+
+    fn store<'a>(
+        &'a self,
+        data: &'a str,
+        condition: &'a WriteCondition,
+    ) -> BoxFuture<'a, Result<PushOutcome, String>> {
+        Box::pin(push(&self.url, &self.username, &self.path, data, condition))
+    }
+
+    fn delete(&self) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(delete(&self.url, &self.username, &self.path))
+    }
 }
 
 /// Test WebDAV connection by listing root.
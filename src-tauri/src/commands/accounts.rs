@@ -2,7 +2,7 @@ use crate::accounts::error::AccountError;
 use crate::accounts::keyring::{
     delete_credentials, get_password, get_token, save_password, save_token,
 };
-use crate::miniflux::AuthConfig;
+use crate::miniflux::{AuthConfig, MinifluxError};
 use crate::utils::serde_helpers::{deserialize_i64_from_string_or_number, serialize_i64_as_string};
 use crate::AppState;
 use chrono::Utc;
@@ -122,14 +122,14 @@ pub async fn save_miniflux_account(
                 old_server_url.as_deref().unwrap_or("unknown"),
                 config.server_url
             );
-            // Clear entries first (due to foreign key constraints)
-            sqlx::query("DELETE FROM entries").execute(&pool).await?;
-            // Clear enclosures
-            sqlx::query("DELETE FROM enclosures").execute(&pool).await?;
-            // Clear feeds
-            sqlx::query("DELETE FROM feeds").execute(&pool).await?;
-            // Clear categories
-            sqlx::query("DELETE FROM categories").execute(&pool).await?;
+            // Only this account's rows: the cache is keyed by account, and
+            // children go before their parents (foreign key constraints)
+            for table in ["enclosures", "entries", "icons", "feeds", "categories"] {
+                sqlx::query(&format!("DELETE FROM {table} WHERE account_id = ?"))
+                    .bind(id)
+                    .execute(&pool)
+                    .await?;
+            }
             // Clear sync state for this account to force a full resync
             sqlx::query("DELETE FROM sync_state WHERE account_id = ?")
                 .bind(id)
@@ -499,85 +499,90 @@ pub async fn switch_miniflux_account(
     Ok(())
 }
 
-#[tauri::command]
-#[specta::specta]
-pub async fn auto_reconnect_miniflux(
-    app_handle: AppHandle,
-    state: State<'_, crate::AppState>,
-) -> Result<(), AccountError> {
-    log::info!("Auto-reconnect: Attempting to reconnect to Miniflux");
-
-    let pool = state
-        .db_pool
-        .lock()
-        .await
-        .as_ref()
-        .ok_or(AccountError::NotFound)
-        .cloned()?;
-
-    // Query for active account
-    let active_account: Option<(i64, String, String, String)> = sqlx::query_as(
-        "SELECT id, username, server_url, auth_method FROM miniflux_connections WHERE is_active = 1",
+/// Builds the server URL and keyring credentials of a saved account into an
+/// `AuthConfig`, e.g. to connect or to sync it in the background.
+pub(crate) async fn stored_auth_config(
+    pool: &SqlitePool,
+    account_id: i64,
+) -> Result<AuthConfig, AccountError> {
+    let (username, server_url, auth_method): (String, String, String) = sqlx::query_as(
+        "SELECT username, server_url, auth_method FROM miniflux_connections WHERE id = ?",
     )
-    .fetch_optional(&pool)
-    .await?;
-
-    // If no active account, return early gracefully
-    if active_account.is_none() {
-        log::info!("Auto-reconnect: No active account found for auto-reconnect");
-        return Ok(());
-    }
+    .bind(account_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or(AccountError::NotFound)?;
 
-    let (account_id, username, server_url, auth_method) = active_account.unwrap();
     log::debug!(
-        "Auto-reconnect: Found active account ID {} for user '{}' at '{}'",
+        "Loading credentials for account ID {} (user '{}' at '{}')",
         account_id,
         username,
         server_url
     );
 
-    // Fetch credentials from keyring based on auth_method
-    let config = match auth_method.as_str() {
+    match auth_method.as_str() {
         "token" => {
-            let token = get_token(&server_url, &username).await.map_err(|e| {
-                log::error!(
-                    "Auto-reconnect: Failed to fetch token from keyring: {:?}",
-                    e
-                );
-                e
-            })?;
-            AuthConfig {
-                server_url: server_url.clone(),
+            let token = get_token(&server_url, &username).await?;
+            Ok(AuthConfig {
+                server_url,
                 auth_token: Some(token),
                 username: None,
                 password: None,
-            }
+            })
         }
         "password" => {
-            let password = get_password(&server_url, &username).await.map_err(|e| {
-                log::error!(
-                    "Auto-reconnect: Failed to fetch password from keyring: {:?}",
-                    e
-                );
-                e
-            })?;
-            AuthConfig {
-                server_url: server_url.clone(),
+            let password = get_password(&server_url, &username).await?;
+            Ok(AuthConfig {
+                server_url,
                 auth_token: None,
-                username: Some(username.clone()),
+                username: Some(username),
                 password: Some(password),
-            }
+            })
         }
         _ => {
             log::error!(
-                "Auto-reconnect: Invalid auth_method '{}' for account ID {}",
+                "Invalid auth_method '{}' for account ID {}",
                 auth_method,
                 account_id
             );
-            return Err(AccountError::InvalidCredentials);
+            Err(AccountError::InvalidCredentials)
         }
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn auto_reconnect_miniflux(
+    app_handle: AppHandle,
+    state: State<'_, crate::AppState>,
+) -> Result<(), AccountError> {
+    log::info!("Auto-reconnect: Attempting to reconnect to Miniflux");
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or(AccountError::NotFound)
+        .cloned()?;
+
+    // Query for active account
+    let active_account: Option<i64> =
+        sqlx::query_scalar("SELECT id FROM miniflux_connections WHERE is_active = 1")
+            .fetch_optional(&pool)
+            .await?;
+
+    // If no active account, return early gracefully
+    let Some(account_id) = active_account else {
+        log::info!("Auto-reconnect: No active account found for auto-reconnect");
+        return Ok(());
     };
 
+    let config = stored_auth_config(&pool, account_id).await.map_err(|e| {
+        log::error!("Auto-reconnect: Failed to load stored credentials: {:?}", e);
+        e
+    })?;
+
     // Call miniflux_connect to establish connection
     log::debug!("Auto-reconnect: Calling miniflux_connect with fetched credentials");
     match crate::commands::miniflux::miniflux_connect(app_handle.clone(), state, config).await {
@@ -590,6 +595,13 @@ pub async fn auto_reconnect_miniflux(
 
             Ok(())
         }
+        Err(MinifluxError::Unauthorized { status }) => {
+            log::error!(
+                "Auto-reconnect: Stored credentials were rejected ({})",
+                status
+            );
+            Err(AccountError::InvalidCredentials)
+        }
         Err(e) => {
             log::error!("Auto-reconnect: Connection failed: {}", e);
             Err(AccountError::KeyringError {
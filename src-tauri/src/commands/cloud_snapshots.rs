@@ -0,0 +1,154 @@
+//! Cloud sync snapshot commands: list, preview and restore earlier versions
+//! of the preferences sync file.
+
+use chrono::Utc;
+use tauri::{AppHandle, Emitter};
+
+use crate::cloud_sync::snapshots::{
+    diff_preferences, new_snapshot_id, snapshot_file_name, PreferenceDiff, SnapshotIndex,
+    SnapshotInfo, SNAPSHOT_INDEX_FILE_NAME,
+};
+use crate::cloud_sync::{PushOutcome, Remote, RemoteFile, WriteCondition};
+use crate::commands::cloud_sync::{decode_payload, seal, sync_map, unseal, CloudSyncPayload};
+use crate::commands::preferences::{load_preferences, save_preferences};
+use crate::types::AppPreferences;
+
+/// Conditional index updates tried before giving up on a busy index.
+const MAX_INDEX_ATTEMPTS: u32 = 3;
+
+async fn fetch_index(
+    remote: &Remote,
+    prefs: &AppPreferences,
+) -> Result<(SnapshotIndex, Option<RemoteFile>), String> {
+    let file = remote.sibling(SNAPSHOT_INDEX_FILE_NAME).fetch().await?;
+    let index = match &file {
+        Some(file) => SnapshotIndex::parse(&unseal(&file.data, prefs)?)?,
+        None => SnapshotIndex::default(),
+    };
+    Ok((index, file))
+}
+
+/// Store `data`, just pushed as the sync file, as a new snapshot and prune
+/// snapshots beyond the retention count.
+pub async fn record_snapshot(
+    remote: &Remote,
+    prefs: &AppPreferences,
+    data: &str,
+) -> Result<(), String> {
+    let retention = prefs.cloud_sync_snapshot_retention;
+    if retention == 0 {
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    let snapshot = SnapshotInfo {
+        id: new_snapshot_id(now),
+        created_at: now.to_rfc3339(),
+        device_name: tauri_plugin_os::hostname(),
+    };
+    // IDs are unique, so there is nothing to race with
+    remote
+        .sibling(&snapshot_file_name(&snapshot.id))
+        .store(data, &WriteCondition::Unconditional)
+        .await?;
+
+    let index_remote = remote.sibling(SNAPSHOT_INDEX_FILE_NAME);
+    for _ in 0..MAX_INDEX_ATTEMPTS {
+        let (mut index, file) = fetch_index(remote, prefs).await?;
+        let evicted = index.add(snapshot.clone(), retention);
+        let json = serde_json::to_string_pretty(&index)
+            .map_err(|e| format!("Failed to serialize snapshot index: {e}"))?;
+        let condition = RemoteFile::replace_condition(file.as_ref());
+
+        match index_remote.store(&seal(json, prefs)?, &condition).await? {
+            PushOutcome::Stored { .. } => {
+                for old in evicted {
+                    let old_file = remote.sibling(&snapshot_file_name(&old.id));
+                    if let Err(e) = old_file.delete().await {
+                        log::warn!("Failed to delete old cloud sync snapshot {}: {e}", old.id);
+                    }
+                }
+                log::info!("Recorded cloud sync snapshot {}", snapshot.id);
+                return Ok(());
+            }
+            PushOutcome::Stale => {
+                log::info!("Snapshot index changed while updating it; retrying");
+            }
+        }
+    }
+
+    Err("Snapshot index kept changing; try again later".to_string())
+}
+
+/// Download and decode a snapshot listed in the index.
+async fn fetch_snapshot(
+    remote: &Remote,
+    prefs: &AppPreferences,
+    snapshot_id: &str,
+) -> Result<CloudSyncPayload, String> {
+    // Only IDs from the index are fetched, so an ID can't point elsewhere
+    let (index, _) = fetch_index(remote, prefs).await?;
+    let snapshot = index
+        .find(snapshot_id)
+        .ok_or_else(|| format!("Snapshot {snapshot_id} not found"))?;
+
+    let file = remote
+        .sibling(&snapshot_file_name(&snapshot.id))
+        .fetch()
+        .await?
+        .ok_or_else(|| format!("Snapshot {snapshot_id} is missing from remote storage"))?;
+    decode_payload(&file.data, prefs)
+}
+
+/// List remote snapshots of the preferences sync file, newest first.
+#[tauri::command]
+#[specta::specta]
+pub async fn cloud_sync_list_snapshots(app: AppHandle) -> Result<Vec<SnapshotInfo>, String> {
+    let prefs = load_preferences(app).await?;
+    let remote = Remote::from_preferences(&prefs)?;
+
+    let (index, _) = fetch_index(&remote, &prefs).await?;
+    Ok(index.snapshots.into_iter().rev().collect())
+}
+
+/// Preferences that would change if the snapshot were restored.
+#[tauri::command]
+#[specta::specta]
+pub async fn cloud_sync_preview_snapshot(
+    app: AppHandle,
+    snapshot_id: String,
+) -> Result<Vec<PreferenceDiff>, String> {
+    let prefs = load_preferences(app).await?;
+    let remote = Remote::from_preferences(&prefs)?;
+
+    let payload = fetch_snapshot(&remote, &prefs, &snapshot_id).await?;
+    Ok(diff_preferences(
+        &sync_map(&prefs)?,
+        &sync_map(&payload.preferences)?,
+    ))
+}
+
+/// Restore the synced preferences from a snapshot.
+///
+/// The restored values are saved as local changes, so the next push spreads
+/// them to other devices and wins over what they changed before.
+#[tauri::command]
+#[specta::specta]
+pub async fn cloud_sync_restore_snapshot(
+    app: AppHandle,
+    snapshot_id: String,
+) -> Result<AppPreferences, String> {
+    let prefs = load_preferences(app.clone()).await?;
+    let remote = Remote::from_preferences(&prefs)?;
+
+    let payload = fetch_snapshot(&remote, &prefs, &snapshot_id).await?;
+    let mut restored = prefs.clone();
+    restored.merge_from_cloud(&payload.preferences);
+    save_preferences(app.clone(), restored.clone()).await?;
+
+    if let Err(e) = app.emit("cloud-sync-preferences-updated", &restored) {
+        log::error!("Failed to emit cloud-sync-preferences-updated event: {e}");
+    }
+    log::info!("Restored preferences from cloud sync snapshot {snapshot_id}");
+    Ok(restored)
+}
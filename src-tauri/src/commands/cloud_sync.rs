@@ -1,22 +1,36 @@
 //! Cloud sync commands for S3 and WebDAV storage.
 
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use specta::Type;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 
-use crate::cloud_sync::{keyring, s3, webdav};
+use crate::cloud_sync::merge::{three_way_merge, MergeOutcome, ModifiedAt, PreferenceConflict};
+use crate::cloud_sync::state::CloudSyncState;
+use crate::cloud_sync::{crypto, keyring, s3, webdav, PushOutcome, Remote, RemoteFile};
+use crate::commands::cloud_snapshots::record_snapshot;
 use crate::commands::preferences::{get_preferences_path, load_preferences};
 use crate::types::AppPreferences;
 use crate::AppState;
 
-/// Payload for the sync file stored remotely.
+/// Payload for the sync file stored remotely, wrapped in a
+/// [`crypto`] envelope when encryption is enabled.
 #[derive(Debug, Serialize, Deserialize, Type)]
 pub struct CloudSyncPayload {
     pub preferences: AppPreferences,
     pub server_urls: Vec<String>,
     pub synced_at: String,
+    /// When each preference was last changed, for settling merge conflicts
+    #[serde(default)]
+    pub modified_at: ModifiedAt,
 }
 
+/// Serializes pushes and pulls so their merges never interleave.
+static PREFERENCES_SYNC: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Conditional uploads tried before giving up on a remote that keeps changing.
+const MAX_PUSH_ATTEMPTS: u32 = 3;
+
 /// Save S3 credentials to keyring.
 #[tauri::command]
 #[specta::specta]
@@ -36,6 +50,21 @@ pub async fn cloud_sync_save_webdav_password(password: String) -> Result<(), Str
     keyring::save_webdav_password(&password)
 }
 
+/// Save the passphrase sync data is encrypted with to keyring.
+#[tauri::command]
+#[specta::specta]
+pub async fn cloud_sync_save_encryption_passphrase(passphrase: String) -> Result<(), String> {
+    crypto::validate_passphrase(&passphrase)?;
+    keyring::save_encryption_passphrase(&passphrase)
+}
+
+/// Check if an encryption passphrase exists in keyring.
+#[tauri::command]
+#[specta::specta]
+pub async fn cloud_sync_has_encryption_passphrase() -> Result<bool, String> {
+    Ok(keyring::get_encryption_passphrase().is_ok())
+}
+
 /// Delete all cloud sync credentials from keyring.
 #[tauri::command]
 #[specta::specta]
@@ -96,114 +125,220 @@ pub async fn cloud_sync_test_webdav_connection(
     webdav::test_connection(&url, &username, &pw).await
 }
 
-/// Push current preferences + server URLs to remote storage.
-#[tauri::command]
-#[specta::specta]
-pub async fn cloud_sync_push(app: AppHandle) -> Result<(), String> {
-    let prefs = load_preferences(app.clone()).await?;
-
-    if !prefs.cloud_sync_enabled {
-        return Err("Cloud sync is not enabled".to_string());
-    }
-
-    let server_urls = get_server_urls(&app).await;
+/// The synced fields of `prefs` as a JSON object.
+pub(crate) fn sync_map(prefs: &AppPreferences) -> Result<Map<String, Value>, String> {
+    Ok(prefs
+        .to_sync_json()?
+        .as_object()
+        .cloned()
+        .unwrap_or_default())
+}
 
-    let sync_prefs = prefs.to_sync_json()?;
-    let mut payload = serde_json::Map::new();
-    payload.insert("preferences".to_string(), sync_prefs);
+/// Serialize the sync file, encrypting it when `prefs` asks for it.
+fn encode_payload(
+    prefs: &AppPreferences,
+    preferences: Map<String, Value>,
+    modified_at: &ModifiedAt,
+    server_urls: &[String],
+) -> Result<String, String> {
+    let mut payload = Map::new();
+    payload.insert("preferences".to_string(), Value::Object(preferences));
     payload.insert(
         "server_urls".to_string(),
-        serde_json::to_value(&server_urls)
+        serde_json::to_value(server_urls)
             .map_err(|e| format!("Failed to serialize server URLs: {e}"))?,
     );
     payload.insert(
         "synced_at".to_string(),
-        serde_json::Value::String(chrono::Utc::now().to_rfc3339()),
+        Value::String(chrono::Utc::now().to_rfc3339()),
+    );
+    payload.insert(
+        "modified_at".to_string(),
+        serde_json::to_value(modified_at)
+            .map_err(|e| format!("Failed to serialize modification times: {e}"))?,
     );
 
     let json = serde_json::to_string_pretty(&payload)
         .map_err(|e| format!("Failed to serialize sync data: {e}"))?;
+    seal(json, prefs)
+}
 
-    match prefs.cloud_sync_protocol.as_str() {
-        "webdav" => {
-            let url = prefs
-                .cloud_sync_webdav_url
-                .as_deref()
-                .ok_or("WebDAV URL not configured")?;
-            let username = prefs
-                .cloud_sync_webdav_username
-                .as_deref()
-                .ok_or("WebDAV username not configured")?;
-            webdav::push(url, username, &prefs.cloud_sync_webdav_path, &json).await?;
-        }
-        _ => {
-            let endpoint = prefs
-                .cloud_sync_endpoint
-                .as_deref()
-                .ok_or("Cloud sync endpoint not configured")?;
-            let bucket = prefs
-                .cloud_sync_bucket
-                .as_deref()
-                .ok_or("Cloud sync bucket not configured")?;
-            s3::push(
-                endpoint,
-                bucket,
-                &prefs.cloud_sync_region,
-                &prefs.cloud_sync_object_key,
-                &json,
-            )
-            .await?;
+/// Parse a downloaded sync file, decrypting it if needed.
+pub(crate) fn decode_payload(
+    data: &str,
+    prefs: &AppPreferences,
+) -> Result<CloudSyncPayload, String> {
+    let json = unseal(data, prefs)?;
+    serde_json::from_str(&json).map_err(|e| format!("Failed to parse sync data: {e}"))
+}
+
+/// Encrypt a sync file's JSON when `prefs` asks for it.
+pub(crate) fn seal(json: String, prefs: &AppPreferences) -> Result<String, String> {
+    if !prefs.cloud_sync_encryption_enabled {
+        return Ok(json);
+    }
+    let passphrase = keyring::get_encryption_passphrase()
+        .map_err(|_| "Cloud sync encryption is enabled but no passphrase is set".to_string())?;
+    crypto::encrypt(&json, &passphrase)
+}
+
+/// The JSON of a downloaded sync file, decrypted if needed.
+pub(crate) fn unseal(data: &str, prefs: &AppPreferences) -> Result<String, String> {
+    if crypto::is_encrypted(data) {
+        let passphrase = keyring::get_encryption_passphrase()
+            .map_err(|_| "Sync data is encrypted; enter its passphrase to pull it".to_string())?;
+        crypto::decrypt(data, &passphrase)
+    } else if prefs.cloud_sync_encryption_enabled {
+        // Anyone who can write to the bucket could plant plain sync data
+        Err(
+            "Sync data is not encrypted; push from a device with encryption enabled first"
+                .to_string(),
+        )
+    } else {
+        Ok(data.to_string())
+    }
+}
+
+/// Three-way merge of local preferences with the remote sync file, if any.
+/// Returns the merged preferences, local-only fields kept, and the outcome.
+fn merge_remote(
+    prefs: &AppPreferences,
+    state: &CloudSyncState,
+    remote: Option<&CloudSyncPayload>,
+) -> Result<(AppPreferences, MergeOutcome), String> {
+    let local = sync_map(prefs)?;
+    let Some(remote) = remote else {
+        let outcome = MergeOutcome {
+            merged: local,
+            modified_at: state.modified_at.clone(),
+            conflicts: Vec::new(),
+        };
+        return Ok((prefs.clone(), outcome));
+    };
+
+    let outcome = three_way_merge(
+        state.base.as_ref(),
+        &local,
+        &state.modified_at,
+        &sync_map(&remote.preferences)?,
+        &remote.modified_at,
+        &chrono::Utc::now().to_rfc3339(),
+    );
+
+    let cloud: AppPreferences = serde_json::from_value(Value::Object(outcome.merged.clone()))
+        .map_err(|e| format!("Failed to apply merged preferences: {e}"))?;
+    let mut merged = prefs.clone();
+    merged.merge_from_cloud(&cloud);
+    Ok((merged, outcome))
+}
+
+/// Keep newly settled conflicts for the frontend and tell it about them.
+fn report_conflicts(app: &AppHandle, state: &mut CloudSyncState, conflicts: &[PreferenceConflict]) {
+    if conflicts.is_empty() {
+        return;
+    }
+    log::info!(
+        "Cloud sync merge settled {} conflicting preference(s)",
+        conflicts.len()
+    );
+    state.add_conflicts(conflicts);
+    if let Err(e) = app.emit("cloud-sync-conflicts", conflicts) {
+        log::error!("Failed to emit cloud-sync-conflicts event: {e}");
+    }
+}
+
+/// Merge current preferences + server URLs into the remote sync file.
+///
+/// The upload is conditional on the remote file being the version that was
+/// merged with, so a push racing another device's merges again instead of
+/// overwriting it. Remote changes the merge takes are applied locally too.
+#[tauri::command]
+#[specta::specta]
+pub async fn cloud_sync_push(app: AppHandle) -> Result<(), String> {
+    let _sync = PREFERENCES_SYNC.lock().await;
+    let mut prefs = load_preferences(app.clone()).await?;
+
+    if !prefs.cloud_sync_enabled {
+        return Err("Cloud sync is not enabled".to_string());
+    }
+
+    let server_urls = get_server_urls(&app).await;
+    let mut state = CloudSyncState::load(&app);
+
+    for attempt in 1..=MAX_PUSH_ATTEMPTS {
+        let remote = Remote::from_preferences(&prefs)?;
+        let file = remote.fetch().await?;
+        let payload = file
+            .as_ref()
+            .map(|file| decode_payload(&file.data, &prefs))
+            .transpose()?;
+        let (merged, outcome) = merge_remote(&prefs, &state, payload.as_ref())?;
+        let changes_remote = match &payload {
+            Some(payload) => sync_map(&payload.preferences)? != outcome.merged,
+            None => true,
+        };
+
+        let condition = RemoteFile::replace_condition(file.as_ref());
+        let data = encode_payload(
+            &merged,
+            outcome.merged.clone(),
+            &outcome.modified_at,
+            &server_urls,
+        )?;
+
+        match remote.store(&data, &condition).await? {
+            PushOutcome::Stored { etag } => {
+                if outcome.merged != sync_map(&prefs)? {
+                    write_preferences(&app, &merged)?;
+                    if let Err(e) = app.emit("cloud-sync-preferences-updated", &merged) {
+                        log::error!("Failed to emit cloud-sync-preferences-updated event: {e}");
+                    }
+                }
+                report_conflicts(&app, &mut state, &outcome.conflicts);
+                state.base = Some(outcome.merged);
+                state.etag = etag;
+                state.modified_at = outcome.modified_at;
+                state.save(&app)?;
+
+                // Keep a copy of every version this device uploads, so a bad
+                // push can be rolled back
+                if changes_remote {
+                    if let Err(e) = record_snapshot(&remote, &merged, &data).await {
+                        log::warn!("Failed to record cloud sync snapshot: {e}");
+                    }
+                }
+
+                save_last_synced(&app)?;
+                return Ok(());
+            }
+            PushOutcome::Stale => {
+                log::info!("Cloud sync push attempt {attempt} lost a race; merging again");
+                prefs = load_preferences(app.clone()).await?;
+            }
         }
     }
 
-    // Save last synced timestamp
-    save_last_synced(&app)?;
-    Ok(())
+    Err("Remote sync data kept changing; try again later".to_string())
 }
 
-/// Pull preferences + server URLs from remote storage.
+/// Merge preferences + server URLs from remote storage into local ones.
+///
+/// Local changes the remote file doesn't have yet are kept and pushed on the
+/// next debounced push.
 #[tauri::command]
 #[specta::specta]
 pub async fn cloud_sync_pull(app: AppHandle) -> Result<AppPreferences, String> {
+    let _sync = PREFERENCES_SYNC.lock().await;
     let prefs = load_preferences(app.clone()).await?;
 
-    let json = match prefs.cloud_sync_protocol.as_str() {
-        "webdav" => {
-            let url = prefs
-                .cloud_sync_webdav_url
-                .as_deref()
-                .ok_or("WebDAV URL not configured")?;
-            let username = prefs
-                .cloud_sync_webdav_username
-                .as_deref()
-                .ok_or("WebDAV username not configured")?;
-            webdav::pull(url, username, &prefs.cloud_sync_webdav_path).await?
-        }
-        _ => {
-            let endpoint = prefs
-                .cloud_sync_endpoint
-                .as_deref()
-                .ok_or("Cloud sync endpoint not configured")?;
-            let bucket = prefs
-                .cloud_sync_bucket
-                .as_deref()
-                .ok_or("Cloud sync bucket not configured")?;
-            s3::pull(
-                endpoint,
-                bucket,
-                &prefs.cloud_sync_region,
-                &prefs.cloud_sync_object_key,
-            )
-            .await?
-        }
-    };
-
-    let payload: CloudSyncPayload =
-        serde_json::from_str(&json).map_err(|e| format!("Failed to parse sync data: {e}"))?;
+    let file = Remote::from_preferences(&prefs)?
+        .fetch()
+        .await?
+        .ok_or("No sync data found on remote")?;
+    let payload = decode_payload(&file.data, &prefs)?;
 
-    // Merge pulled preferences, preserving local-only fields (paths, last_synced)
-    let mut merged = prefs;
-    merged.merge_from_cloud(&payload.preferences);
+    let mut state = CloudSyncState::load(&app);
+    let (mut merged, outcome) = merge_remote(&prefs, &state, Some(&payload))?;
     merged.cloud_sync_last_synced = Some(chrono::Utc::now().to_rfc3339());
 
     // Auto-download background image from synced URL if no local file exists
@@ -227,19 +362,55 @@ pub async fn cloud_sync_pull(app: AppHandle) -> Result<AppPreferences, String> {
         }
     }
 
-    // Apply merged preferences to disk
-    let prefs_path = get_preferences_path(&app)?;
-    let prefs_json = serde_json::to_string_pretty(&merged)
+    write_preferences(&app, &merged)?;
+
+    // The remote file is the new base; whatever the merge kept from local
+    // still has to be pushed
+    let remote = sync_map(&payload.preferences)?;
+    let local_changes_pending = outcome.merged != remote;
+    report_conflicts(&app, &mut state, &outcome.conflicts);
+    state.base = Some(remote);
+    state.etag = file.etag;
+    state.modified_at = outcome.modified_at;
+    state.save(&app)?;
+
+    if local_changes_pending && merged.cloud_sync_enabled {
+        let app_state: tauri::State<'_, AppState> = app.state();
+        app_state.cloud_sync_notify.notify_one();
+    }
+
+    log::info!("Cloud sync pull applied successfully");
+    Ok(merged)
+}
+
+/// Preference conflicts settled by cloud sync merges, oldest first.
+#[tauri::command]
+#[specta::specta]
+pub async fn cloud_sync_get_conflicts(app: AppHandle) -> Result<Vec<PreferenceConflict>, String> {
+    Ok(CloudSyncState::load(&app).conflicts)
+}
+
+/// Clear the recorded preference conflicts.
+#[tauri::command]
+#[specta::specta]
+pub async fn cloud_sync_clear_conflicts(app: AppHandle) -> Result<(), String> {
+    let _sync = PREFERENCES_SYNC.lock().await;
+    let mut state = CloudSyncState::load(&app);
+    state.conflicts.clear();
+    state.save(&app)
+}
+
+/// Write preferences to disk atomically.
+fn write_preferences(app: &AppHandle, prefs: &AppPreferences) -> Result<(), String> {
+    let prefs_path = get_preferences_path(app)?;
+    let prefs_json = serde_json::to_string_pretty(prefs)
         .map_err(|e| format!("Failed to serialize preferences: {e}"))?;
 
     let temp_path = prefs_path.with_extension("tmp");
     std::fs::write(&temp_path, &prefs_json)
         .map_err(|e| format!("Failed to write preferences: {e}"))?;
     std::fs::rename(&temp_path, &prefs_path)
-        .map_err(|e| format!("Failed to finalize preferences: {e}"))?;
-
-    log::info!("Cloud sync pull applied successfully");
-    Ok(merged)
+        .map_err(|e| format!("Failed to finalize preferences: {e}"))
 }
 
 /// Save the current timestamp as the last synced time in preferences.
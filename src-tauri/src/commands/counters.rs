@@ -1,35 +1,49 @@
-use crate::commands::miniflux::get_active_user_id;
+use crate::commands::miniflux::get_active_account_id;
+use crate::commands::smart_folders::count_smart_folder_unread;
 use crate::miniflux::counters::{CategoryUnread, FeedUnread, UnreadCounts};
 use crate::AppState;
+use sqlx::SqlitePool;
 use tauri::State;
 
+/// Which of `entries` are counted. Binds `all_accounts`, then the account ID:
+/// every configured account's entries, or just the active account's.
+const ENTRY_OWNER: &str =
+    "CASE WHEN ? THEN account_id IN (SELECT id FROM miniflux_connections) ELSE account_id = ? END";
+
 async fn query_unread_count_for_window(
-    pool: &sqlx::SqlitePool,
+    pool: &SqlitePool,
     window_start_utc: &str,
     window_end_utc: &str,
-    user_id: i64,
+    account_id: i64,
+    all_accounts: bool,
 ) -> Result<i64, sqlx::Error> {
-    sqlx::query_scalar(
+    sqlx::query_scalar(&format!(
         r#"
         SELECT COUNT(*)
         FROM entries
         WHERE status = 'unread'
-        AND user_id = ?
+        AND {ENTRY_OWNER}
+        AND hidden_by_rule IS NULL
         AND datetime(published_at) >= datetime(?)
         AND datetime(published_at) < datetime(?)
-        "#,
-    )
-    .bind(user_id)
+        "#
+    ))
+    .bind(all_accounts)
+    .bind(account_id)
     .bind(window_start_utc)
     .bind(window_end_utc)
     .fetch_one(pool)
     .await
 }
 
-/// Get unread counts from local database
+/// Get unread counts from local database, for the active account or with
+/// `all_accounts` summed over every configured one
 #[tauri::command]
 #[specta::specta]
-pub async fn get_unread_counts(state: State<'_, AppState>) -> Result<UnreadCounts, String> {
+pub async fn get_unread_counts(
+    state: State<'_, AppState>,
+    all_accounts: Option<bool>,
+) -> Result<UnreadCounts, String> {
     let pool = state
         .db_pool
         .lock()
@@ -38,67 +52,83 @@ pub async fn get_unread_counts(state: State<'_, AppState>) -> Result<UnreadCount
         .ok_or("Database not initialized")?
         .clone();
 
-    let user_id = get_active_user_id(&state).await?;
+    let account_id = get_active_account_id(&state).await?;
+    get_unread_counts_from_db(&pool, account_id, all_accounts.unwrap_or(false)).await
+}
 
-    let total: i64 =
-        sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE status = 'unread' AND user_id = ?")
-            .bind(user_id)
-            .fetch_one(&pool)
-            .await
-            .map_err(|e| format!("Failed to fetch total unread count: {e}"))?;
+pub async fn get_unread_counts_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    all_accounts: bool,
+) -> Result<UnreadCounts, String> {
+    let total: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM entries WHERE status = 'unread' AND {ENTRY_OWNER} AND hidden_by_rule IS NULL",
+    ))
+    .bind(all_accounts)
+    .bind(account_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch total unread count: {e}"))?;
 
     let by_category: Vec<CategoryUnread> = sqlx::query_as(
         r#"
         SELECT c.id as category_id, COUNT(e.id) as unread_count
         FROM categories c
-        LEFT JOIN feeds f ON f.category_id = c.id
-        LEFT JOIN entries e ON e.feed_id = f.id AND e.status = 'unread'
-        WHERE c.user_id = ?
+        LEFT JOIN feeds f ON f.account_id = c.account_id AND f.category_id = c.id
+        LEFT JOIN entries e ON e.account_id = f.account_id AND e.feed_id = f.id
+            AND e.status = 'unread' AND e.hidden_by_rule IS NULL
+        WHERE CASE WHEN ? THEN c.account_id IN (SELECT id FROM miniflux_connections) ELSE c.account_id = ? END
         GROUP BY c.id
         "#,
     )
-    .bind(user_id)
-    .fetch_all(&pool)
+    .bind(all_accounts)
+    .bind(account_id)
+    .fetch_all(pool)
     .await
     .map_err(|e| format!("Failed to fetch category unread counts: {e}"))?;
 
-    let by_feed: Vec<FeedUnread> = sqlx::query_as(
+    let by_feed: Vec<FeedUnread> = sqlx::query_as(&format!(
         r#"
         SELECT feed_id, COUNT(*) as unread_count
         FROM entries
-        WHERE status = 'unread' AND user_id = ?
+        WHERE status = 'unread' AND {ENTRY_OWNER} AND hidden_by_rule IS NULL
         GROUP BY feed_id
-        "#,
-    )
-    .bind(user_id)
-    .fetch_all(&pool)
+        "#
+    ))
+    .bind(all_accounts)
+    .bind(account_id)
+    .fetch_all(pool)
     .await
     .map_err(|e| format!("Failed to fetch feed unread counts: {e}"))?;
 
     let today_window_start_utc: String =
         sqlx::query_scalar("SELECT datetime('now', 'localtime', 'start of day', 'utc')")
-            .fetch_one(&pool)
+            .fetch_one(pool)
             .await
             .map_err(|e| format!("Failed to calculate today's start boundary: {e}"))?;
     let today_window_end_utc: String =
         sqlx::query_scalar("SELECT datetime('now', 'localtime', 'start of day', '+1 day', 'utc')")
-            .fetch_one(&pool)
+            .fetch_one(pool)
             .await
             .map_err(|e| format!("Failed to calculate today's end boundary: {e}"))?;
 
     let today = query_unread_count_for_window(
-        &pool,
+        pool,
         &today_window_start_utc,
         &today_window_end_utc,
-        user_id,
+        account_id,
+        all_accounts,
     )
     .await
     .map_err(|e| format!("Failed to fetch today unread count: {e}"))?;
 
+    let by_smart_folder = count_smart_folder_unread(pool, account_id, all_accounts).await?;
+
     Ok(UnreadCounts {
         total,
         by_category,
         by_feed,
+        by_smart_folder,
         today,
     })
 }
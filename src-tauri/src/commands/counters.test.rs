@@ -13,15 +13,16 @@ mod tests {
         pool
     }
 
-    async fn insert_minimum_feed_data(pool: &SqlitePool) {
+    async fn insert_minimum_feed_data(pool: &SqlitePool, account_id: i64) {
         let now = "2026-02-12T00:00:00Z";
 
         sqlx::query(
             r#"
-            INSERT INTO categories (id, user_id, title, hide_globally, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?)
+            INSERT INTO categories (account_id, id, user_id, title, hide_globally, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
             "#,
         )
+        .bind(account_id)
         .bind(1_i64)
         .bind(1_i64)
         .bind("General")
@@ -34,10 +35,11 @@ mod tests {
 
         sqlx::query(
             r#"
-            INSERT INTO feeds (id, user_id, title, site_url, feed_url, category_id, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, category_id, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
+        .bind(account_id)
         .bind(1_i64)
         .bind(1_i64)
         .bind("Feed")
@@ -51,15 +53,22 @@ mod tests {
         .expect("Failed to insert feed");
     }
 
-    async fn insert_entry(pool: &SqlitePool, id: i64, status: &str, published_at: &str) {
+    async fn insert_entry(
+        pool: &SqlitePool,
+        account_id: i64,
+        id: i64,
+        status: &str,
+        published_at: &str,
+    ) {
         sqlx::query(
             r#"
-            INSERT INTO entries (id, user_id, feed_id, title, url, hash, published_at, created_at, status)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
+        .bind(account_id)
         .bind(id)
-        .bind(1_i64)
+        .bind(account_id)
         .bind(1_i64)
         .bind(format!("Entry {id}"))
         .bind(format!("https://example.com/{id}"))
@@ -75,20 +84,21 @@ mod tests {
     #[tokio::test]
     async fn test_query_unread_count_for_window_normalizes_timezone_offsets() {
         let pool = setup_test_db().await;
-        insert_minimum_feed_data(&pool).await;
+        insert_minimum_feed_data(&pool, 1).await;
 
         // This timestamp is 2026-02-12 04:30:00 UTC and should be counted in the 12th UTC window.
-        insert_entry(&pool, 1, "unread", "2026-02-11T20:30:00-08:00").await;
+        insert_entry(&pool, 1, 1, "unread", "2026-02-11T20:30:00-08:00").await;
         // This timestamp is 2026-02-11 23:30:00 UTC and should be excluded from the 12th UTC window.
-        insert_entry(&pool, 2, "unread", "2026-02-11T15:30:00-08:00").await;
+        insert_entry(&pool, 1, 2, "unread", "2026-02-11T15:30:00-08:00").await;
         // Read entries must never be counted.
-        insert_entry(&pool, 3, "read", "2026-02-12T09:00:00Z").await;
+        insert_entry(&pool, 1, 3, "read", "2026-02-12T09:00:00Z").await;
 
         let count = super::super::query_unread_count_for_window(
             &pool,
             "2026-02-12 00:00:00",
             "2026-02-13 00:00:00",
             1,
+            false,
         )
         .await
         .expect("Query should succeed");
@@ -98,4 +108,41 @@ mod tests {
             "Only one unread entry should be inside the window"
         );
     }
+
+    #[tokio::test]
+    async fn test_unread_counts_span_accounts_on_request() {
+        let pool = setup_test_db().await;
+        for (id, user_id) in [(1_i64, 1_i64), (2, 2)] {
+            sqlx::query(
+                "INSERT INTO miniflux_connections (id, username, server_url, auth_method, is_active, miniflux_user_id, created_at, updated_at) VALUES (?, 'reader', ?, 'token', ?, ?, '', '')",
+            )
+            .bind(id)
+            .bind(format!("https://miniflux-{id}.example.com"))
+            .bind(id == 1)
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to insert account");
+        }
+
+        // Both servers hand out the same feed and entry IDs
+        for account_id in [1, 2] {
+            insert_minimum_feed_data(&pool, account_id).await;
+            insert_entry(&pool, account_id, 1, "unread", "2026-02-12T09:00:00Z").await;
+        }
+
+        let active = super::super::get_unread_counts_from_db(&pool, 1, false)
+            .await
+            .expect("Counts should load");
+        assert_eq!(active.total, 1);
+        assert_eq!(active.by_category.len(), 1);
+        assert_eq!(active.by_category[0].unread_count, 1);
+
+        let all = super::super::get_unread_counts_from_db(&pool, 1, true)
+            .await
+            .expect("Counts should load");
+        assert_eq!(all.total, 2);
+        assert_eq!(all.by_feed.len(), 1);
+        assert_eq!(all.by_feed[0].unread_count, 2);
+    }
 }
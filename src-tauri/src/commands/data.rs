@@ -6,6 +6,7 @@ use std::path::Path;
 
 use serde::Serialize;
 use specta::Type;
+use sqlx::{SqliteConnection, SqlitePool};
 use tauri::{AppHandle, Manager, State};
 
 use crate::accounts::error::AccountError;
@@ -33,6 +34,43 @@ pub struct LocalDataSize {
     pub total_bytes: u64,
 }
 
+/// Rows removed because the entry or feed they belong to no longer exists.
+#[derive(Debug, Clone, Default, Serialize, Type)]
+pub struct OrphanedRows {
+    pub enclosures: u64,
+    pub icons: u64,
+    pub summaries: u64,
+    pub tags: u64,
+    pub podcast_progress: u64,
+    /// Full-text search rows
+    pub search_index: u64,
+}
+
+/// Result of [`run_database_maintenance`].
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct DatabaseMaintenanceReport {
+    /// Whether `PRAGMA integrity_check` passed after any repair
+    pub integrity_ok: bool,
+    /// Problems reported by the first integrity check
+    pub integrity_errors: Vec<String>,
+    /// Whether a REINDEX was run to repair a failed integrity check
+    pub reindexed: bool,
+    /// `PRAGMA foreign_key_check` rows left after orphan cleanup, as `table(rowid) -> parent`
+    pub foreign_key_violations: Vec<String>,
+    pub orphans_removed: OrphanedRows,
+    pub expired_translations_removed: u64,
+    /// Whether the database was vacuumed (skipped when integrity still fails)
+    pub vacuumed: bool,
+    /// Database plus WAL size before maintenance
+    pub bytes_before: u64,
+    /// Database plus WAL size after maintenance
+    pub bytes_after: u64,
+    pub bytes_reclaimed: u64,
+}
+
+/// Translations cached longer than this are dropped by maintenance.
+const TRANSLATION_CACHE_TTL_SECS: i64 = 30 * 24 * 60 * 60;
+
 fn get_path_size(path: &Path) -> u64 {
     if !path.exists() {
         return 0;
@@ -122,7 +160,7 @@ pub async fn get_local_data_size(app_handle: AppHandle) -> Result<LocalDataSize,
 /// Clears synced data for the current active account only.
 ///
 /// Removes entries, feeds, categories, enclosures, and sync state for the
-/// active user. Other accounts' data, preferences, and downloads are preserved.
+/// active account. Other accounts' data, preferences, and downloads are preserved.
 #[tauri::command]
 #[specta::specta]
 pub async fn clear_local_data(
@@ -151,62 +189,39 @@ pub async fn clear_local_data(
         return Err("No active account found".to_string());
     };
 
-    // Resolve the Miniflux user_id for this account
-    let user_id: Option<i64> = {
-        let guard = state.miniflux.user_id.lock().await;
-        *guard
-    };
-
-    // If we don't have a cached user_id, try to find it from existing entries
-    let user_id = match user_id {
-        Some(id) => id,
-        None => {
-            let maybe: Option<i64> =
-                sqlx::query_scalar("SELECT DISTINCT user_id FROM entries LIMIT 1")
-                    .fetch_optional(&pool)
-                    .await
-                    .map_err(|e| format!("Failed to determine user_id: {e}"))?;
-
-            maybe.ok_or("Cannot determine user_id for cleanup")?
-        }
-    };
-
     log::info!(
-        "Clearing data for account {} (user_id={}, username={})",
+        "Clearing data for account {} (username={})",
         account_id,
-        user_id,
         username
     );
 
     // Delete in dependency order: enclosures → entries → feeds → categories → sync_state
-    sqlx::query(
-        "DELETE FROM enclosures WHERE entry_id IN (SELECT id FROM entries WHERE user_id = ?)",
-    )
-    .bind(user_id)
-    .execute(&pool)
-    .await
-    .map_err(|e| format!("Failed to delete enclosures: {e}"))?;
+    sqlx::query("DELETE FROM enclosures WHERE account_id = ?")
+        .bind(account_id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to delete enclosures: {e}"))?;
 
-    sqlx::query("DELETE FROM entries WHERE user_id = ?")
-        .bind(user_id)
+    sqlx::query("DELETE FROM entries WHERE account_id = ?")
+        .bind(account_id)
         .execute(&pool)
         .await
         .map_err(|e| format!("Failed to delete entries: {e}"))?;
 
-    sqlx::query("DELETE FROM icons WHERE feed_id IN (SELECT id FROM feeds WHERE user_id = ?)")
-        .bind(user_id)
+    sqlx::query("DELETE FROM icons WHERE account_id = ?")
+        .bind(account_id)
         .execute(&pool)
         .await
         .map_err(|e| format!("Failed to delete icons: {e}"))?;
 
-    sqlx::query("DELETE FROM feeds WHERE user_id = ?")
-        .bind(user_id)
+    sqlx::query("DELETE FROM feeds WHERE account_id = ?")
+        .bind(account_id)
         .execute(&pool)
         .await
         .map_err(|e| format!("Failed to delete feeds: {e}"))?;
 
-    sqlx::query("DELETE FROM categories WHERE user_id = ?")
-        .bind(user_id)
+    sqlx::query("DELETE FROM categories WHERE account_id = ?")
+        .bind(account_id)
         .execute(&pool)
         .await
         .map_err(|e| format!("Failed to delete categories: {e}"))?;
@@ -236,6 +251,158 @@ pub async fn clear_local_data(
     Ok(())
 }
 
+/// Checks, cleans up and compacts the local database.
+///
+/// Runs the integrity and foreign key checks, removes orphaned rows and
+/// expired translations, then checkpoints the WAL and vacuums.
+#[tauri::command]
+#[specta::specta]
+pub async fn run_database_maintenance(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<DatabaseMaintenanceReport, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let db_path = get_db_path(&app_handle)?;
+
+    log::info!("Running database maintenance on {:?}", db_path);
+    let report = maintain_database(&pool, &db_path)
+        .await
+        .map_err(|e| format!("Database maintenance failed: {e}"))?;
+    log::info!(
+        "Database maintenance finished: integrity_ok={}, reclaimed {} bytes",
+        report.integrity_ok,
+        report.bytes_reclaimed
+    );
+
+    Ok(report)
+}
+
+fn database_size(db_path: &Path) -> u64 {
+    get_path_size(db_path) + get_path_size(&db_path.with_extension("db-wal"))
+}
+
+async fn integrity_errors(pool: &SqlitePool) -> Result<Vec<String>, sqlx::Error> {
+    let rows: Vec<String> = sqlx::query_scalar("PRAGMA integrity_check")
+        .fetch_all(pool)
+        .await?;
+    Ok(rows.into_iter().filter(|row| row != "ok").collect())
+}
+
+async fn delete_rows(conn: &mut SqliteConnection, sql: &str) -> Result<u64, sqlx::Error> {
+    Ok(sqlx::query(sql).execute(conn).await?.rows_affected())
+}
+
+async fn delete_orphans(pool: &SqlitePool) -> Result<OrphanedRows, sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+
+    let orphans = OrphanedRows {
+        enclosures: delete_rows(
+            &mut transaction,
+            "DELETE FROM enclosures WHERE (account_id, entry_id) NOT IN (SELECT account_id, id FROM entries)",
+        )
+        .await?,
+        icons: delete_rows(
+            &mut transaction,
+            "DELETE FROM icons WHERE (account_id, feed_id) NOT IN (SELECT account_id, id FROM feeds)",
+        )
+        .await?,
+        // Summaries are keyed by the entry id as text
+        summaries: delete_rows(
+            &mut transaction,
+            "DELETE FROM article_summaries WHERE (account_id, entry_id) NOT IN (SELECT account_id, CAST(id AS TEXT) FROM entries)",
+        )
+        .await?,
+        tags: delete_rows(
+            &mut transaction,
+            "DELETE FROM tags WHERE (account_id, entry_id) NOT IN (SELECT account_id, id FROM entries)",
+        )
+        .await?,
+        podcast_progress: delete_rows(
+            &mut transaction,
+            "DELETE FROM podcast_progress WHERE (account_id, entry_id) NOT IN (SELECT account_id, id FROM entries)",
+        )
+        .await?,
+        search_index: delete_rows(
+            &mut transaction,
+            "DELETE FROM entries_fts WHERE rowid NOT IN (SELECT local_id FROM entries)",
+        )
+        .await?,
+    };
+
+    transaction.commit().await?;
+    Ok(orphans)
+}
+
+pub(crate) async fn maintain_database(
+    pool: &SqlitePool,
+    db_path: &Path,
+) -> Result<DatabaseMaintenanceReport, sqlx::Error> {
+    let bytes_before = database_size(db_path);
+
+    let errors = integrity_errors(pool).await?;
+    let mut reindexed = false;
+    let mut integrity_ok = errors.is_empty();
+    if !integrity_ok {
+        log::warn!("Integrity check failed, reindexing: {:?}", errors);
+        // Most corruption seen in the wild is in indexes, which REINDEX rebuilds
+        sqlx::query("REINDEX").execute(pool).await?;
+        reindexed = true;
+        integrity_ok = integrity_errors(pool).await?.is_empty();
+    }
+
+    let orphans_removed = delete_orphans(pool).await?;
+
+    let foreign_key_violations: Vec<(String, Option<i64>, String)> =
+        sqlx::query_as("PRAGMA foreign_key_check")
+            .fetch_all(pool)
+            .await?;
+    let foreign_key_violations = foreign_key_violations
+        .into_iter()
+        .map(|(table, rowid, parent)| match rowid {
+            Some(rowid) => format!("{table}({rowid}) -> {parent}"),
+            None => format!("{table} -> {parent}"),
+        })
+        .collect();
+
+    let expired_before = chrono::Utc::now().timestamp() - TRANSLATION_CACHE_TTL_SECS;
+    let expired_translations_removed =
+        sqlx::query("DELETE FROM translation_cache WHERE cached_at < ?")
+            .bind(expired_before)
+            .execute(pool)
+            .await?
+            .rows_affected();
+
+    // VACUUM rewrites every page, which would spread corruption rather than fix it
+    let vacuumed = integrity_ok;
+    if vacuumed {
+        sqlx::query("VACUUM").execute(pool).await?;
+    }
+    sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+        .execute(pool)
+        .await?;
+
+    let bytes_after = database_size(db_path);
+
+    Ok(DatabaseMaintenanceReport {
+        integrity_ok,
+        integrity_errors: errors,
+        reindexed,
+        foreign_key_violations,
+        orphans_removed,
+        expired_translations_removed,
+        vacuumed,
+        bytes_before,
+        bytes_after,
+        bytes_reclaimed: bytes_before.saturating_sub(bytes_after),
+    })
+}
+
 /// Factory reset: deletes all local data and re-initializes an empty database.
 ///
 /// This is the nuclear option — removes the database, preferences, downloads,
@@ -347,4 +514,90 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&root);
     }
+
+    #[tokio::test]
+    async fn maintenance_removes_orphans_and_expired_translations() {
+        let suffix = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time ok")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("minikyu-maintenance-{suffix}"));
+        std::fs::create_dir_all(&root).expect("create temp dir");
+        let db_path = root.join("minikyu.db");
+
+        // A single connection so the foreign_keys pragma below sticks
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect(&format!("sqlite://{}?mode=rwc", db_path.display()))
+            .await
+            .expect("open db");
+        sqlx::query("PRAGMA journal_mode = WAL")
+            .execute(&pool)
+            .await
+            .unwrap();
+        crate::database::migrations::run_migrations(&pool)
+            .await
+            .expect("migrate");
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        sqlx::query(
+            "INSERT INTO enclosures (account_id, id, entry_id, url, mime_type, created_at) VALUES (1, 7, 42, 'https://example.com/a.mp3', 'audio/mpeg', '2024-01-01T00:00:00Z')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO tags (account_id, entry_id, tag, created_at) VALUES (1, 42, 'later', '2024-01-01T00:00:00Z')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO article_summaries (account_id, entry_id, summary, created_at, updated_at) VALUES (1, '42', 'gone', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let now = chrono::Utc::now().timestamp();
+        for (key, cached_at) in [
+            ("old", now - TRANSLATION_CACHE_TTL_SECS - 60),
+            ("fresh", now),
+        ] {
+            sqlx::query(
+                "INSERT INTO translation_cache (cache_key, translated_text, provider_used, cached_at) VALUES (?, 'text', 'test', ?)",
+            )
+            .bind(key)
+            .bind(cached_at)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let report = maintain_database(&pool, &db_path)
+            .await
+            .expect("maintenance");
+
+        assert!(report.integrity_ok);
+        assert!(!report.reindexed);
+        assert!(report.vacuumed);
+        assert!(report.foreign_key_violations.is_empty());
+        assert_eq!(report.orphans_removed.enclosures, 1);
+        assert_eq!(report.orphans_removed.tags, 1);
+        assert_eq!(report.orphans_removed.summaries, 1);
+        assert_eq!(report.expired_translations_removed, 1);
+        assert_eq!(
+            report.bytes_reclaimed,
+            report.bytes_before.saturating_sub(report.bytes_after)
+        );
+
+        let remaining: Vec<String> = sqlx::query_scalar("SELECT cache_key FROM translation_cache")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, vec!["fresh".to_string()]);
+
+        pool.close().await;
+        let _ = std::fs::remove_dir_all(&root);
+    }
 }
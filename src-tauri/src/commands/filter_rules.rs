@@ -0,0 +1,811 @@
+//! Client-side filter rules.
+//!
+//! Miniflux's `blocklist_rules`/`keeplist_rules` are per-feed regexes that run
+//! on the server. These rules run locally on entries first pulled during a
+//! sync, can look at any feed or category, and apply local actions. Read and
+//! star actions go through the sync queue so they reach Miniflux too.
+
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, BTreeSet};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::{QueryBuilder, Row, SqlitePool};
+use tauri::{AppHandle, Runtime, State};
+
+use crate::commands::miniflux::{
+    get_active_account_id, get_all_entries_page_from_db, mark_entries_read_in_db,
+};
+use crate::commands::sync::{queue_entry_starred_change, queue_entry_status_change};
+use crate::commands::tags::{add_tags_to_entries, normalize_tag};
+use crate::database::fts::strip_html;
+use crate::miniflux::Entry;
+use crate::utils::serde_helpers::{deserialize_i64_from_string_or_number, serialize_i64_as_string};
+use crate::AppState;
+
+const DEFAULT_DRY_RUN_SAMPLE: u32 = 100;
+const DRY_RUN_PAGE_SIZE: i64 = 500;
+
+/// Entry attribute a condition looks at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleField {
+    Title,
+    Author,
+    /// Entry content with HTML tags stripped
+    Content,
+    Url,
+    /// Feed title
+    Feed,
+    /// Category title
+    Category,
+    /// Estimated reading time in minutes
+    ReadingTime,
+}
+
+/// How a condition compares its value.
+///
+/// `AtLeast`/`AtMost` compare the character length of text fields and the
+/// number of minutes for `ReadingTime`. Text comparisons ignore case; regexes
+/// are used as written (prefix with `(?i)` for case-insensitive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleOperator {
+    Contains,
+    Equals,
+    Regex,
+    AtLeast,
+    AtMost,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct RuleCondition {
+    pub field: RuleField,
+    pub operator: RuleOperator,
+    pub value: String,
+    /// Inverts the result of the comparison
+    #[serde(default)]
+    pub negate: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleAction {
+    MarkRead,
+    Star,
+    Tag {
+        tag: String,
+    },
+    /// Keep the entry out of entry lists and unread counts
+    Hide,
+    /// Show a native notification
+    Notify,
+}
+
+/// Rule fields as sent by the frontend for create, update and dry run
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct FilterRuleInput {
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Match when any condition holds instead of all of them
+    #[serde(default)]
+    pub match_any: bool,
+    pub conditions: Vec<RuleCondition>,
+    pub actions: Vec<RuleAction>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct FilterRule {
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub id: i64,
+    pub name: String,
+    pub enabled: bool,
+    pub match_any: bool,
+    pub conditions: Vec<RuleCondition>,
+    pub actions: Vec<RuleAction>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// A cached entry matched by a dry run
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct FilterRuleMatch {
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub entry_id: i64,
+    pub title: String,
+    pub feed_title: String,
+    pub published_at: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct FilterRuleDryRun {
+    pub scanned: u32,
+    pub matched: u32,
+    /// First matches, newest first, up to the requested sample size
+    pub entries: Vec<FilterRuleMatch>,
+}
+
+/// Entries a notify rule matched during one sync batch
+#[derive(Debug, Clone)]
+pub struct RuleNotification {
+    pub rule_name: String,
+    pub entry_titles: Vec<String>,
+}
+
+/// What applying rules to a batch of entries did
+#[derive(Debug, Clone, Default)]
+pub struct RuleRunOutcome {
+    pub matched_entries: u32,
+    /// Whether read or star changes were queued for Miniflux
+    pub queued_changes: bool,
+    pub notifications: Vec<RuleNotification>,
+}
+
+struct CompiledCondition {
+    condition: RuleCondition,
+    value_lower: String,
+    regex: Option<Regex>,
+    threshold: Option<i64>,
+}
+
+/// A rule with its regexes and thresholds parsed once per batch
+pub struct CompiledFilterRule {
+    id: i64,
+    name: String,
+    match_any: bool,
+    conditions: Vec<CompiledCondition>,
+    actions: Vec<RuleAction>,
+}
+
+fn compile_conditions(conditions: &[RuleCondition]) -> Result<Vec<CompiledCondition>, String> {
+    conditions
+        .iter()
+        .map(|condition| {
+            let regex = match condition.operator {
+                RuleOperator::Regex => Some(
+                    Regex::new(&condition.value)
+                        .map_err(|e| format!("Invalid regex \"{}\": {e}", condition.value))?,
+                ),
+                _ => None,
+            };
+            let threshold = match condition.operator {
+                RuleOperator::AtLeast | RuleOperator::AtMost => Some(
+                    condition
+                        .value
+                        .trim()
+                        .parse::<i64>()
+                        .map_err(|e| format!("Invalid number \"{}\": {e}", condition.value))?,
+                ),
+                _ => None,
+            };
+
+            Ok(CompiledCondition {
+                condition: condition.clone(),
+                value_lower: condition.value.to_lowercase(),
+                regex,
+                threshold,
+            })
+        })
+        .collect()
+}
+
+/// Checks and normalizes rule input before it is saved or dry-run.
+fn validate_rule_input(input: FilterRuleInput) -> Result<FilterRuleInput, String> {
+    let name = input.name.trim().to_string();
+    if name.is_empty() {
+        return Err("Rule name cannot be empty".to_string());
+    }
+    if input.conditions.is_empty() {
+        return Err("Rule needs at least one condition".to_string());
+    }
+    compile_conditions(&input.conditions)?;
+
+    let actions = input
+        .actions
+        .into_iter()
+        .map(|action| match action {
+            RuleAction::Tag { tag } => normalize_tag(&tag).map(|tag| RuleAction::Tag { tag }),
+            action => Ok(action),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(FilterRuleInput {
+        name,
+        actions,
+        ..input
+    })
+}
+
+impl CompiledFilterRule {
+    pub fn compile(rule: &FilterRule) -> Result<Self, String> {
+        Ok(Self {
+            id: rule.id,
+            name: rule.name.clone(),
+            match_any: rule.match_any,
+            conditions: compile_conditions(&rule.conditions)?,
+            actions: rule.actions.clone(),
+        })
+    }
+
+    /// `content_text` caches the stripped content across rules for one entry.
+    pub fn matches(&self, entry: &Entry, content_text: &OnceCell<String>) -> bool {
+        let mut results = self
+            .conditions
+            .iter()
+            .map(|condition| condition_matches(condition, entry, content_text));
+
+        if self.match_any {
+            results.any(|matched| matched)
+        } else {
+            results.all(|matched| matched)
+        }
+    }
+}
+
+fn condition_matches(
+    compiled: &CompiledCondition,
+    entry: &Entry,
+    content_text: &OnceCell<String>,
+) -> bool {
+    let condition = &compiled.condition;
+
+    let matched = if condition.field == RuleField::ReadingTime {
+        let minutes = i64::from(entry.reading_time.unwrap_or(0));
+        match condition.operator {
+            RuleOperator::AtLeast => compiled.threshold.is_some_and(|n| minutes >= n),
+            RuleOperator::AtMost => compiled.threshold.is_some_and(|n| minutes <= n),
+            _ => compare_text(compiled, &minutes.to_string()),
+        }
+    } else {
+        let text = match condition.field {
+            RuleField::Title => entry.title.as_str(),
+            RuleField::Author => entry.author.as_deref().unwrap_or_default(),
+            RuleField::Content => content_text
+                .get_or_init(|| strip_html(entry.content.as_deref().unwrap_or_default())),
+            RuleField::Url => entry.url.as_str(),
+            RuleField::Feed => entry.feed.title.as_str(),
+            RuleField::Category => entry
+                .feed
+                .category
+                .as_ref()
+                .map_or("", |category| category.title.as_str()),
+            RuleField::ReadingTime => unreachable!("reading time is handled above"),
+        };
+        compare_text(compiled, text)
+    };
+
+    matched != condition.negate
+}
+
+fn compare_text(compiled: &CompiledCondition, text: &str) -> bool {
+    match compiled.condition.operator {
+        RuleOperator::Contains => text.to_lowercase().contains(&compiled.value_lower),
+        RuleOperator::Equals => text.trim().to_lowercase() == compiled.value_lower.trim(),
+        RuleOperator::Regex => compiled
+            .regex
+            .as_ref()
+            .is_some_and(|regex| regex.is_match(text)),
+        RuleOperator::AtLeast => compiled
+            .threshold
+            .is_some_and(|n| text.chars().count() as i64 >= n),
+        RuleOperator::AtMost => compiled
+            .threshold
+            .is_some_and(|n| text.chars().count() as i64 <= n),
+    }
+}
+
+fn build_filter_rule_from_row(row: &sqlx::sqlite::SqliteRow) -> Result<FilterRule, String> {
+    let id: i64 = row.get("id");
+    let conditions: String = row.get("conditions");
+    let actions: String = row.get("actions");
+
+    Ok(FilterRule {
+        id,
+        name: row.get("name"),
+        enabled: row.get("enabled"),
+        match_any: row.get("match_any"),
+        conditions: serde_json::from_str(&conditions)
+            .map_err(|e| format!("Invalid conditions for rule {id}: {e}"))?,
+        actions: serde_json::from_str(&actions)
+            .map_err(|e| format!("Invalid actions for rule {id}: {e}"))?,
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub async fn list_filter_rules_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+) -> Result<Vec<FilterRule>, String> {
+    let rows = sqlx::query(
+        "SELECT id, name, enabled, match_any, conditions, actions, created_at, updated_at FROM filter_rules WHERE account_id = ? ORDER BY id",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch filter rules: {e}"))?;
+
+    rows.iter().map(build_filter_rule_from_row).collect()
+}
+
+/// Enabled rules ready to run. Rules that no longer compile are skipped.
+pub async fn load_enabled_filter_rules(
+    pool: &SqlitePool,
+    account_id: i64,
+) -> Result<Vec<CompiledFilterRule>, String> {
+    let rules = list_filter_rules_from_db(pool, account_id).await?;
+
+    Ok(rules
+        .iter()
+        .filter(|rule| rule.enabled)
+        .filter_map(|rule| match CompiledFilterRule::compile(rule) {
+            Ok(compiled) => Some(compiled),
+            Err(e) => {
+                log::warn!("Skipping filter rule \"{}\": {}", rule.name, e);
+                None
+            }
+        })
+        .collect())
+}
+
+pub async fn create_filter_rule_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    input: FilterRuleInput,
+) -> Result<FilterRule, String> {
+    let input = validate_rule_input(input)?;
+    let conditions = serde_json::to_string(&input.conditions)
+        .map_err(|e| format!("Failed to serialize conditions: {e}"))?;
+    let actions = serde_json::to_string(&input.actions)
+        .map_err(|e| format!("Failed to serialize actions: {e}"))?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let id: i64 = sqlx::query_scalar(
+        "INSERT INTO filter_rules (account_id, name, enabled, match_any, conditions, actions, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?) RETURNING id",
+    )
+    .bind(account_id)
+    .bind(&input.name)
+    .bind(input.enabled)
+    .bind(input.match_any)
+    .bind(conditions)
+    .bind(actions)
+    .bind(&now)
+    .bind(&now)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("Failed to save filter rule: {e}"))?;
+
+    Ok(FilterRule {
+        id,
+        name: input.name,
+        enabled: input.enabled,
+        match_any: input.match_any,
+        conditions: input.conditions,
+        actions: input.actions,
+        created_at: now.clone(),
+        updated_at: now,
+    })
+}
+
+pub async fn update_filter_rule_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    id: i64,
+    input: FilterRuleInput,
+) -> Result<FilterRule, String> {
+    let input = validate_rule_input(input)?;
+    let conditions = serde_json::to_string(&input.conditions)
+        .map_err(|e| format!("Failed to serialize conditions: {e}"))?;
+    let actions = serde_json::to_string(&input.actions)
+        .map_err(|e| format!("Failed to serialize actions: {e}"))?;
+
+    let row = sqlx::query(
+        "UPDATE filter_rules SET name = ?, enabled = ?, match_any = ?, conditions = ?, actions = ?, updated_at = ? WHERE id = ? AND account_id = ? RETURNING id, name, enabled, match_any, conditions, actions, created_at, updated_at",
+    )
+    .bind(&input.name)
+    .bind(input.enabled)
+    .bind(input.match_any)
+    .bind(conditions)
+    .bind(actions)
+    .bind(chrono::Utc::now().to_rfc3339())
+    .bind(id)
+    .bind(account_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to update filter rule: {e}"))?
+    .ok_or_else(|| format!("Filter rule with id {id} not found"))?;
+
+    build_filter_rule_from_row(&row)
+}
+
+/// Deletes a rule and shows the entries it had hidden again.
+pub async fn delete_filter_rule_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    id: i64,
+) -> Result<(), String> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {e}"))?;
+
+    let result = sqlx::query("DELETE FROM filter_rules WHERE id = ? AND account_id = ?")
+        .bind(id)
+        .bind(account_id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| format!("Failed to delete filter rule: {e}"))?;
+
+    if result.rows_affected() == 0 {
+        return Err(format!("Filter rule with id {id} not found"));
+    }
+
+    sqlx::query("UPDATE entries SET hidden_by_rule = NULL WHERE hidden_by_rule = ?")
+        .bind(id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| format!("Failed to unhide entries: {e}"))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit filter rule deletion: {e}"))
+}
+
+/// Applies `rules` to `entries` of `account_id` and performs their actions in
+/// the local database. Entries are expected to be upserted already.
+pub async fn apply_filter_rules(
+    pool: &SqlitePool,
+    account_id: i64,
+    rules: &[CompiledFilterRule],
+    entries: &[Entry],
+) -> Result<RuleRunOutcome, String> {
+    let mut outcome = RuleRunOutcome::default();
+    if rules.is_empty() || entries.is_empty() {
+        return Ok(outcome);
+    }
+
+    let content_cache: Vec<OnceCell<String>> = entries.iter().map(|_| OnceCell::new()).collect();
+    let mut matched_ids = BTreeSet::new();
+    let mut read_ids = BTreeSet::new();
+    let mut star_ids = BTreeSet::new();
+    let mut tagged: BTreeMap<String, Vec<i64>> = BTreeMap::new();
+    let mut hidden: BTreeMap<i64, i64> = BTreeMap::new();
+
+    for rule in rules {
+        let matches: Vec<&Entry> = entries
+            .iter()
+            .zip(&content_cache)
+            .filter(|(entry, content_text)| rule.matches(entry, content_text))
+            .map(|(entry, _)| entry)
+            .collect();
+        if matches.is_empty() {
+            continue;
+        }
+
+        for action in &rule.actions {
+            match action {
+                RuleAction::MarkRead => read_ids.extend(
+                    matches
+                        .iter()
+                        .filter(|entry| entry.status == "unread")
+                        .map(|entry| entry.id),
+                ),
+                RuleAction::Star => star_ids.extend(
+                    matches
+                        .iter()
+                        .filter(|entry| !entry.starred)
+                        .map(|entry| entry.id),
+                ),
+                RuleAction::Tag { tag } => {
+                    for entry in &matches {
+                        tagged.entry(tag.clone()).or_default().push(entry.id);
+                    }
+                }
+                RuleAction::Hide => {
+                    // The first rule to hide an entry owns it
+                    for entry in &matches {
+                        hidden.entry(entry.id).or_insert(rule.id);
+                    }
+                }
+                RuleAction::Notify => outcome.notifications.push(RuleNotification {
+                    rule_name: rule.name.clone(),
+                    entry_titles: matches.iter().map(|entry| entry.title.clone()).collect(),
+                }),
+            }
+        }
+
+        matched_ids.extend(matches.iter().map(|entry| entry.id));
+    }
+
+    outcome.matched_entries = matched_ids.len() as u32;
+
+    if !read_ids.is_empty() {
+        let read_ids: Vec<i64> = read_ids.into_iter().collect();
+        mark_entries_read_in_db(pool, account_id, &read_ids).await?;
+        queue_entry_status_change(pool, account_id, &read_ids, "read").await?;
+        outcome.queued_changes = true;
+    }
+
+    for entry_id in star_ids {
+        sqlx::query("UPDATE entries SET starred = 1 WHERE account_id = ? AND id = ?")
+            .bind(account_id)
+            .bind(entry_id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("Failed to star entry: {e}"))?;
+        queue_entry_starred_change(pool, account_id, entry_id, true).await?;
+        outcome.queued_changes = true;
+    }
+
+    for (tag, entry_ids) in tagged {
+        add_tags_to_entries(pool, account_id, &entry_ids, &[tag]).await?;
+    }
+
+    for (entry_id, rule_id) in hidden {
+        sqlx::query(
+            "UPDATE entries SET hidden_by_rule = ? WHERE account_id = ? AND id = ? AND hidden_by_rule IS NULL",
+        )
+        .bind(rule_id)
+        .bind(account_id)
+        .bind(entry_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to hide entry: {e}"))?;
+    }
+
+    Ok(outcome)
+}
+
+/// Shows one native notification per notify rule.
+pub async fn send_rule_notifications<R: Runtime>(
+    app: &AppHandle<R>,
+    notifications: Vec<RuleNotification>,
+) {
+    for notification in notifications {
+        let body = match notification.entry_titles.as_slice() {
+            [title] => title.clone(),
+            titles => format!("{} new entries matched", titles.len()),
+        };
+
+        if let Err(e) = crate::commands::notifications::show_notification(
+            app,
+            notification.rule_name,
+            Some(body),
+        ) {
+            log::warn!("Failed to send filter rule notification: {e}");
+        }
+    }
+}
+
+/// Evaluates a rule against the account's cached entries without applying it.
+pub async fn dry_run_filter_rule_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    input: FilterRuleInput,
+    sample_size: u32,
+) -> Result<FilterRuleDryRun, String> {
+    let input = validate_rule_input(input)?;
+    let rule = CompiledFilterRule {
+        id: 0,
+        name: input.name,
+        match_any: input.match_any,
+        conditions: compile_conditions(&input.conditions)?,
+        actions: input.actions,
+    };
+
+    let mut dry_run = FilterRuleDryRun {
+        scanned: 0,
+        matched: 0,
+        entries: Vec::new(),
+    };
+    let mut offset = 0;
+
+    loop {
+        // Entries other rules hid are scanned too, so the count is the rule's own
+        let entries =
+            get_all_entries_page_from_db(pool, account_id, DRY_RUN_PAGE_SIZE, offset).await?;
+        if entries.is_empty() {
+            break;
+        }
+
+        for entry in &entries {
+            dry_run.scanned += 1;
+            if !rule.matches(entry, &OnceCell::new()) {
+                continue;
+            }
+
+            dry_run.matched += 1;
+            if dry_run.entries.len() < sample_size as usize {
+                dry_run.entries.push(FilterRuleMatch {
+                    entry_id: entry.id,
+                    title: entry.title.clone(),
+                    feed_title: entry.feed.title.clone(),
+                    published_at: entry.published_at.clone(),
+                    status: entry.status.clone(),
+                });
+            }
+        }
+
+        if (entries.len() as i64) < DRY_RUN_PAGE_SIZE {
+            break;
+        }
+        offset += DRY_RUN_PAGE_SIZE;
+    }
+
+    Ok(dry_run)
+}
+
+/// Shows previously hidden entries of the account again, for one rule or all
+/// rules.
+async fn unhide_entries_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    rule_id: Option<i64>,
+) -> Result<u64, String> {
+    let mut query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        "UPDATE entries SET hidden_by_rule = NULL WHERE hidden_by_rule IS NOT NULL AND account_id = ",
+    );
+    query.push_bind(account_id);
+    if let Some(rule_id) = rule_id {
+        query.push(" AND hidden_by_rule = ");
+        query.push_bind(rule_id);
+    }
+
+    query
+        .build()
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected())
+        .map_err(|e| format!("Failed to unhide entries: {e}"))
+}
+
+/// List the active account's filter rules
+#[tauri::command]
+#[specta::specta]
+pub async fn list_filter_rules(state: State<'_, AppState>) -> Result<Vec<FilterRule>, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    list_filter_rules_from_db(&pool, account_id).await
+}
+
+/// Create a filter rule. It applies to entries pulled by later syncs.
+#[tauri::command]
+#[specta::specta]
+pub async fn create_filter_rule(
+    state: State<'_, AppState>,
+    rule: FilterRuleInput,
+) -> Result<FilterRule, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    create_filter_rule_in_db(&pool, account_id, rule).await
+}
+
+/// Replace a filter rule's settings
+#[tauri::command]
+#[specta::specta]
+pub async fn update_filter_rule(
+    state: State<'_, AppState>,
+    id: String,
+    rule: FilterRuleInput,
+) -> Result<FilterRule, String> {
+    let id = id
+        .parse::<i64>()
+        .map_err(|e| format!("Invalid filter rule ID: {}", e))?;
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    update_filter_rule_in_db(&pool, account_id, id, rule).await
+}
+
+/// Delete a filter rule and unhide the entries it hid
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_filter_rule(state: State<'_, AppState>, id: String) -> Result<(), String> {
+    let id = id
+        .parse::<i64>()
+        .map_err(|e| format!("Invalid filter rule ID: {}", e))?;
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    delete_filter_rule_from_db(&pool, account_id, id).await
+}
+
+/// Report which cached entries a rule would match, without applying it
+#[tauri::command]
+#[specta::specta]
+pub async fn dry_run_filter_rule(
+    state: State<'_, AppState>,
+    rule: FilterRuleInput,
+    limit: Option<u32>,
+) -> Result<FilterRuleDryRun, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    dry_run_filter_rule_in_db(
+        &pool,
+        account_id,
+        rule,
+        limit.unwrap_or(DEFAULT_DRY_RUN_SAMPLE),
+    )
+    .await
+}
+
+/// Show entries hidden by filter rules again; all of them if `rule_id` is omitted
+#[tauri::command]
+#[specta::specta]
+pub async fn unhide_filtered_entries(
+    state: State<'_, AppState>,
+    rule_id: Option<String>,
+) -> Result<u32, String> {
+    let rule_id = rule_id
+        .map(|id| {
+            id.parse::<i64>()
+                .map_err(|e| format!("Invalid filter rule ID: {}", e))
+        })
+        .transpose()?;
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    let unhidden = unhide_entries_in_db(&pool, account_id, rule_id).await?;
+    Ok(unhidden as u32)
+}
+
+#[cfg(test)]
+#[path = "filter_rules.test.rs"]
+mod tests;
@@ -0,0 +1,345 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        apply_filter_rules, create_filter_rule_in_db, delete_filter_rule_from_db,
+        dry_run_filter_rule_in_db, load_enabled_filter_rules, FilterRuleInput, RuleAction,
+        RuleCondition, RuleField, RuleOperator,
+    };
+    use crate::commands::miniflux::get_entries_from_db;
+    use crate::database::migrations::run_migrations;
+    use crate::miniflux::{Entry, EntryFilters};
+    use sqlx::SqlitePool;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+        pool
+    }
+
+    async fn seed(pool: &SqlitePool) {
+        let now = "2026-02-12T00:00:00Z";
+
+        sqlx::query(
+            "INSERT INTO categories (account_id, id, user_id, title, hide_globally, created_at, updated_at) VALUES (1, 1, 1, 'Programming', false, ?, ?)",
+        )
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .expect("Failed to insert category");
+
+        for (id, title) in [(1, "Rust Blog"), (2, "Hacker News")] {
+            sqlx::query(
+                r#"
+                INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, category_id, created_at, updated_at)
+                VALUES (1, ?, 1, ?, 'https://example.com', ?, 1, ?, ?)
+                "#,
+            )
+            .bind(id)
+            .bind(title)
+            .bind(format!("https://example.com/{id}.xml"))
+            .bind(now)
+            .bind(now)
+            .execute(pool)
+            .await
+            .expect("Failed to insert feed");
+        }
+
+        for (id, feed_id, title, content, reading_time) in [
+            (
+                1,
+                1,
+                "Announcing Rust 2.0",
+                "<p>A new <b>edition</b></p>",
+                3,
+            ),
+            (2, 2, "Sponsored: buy our course", "<p>Limited offer</p>", 1),
+            (
+                3,
+                2,
+                "A long read on compilers",
+                "<p>Parsing and lowering</p>",
+                25,
+            ),
+        ] {
+            sqlx::query(
+                r#"
+                INSERT INTO entries (account_id, id, user_id, feed_id, title, url, content, hash, published_at, created_at, status, starred, reading_time)
+                VALUES (1, ?, 1, ?, ?, ?, ?, ?, ?, ?, 'unread', 0, ?)
+                "#,
+            )
+            .bind(id)
+            .bind(feed_id)
+            .bind(title)
+            .bind(format!("https://example.com/post/{id}"))
+            .bind(content)
+            .bind(format!("hash-{id}"))
+            .bind(now)
+            .bind(now)
+            .bind(reading_time)
+            .execute(pool)
+            .await
+            .expect("Failed to insert entry");
+        }
+    }
+
+    async fn load_entries(pool: &SqlitePool) -> Vec<Entry> {
+        get_entries_from_db(pool, &EntryFilters::default(), 1)
+            .await
+            .expect("get_entries_from_db should not error")
+            .entries
+            .unwrap_or_default()
+    }
+
+    fn condition(field: RuleField, operator: RuleOperator, value: &str) -> RuleCondition {
+        RuleCondition {
+            field,
+            operator,
+            value: value.to_string(),
+            negate: false,
+        }
+    }
+
+    fn rule(
+        name: &str,
+        match_any: bool,
+        conditions: Vec<RuleCondition>,
+        actions: Vec<RuleAction>,
+    ) -> FilterRuleInput {
+        FilterRuleInput {
+            name: name.to_string(),
+            enabled: true,
+            match_any,
+            conditions,
+            actions,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rule_validation_rejects_bad_input() {
+        let pool = setup_test_db().await;
+
+        let bad_regex = rule(
+            "Bad",
+            false,
+            vec![condition(RuleField::Title, RuleOperator::Regex, "(")],
+            vec![RuleAction::MarkRead],
+        );
+        assert!(create_filter_rule_in_db(&pool, 1, bad_regex).await.is_err());
+
+        let bad_number = rule(
+            "Bad",
+            false,
+            vec![condition(
+                RuleField::ReadingTime,
+                RuleOperator::AtLeast,
+                "ten",
+            )],
+            vec![RuleAction::MarkRead],
+        );
+        assert!(create_filter_rule_in_db(&pool, 1, bad_number)
+            .await
+            .is_err());
+
+        let no_conditions = rule("Empty", false, vec![], vec![RuleAction::MarkRead]);
+        assert!(create_filter_rule_in_db(&pool, 1, no_conditions)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_reports_matching_cached_entries() {
+        let pool = setup_test_db().await;
+        seed(&pool).await;
+
+        let any_of = rule(
+            "Noise",
+            true,
+            vec![
+                condition(RuleField::Title, RuleOperator::Regex, "^(?i)sponsored:"),
+                condition(RuleField::Content, RuleOperator::Contains, "EDITION"),
+            ],
+            vec![RuleAction::Hide],
+        );
+        let dry_run = dry_run_filter_rule_in_db(&pool, 1, any_of, 10)
+            .await
+            .expect("Dry run should succeed");
+        assert_eq!(dry_run.scanned, 3);
+        assert_eq!(dry_run.matched, 2);
+
+        let all_of = rule(
+            "Long reads on HN",
+            false,
+            vec![
+                condition(RuleField::Feed, RuleOperator::Equals, "hacker news"),
+                condition(RuleField::Category, RuleOperator::Equals, "Programming"),
+                condition(RuleField::ReadingTime, RuleOperator::AtLeast, "10"),
+            ],
+            vec![RuleAction::Star],
+        );
+        let dry_run = dry_run_filter_rule_in_db(&pool, 1, all_of, 10)
+            .await
+            .expect("Dry run should succeed");
+        assert_eq!(dry_run.matched, 1);
+        assert_eq!(dry_run.entries[0].entry_id, 3);
+
+        let mut negated = condition(RuleField::Url, RuleOperator::Contains, "/post/1");
+        negated.negate = true;
+        let short_titles = rule(
+            "Short titles",
+            false,
+            vec![
+                negated,
+                condition(RuleField::Title, RuleOperator::AtMost, "24"),
+            ],
+            vec![RuleAction::Notify],
+        );
+        let dry_run = dry_run_filter_rule_in_db(&pool, 1, short_titles, 10)
+            .await
+            .expect("Dry run should succeed");
+        assert_eq!(dry_run.matched, 1);
+        assert_eq!(dry_run.entries[0].entry_id, 3);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_counts_entries_hidden_by_other_rules() {
+        let pool = setup_test_db().await;
+        seed(&pool).await;
+        sqlx::query("UPDATE entries SET hidden_by_rule = 99 WHERE id = 2")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let sponsored = rule(
+            "Sponsored",
+            false,
+            vec![condition(
+                RuleField::Title,
+                RuleOperator::Contains,
+                "sponsored",
+            )],
+            vec![RuleAction::MarkRead],
+        );
+        let dry_run = dry_run_filter_rule_in_db(&pool, 1, sponsored, 10)
+            .await
+            .expect("Dry run should succeed");
+
+        assert_eq!(dry_run.scanned, 3);
+        assert_eq!(dry_run.matched, 1);
+        assert_eq!(dry_run.entries[0].entry_id, 2);
+    }
+
+    #[tokio::test]
+    async fn test_rules_are_scoped_to_their_account() {
+        let pool = setup_test_db().await;
+
+        let other = create_filter_rule_in_db(
+            &pool,
+            2,
+            rule(
+                "Other account",
+                false,
+                vec![condition(RuleField::Title, RuleOperator::Contains, "rust")],
+                vec![RuleAction::Hide],
+            ),
+        )
+        .await
+        .unwrap();
+
+        assert!(load_enabled_filter_rules(&pool, 1)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(delete_filter_rule_from_db(&pool, 1, other.id)
+            .await
+            .is_err());
+        assert_eq!(load_enabled_filter_rules(&pool, 2).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_apply_filter_rules_performs_actions() {
+        let pool = setup_test_db().await;
+        seed(&pool).await;
+
+        let hide = create_filter_rule_in_db(
+            &pool,
+            1,
+            rule(
+                "Hide sponsored",
+                false,
+                vec![condition(
+                    RuleField::Title,
+                    RuleOperator::Contains,
+                    "sponsored",
+                )],
+                vec![RuleAction::MarkRead, RuleAction::Hide],
+            ),
+        )
+        .await
+        .unwrap();
+        create_filter_rule_in_db(
+            &pool,
+            1,
+            rule(
+                "Long reads",
+                false,
+                vec![condition(
+                    RuleField::ReadingTime,
+                    RuleOperator::AtLeast,
+                    "20",
+                )],
+                vec![
+                    RuleAction::Star,
+                    RuleAction::Tag {
+                        tag: " long read ".to_string(),
+                    },
+                    RuleAction::Notify,
+                ],
+            ),
+        )
+        .await
+        .unwrap();
+
+        let rules = load_enabled_filter_rules(&pool, 1).await.unwrap();
+        let entries = load_entries(&pool).await;
+        let outcome = apply_filter_rules(&pool, 1, &rules, &entries)
+            .await
+            .expect("Applying rules should succeed");
+
+        assert_eq!(outcome.matched_entries, 2);
+        assert!(outcome.queued_changes);
+        assert_eq!(outcome.notifications.len(), 1);
+        assert_eq!(outcome.notifications[0].rule_name, "Long reads");
+
+        let (status, hidden_by): (String, Option<i64>) =
+            sqlx::query_as("SELECT status, hidden_by_rule FROM entries WHERE id = 2")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(status, "read");
+        assert_eq!(hidden_by, Some(hide.id));
+
+        let queued: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM sync_queue WHERE status = 'pending' AND account_id = 1 AND entity_id IN (2, 3)",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(queued, 2);
+
+        // Hidden entries drop out of entry lists
+        let visible = load_entries(&pool).await;
+        assert_eq!(visible.len(), 2);
+        let long_read = visible.iter().find(|entry| entry.id == 3).unwrap();
+        assert!(long_read.starred);
+        assert_eq!(long_read.tags, Some(vec!["long read".to_string()]));
+
+        // Deleting the rule shows its hidden entries again
+        delete_filter_rule_from_db(&pool, 1, hide.id).await.unwrap();
+        assert_eq!(load_entries(&pool).await.len(), 3);
+    }
+}
@@ -0,0 +1,519 @@
+//! Cloud sync of local-only reading data.
+//!
+//! Syncs the [`library`](crate::cloud_sync::library) document next to the
+//! preferences sync file, on the same backend and with the same encryption.
+//! Records are keyed by server URL and entry ID, so they only land on
+//! devices that have the entry from the same Miniflux server. Stars and read
+//! status already sync through Miniflux, and translation exclusions travel
+//! with preferences.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use sqlx::{FromRow, SqliteConnection, SqlitePool};
+use tauri::{AppHandle, Manager, State};
+
+use crate::cloud_sync::library::{
+    bound, deleted_since, entry_key, local_changes, merge_documents, split_key, tag_key,
+    LibraryDocument, Record, ARTICLE_SUMMARIES, LIBRARY_FILE_NAME, PODCAST_PROGRESS,
+    READING_POSITION, TAGS,
+};
+use crate::cloud_sync::state::LibrarySyncState;
+use crate::cloud_sync::{PushOutcome, Remote, RemoteFile};
+use crate::commands::cloud_sync::{seal, unseal};
+use crate::commands::preferences::load_preferences;
+use crate::commands::reading_state::{load_last_reading, save_last_reading, LastReadingEntry};
+use crate::AppState;
+
+/// Serializes library syncs so two runs never merge the same records.
+static LIBRARY_SYNC: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Conditional uploads tried before giving up on a remote that keeps changing.
+const MAX_SYNC_ATTEMPTS: u32 = 3;
+
+/// The reading position dataset holds a single record
+const READING_POSITION_KEY: &str = "last";
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct LibrarySyncSummary {
+    /// Records from other devices written locally
+    pub records_applied: u32,
+    /// Whether the remote library file was updated
+    pub uploaded: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProgressValue {
+    current_time: i64,
+    total_time: i64,
+    completed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SummaryValue {
+    summary: String,
+    provider_used: Option<String>,
+    model_used: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+struct SummaryRow {
+    entry_id: i64,
+    server_url: String,
+    summary: String,
+    provider_used: Option<String>,
+    model_used: Option<String>,
+    updated_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReadingValue {
+    server_url: String,
+    entry_id: i64,
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<serde_json::Value, String> {
+    serde_json::to_value(value).map_err(|e| format!("Failed to serialize library record: {e}"))
+}
+
+fn from_value<T: DeserializeOwned>(record: &Record) -> Result<T, String> {
+    serde_json::from_value(record.value.clone().unwrap_or_default())
+        .map_err(|e| format!("Invalid library record: {e}"))
+}
+
+/// Server URL of the account a local entry was synced from, preferring the
+/// active account when several have an entry with this ID
+async fn entry_server_url(pool: &SqlitePool, entry_id: i64) -> Result<Option<String>, String> {
+    sqlx::query_scalar(
+        r#"
+        SELECT c.server_url
+        FROM entries e
+        JOIN miniflux_connections c ON c.id = e.account_id
+        WHERE e.id = ?
+        ORDER BY c.is_active DESC, c.id
+        LIMIT 1
+        "#,
+    )
+    .bind(entry_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to look up entry account: {e}"))
+}
+
+/// The local account holding the entry synced from `server_url`, if any
+async fn entry_account(
+    conn: &mut SqliteConnection,
+    server_url: &str,
+    entry_id: i64,
+) -> Result<Option<i64>, String> {
+    sqlx::query_scalar(
+        r#"
+        SELECT e.account_id
+        FROM entries e
+        JOIN miniflux_connections c ON c.id = e.account_id
+        WHERE e.id = ? AND RTRIM(c.server_url, '/') = ?
+        ORDER BY c.is_active DESC, c.id
+        LIMIT 1
+        "#,
+    )
+    .bind(entry_id)
+    .bind(server_url.trim_end_matches('/'))
+    .fetch_optional(conn)
+    .await
+    .map_err(|e| format!("Failed to look up entry: {e}"))
+}
+
+/// Builds the library document from local data. Records of entries whose
+/// account has been removed are left out, since they can't be keyed.
+pub async fn snapshot_library_from_db(
+    pool: &SqlitePool,
+    last_reading: Option<&LastReadingEntry>,
+) -> Result<LibraryDocument, String> {
+    let mut document = LibraryDocument::default();
+
+    if let Some(reading) = last_reading {
+        let updated_at = DateTime::from_timestamp_millis(reading.timestamp)
+            .ok_or("Invalid last reading timestamp")?
+            .to_rfc3339();
+        if let Some(server_url) = entry_server_url(pool, reading.entry_id).await? {
+            let value = to_value(&ReadingValue {
+                server_url: server_url.trim_end_matches('/').to_string(),
+                entry_id: reading.entry_id,
+            })?;
+            document.dataset_mut(READING_POSITION).insert(
+                READING_POSITION_KEY.to_string(),
+                Record::live(value, updated_at),
+            );
+        }
+    }
+
+    let progress: Vec<(i64, String, i64, i64, Option<bool>, String)> = sqlx::query_as(
+        r#"
+        SELECT p.entry_id, c.server_url, p."current_time", p.total_time, p.completed, p.last_played_at
+        FROM podcast_progress p
+        JOIN miniflux_connections c ON c.id = p.account_id
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to read podcast progress: {e}"))?;
+    let dataset = document.dataset_mut(PODCAST_PROGRESS);
+    for (entry_id, server_url, current_time, total_time, completed, last_played_at) in progress {
+        let value = to_value(&ProgressValue {
+            current_time,
+            total_time,
+            completed: completed.unwrap_or(false),
+        })?;
+        dataset.insert(
+            entry_key(&server_url, entry_id),
+            Record::live(value, last_played_at),
+        );
+    }
+
+    let summaries: Vec<SummaryRow> = sqlx::query_as(
+        r#"
+        SELECT e.id AS entry_id, c.server_url, s.summary, s.provider_used, s.model_used, s.updated_at
+        FROM article_summaries s
+        JOIN entries e ON e.account_id = s.account_id AND e.id = CAST(s.entry_id AS INTEGER)
+        JOIN miniflux_connections c ON c.id = e.account_id
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to read article summaries: {e}"))?;
+    let dataset = document.dataset_mut(ARTICLE_SUMMARIES);
+    for row in summaries {
+        let value = to_value(&SummaryValue {
+            summary: row.summary,
+            provider_used: row.provider_used,
+            model_used: row.model_used,
+        })?;
+        dataset.insert(
+            entry_key(&row.server_url, row.entry_id),
+            Record::live(value, row.updated_at),
+        );
+    }
+
+    let tags: Vec<(i64, String, String, String)> = sqlx::query_as(
+        r#"
+        SELECT t.entry_id, c.server_url, t.tag, t.created_at
+        FROM tags t
+        JOIN miniflux_connections c ON c.id = t.account_id
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to read tags: {e}"))?;
+    let dataset = document.dataset_mut(TAGS);
+    for (entry_id, server_url, tag, created_at) in tags {
+        dataset.insert(
+            tag_key(&server_url, entry_id, &tag),
+            Record::live(serde_json::Value::Bool(true), created_at),
+        );
+    }
+
+    document.datasets.retain(|_, dataset| !dataset.is_empty());
+    Ok(document)
+}
+
+/// Adds tombstones for records that were present after the last sync and
+/// have since been deleted. Records that went away with their entry (e.g.
+/// through retention) aren't deleted for other devices.
+pub async fn mark_local_deletions(
+    pool: &SqlitePool,
+    document: &mut LibraryDocument,
+    previously_live: &BTreeMap<String, BTreeSet<String>>,
+    now: &str,
+) -> Result<(), String> {
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| format!("Failed to acquire connection: {e}"))?;
+
+    for (dataset, key) in deleted_since(document, previously_live) {
+        if dataset == READING_POSITION {
+            continue;
+        }
+        let Some((server_url, entry_id, _)) = split_key(&key) else {
+            continue;
+        };
+        if entry_account(&mut conn, server_url, entry_id)
+            .await?
+            .is_some()
+        {
+            document
+                .dataset_mut(&dataset)
+                .insert(key, Record::tombstone(now));
+        }
+    }
+    Ok(())
+}
+
+/// Writes merged records to the local tables and returns the new reading
+/// position, if any. Records of entries this device doesn't have are
+/// skipped. `live_keys` is updated with what was written.
+pub async fn apply_library_changes_to_db(
+    pool: &SqlitePool,
+    changes: &[(String, String, Record)],
+    live_keys: &mut BTreeMap<String, BTreeSet<String>>,
+) -> Result<(u32, Option<LastReadingEntry>), String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to begin transaction: {e}"))?;
+    let mut applied = 0u32;
+    let mut reading_position = None;
+
+    for (dataset, key, record) in changes {
+        if dataset == READING_POSITION {
+            let Ok(reading) = from_value::<ReadingValue>(record) else {
+                continue;
+            };
+            let Some(timestamp) = DateTime::parse_from_rfc3339(&record.updated_at)
+                .ok()
+                .map(|time| time.timestamp_millis())
+            else {
+                continue;
+            };
+            if entry_account(&mut tx, &reading.server_url, reading.entry_id)
+                .await?
+                .is_some()
+            {
+                reading_position = Some(LastReadingEntry {
+                    entry_id: reading.entry_id,
+                    timestamp,
+                });
+                live_keys
+                    .entry(dataset.clone())
+                    .or_default()
+                    .insert(key.clone());
+                applied += 1;
+            }
+            continue;
+        }
+
+        let Some((server_url, entry_id, tag)) = split_key(key) else {
+            log::warn!("Skipping library record with malformed key: {key}");
+            continue;
+        };
+        let Some(account_id) = entry_account(&mut tx, server_url, entry_id).await? else {
+            continue;
+        };
+
+        match (dataset.as_str(), &record.value) {
+            (PODCAST_PROGRESS, None) => {
+                sqlx::query("DELETE FROM podcast_progress WHERE account_id = ? AND entry_id = ?")
+                    .bind(account_id)
+                    .bind(entry_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| format!("Failed to delete podcast progress: {e}"))?;
+            }
+            (PODCAST_PROGRESS, Some(_)) => {
+                let progress: ProgressValue = from_value(record)?;
+                sqlx::query(
+                    r#"
+                    INSERT INTO podcast_progress (account_id, entry_id, "current_time", total_time, completed, last_played_at)
+                    VALUES (?, ?, ?, ?, ?, ?)
+                    ON CONFLICT(account_id, entry_id) DO UPDATE SET
+                        "current_time" = excluded."current_time",
+                        total_time = excluded.total_time,
+                        completed = excluded.completed,
+                        last_played_at = excluded.last_played_at
+                    "#,
+                )
+                .bind(account_id)
+                .bind(entry_id)
+                .bind(progress.current_time)
+                .bind(progress.total_time)
+                .bind(progress.completed)
+                .bind(&record.updated_at)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to save podcast progress: {e}"))?;
+            }
+            (ARTICLE_SUMMARIES, None) => {
+                sqlx::query("DELETE FROM article_summaries WHERE account_id = ? AND entry_id = ?")
+                    .bind(account_id)
+                    .bind(entry_id.to_string())
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| format!("Failed to delete article summary: {e}"))?;
+            }
+            (ARTICLE_SUMMARIES, Some(_)) => {
+                let summary: SummaryValue = from_value(record)?;
+                sqlx::query(
+                    r#"
+                    INSERT INTO article_summaries (account_id, entry_id, summary, provider_used, model_used, created_at, updated_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                    ON CONFLICT(account_id, entry_id) DO UPDATE SET
+                        summary = excluded.summary,
+                        provider_used = excluded.provider_used,
+                        model_used = excluded.model_used,
+                        updated_at = excluded.updated_at
+                    "#,
+                )
+                .bind(account_id)
+                .bind(entry_id.to_string())
+                .bind(&summary.summary)
+                .bind(&summary.provider_used)
+                .bind(&summary.model_used)
+                .bind(&record.updated_at)
+                .bind(&record.updated_at)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to save article summary: {e}"))?;
+            }
+            (TAGS, value) => {
+                let Some(tag) = tag else {
+                    log::warn!("Skipping tag record without a tag: {key}");
+                    continue;
+                };
+                let query = if value.is_some() {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO tags (account_id, entry_id, tag, created_at)
+                        VALUES (?, ?, ?, ?)
+                        ON CONFLICT(account_id, entry_id, tag) DO UPDATE SET created_at = excluded.created_at
+                        "#,
+                    )
+                    .bind(account_id)
+                    .bind(entry_id)
+                    .bind(tag)
+                    .bind(&record.updated_at)
+                } else {
+                    sqlx::query(
+                        "DELETE FROM tags WHERE account_id = ? AND entry_id = ? AND tag = ?",
+                    )
+                    .bind(account_id)
+                    .bind(entry_id)
+                    .bind(tag)
+                };
+                query
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| format!("Failed to save tag: {e}"))?;
+            }
+            _ => {
+                // A dataset from a newer app version
+                continue;
+            }
+        }
+
+        let keys = live_keys.entry(dataset.clone()).or_default();
+        if record.is_tombstone() {
+            keys.remove(key);
+        } else {
+            keys.insert(key.clone());
+        }
+        applied += 1;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Failed to commit library changes: {e}"))?;
+    Ok((applied, reading_position))
+}
+
+/// Merge local reading data with the remote library file and apply what
+/// other devices changed.
+#[tauri::command]
+#[specta::specta]
+pub async fn cloud_sync_library(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<LibrarySyncSummary, String> {
+    let _sync = LIBRARY_SYNC.lock().await;
+    let prefs = load_preferences(app.clone()).await?;
+
+    if !prefs.cloud_sync_enabled {
+        return Err("Cloud sync is not enabled".to_string());
+    }
+    if !prefs.cloud_sync_library_enabled {
+        return Err("Library sync is not enabled".to_string());
+    }
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let remote = Remote::from_preferences(&prefs)?.sibling(LIBRARY_FILE_NAME);
+    let mut sync_state = LibrarySyncState::load(&app);
+
+    for attempt in 1..=MAX_SYNC_ATTEMPTS {
+        let now = Utc::now();
+        let last_reading = load_last_reading(app.clone()).await?;
+        let mut local = snapshot_library_from_db(&pool, last_reading.as_ref()).await?;
+        mark_local_deletions(&pool, &mut local, &sync_state.live_keys, &now.to_rfc3339()).await?;
+
+        let file = remote.fetch().await?;
+        let remote_document = match &file {
+            Some(file) => LibraryDocument::parse(&unseal(&file.data, &prefs)?)?,
+            None => LibraryDocument::default(),
+        };
+
+        let mut merged = merge_documents(&local, &remote_document);
+        let changes = local_changes(&local, &merged);
+        bound(&mut merged, now);
+
+        let uploaded = merged != remote_document;
+        if uploaded {
+            let json = serde_json::to_string(&merged)
+                .map_err(|e| format!("Failed to serialize library sync data: {e}"))?;
+            let data = seal(json, &prefs)?;
+            let condition = RemoteFile::replace_condition(file.as_ref());
+            if let PushOutcome::Stale = remote.store(&data, &condition).await? {
+                log::info!("Library sync attempt {attempt} lost a race; merging again");
+                continue;
+            }
+        }
+
+        let mut live_keys = local.live_keys();
+        let (records_applied, reading_position) =
+            apply_library_changes_to_db(&pool, &changes, &mut live_keys).await?;
+        if let Some(entry) = reading_position {
+            save_last_reading(app.clone(), entry).await?;
+        }
+
+        sync_state.live_keys = live_keys;
+        sync_state.save(&app)?;
+
+        log::info!(
+            "Library sync applied {records_applied} record(s){}",
+            if uploaded {
+                " and uploaded changes"
+            } else {
+                ""
+            }
+        );
+        return Ok(LibrarySyncSummary {
+            records_applied,
+            uploaded,
+        });
+    }
+
+    Err("Remote library data kept changing; try again later".to_string())
+}
+
+/// Runs a library sync after other background work, if it is enabled.
+/// Failures are only logged.
+pub async fn sync_library_in_background(app: &AppHandle) {
+    let enabled = crate::commands::preferences::load_preferences_sync(app)
+        .is_some_and(|prefs| prefs.cloud_sync_enabled && prefs.cloud_sync_library_enabled);
+    if !enabled {
+        return;
+    }
+    if let Err(e) = cloud_sync_library(app.clone(), app.state()).await {
+        log::warn!("Background library sync failed: {e}");
+    }
+}
+
+#[cfg(test)]
+#[path = "library_sync.test.rs"]
+mod tests;
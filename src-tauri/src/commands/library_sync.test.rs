@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use serde_json::json;
+    use sqlx::SqlitePool;
+
+    use crate::cloud_sync::library::{
+        local_changes, merge_documents, LibraryDocument, Record, ARTICLE_SUMMARIES,
+        PODCAST_PROGRESS, READING_POSITION, TAGS,
+    };
+    use crate::commands::library_sync::{
+        apply_library_changes_to_db, mark_local_deletions, snapshot_library_from_db,
+    };
+    use crate::commands::reading_state::LastReadingEntry;
+    use crate::database::migrations::run_migrations;
+
+    const SERVER: &str = "https://rss.example.com";
+    const NOW: &str = "2026-10-16T12:00:00+00:00";
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+
+        sqlx::query(
+            "INSERT INTO miniflux_connections (id, username, server_url, auth_method, is_active, created_at, updated_at) VALUES (1, 'reader', 'https://rss.example.com/', 'token', 1, '', '')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        // Account 2 has been removed, so its entry 3 can't be keyed
+        for account_id in [1, 2] {
+            sqlx::query(
+                "INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (?, 1, 1, 'Feed', 'https://example.com', 'https://example.com/rss', '', '')",
+            )
+            .bind(account_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        for (id, account_id) in [(1, 1), (2, 1), (3, 2)] {
+            sqlx::query(
+                "INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (?, ?, 1, 1, 'Entry', 'https://example.com/a', ?, '', '', 'unread')",
+            )
+            .bind(account_id)
+            .bind(id)
+            .bind(format!("hash-{id}"))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        pool
+    }
+
+    async fn insert_tag(pool: &SqlitePool, account_id: i64, entry_id: i64, tag: &str) {
+        sqlx::query("INSERT INTO tags (account_id, entry_id, tag, created_at) VALUES (?, ?, ?, ?)")
+            .bind(account_id)
+            .bind(entry_id)
+            .bind(tag)
+            .bind("2026-10-16T10:00:00+00:00")
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_keys_records_by_server_and_entry() {
+        let pool = setup_test_db().await;
+        insert_tag(&pool, 1, 1, "research").await;
+        insert_tag(&pool, 2, 3, "orphan").await;
+        sqlx::query(
+            r#"INSERT INTO podcast_progress (account_id, entry_id, "current_time", total_time, completed, last_played_at) VALUES (1, 2, 120, 600, FALSE, '2026-10-16T09:00:00+00:00')"#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            // Account 2's summary of its own entry 1 isn't account 1's entry 1
+            "INSERT INTO article_summaries (account_id, entry_id, summary, provider_used, model_used, created_at, updated_at) VALUES (1, '1', 'Short version', 'openai', NULL, '', '2026-10-16T08:00:00+00:00'), (2, '1', 'Other entry', NULL, NULL, '', '2026-10-16T10:00:00+00:00')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let reading = LastReadingEntry {
+            entry_id: 2,
+            timestamp: 1_792_152_000_000,
+        };
+
+        let document = snapshot_library_from_db(&pool, Some(&reading))
+            .await
+            .unwrap();
+
+        let tags: Vec<&String> = document.dataset(TAGS).unwrap().keys().collect();
+        assert_eq!(tags, vec!["https://rss.example.com|1|research"]);
+        let progress = &document.dataset(PODCAST_PROGRESS).unwrap()["https://rss.example.com|2"];
+        assert_eq!(
+            progress.value,
+            Some(json!({ "current_time": 120, "total_time": 600, "completed": false }))
+        );
+        let summaries = document.dataset(ARTICLE_SUMMARIES).unwrap();
+        assert_eq!(summaries.len(), 1);
+        let summary = &summaries["https://rss.example.com|1"];
+        assert_eq!(summary.value.as_ref().unwrap()["summary"], "Short version");
+        let reading = &document.dataset(READING_POSITION).unwrap()["last"];
+        assert_eq!(
+            reading.value,
+            Some(json!({ "server_url": SERVER, "entry_id": 2 }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deletions_are_marked_only_while_the_entry_exists() {
+        let pool = setup_test_db().await;
+        let mut document = snapshot_library_from_db(&pool, None).await.unwrap();
+        let previously_live = BTreeMap::from([(
+            TAGS.to_string(),
+            BTreeSet::from([
+                "https://rss.example.com|1|removed".to_string(),
+                "https://rss.example.com|99|entry-gone".to_string(),
+            ]),
+        )]);
+
+        mark_local_deletions(&pool, &mut document, &previously_live, NOW)
+            .await
+            .unwrap();
+
+        let tags = document.dataset(TAGS).unwrap();
+        assert_eq!(tags.len(), 1);
+        assert!(tags["https://rss.example.com|1|removed"].is_tombstone());
+    }
+
+    #[tokio::test]
+    async fn test_remote_changes_are_applied_to_local_entries() {
+        let pool = setup_test_db().await;
+        insert_tag(&pool, 1, 1, "stale").await;
+        let local = snapshot_library_from_db(&pool, None).await.unwrap();
+
+        let mut remote = LibraryDocument::default();
+        let tags = remote.dataset_mut(TAGS);
+        tags.insert(
+            "https://rss.example.com|1|stale".to_string(),
+            Record::tombstone("2026-10-16T11:00:00+00:00"),
+        );
+        tags.insert(
+            "https://rss.example.com|2|later".to_string(),
+            Record::live(json!(true), "2026-10-16T11:00:00+00:00"),
+        );
+        tags.insert(
+            "https://other.example.com|2|elsewhere".to_string(),
+            Record::live(json!(true), "2026-10-16T11:00:00+00:00"),
+        );
+        remote.dataset_mut(PODCAST_PROGRESS).insert(
+            "https://rss.example.com|1".to_string(),
+            Record::live(
+                json!({ "current_time": 300, "total_time": 600, "completed": true }),
+                "2026-10-16T11:30:00+00:00",
+            ),
+        );
+
+        let merged = merge_documents(&local, &remote);
+        let changes = local_changes(&local, &merged);
+        let mut live_keys = local.live_keys();
+        let (applied, reading) = apply_library_changes_to_db(&pool, &changes, &mut live_keys)
+            .await
+            .unwrap();
+
+        assert_eq!(applied, 3);
+        assert!(reading.is_none());
+        let tags: Vec<(i64, String)> =
+            sqlx::query_as("SELECT entry_id, tag FROM tags ORDER BY entry_id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(tags, vec![(2, "later".to_string())]);
+        let progress: (i64, bool) = sqlx::query_as(
+            r#"SELECT "current_time", completed FROM podcast_progress WHERE entry_id = 1"#,
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(progress, (300, true));
+        assert_eq!(
+            live_keys[TAGS],
+            BTreeSet::from(["https://rss.example.com|2|later".to_string()])
+        );
+
+        // Applied records read back as merged; only the record for an entry
+        // this device doesn't have is still outstanding
+        let again = snapshot_library_from_db(&pool, None).await.unwrap();
+        let outstanding: Vec<String> = local_changes(&again, &merge_documents(&again, &merged))
+            .into_iter()
+            .map(|(_, key, _)| key)
+            .collect();
+        assert_eq!(outstanding, vec!["https://other.example.com|2|elsewhere"]);
+    }
+}
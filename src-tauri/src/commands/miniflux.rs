@@ -1,4 +1,10 @@
-use crate::miniflux::{AuthConfig, EntryFilters, EntryUpdate, FeedUpdate, MinifluxClient};
+use crate::commands::sync::{
+    complete_sync_operations, queue_entry_starred_change, queue_entry_status_change,
+    set_entry_starred_remote, stored_account_client,
+};
+use crate::miniflux::{
+    AuthConfig, EntryFilters, EntryUpdate, FeedUpdate, MinifluxClient, MinifluxError, RetryPolicy,
+};
 use crate::AppState;
 use chrono::{TimeZone, Utc};
 use sqlx::sqlite::SqlitePool;
@@ -22,33 +28,27 @@ pub async fn miniflux_connect(
     app_handle: AppHandle,
     state: State<'_, AppState>,
     config: AuthConfig,
-) -> Result<bool, String> {
+) -> Result<bool, MinifluxError> {
     log::info!("Connecting to Miniflux server: {}", config.server_url);
 
-    let server_url = config.server_url.clone();
-    let client = if let Some(ref token) = config.auth_token {
-        MinifluxClient::new(server_url).with_token(token.clone())
-    } else if let (Some(username), Some(password)) =
-        (config.username.clone(), config.password.clone())
-    {
-        MinifluxClient::new(server_url).with_credentials(username, password)
-    } else {
-        return Err("Either auth_token or username/password must be provided".to_string());
-    };
+    // Fail fast while the user is waiting on the login form; the stored client
+    // gets the default retry policy once the credentials are known to work
+    let client = MinifluxClient::from_auth_config(&config)?.with_retry_policy(RetryPolicy::none());
 
     // Test authentication
     match client.authenticate().await {
         Ok(true) => {
             log::info!("Successfully authenticated with Miniflux server");
 
-            let user = client.get_current_user().await.map_err(|e| {
-                log::error!("Failed to fetch current user: {}", e);
-                format!("Failed to fetch current user: {}", e)
-            })?;
+            let user = client
+                .get_current_user()
+                .await
+                .inspect_err(|e| log::error!("Failed to fetch current user: {}", e))?;
 
             log::info!("Fetched current user: {} (ID: {})", user.username, user.id);
 
-            *state.miniflux.client.lock().await = Some(client);
+            *state.miniflux.client.lock().await =
+                Some(client.with_retry_policy(RetryPolicy::default()));
             *state.miniflux.user_id.lock().await = Some(user.id);
 
             // Per-account sync state is preserved across account switches.
@@ -91,8 +91,11 @@ pub async fn miniflux_connect(
 
             Ok(true)
         }
-        Ok(false) => Err("Authentication failed: Invalid credentials".to_string()),
-        Err(e) => Err(format!("Connection error: {}", e)),
+        Ok(false) => Err(MinifluxError::Unauthorized { status: 401 }),
+        Err(e) => {
+            log::error!("Failed to connect to Miniflux server: {}", e);
+            Err(e)
+        }
     }
 }
 
@@ -127,8 +130,8 @@ pub async fn get_categories(
         .ok_or("Database not initialized")?
         .clone();
 
-    let user_id = get_active_user_id(&state).await?;
-    get_categories_from_db(&pool, user_id).await
+    let account_id = get_active_account_id(&state).await?;
+    get_categories_from_db(&pool, account_id).await
 }
 
 /// Create a new category
@@ -137,13 +140,15 @@ pub async fn get_categories(
 pub async fn create_category(
     state: State<'_, AppState>,
     title: String,
-) -> Result<crate::miniflux::Category, String> {
+) -> Result<crate::miniflux::Category, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     let trimmed_title = title.trim().to_string();
     if trimmed_title.is_empty() {
-        return Err("Category title cannot be empty".to_string());
+        return Err(MinifluxError::InvalidInput {
+            message: "Category title cannot be empty".to_string(),
+        });
     }
 
     client.create_category(trimmed_title).await
@@ -156,17 +161,17 @@ pub async fn update_category(
     state: State<'_, AppState>,
     id: String,
     title: String,
-) -> Result<crate::miniflux::Category, String> {
+) -> Result<crate::miniflux::Category, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid category ID: {}", e))?;
+    let id_parsed = parse_id(&id, "category")?;
 
     let trimmed_title = title.trim().to_string();
     if trimmed_title.is_empty() {
-        return Err("Category title cannot be empty".to_string());
+        return Err(MinifluxError::InvalidInput {
+            message: "Category title cannot be empty".to_string(),
+        });
     }
 
     client.update_category(id_parsed, trimmed_title).await
@@ -175,13 +180,11 @@ pub async fn update_category(
 /// Delete a category
 #[tauri::command]
 #[specta::specta]
-pub async fn delete_category(state: State<'_, AppState>, id: String) -> Result<(), String> {
+pub async fn delete_category(state: State<'_, AppState>, id: String) -> Result<(), MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid category ID: {}", e))?;
+    let id_parsed = parse_id(&id, "category")?;
 
     client.delete_category(id_parsed).await
 }
@@ -198,8 +201,8 @@ pub async fn get_feeds(state: State<'_, AppState>) -> Result<Vec<crate::miniflux
         .ok_or("Database not initialized")?
         .clone();
 
-    let user_id = get_active_user_id(&state).await?;
-    get_feeds_from_db(&pool, user_id).await
+    let account_id = get_active_account_id(&state).await?;
+    get_feeds_from_db(&pool, account_id).await
 }
 
 /// Get feeds by category
@@ -221,8 +224,8 @@ pub async fn get_category_feeds(
         .parse::<i64>()
         .map_err(|e| format!("Invalid category ID: {}", e))?;
 
-    let user_id = get_active_user_id(&state).await?;
-    get_category_feeds_from_db(&pool, category_id_parsed, user_id).await
+    let account_id = get_active_account_id(&state).await?;
+    get_category_feeds_from_db(&pool, category_id_parsed, account_id).await
 }
 
 /// Get entries with filters
@@ -240,8 +243,8 @@ pub async fn get_entries(
         .ok_or("Database not initialized")?
         .clone();
 
-    let user_id = get_active_user_id(&state).await?;
-    get_entries_from_db(&pool, &filters, user_id).await
+    let account_id = get_active_account_id(&state).await?;
+    get_entries_from_db(&pool, &filters, account_id).await
 }
 
 /// Get lightweight entries for list UI with content preview
@@ -259,16 +262,18 @@ pub async fn get_entries_list(
         .ok_or("Database not initialized")?
         .clone();
 
-    let user_id = get_active_user_id(&state).await?;
-    get_entries_list_from_db(&pool, &filters, user_id).await
+    let account_id = get_active_account_id(&state).await?;
+    get_entries_list_from_db(&pool, &filters, account_id).await
 }
 
-/// Get a single entry
+/// Get a single entry, of `account_id` in the all-accounts view or else of the
+/// active account
 #[tauri::command]
 #[specta::specta]
 pub async fn get_entry(
     state: State<'_, AppState>,
     entry_id: String,
+    account_id: Option<String>,
 ) -> Result<crate::miniflux::Entry, String> {
     let pool = state
         .db_pool
@@ -282,7 +287,42 @@ pub async fn get_entry(
         .parse::<i64>()
         .map_err(|e| format!("Invalid entry ID: {}", e))?;
 
-    get_entry_from_db(&pool, id_parsed).await
+    let account_id = entry_account_id(&state, account_id).await?;
+    get_entry_from_db(&pool, account_id, id_parsed).await
+}
+
+/// Fetch a feed's entries straight from the Miniflux server
+#[tauri::command]
+#[specta::specta]
+pub async fn fetch_feed_entries(
+    state: State<'_, AppState>,
+    feed_id: String,
+    filters: EntryFilters,
+) -> Result<crate::miniflux::EntryResponse, MinifluxError> {
+    let guard = state.miniflux.client.lock().await;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
+
+    let feed_id_parsed = parse_id(&feed_id, "feed")?;
+
+    client.get_feed_entries(feed_id_parsed, &filters).await
+}
+
+/// Fetch a category's entries straight from the Miniflux server
+#[tauri::command]
+#[specta::specta]
+pub async fn fetch_category_entries(
+    state: State<'_, AppState>,
+    category_id: String,
+    filters: EntryFilters,
+) -> Result<crate::miniflux::EntryResponse, MinifluxError> {
+    let guard = state.miniflux.client.lock().await;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
+
+    let category_id_parsed = parse_id(&category_id, "category")?;
+
+    client
+        .get_category_entries(category_id_parsed, &filters)
+        .await
 }
 
 /// Mark entry as read
@@ -292,37 +332,88 @@ pub async fn get_entry(
 /// failing server.
 #[tauri::command]
 #[specta::specta]
-pub async fn mark_entry_read(state: State<'_, AppState>, id: String) -> Result<(), String> {
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid entry ID: {}", e))?;
+pub async fn mark_entry_read(
+    state: State<'_, AppState>,
+    id: String,
+    account_id: Option<String>,
+) -> Result<(), MinifluxError> {
+    let id_parsed = parse_id(&id, "entry")?;
 
-    mark_entries_read_internal(&state, &[id_parsed]).await
+    let account_id = entry_account_id(&state, account_id).await?;
+    mark_entries_read_internal(&state, account_id, &[id_parsed]).await
 }
 
-/// Mark multiple entries as read
+/// Mark multiple entries of one account as read
 #[tauri::command]
 #[specta::specta]
-pub async fn mark_entries_read(state: State<'_, AppState>, ids: Vec<String>) -> Result<(), String> {
+pub async fn mark_entries_read(
+    state: State<'_, AppState>,
+    ids: Vec<String>,
+    account_id: Option<String>,
+) -> Result<(), MinifluxError> {
     let ids_parsed: Vec<i64> = ids
         .iter()
-        .map(|id| {
-            id.parse::<i64>()
-                .map_err(|e| format!("Invalid entry ID: {}", e))
-        })
+        .map(|id| parse_id(id, "entry"))
         .collect::<Result<Vec<_>, _>>()?;
 
-    mark_entries_read_internal(&state, &ids_parsed).await
+    let account_id = entry_account_id(&state, account_id).await?;
+    mark_entries_read_internal(&state, account_id, &ids_parsed).await
+}
+
+/// Parses an ID argument of a command, e.g. `parse_id(&id, "feed")`
+fn parse_id(value: &str, kind: &str) -> Result<i64, MinifluxError> {
+    value
+        .parse::<i64>()
+        .map_err(|e| MinifluxError::InvalidInput {
+            message: format!("Invalid {kind} ID: {e}"),
+        })
+}
+
+/// The account an entry command acts on: the entry's own account when the
+/// all-accounts view passes one, else the active account.
+pub(crate) async fn entry_account_id(
+    state: &AppState,
+    account_id: Option<String>,
+) -> Result<i64, MinifluxError> {
+    match account_id {
+        Some(account_id) => parse_id(&account_id, "account"),
+        None => get_active_account_id(state)
+            .await
+            .map_err(MinifluxError::from),
+    }
+}
+
+/// The client that pushes changes to `account_id`: the connected client for the
+/// active account, else one built from the account's saved credentials.
+async fn entry_account_client(
+    state: &AppState,
+    pool: &SqlitePool,
+    account_id: i64,
+) -> Result<MinifluxClient, MinifluxError> {
+    if get_active_account_id(state).await.ok() == Some(account_id) {
+        return state
+            .miniflux
+            .client
+            .lock()
+            .await
+            .clone()
+            .ok_or(MinifluxError::NotConnected);
+    }
+    stored_account_client(pool, account_id).await.map_err(|e| {
+        log::warn!("Failed to build a client for account {account_id}: {e}");
+        MinifluxError::NotConnected
+    })
 }
 
 /// Set `status = 'read'` locally (stamping `changed_at` so History orders by
-/// when the entry was read) and then push the change to Miniflux. An API
-/// failure is logged, not returned: the local DB already holds the truth and
-/// the next sync reconciles it.
+/// when the entry was read), record the change in `sync_queue`, and then push
+/// it to the account's Miniflux server. An API failure is logged, not returned:
+/// the queued operation is replayed at the start of the account's next sync.
 async fn mark_entries_read_internal(
     state: &State<'_, AppState>,
+    account_id: i64,
     ids: &[i64],
-) -> Result<(), String> {
+) -> Result<(), MinifluxError> {
     if ids.is_empty() {
         return Ok(());
     }
@@ -335,25 +426,25 @@ async fn mark_entries_read_internal(
         .ok_or("Database not initialized")?
         .clone();
 
-    mark_entries_read_in_db(&pool, ids).await?;
+    mark_entries_read_in_db(&pool, account_id, ids).await?;
+    let queue_ids = queue_entry_status_change(&pool, account_id, ids, "read").await?;
 
-    let api_result = {
-        let guard = state.miniflux.client.lock().await;
-        if let Some(client) = guard.as_ref() {
+    let api_result = match entry_account_client(state, &pool, account_id).await {
+        Ok(client) => {
             client
                 .update_entries(ids.to_vec(), "read".to_string())
                 .await
-        } else {
-            Ok(())
         }
+        Err(e) => Err(e),
     };
 
-    if let Err(e) = api_result {
-        log::warn!(
-            "Failed to sync {} entries' read status with Miniflux API: {}. Local DB updated successfully.",
+    match api_result {
+        Ok(()) => complete_sync_operations(&pool, &queue_ids).await?,
+        Err(e) => log::warn!(
+            "Failed to sync {} entries' read status with Miniflux API: {}. Queued for next sync.",
             ids.len(),
             e
-        );
+        ),
     }
 
     Ok(())
@@ -363,7 +454,11 @@ async fn mark_entries_read_internal(
 /// time they were read, so History (ordered by `changed_at`) lists them by when
 /// they were read rather than when they were published. Entries already read
 /// keep their original timestamp.
-pub async fn mark_entries_read_in_db(pool: &SqlitePool, ids: &[i64]) -> Result<(), String> {
+pub async fn mark_entries_read_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    ids: &[i64],
+) -> Result<(), String> {
     if ids.is_empty() {
         return Ok(());
     }
@@ -371,7 +466,9 @@ pub async fn mark_entries_read_in_db(pool: &SqlitePool, ids: &[i64]) -> Result<(
     let mut update: QueryBuilder<sqlx::Sqlite> =
         QueryBuilder::new("UPDATE entries SET status = 'read', changed_at = ");
     update.push_bind(Utc::now().to_rfc3339());
-    update.push(" WHERE status != 'read' AND id IN (");
+    update.push(" WHERE status != 'read' AND account_id = ");
+    update.push_bind(account_id);
+    update.push(" AND id IN (");
     let mut separated = update.separated(", ");
     for id in ids {
         separated.push_bind(*id);
@@ -387,38 +484,105 @@ pub async fn mark_entries_read_in_db(pool: &SqlitePool, ids: &[i64]) -> Result<(
     Ok(())
 }
 
-/// Mark all entries in a feed as read
+/// Mark all entries in a feed as read.
+///
+/// Local-first like `mark_entries_read`: unread entries of the feed are marked
+/// read in SQLite and queued, then the feed-wide call is made to Miniflux.
 #[tauri::command]
 #[specta::specta]
-pub async fn mark_feed_as_read(state: State<'_, AppState>, id: String) -> Result<(), String> {
-    let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+pub async fn mark_feed_as_read(
+    state: State<'_, AppState>,
+    id: String,
+) -> Result<(), MinifluxError> {
+    let id_parsed = parse_id(&id, "feed")?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid feed ID: {}", e))?;
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let account_id = get_active_account_id(&state).await?;
+
+    let ids =
+        mark_scope_read_in_db(&pool, account_id, MarkReadScope::Feed(id_parsed), None).await?;
+    let queue_ids = queue_entry_status_change(&pool, account_id, &ids, "read").await?;
+
+    let api_result = {
+        let guard = state.miniflux.client.lock().await;
+        if let Some(client) = guard.as_ref() {
+            client.mark_feed_as_read(id_parsed).await
+        } else {
+            Err(MinifluxError::NotConnected)
+        }
+    };
 
-    client.mark_feed_as_read(id_parsed).await
+    match api_result {
+        Ok(()) => complete_sync_operations(&pool, &queue_ids).await?,
+        Err(e) => log::warn!(
+            "Failed to mark feed {} as read on Miniflux: {}. {} entries queued for next sync.",
+            id_parsed,
+            e,
+            ids.len()
+        ),
+    }
+
+    Ok(())
 }
 
-/// Mark all entries in a category as read
+/// Mark all entries in a category as read.
+///
+/// Local-first like `mark_feed_as_read`.
 #[tauri::command]
 #[specta::specta]
-pub async fn mark_category_as_read(state: State<'_, AppState>, id: String) -> Result<(), String> {
-    let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+pub async fn mark_category_as_read(
+    state: State<'_, AppState>,
+    id: String,
+) -> Result<(), MinifluxError> {
+    let id_parsed = parse_id(&id, "category")?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid category ID: {}", e))?;
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let account_id = get_active_account_id(&state).await?;
+
+    let ids =
+        mark_scope_read_in_db(&pool, account_id, MarkReadScope::Category(id_parsed), None).await?;
+    let queue_ids = queue_entry_status_change(&pool, account_id, &ids, "read").await?;
 
-    client.mark_category_as_read(id_parsed).await
+    let api_result = {
+        let guard = state.miniflux.client.lock().await;
+        if let Some(client) = guard.as_ref() {
+            client.mark_category_as_read(id_parsed).await
+        } else {
+            Err(MinifluxError::NotConnected)
+        }
+    };
+
+    match api_result {
+        Ok(()) => complete_sync_operations(&pool, &queue_ids).await?,
+        Err(e) => log::warn!(
+            "Failed to mark category {} as read on Miniflux: {}. {} entries queued for next sync.",
+            id_parsed,
+            e,
+            ids.len()
+        ),
+    }
+
+    Ok(())
 }
 
-/// Toggle entry star
+/// Mark every entry of the active account as read.
+///
+/// Local-first like `mark_feed_as_read`, using Miniflux's user-wide endpoint.
 #[tauri::command]
 #[specta::specta]
-pub async fn toggle_entry_star(state: State<'_, AppState>, id: String) -> Result<bool, String> {
+pub async fn mark_all_as_read(state: State<'_, AppState>) -> Result<(), MinifluxError> {
     let pool = state
         .db_pool
         .lock()
@@ -426,23 +590,188 @@ pub async fn toggle_entry_star(state: State<'_, AppState>, id: String) -> Result
         .as_ref()
         .ok_or("Database not initialized")?
         .clone();
+    let account_id = get_active_account_id(&state).await?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid entry ID: {}", e))?;
+    let ids = mark_scope_read_in_db(&pool, account_id, MarkReadScope::All, None).await?;
+    let queue_ids = queue_entry_status_change(&pool, account_id, &ids, "read").await?;
 
-    // Get current starred status from local database
-    let current_starred: bool = sqlx::query_scalar("SELECT starred FROM entries WHERE id = ?")
-        .bind(id_parsed)
-        .fetch_one(&pool)
+    let miniflux_user_id = *state.miniflux.user_id.lock().await;
+    let api_result = {
+        let guard = state.miniflux.client.lock().await;
+        match (guard.as_ref(), miniflux_user_id) {
+            (Some(client), Some(miniflux_user_id)) => {
+                client.mark_user_entries_as_read(miniflux_user_id).await
+            }
+            _ => Err(MinifluxError::NotConnected),
+        }
+    };
+
+    match api_result {
+        Ok(()) => complete_sync_operations(&pool, &queue_ids).await?,
+        Err(e) => log::warn!(
+            "Failed to mark all entries as read on Miniflux: {}. {} entries queued for next sync.",
+            e,
+            ids.len()
+        ),
+    }
+
+    Ok(())
+}
+
+/// Mark entries published before `before` (RFC 3339) as read, optionally
+/// limited to one feed or category. Returns the number of entries marked.
+///
+/// Miniflux has no "before" variant of its mark-all endpoints, so the affected
+/// entry IDs are pushed explicitly.
+#[tauri::command]
+#[specta::specta]
+pub async fn mark_entries_read_before(
+    state: State<'_, AppState>,
+    before: String,
+    feed_id: Option<String>,
+    category_id: Option<String>,
+) -> Result<u32, MinifluxError> {
+    let before = chrono::DateTime::parse_from_rfc3339(&before)
+        .map_err(|e| MinifluxError::InvalidInput {
+            message: format!("Invalid date: {e}"),
+        })?
+        .with_timezone(&Utc)
+        .to_rfc3339();
+
+    let scope = match (feed_id, category_id) {
+        (Some(feed_id), _) => MarkReadScope::Feed(parse_id(&feed_id, "feed")?),
+        (None, Some(category_id)) => MarkReadScope::Category(parse_id(&category_id, "category")?),
+        (None, None) => MarkReadScope::All,
+    };
+
+    let pool = state
+        .db_pool
+        .lock()
         .await
-        .map_err(|e| format!("Failed to get entry starred status: {e}"))?;
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let account_id = get_active_account_id(&state).await?;
+
+    let ids = mark_scope_read_in_db(&pool, account_id, scope, Some(&before)).await?;
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    let queue_ids = queue_entry_status_change(&pool, account_id, &ids, "read").await?;
+
+    let api_result = {
+        let guard = state.miniflux.client.lock().await;
+        if let Some(client) = guard.as_ref() {
+            client.update_entries(ids.clone(), "read".to_string()).await
+        } else {
+            Err(MinifluxError::NotConnected)
+        }
+    };
+
+    match api_result {
+        Ok(()) => complete_sync_operations(&pool, &queue_ids).await?,
+        Err(e) => log::warn!(
+            "Failed to sync {} entries read before {} with Miniflux API: {}. Queued for next sync.",
+            ids.len(),
+            before,
+            e
+        ),
+    }
+
+    Ok(ids.len() as u32)
+}
+
+/// Which entries a bulk mark-as-read applies to
+#[derive(Debug, Clone, Copy)]
+pub enum MarkReadScope {
+    Feed(i64),
+    Category(i64),
+    All,
+}
+
+/// Set `status = 'read'` on every unread entry of `account_id` in `scope`,
+/// stamping `changed_at` like `mark_entries_read_in_db`. When
+/// `published_before` is set only entries published before it are touched.
+/// Returns the IDs of the entries that changed.
+pub async fn mark_scope_read_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    scope: MarkReadScope,
+    published_before: Option<&str>,
+) -> Result<Vec<i64>, String> {
+    let mut update: QueryBuilder<sqlx::Sqlite> =
+        QueryBuilder::new("UPDATE entries SET status = 'read', changed_at = ");
+    update.push_bind(Utc::now().to_rfc3339());
+    update.push(" WHERE status = 'unread' AND account_id = ");
+    update.push_bind(account_id);
+
+    match scope {
+        MarkReadScope::Feed(feed_id) => {
+            update.push(" AND feed_id = ");
+            update.push_bind(feed_id);
+        }
+        MarkReadScope::Category(category_id) => {
+            update.push(" AND feed_id IN (SELECT id FROM feeds WHERE account_id = ");
+            update.push_bind(account_id);
+            update.push(" AND category_id = ");
+            update.push_bind(category_id);
+            update.push(")");
+        }
+        MarkReadScope::All => {}
+    }
+
+    if let Some(before) = published_before {
+        // Entries keep the server's UTC offset, so compare instants rather
+        // than strings
+        update.push(" AND julianday(published_at) < julianday(");
+        update.push_bind(before.to_string());
+        update.push(")");
+    }
+
+    update.push(" RETURNING id");
+
+    update
+        .build_query_scalar()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to update entry status in database: {e}"))
+}
+
+/// Toggle entry star, on `account_id` in the all-accounts view or else on the
+/// active account
+#[tauri::command]
+#[specta::specta]
+pub async fn toggle_entry_star(
+    state: State<'_, AppState>,
+    id: String,
+    account_id: Option<String>,
+) -> Result<bool, MinifluxError> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let id_parsed = parse_id(&id, "entry")?;
+    let account_id = entry_account_id(&state, account_id).await?;
+
+    // Get current starred status from local database
+    let current_starred: bool =
+        sqlx::query_scalar("SELECT starred FROM entries WHERE account_id = ? AND id = ?")
+            .bind(account_id)
+            .bind(id_parsed)
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| format!("Failed to get entry starred status: {e}"))?;
 
     let new_starred = !current_starred;
 
     // Update local database first (local-first approach)
-    sqlx::query("UPDATE entries SET starred = ? WHERE id = ?")
+    sqlx::query("UPDATE entries SET starred = ? WHERE account_id = ? AND id = ?")
         .bind(new_starred)
+        .bind(account_id)
         .bind(id_parsed)
         .execute(&pool)
         .await
@@ -455,51 +784,53 @@ pub async fn toggle_entry_star(state: State<'_, AppState>, id: String) -> Result
         new_starred
     );
 
+    let queue_id = queue_entry_starred_change(&pool, account_id, id_parsed, new_starred).await?;
+
     // Try to sync with Miniflux API
-    let api_result = {
-        let guard = state.miniflux.client.lock().await;
-        if let Some(client) = guard.as_ref() {
-            client.toggle_bookmark(id_parsed).await
-        } else {
-            Ok(())
-        }
+    let api_result = match entry_account_client(&state, &pool, account_id).await {
+        Ok(client) => set_entry_starred_remote(&client, id_parsed, new_starred).await,
+        Err(e) => Err(e),
     };
 
-    if let Err(e) = api_result {
-        let error_lower = e.to_lowercase();
-        let is_not_found = error_lower.contains("404") || error_lower.contains("not found");
-
-        if is_not_found {
-            sqlx::query("DELETE FROM entries WHERE id = ?")
-                .bind(id_parsed)
-                .execute(&pool)
-                .await
-                .ok();
+    match api_result {
+        Ok(()) => complete_sync_operations(&pool, &[queue_id]).await?,
+        Err(e) => {
+            if e.is_not_found() {
+                complete_sync_operations(&pool, &[queue_id]).await?;
+                sqlx::query("DELETE FROM entries WHERE account_id = ? AND id = ?")
+                    .bind(account_id)
+                    .bind(id_parsed)
+                    .execute(&pool)
+                    .await
+                    .ok();
+
+                log::info!(
+                    "Entry {} deleted from local database (not found on server)",
+                    id_parsed
+                );
+                return Err(e);
+            }
 
-            log::info!(
-                "Entry {} deleted from local database (not found on server)",
-                id_parsed
+            log::warn!(
+                "Failed to sync entry {} starred status with Miniflux API: {}. Queued for next sync.",
+                id_parsed,
+                e
             );
-            return Err(format!(
-                "Entry not found on server (may have been deleted). ID: {}",
-                id_parsed
-            ));
         }
-
-        log::warn!(
-            "Failed to sync entry {} starred status with Miniflux API: {}. Local DB updated successfully.",
-            id_parsed,
-            e
-        );
     }
 
     Ok(new_starred)
 }
 
-/// Toggle entry read status between "read" and "unread"
+/// Toggle entry read status between "read" and "unread", on `account_id` in the
+/// all-accounts view or else on the active account
 #[tauri::command]
 #[specta::specta]
-pub async fn toggle_entry_read(state: State<'_, AppState>, id: String) -> Result<String, String> {
+pub async fn toggle_entry_read(
+    state: State<'_, AppState>,
+    id: String,
+    account_id: Option<String>,
+) -> Result<String, MinifluxError> {
     let pool = state
         .db_pool
         .lock()
@@ -508,15 +839,17 @@ pub async fn toggle_entry_read(state: State<'_, AppState>, id: String) -> Result
         .ok_or("Database not initialized")?
         .clone();
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid entry ID: {}", e))?;
+    let id_parsed = parse_id(&id, "entry")?;
 
-    let current_status: String = sqlx::query_scalar("SELECT status FROM entries WHERE id = ?")
-        .bind(id_parsed)
-        .fetch_one(&pool)
-        .await
-        .map_err(|e| format!("Failed to get entry status: {e}"))?;
+    let account_id = entry_account_id(&state, account_id).await?;
+
+    let current_status: String =
+        sqlx::query_scalar("SELECT status FROM entries WHERE account_id = ? AND id = ?")
+            .bind(account_id)
+            .bind(id_parsed)
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| format!("Failed to get entry status: {e}"))?;
 
     let new_status = if current_status == "read" {
         "unread"
@@ -526,9 +859,10 @@ pub async fn toggle_entry_read(state: State<'_, AppState>, id: String) -> Result
 
     // Stamp changed_at so History (ordered by changed_at) surfaces the entry at
     // the time it was read, not at its publication date.
-    sqlx::query("UPDATE entries SET status = ?, changed_at = ? WHERE id = ?")
+    sqlx::query("UPDATE entries SET status = ?, changed_at = ? WHERE account_id = ? AND id = ?")
         .bind(new_status)
         .bind(Utc::now().to_rfc3339())
+        .bind(account_id)
         .bind(id_parsed)
         .execute(&pool)
         .await
@@ -541,23 +875,24 @@ pub async fn toggle_entry_read(state: State<'_, AppState>, id: String) -> Result
         new_status
     );
 
-    let api_result = {
-        let guard = state.miniflux.client.lock().await;
-        if let Some(client) = guard.as_ref() {
+    let queue_ids = queue_entry_status_change(&pool, account_id, &[id_parsed], new_status).await?;
+
+    let api_result = match entry_account_client(&state, &pool, account_id).await {
+        Ok(client) => {
             client
                 .update_entries(vec![id_parsed], new_status.to_string())
                 .await
-        } else {
-            Ok(())
         }
+        Err(e) => Err(e),
     };
 
-    if let Err(e) = api_result {
-        log::warn!(
-            "Failed to sync entry {} status with Miniflux API: {}. Local DB updated successfully.",
+    match api_result {
+        Ok(()) => complete_sync_operations(&pool, &queue_ids).await?,
+        Err(e) => log::warn!(
+            "Failed to sync entry {} status with Miniflux API: {}. Queued for next sync.",
             id_parsed,
             e
-        );
+        ),
     }
 
     Ok(new_status.to_string())
@@ -570,13 +905,11 @@ pub async fn update_entry(
     state: State<'_, AppState>,
     id: String,
     updates: EntryUpdate,
-) -> Result<crate::miniflux::Entry, String> {
+) -> Result<crate::miniflux::Entry, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid entry ID: {}", e))?;
+    let id_parsed = parse_id(&id, "entry")?;
 
     client.update_entry(id_parsed, updates).await
 }
@@ -584,13 +917,11 @@ pub async fn update_entry(
 /// Refresh a feed
 #[tauri::command]
 #[specta::specta]
-pub async fn refresh_feed(state: State<'_, AppState>, id: String) -> Result<(), String> {
+pub async fn refresh_feed(state: State<'_, AppState>, id: String) -> Result<(), MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid feed ID: {}", e))?;
+    let id_parsed = parse_id(&id, "feed")?;
 
     client.refresh_feed(id_parsed).await
 }
@@ -598,13 +929,67 @@ pub async fn refresh_feed(state: State<'_, AppState>, id: String) -> Result<(),
 /// Refresh all feeds
 #[tauri::command]
 #[specta::specta]
-pub async fn refresh_all_feeds(state: State<'_, AppState>) -> Result<(), String> {
+pub async fn refresh_all_feeds(state: State<'_, AppState>) -> Result<(), MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     client.refresh_all_feeds().await
 }
 
+/// Refresh every feed in a category
+#[tauri::command]
+#[specta::specta]
+pub async fn refresh_category(state: State<'_, AppState>, id: String) -> Result<(), MinifluxError> {
+    let guard = state.miniflux.client.lock().await;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
+
+    let id_parsed = parse_id(&id, "category")?;
+
+    client.refresh_category(id_parsed).await
+}
+
+/// Fetch a category's feeds straight from the Miniflux server
+#[tauri::command]
+#[specta::specta]
+pub async fn fetch_category_feeds(
+    state: State<'_, AppState>,
+    category_id: String,
+) -> Result<Vec<crate::miniflux::Feed>, MinifluxError> {
+    let guard = state.miniflux.client.lock().await;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
+
+    let category_id_parsed = parse_id(&category_id, "category")?;
+
+    client.get_category_feeds(category_id_parsed).await
+}
+
+/// Get per-feed read and unread counts from the Miniflux server
+#[tauri::command]
+#[specta::specta]
+pub async fn get_feed_counters(
+    state: State<'_, AppState>,
+) -> Result<crate::miniflux::FeedCounters, MinifluxError> {
+    let guard = state.miniflux.client.lock().await;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
+
+    client.get_feed_counters().await
+}
+
+/// Get an icon by its ID
+#[tauri::command]
+#[specta::specta]
+pub async fn get_icon(
+    state: State<'_, AppState>,
+    icon_id: String,
+) -> Result<crate::miniflux::Icon, MinifluxError> {
+    let guard = state.miniflux.client.lock().await;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
+
+    let icon_id_parsed = parse_id(&icon_id, "icon")?;
+
+    client.get_icon(icon_id_parsed).await
+}
+
 /// Create a new feed
 #[tauri::command]
 #[specta::specta]
@@ -612,15 +997,12 @@ pub async fn create_feed(
     state: State<'_, AppState>,
     feed_url: String,
     category_id: Option<String>,
-) -> Result<i64, String> {
+) -> Result<i64, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     let category_id_parsed = category_id
-        .map(|id| {
-            id.parse::<i64>()
-                .map_err(|e| format!("Invalid category ID: {}", e))
-        })
+        .map(|id| parse_id(&id, "category"))
         .transpose()?;
 
     client.create_feed(feed_url, category_id_parsed).await
@@ -633,27 +1015,40 @@ pub async fn update_feed(
     state: State<'_, AppState>,
     id: String,
     updates: FeedUpdate,
-) -> Result<crate::miniflux::Feed, String> {
+) -> Result<crate::miniflux::Feed, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid feed ID: {}", e))?;
+    let id_parsed = parse_id(&id, "feed")?;
+
+    let feed = client.update_feed(id_parsed, updates).await?;
+
+    // Rename the cached feed right away, so search finds it by its new title
+    // before the next sync
+    let pool = state.db_pool.lock().await.as_ref().cloned();
+    if let (Some(pool), Ok(account_id)) = (pool, get_active_account_id(&state).await) {
+        if let Err(e) = sqlx::query("UPDATE feeds SET title = ? WHERE account_id = ? AND id = ?")
+            .bind(&feed.title)
+            .bind(account_id)
+            .bind(feed.id)
+            .execute(&pool)
+            .await
+        {
+            log::warn!("Failed to update cached feed title: {e}");
+        }
+    }
 
-    client.update_feed(id_parsed, updates).await
+    Ok(feed)
 }
 
 /// Delete a feed
 #[tauri::command]
 #[specta::specta]
-pub async fn delete_feed(state: State<'_, AppState>, id: String) -> Result<(), String> {
+pub async fn delete_feed(state: State<'_, AppState>, id: String) -> Result<(), MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid feed ID: {}", e))?;
+    let id_parsed = parse_id(&id, "feed")?;
 
     client.delete_feed(id_parsed).await
 }
@@ -661,13 +1056,15 @@ pub async fn delete_feed(state: State<'_, AppState>, id: String) -> Result<(), S
 /// Get current user
 #[tauri::command]
 #[specta::specta]
-pub async fn get_current_user(state: State<'_, AppState>) -> Result<crate::miniflux::User, String> {
+pub async fn get_current_user(
+    state: State<'_, AppState>,
+) -> Result<crate::miniflux::User, MinifluxError> {
     log::debug!("[get_current_user] Command invoked");
 
     let guard = state.miniflux.client.lock().await;
     let client = guard.as_ref().ok_or_else(|| {
         log::error!("[get_current_user] No Miniflux client available - not connected to server");
-        "Not connected to Miniflux server".to_string()
+        MinifluxError::NotConnected
     })?;
 
     log::debug!("[get_current_user] Client acquired, fetching user from Miniflux API");
@@ -694,9 +1091,11 @@ pub async fn get_current_user(state: State<'_, AppState>) -> Result<crate::minif
 /// Get all users
 #[tauri::command]
 #[specta::specta]
-pub async fn get_users(state: State<'_, AppState>) -> Result<Vec<crate::miniflux::User>, String> {
+pub async fn get_users(
+    state: State<'_, AppState>,
+) -> Result<Vec<crate::miniflux::User>, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     client.get_users().await
 }
@@ -707,16 +1106,20 @@ pub async fn get_users(state: State<'_, AppState>) -> Result<Vec<crate::miniflux
 pub async fn create_user(
     state: State<'_, AppState>,
     user: crate::miniflux::UserCreate,
-) -> Result<crate::miniflux::User, String> {
+) -> Result<crate::miniflux::User, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     if user.username.trim().is_empty() {
-        return Err("Username cannot be empty".to_string());
+        return Err(MinifluxError::InvalidInput {
+            message: "Username cannot be empty".to_string(),
+        });
     }
 
     if user.password.trim().is_empty() {
-        return Err("Password cannot be empty".to_string());
+        return Err(MinifluxError::InvalidInput {
+            message: "Password cannot be empty".to_string(),
+        });
     }
 
     client.create_user(user).await
@@ -729,13 +1132,11 @@ pub async fn update_user(
     state: State<'_, AppState>,
     id: String,
     updates: crate::miniflux::UserUpdate,
-) -> Result<crate::miniflux::User, String> {
+) -> Result<crate::miniflux::User, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid user ID: {}", e))?;
+    let id_parsed = parse_id(&id, "user")?;
 
     client.update_user(id_parsed, updates).await
 }
@@ -743,25 +1144,70 @@ pub async fn update_user(
 /// Delete a user
 #[tauri::command]
 #[specta::specta]
-pub async fn delete_user(state: State<'_, AppState>, id: String) -> Result<(), String> {
+pub async fn delete_user(state: State<'_, AppState>, id: String) -> Result<(), MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid user ID: {}", e))?;
+    let id_parsed = parse_id(&id, "user")?;
 
     client.delete_user(id_parsed).await
 }
 
+/// Update the current user's reading settings, such as `entries_per_page`
+/// and `entry_sorting_direction`
+#[tauri::command]
+#[specta::specta]
+pub async fn update_user_preferences(
+    state: State<'_, AppState>,
+    updates: crate::miniflux::UserUpdate,
+) -> Result<crate::miniflux::User, MinifluxError> {
+    let guard = state.miniflux.client.lock().await;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
+
+    client.update_current_user(updates).await
+}
+
+/// Get an enclosure with its media progression
+#[tauri::command]
+#[specta::specta]
+pub async fn get_enclosure(
+    state: State<'_, AppState>,
+    id: String,
+) -> Result<crate::miniflux::Enclosure, MinifluxError> {
+    let guard = state.miniflux.client.lock().await;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
+
+    let id_parsed = parse_id(&id, "enclosure")?;
+
+    client.get_enclosure(id_parsed).await
+}
+
+/// Save an enclosure's playback position (seconds) on the Miniflux server
+#[tauri::command]
+#[specta::specta]
+pub async fn update_enclosure_progression(
+    state: State<'_, AppState>,
+    id: String,
+    media_progression: u32,
+) -> Result<(), MinifluxError> {
+    let guard = state.miniflux.client.lock().await;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
+
+    let id_parsed = parse_id(&id, "enclosure")?;
+
+    client
+        .update_enclosure_progression(id_parsed, i64::from(media_progression))
+        .await
+}
+
 /// Get all API keys
 #[tauri::command]
 #[specta::specta]
 pub async fn get_api_keys(
     state: State<'_, AppState>,
-) -> Result<Vec<crate::miniflux::ApiKey>, String> {
+) -> Result<Vec<crate::miniflux::ApiKey>, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     client.get_api_keys().await
 }
@@ -772,12 +1218,14 @@ pub async fn get_api_keys(
 pub async fn create_api_key(
     state: State<'_, AppState>,
     request: crate::miniflux::ApiKeyCreate,
-) -> Result<crate::miniflux::ApiKey, String> {
+) -> Result<crate::miniflux::ApiKey, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     if request.description.trim().is_empty() {
-        return Err("Description cannot be empty".to_string());
+        return Err(MinifluxError::InvalidInput {
+            message: "Description cannot be empty".to_string(),
+        });
     }
 
     client.create_api_key(request).await
@@ -786,13 +1234,11 @@ pub async fn create_api_key(
 /// Delete an API key
 #[tauri::command]
 #[specta::specta]
-pub async fn delete_api_key(state: State<'_, AppState>, id: String) -> Result<(), String> {
+pub async fn delete_api_key(state: State<'_, AppState>, id: String) -> Result<(), MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid API key ID: {e}"))?;
+    let id_parsed = parse_id(&id, "API key")?;
 
     client.delete_api_key(id_parsed).await
 }
@@ -800,13 +1246,11 @@ pub async fn delete_api_key(state: State<'_, AppState>, id: String) -> Result<()
 /// Save entry to third-party services (Pocket, Wallabag, etc.)
 #[tauri::command]
 #[specta::specta]
-pub async fn save_entry(state: State<'_, AppState>, id: String) -> Result<(), String> {
+pub async fn save_entry(state: State<'_, AppState>, id: String) -> Result<(), MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid entry ID: {e}"))?;
+    let id_parsed = parse_id(&id, "entry")?;
 
     client.save_entry(id_parsed).await
 }
@@ -814,9 +1258,11 @@ pub async fn save_entry(state: State<'_, AppState>, id: String) -> Result<(), St
 /// Get counters
 #[tauri::command]
 #[specta::specta]
-pub async fn get_counters(state: State<'_, AppState>) -> Result<crate::miniflux::Counters, String> {
+pub async fn get_counters(
+    state: State<'_, AppState>,
+) -> Result<crate::miniflux::Counters, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     client.get_counters().await
 }
@@ -827,9 +1273,9 @@ pub async fn get_counters(state: State<'_, AppState>) -> Result<crate::miniflux:
 pub async fn discover_subscriptions(
     state: State<'_, AppState>,
     url: String,
-) -> Result<Vec<crate::miniflux::Subscription>, String> {
+) -> Result<Vec<crate::miniflux::Subscription>, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     client.discover(url).await
 }
@@ -837,7 +1283,7 @@ pub async fn discover_subscriptions(
 /// Export OPML — tries online API first, falls back to generating from local cache
 #[tauri::command]
 #[specta::specta]
-pub async fn export_opml(state: State<'_, AppState>) -> Result<String, String> {
+pub async fn export_opml(state: State<'_, AppState>) -> Result<String, MinifluxError> {
     // Try online export first
     {
         let guard = state.miniflux.client.lock().await;
@@ -858,9 +1304,9 @@ pub async fn export_opml(state: State<'_, AppState>) -> Result<String, String> {
         .ok_or("Database not initialized")?
         .clone();
 
-    let user_id = get_active_user_id(&state).await?;
+    let account_id = get_active_account_id(&state).await?;
 
-    let feeds = get_feeds_from_db(&pool, user_id).await?;
+    let feeds = get_feeds_from_db(&pool, account_id).await?;
     Ok(generate_opml_from_feeds(&feeds))
 }
 
@@ -920,21 +1366,79 @@ fn escape_xml(s: &str) -> String {
 /// Import OPML
 #[tauri::command]
 #[specta::specta]
-pub async fn import_opml(state: State<'_, AppState>, opml_content: String) -> Result<(), String> {
+pub async fn import_opml(
+    state: State<'_, AppState>,
+    opml_content: String,
+) -> Result<(), MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     client.import_opml(opml_content).await
 }
 
+/// Export OPML from the server straight into `path` without buffering it in
+/// memory. Returns the number of bytes written.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_opml_to_file(
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<u64, MinifluxError> {
+    use futures_util::StreamExt;
+    use tokio::io::AsyncWriteExt;
+
+    let client = {
+        let guard = state.miniflux.client.lock().await;
+        guard.as_ref().cloned().ok_or(MinifluxError::NotConnected)?
+    };
+
+    let mut stream = Box::pin(client.export_opml_stream().await?);
+    let mut file = tokio::fs::File::create(&path)
+        .await
+        .map_err(|e| format!("Failed to create file: {}", e))?;
+
+    let mut written = 0u64;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        written += chunk.len() as u64;
+        file.write_all(&chunk)
+            .await
+            .map_err(|e| format!("Failed to write file: {}", e))?;
+    }
+    file.flush()
+        .await
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+
+    Ok(written)
+}
+
+/// Import OPML by streaming the file at `path` to the server
+#[tauri::command]
+#[specta::specta]
+pub async fn import_opml_from_file(
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<(), MinifluxError> {
+    let file = tokio::fs::File::open(&path)
+        .await
+        .map_err(|e| format!("Failed to open file: {}", e))?;
+
+    let client = {
+        let guard = state.miniflux.client.lock().await;
+        guard.as_ref().cloned().ok_or(MinifluxError::NotConnected)?
+    };
+
+    client.import_opml(file).await
+}
+
 /// Get Miniflux version information
 #[tauri::command]
 #[specta::specta]
 pub async fn get_miniflux_version(
     state: State<'_, AppState>,
-) -> Result<crate::miniflux::MinifluxVersion, String> {
+) -> Result<crate::miniflux::MinifluxVersion, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     client.get_version().await
 }
@@ -944,9 +1448,9 @@ pub async fn get_miniflux_version(
 #[specta::specta]
 pub async fn get_integrations(
     state: State<'_, AppState>,
-) -> Result<crate::miniflux::Integration, String> {
+) -> Result<crate::miniflux::Integration, MinifluxError> {
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     client.get_integrations().await
 }
@@ -958,18 +1462,13 @@ pub async fn fetch_entry_content(
     state: State<'_, AppState>,
     id: String,
     update_content: bool,
-) -> Result<String, String> {
+) -> Result<String, MinifluxError> {
     let client = {
         let guard = state.miniflux.client.lock().await;
-        guard
-            .as_ref()
-            .cloned()
-            .ok_or("Not connected to Miniflux server")?
+        guard.as_ref().cloned().ok_or(MinifluxError::NotConnected)?
     };
 
-    let id_parsed = id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid entry ID: {e}"))?;
+    let id_parsed = parse_id(&id, "entry")?;
 
     let fetched_content = client.fetch_content(id_parsed, update_content).await?;
 
@@ -981,7 +1480,8 @@ pub async fn fetch_entry_content(
         .ok_or("Database not initialized")?
         .clone();
 
-    update_entry_content_in_db(&pool, id_parsed, &fetched_content).await?;
+    let account_id = get_active_account_id(&state).await?;
+    update_entry_content_in_db(&pool, account_id, id_parsed, &fetched_content).await?;
 
     Ok(fetched_content)
 }
@@ -989,10 +1489,10 @@ pub async fn fetch_entry_content(
 /// Flush history (delete all read entries from Miniflux server)
 #[tauri::command]
 #[specta::specta]
-pub async fn flush_history(state: State<'_, AppState>) -> Result<(), String> {
+pub async fn flush_history(state: State<'_, AppState>) -> Result<(), MinifluxError> {
     log::info!("Flushing history from Miniflux server");
     let guard = state.miniflux.client.lock().await;
-    let client = guard.as_ref().ok_or("Not connected to Miniflux server")?;
+    let client = guard.as_ref().ok_or(MinifluxError::NotConnected)?;
 
     client.flush_history().await
 }
@@ -1015,10 +1515,8 @@ fn to_data_url(data: &str, mime_type: &str) -> String {
 pub async fn get_feed_icon_data(
     state: State<'_, AppState>,
     feed_id: String,
-) -> Result<Option<String>, String> {
-    let feed_id_parsed = feed_id
-        .parse::<i64>()
-        .map_err(|e| format!("Invalid feed ID: {e}"))?;
+) -> Result<Option<String>, MinifluxError> {
+    let feed_id_parsed = parse_id(&feed_id, "feed")?;
 
     let pool = state
         .db_pool
@@ -1028,12 +1526,16 @@ pub async fn get_feed_icon_data(
         .ok_or("Database not initialized")?
         .clone();
 
+    let account_id = get_active_account_id(&state).await?;
+
     // Check local cache first
-    let cached = sqlx::query("SELECT data, mime_type FROM icons WHERE feed_id = ?")
-        .bind(feed_id_parsed)
-        .fetch_optional(&pool)
-        .await
-        .map_err(|e| format!("{e}"))?;
+    let cached =
+        sqlx::query("SELECT data, mime_type FROM icons WHERE account_id = ? AND feed_id = ?")
+            .bind(account_id)
+            .bind(feed_id_parsed)
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e| format!("{e}"))?;
 
     if let Some(row) = cached {
         let data: String = row.get("data");
@@ -1052,9 +1554,9 @@ pub async fn get_feed_icon_data(
         Ok(icon) => {
             let now = chrono::Utc::now().to_rfc3339();
             let _ = sqlx::query(
-                "INSERT OR REPLACE INTO icons (id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+                "INSERT OR REPLACE INTO icons (account_id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?)",
             )
-            .bind(icon.id)
+            .bind(account_id)
             .bind(feed_id_parsed)
             .bind(icon.id)
             .bind(&icon.data)
@@ -1137,11 +1639,12 @@ pub async fn get_active_user_id(state: &AppState) -> Result<i64, String> {
         r#"
         SELECT user_id
         FROM entries
-        WHERE user_id IS NOT NULL
+        WHERE account_id = ? AND user_id IS NOT NULL
         ORDER BY datetime(created_at) DESC, id DESC
         LIMIT 1
         "#,
     )
+    .bind(account_id)
     .fetch_optional(&pool)
     .await
     .map_err(|e| format!("Failed to infer user id from local cache: {e}"))?;
@@ -1166,19 +1669,37 @@ pub async fn get_active_user_id(state: &AppState) -> Result<i64, String> {
     Err("Not connected to Miniflux server".to_string())
 }
 
+/// Local ID of the active account. The cache is keyed by it, since Miniflux
+/// IDs only identify rows within one server.
+pub async fn get_active_account_id(state: &AppState) -> Result<i64, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    sqlx::query_scalar("SELECT id FROM miniflux_connections WHERE is_active = 1 LIMIT 1")
+        .fetch_optional(&pool)
+        .await
+        .map_err(|e| format!("Failed to query active account: {e}"))?
+        .ok_or_else(|| "Not connected to Miniflux server".to_string())
+}
+
 pub async fn get_categories_from_db(
     pool: &SqlitePool,
-    user_id: i64,
+    account_id: i64,
 ) -> Result<Vec<crate::miniflux::Category>, String> {
     let rows = sqlx::query(
         r#"
         SELECT id, user_id, title, hide_globally, created_at, updated_at
         FROM categories
-        WHERE user_id = ?
+        WHERE account_id = ?
         ORDER BY title ASC
         "#,
     )
-    .bind(user_id)
+    .bind(account_id)
     .fetch_all(pool)
     .await
     .map_err(|e| format!("Failed to fetch categories: {e}"))?;
@@ -1200,7 +1721,7 @@ pub async fn get_categories_from_db(
 
 pub async fn get_feeds_from_db(
     pool: &SqlitePool,
-    user_id: i64,
+    account_id: i64,
 ) -> Result<Vec<crate::miniflux::Feed>, String> {
     let rows = sqlx::query(
         r#"
@@ -1215,12 +1736,12 @@ pub async fn get_feeds_from_db(
                c.hide_globally as cat_hide_globally, c.created_at as cat_created_at,
                c.updated_at as cat_updated_at
         FROM feeds f
-        LEFT JOIN categories c ON f.category_id = c.id
-        WHERE f.user_id = ?
+        LEFT JOIN categories c ON c.account_id = f.account_id AND c.id = f.category_id
+        WHERE f.account_id = ?
         ORDER BY f.title ASC
         "#,
     )
-    .bind(user_id)
+    .bind(account_id)
     .fetch_all(pool)
     .await
     .map_err(|e| format!("Failed to fetch feeds: {e}"))?;
@@ -1233,7 +1754,7 @@ pub async fn get_feeds_from_db(
 pub async fn get_category_feeds_from_db(
     pool: &SqlitePool,
     category_id: i64,
-    user_id: i64,
+    account_id: i64,
 ) -> Result<Vec<crate::miniflux::Feed>, String> {
     let rows = sqlx::query(
         r#"
@@ -1248,13 +1769,13 @@ pub async fn get_category_feeds_from_db(
                c.hide_globally as cat_hide_globally, c.created_at as cat_created_at,
                c.updated_at as cat_updated_at
         FROM feeds f
-        LEFT JOIN categories c ON f.category_id = c.id
-        WHERE f.category_id = ? AND f.user_id = ?
+        LEFT JOIN categories c ON c.account_id = f.account_id AND c.id = f.category_id
+        WHERE f.category_id = ? AND f.account_id = ?
         ORDER BY f.title ASC
         "#,
     )
     .bind(category_id)
-    .bind(user_id)
+    .bind(account_id)
     .fetch_all(pool)
     .await
     .map_err(|e| format!("Failed to fetch category feeds: {e}"))?;
@@ -1267,37 +1788,26 @@ pub async fn get_category_feeds_from_db(
 pub async fn get_entries_from_db(
     pool: &SqlitePool,
     filters: &EntryFilters,
-    user_id: i64,
+    account_id: i64,
 ) -> Result<crate::miniflux::EntryResponse, String> {
-    get_entries_from_db_with_projection(pool, filters, false, user_id).await
+    get_entries_from_db_with_projection(pool, filters, false, account_id).await
 }
 
 pub async fn get_entries_list_from_db(
     pool: &SqlitePool,
     filters: &EntryFilters,
-    user_id: i64,
+    account_id: i64,
 ) -> Result<crate::miniflux::EntryResponse, String> {
-    get_entries_from_db_with_projection(pool, filters, true, user_id).await
+    get_entries_from_db_with_projection(pool, filters, true, account_id).await
 }
 
 async fn get_entries_from_db_with_projection(
     pool: &SqlitePool,
     filters: &EntryFilters,
     use_content_preview: bool,
-    user_id: i64,
+    account_id: i64,
 ) -> Result<crate::miniflux::EntryResponse, String> {
-    let mut count_query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-        r#"
-        SELECT COUNT(*)
-        FROM entries e
-        JOIN feeds f ON e.feed_id = f.id
-        LEFT JOIN categories c ON f.category_id = c.id
-        WHERE e.user_id =
-        "#,
-    );
-
-    count_query.push_bind(user_id);
-    apply_entry_filters(&mut count_query, filters);
+    let mut count_query = entry_count_query(filters, account_id);
 
     let total: i64 = count_query
         .build_query_scalar()
@@ -1320,35 +1830,9 @@ async fn get_entries_from_db_with_projection(
         "e.content"
     };
 
-    let mut query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(format!(
-        r#"
-        SELECT e.id, e.user_id, e.feed_id, e.title, e.url, e.comments_url, e.author,
-               {content_select} as content, e.hash, e.published_at, e.created_at, e.changed_at, e.status,
-               e.share_code, e.starred, e.reading_time,
-               f.id as f_id, f.user_id as f_user_id, f.title as f_title, f.site_url as f_site_url,
-               f.feed_url as f_feed_url, f.category_id as f_category_id, f.checked_at as f_checked_at,
-               f.etag_header as f_etag_header, f.last_modified_header as f_last_modified_header,
-               f.parsing_error_message as f_parsing_error_message, f.parsing_error_count as f_parsing_error_count,
-               f.scraper_rules as f_scraper_rules, f.rewrite_rules as f_rewrite_rules,
-               f.crawler as f_crawler, f.blocklist_rules as f_blocklist_rules,
-               f.keeplist_rules as f_keeplist_rules, f.user_agent as f_user_agent,
-               f.username as f_username, f.password as f_password, f.disabled as f_disabled,
-               f.ignore_http_cache as f_ignore_http_cache, f.fetch_via_proxy as f_fetch_via_proxy,
-               f.no_media_player as f_no_media_player, f.allow_self_signed_certificates as f_allow_self_signed_certificates,
-               f.urlrewrite_rules as f_urlrewrite_rules, f.cookie as f_cookie,
-               f.apprise_service_urls as f_apprise_service_urls, f.hide_globally as f_hide_globally,
-               f.created_at as f_created_at, f.updated_at as f_updated_at,
-               c.id as c_id, c.user_id as c_user_id, c.title as c_title,
-               c.hide_globally as c_hide_globally, c.created_at as c_created_at,
-               c.updated_at as c_updated_at
-        FROM entries e
-        JOIN feeds f ON e.feed_id = f.id
-        LEFT JOIN categories c ON f.category_id = c.id
-        WHERE e.user_id =
-        "#,
-    ));
+    let mut query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(entry_select(content_select));
 
-    query.push_bind(user_id);
+    push_entry_owner(&mut query, filters, account_id);
     apply_entry_filters(&mut query, filters);
 
     // Respect requested sort; whitelist columns so nothing user-controlled
@@ -1378,6 +1862,7 @@ async fn get_entries_from_db_with_projection(
 
     // Load enclosures for all entries in a single batch query
     load_enclosures_for_entries(pool, &mut entries).await?;
+    crate::commands::tags::load_tags_for_entries(pool, &mut entries).await?;
 
     Ok(crate::miniflux::EntryResponse {
         total,
@@ -1385,7 +1870,103 @@ async fn get_entries_from_db_with_projection(
     })
 }
 
+/// `SELECT` of entry rows with their feed and category, as read by
+/// `build_entry_from_row`, ready for the `WHERE` conditions.
+fn entry_select(content_select: &str) -> String {
+    format!(
+        r#"
+        SELECT e.id, e.user_id, e.feed_id, e.title, e.url, e.comments_url, e.author,
+               {content_select} as content, e.hash, e.published_at, e.created_at, e.changed_at, e.status,
+               e.share_code, e.starred, e.reading_time, e.account_id,
+               f.id as f_id, f.user_id as f_user_id, f.title as f_title, f.site_url as f_site_url,
+               f.feed_url as f_feed_url, f.category_id as f_category_id, f.checked_at as f_checked_at,
+               f.etag_header as f_etag_header, f.last_modified_header as f_last_modified_header,
+               f.parsing_error_message as f_parsing_error_message, f.parsing_error_count as f_parsing_error_count,
+               f.scraper_rules as f_scraper_rules, f.rewrite_rules as f_rewrite_rules,
+               f.crawler as f_crawler, f.blocklist_rules as f_blocklist_rules,
+               f.keeplist_rules as f_keeplist_rules, f.user_agent as f_user_agent,
+               f.username as f_username, f.password as f_password, f.disabled as f_disabled,
+               f.ignore_http_cache as f_ignore_http_cache, f.fetch_via_proxy as f_fetch_via_proxy,
+               f.no_media_player as f_no_media_player, f.allow_self_signed_certificates as f_allow_self_signed_certificates,
+               f.urlrewrite_rules as f_urlrewrite_rules, f.cookie as f_cookie,
+               f.apprise_service_urls as f_apprise_service_urls, f.hide_globally as f_hide_globally,
+               f.created_at as f_created_at, f.updated_at as f_updated_at,
+               c.id as c_id, c.user_id as c_user_id, c.title as c_title,
+               c.hide_globally as c_hide_globally, c.created_at as c_created_at,
+               c.updated_at as c_updated_at
+        FROM entries e
+        JOIN feeds f ON f.account_id = e.account_id AND f.id = e.feed_id
+        LEFT JOIN categories c ON c.account_id = f.account_id AND c.id = f.category_id
+        WHERE
+        "#,
+    )
+}
+
+/// A page of the account's entries, newest first, including entries hidden by
+/// filter rules. Enclosures and tags are not loaded.
+pub(crate) async fn get_all_entries_page_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<crate::miniflux::Entry>, String> {
+    let mut query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(entry_select("e.content"));
+    query.push(" e.account_id = ");
+    query.push_bind(account_id);
+    query.push(" ORDER BY e.published_at DESC, e.id DESC LIMIT ");
+    query.push_bind(limit);
+    query.push(" OFFSET ");
+    query.push_bind(offset);
+
+    let rows = query
+        .build()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to fetch entries: {e}"))?;
+
+    Ok(rows.iter().map(build_entry_from_row).collect())
+}
+
+/// `SELECT COUNT(*)` over the account's entries matching `filters`, ready for
+/// further conditions.
+pub(crate) fn entry_count_query(
+    filters: &EntryFilters,
+    account_id: i64,
+) -> QueryBuilder<'static, sqlx::Sqlite> {
+    let mut query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        r#"
+        SELECT COUNT(*)
+        FROM entries e
+        JOIN feeds f ON f.account_id = e.account_id AND f.id = e.feed_id
+        LEFT JOIN categories c ON c.account_id = f.account_id AND c.id = f.category_id
+        WHERE
+        "#,
+    );
+
+    push_entry_owner(&mut query, filters, account_id);
+    apply_entry_filters(&mut query, filters);
+    query
+}
+
+/// Limits `e` to the active account's entries, or with `all_accounts` to
+/// those of any configured account.
+fn push_entry_owner(
+    query: &mut QueryBuilder<sqlx::Sqlite>,
+    filters: &EntryFilters,
+    account_id: i64,
+) {
+    if filters.all_accounts == Some(true) {
+        query.push(" e.account_id IN (SELECT id FROM miniflux_connections)");
+    } else {
+        query.push(" e.account_id = ");
+        query.push_bind(account_id);
+    }
+}
+
 fn apply_entry_filters(query: &mut QueryBuilder<sqlx::Sqlite>, filters: &EntryFilters) {
+    // Entries hidden by a filter rule stay out of lists and counts
+    query.push(" AND e.hidden_by_rule IS NULL");
+
     if let Some(status) = &filters.status {
         query.push(" AND e.status = ");
         query.push_bind(status.clone());
@@ -1406,12 +1987,39 @@ fn apply_entry_filters(query: &mut QueryBuilder<sqlx::Sqlite>, filters: &EntryFi
         query.push_bind(feed_id);
     }
 
-    if let Some(search) = &filters.search {
-        let like_pattern = format!("%{search}%");
-        query.push(" AND (e.title LIKE ");
-        query.push_bind(like_pattern.clone());
-        query.push(" OR e.content LIKE ");
-        query.push_bind(like_pattern);
+    if let Some(search) = filters
+        .search
+        .as_deref()
+        .filter(|search| !search.trim().is_empty())
+    {
+        match crate::database::fts::build_fts_query(search) {
+            Some(match_query) => {
+                query.push(
+                    " AND e.local_id IN (SELECT rowid FROM entries_fts WHERE entries_fts MATCH ",
+                );
+                query.push_bind(match_query);
+                query.push(")");
+            }
+            // Only punctuation or operators: nothing can match
+            None => {
+                query.push(" AND 0");
+            }
+        }
+    }
+
+    if let Some(tags) = filters.tags.as_deref().filter(|tags| !tags.is_empty()) {
+        let tags: std::collections::BTreeSet<&String> = tags.iter().collect();
+        // Entries must carry every requested tag
+        query.push(
+            " AND (e.account_id, e.id) IN (SELECT account_id, entry_id FROM tags WHERE tag IN (",
+        );
+        let mut separated = query.separated(", ");
+        for tag in &tags {
+            separated.push_bind((*tag).clone());
+        }
+        separated.push_unseparated(")");
+        query.push(" GROUP BY account_id, entry_id HAVING COUNT(*) = ");
+        query.push_bind(tags.len() as i64);
         query.push(")");
     }
 
@@ -1434,13 +2042,14 @@ fn apply_entry_filters(query: &mut QueryBuilder<sqlx::Sqlite>, filters: &EntryFi
 
 pub async fn get_entry_from_db(
     pool: &SqlitePool,
+    account_id: i64,
     id: i64,
 ) -> Result<crate::miniflux::Entry, String> {
     let row = sqlx::query(
         r#"
         SELECT e.id, e.user_id, e.feed_id, e.title, e.url, e.comments_url, e.author,
                e.content, e.hash, e.published_at, e.created_at, e.changed_at, e.status,
-               e.share_code, e.starred, e.reading_time,
+               e.share_code, e.starred, e.reading_time, e.account_id,
                f.id as f_id, f.user_id as f_user_id, f.title as f_title, f.site_url as f_site_url,
                f.feed_url as f_feed_url, f.category_id as f_category_id, f.checked_at as f_checked_at,
                f.etag_header as f_etag_header, f.last_modified_header as f_last_modified_header,
@@ -1458,11 +2067,12 @@ pub async fn get_entry_from_db(
                c.hide_globally as c_hide_globally, c.created_at as c_created_at,
                c.updated_at as c_updated_at
         FROM entries e
-        JOIN feeds f ON e.feed_id = f.id
-        LEFT JOIN categories c ON f.category_id = c.id
-        WHERE e.id = ?
+        JOIN feeds f ON f.account_id = e.account_id AND f.id = e.feed_id
+        LEFT JOIN categories c ON c.account_id = f.account_id AND c.id = f.category_id
+        WHERE e.account_id = ? AND e.id = ?
         "#,
     )
+    .bind(account_id)
     .bind(id)
     .fetch_optional(pool)
     .await
@@ -1471,16 +2081,19 @@ pub async fn get_entry_from_db(
 
     let mut entries = vec![build_entry_from_row(&row)];
     load_enclosures_for_entries(pool, &mut entries).await?;
+    crate::commands::tags::load_tags_for_entries(pool, &mut entries).await?;
     Ok(entries.remove(0))
 }
 
 async fn update_entry_content_in_db(
     pool: &SqlitePool,
+    account_id: i64,
     entry_id: i64,
     content: &str,
 ) -> Result<(), String> {
-    let result = sqlx::query("UPDATE entries SET content = ? WHERE id = ?")
+    let result = sqlx::query("UPDATE entries SET content = ? WHERE account_id = ? AND id = ?")
         .bind(content)
+        .bind(account_id)
         .bind(entry_id)
         .execute(pool)
         .await
@@ -1492,6 +2105,10 @@ async fn update_entry_content_in_db(
         ));
     }
 
+    crate::database::fts::refresh_entries_fts(pool, account_id, &[entry_id])
+        .await
+        .map_err(|e| format!("Failed to update search index: {e}"))?;
+
     Ok(())
 }
 
@@ -1550,27 +2167,32 @@ async fn load_enclosures_for_entries(
         return Ok(());
     }
 
-    let entry_ids: Vec<i64> = entries.iter().map(|e| e.id).collect();
-    let placeholders: Vec<String> = entry_ids.iter().map(|_| "?".to_string()).collect();
-    let query_str = format!(
-        "SELECT id, entry_id, url, mime_type, length, position FROM enclosures WHERE entry_id IN ({})",
-        placeholders.join(",")
+    let mut query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        "SELECT account_id, id, entry_id, url, mime_type, length, position, COALESCE(media_progression, 0) AS media_progression FROM enclosures WHERE (account_id, entry_id) IN (VALUES ",
     );
-
-    let mut query = sqlx::query(&query_str);
-    for id in &entry_ids {
-        query = query.bind(id);
+    let mut separated = query.separated(", ");
+    for entry in entries.iter() {
+        separated.push("(");
+        separated.push_bind_unseparated(entry.account_id);
+        separated.push_unseparated(", ");
+        separated.push_bind_unseparated(entry.id);
+        separated.push_unseparated(")");
     }
+    query.push(")");
 
     let rows = query
+        .build()
         .fetch_all(pool)
         .await
         .map_err(|e| format!("Failed to fetch enclosures: {e}"))?;
 
-    // Group enclosures by entry_id
-    let mut enc_map: std::collections::HashMap<i64, Vec<crate::miniflux::Enclosure>> =
-        std::collections::HashMap::new();
+    // Group enclosures by entry
+    let mut enc_map: std::collections::HashMap<
+        (Option<i64>, i64),
+        Vec<crate::miniflux::Enclosure>,
+    > = std::collections::HashMap::new();
     for row in &rows {
+        let account_id: Option<i64> = row.get("account_id");
         let entry_id: i64 = row.get("entry_id");
         let enc = crate::miniflux::Enclosure {
             id: row.get("id"),
@@ -1579,13 +2201,14 @@ async fn load_enclosures_for_entries(
             mime_type: row.get("mime_type"),
             length: row.get("length"),
             position: row.get("position"),
+            media_progression: row.get("media_progression"),
         };
-        enc_map.entry(entry_id).or_default().push(enc);
+        enc_map.entry((account_id, entry_id)).or_default().push(enc);
     }
 
     // Attach enclosures to entries
     for entry in entries.iter_mut() {
-        if let Some(encs) = enc_map.remove(&entry.id) {
+        if let Some(encs) = enc_map.remove(&(entry.account_id, entry.id)) {
             entry.enclosures = Some(encs);
         }
     }
@@ -1659,6 +2282,7 @@ fn build_entry_from_row(row: &sqlx::sqlite::SqliteRow) -> crate::miniflux::Entry
         enclosures: None,
         feed,
         tags: None,
+        account_id: row.get("account_id"),
     }
 }
 
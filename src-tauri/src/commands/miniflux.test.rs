@@ -27,14 +27,15 @@ mod tests {
                 user_id: Arc::new(Mutex::new(None)),
             },
             cloud_sync_notify: Arc::new(Notify::new()),
+            sync_scheduler: crate::sync_scheduler::SyncSchedulerState::default(),
         }
     }
 
     async fn insert_category(pool: &SqlitePool, id: i64, title: &str, now: &str) {
         sqlx::query(
             r#"
-            INSERT INTO categories (id, user_id, title, hide_globally, created_at, updated_at)
-            VALUES (?, 1, ?, false, ?, ?)
+            INSERT INTO categories (account_id, id, user_id, title, hide_globally, created_at, updated_at)
+            VALUES (1, ?, 1, ?, false, ?, ?)
             "#,
         )
         .bind(id)
@@ -57,8 +58,8 @@ mod tests {
     ) {
         sqlx::query(
             r#"
-            INSERT INTO feeds (id, user_id, title, site_url, feed_url, category_id, created_at, updated_at)
-            VALUES (?, 1, ?, ?, ?, ?, ?, ?)
+            INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, category_id, created_at, updated_at)
+            VALUES (1, ?, 1, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(id)
@@ -85,8 +86,8 @@ mod tests {
     ) {
         sqlx::query(
             r#"
-            INSERT INTO entries (id, user_id, feed_id, title, url, hash, published_at, created_at, status, starred, content)
-            VALUES (?, 1, ?, ?, 'https://example.com/article', ?, ?, ?, ?, ?, ?)
+            INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status, starred, content)
+            VALUES (1, ?, 1, ?, ?, 'https://example.com/article', ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(id)
@@ -362,8 +363,8 @@ mod tests {
         .await;
         sqlx::query(
             r#"
-            INSERT INTO entries (id, user_id, feed_id, title, url, hash, published_at, created_at, status, starred, content)
-            VALUES (1, 42, 1, 'Offline Entry', 'https://example.com/offline', 'hash-offline', ?, ?, 'unread', 0, 'content')
+            INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status, starred, content)
+            VALUES (1, 1, 42, 1, 'Offline Entry', 'https://example.com/offline', 'hash-offline', ?, ?, 'unread', 0, 'content')
             "#,
         )
         .bind(&now)
@@ -459,8 +460,8 @@ mod tests {
         .await;
         sqlx::query(
             r#"
-            INSERT INTO entries (id, user_id, feed_id, title, url, hash, published_at, created_at, status, starred, content)
-            VALUES (1, 11, 1, 'Entry A', 'https://example.com/a', 'hash-a', ?, ?, 'unread', 0, 'content')
+            INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status, starred, content)
+            VALUES (1, 1, 11, 1, 'Entry A', 'https://example.com/a', 'hash-a', ?, ?, 'unread', 0, 'content')
             "#,
         )
         .bind(&now)
@@ -481,6 +482,47 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_entry_commands_act_on_the_given_account_or_the_active_one() {
+        let pool = setup_test_db().await;
+        let now = Utc::now().to_rfc3339();
+
+        sqlx::query(
+            r#"
+            INSERT INTO miniflux_connections (id, username, server_url, auth_method, is_active, created_at, updated_at, is_admin)
+            VALUES (1, 'account-a', 'https://a.example.com', 'token', 1, ?, ?, 0),
+                   (2, 'account-b', 'https://b.example.com', 'token', 0, ?, ?, 0)
+            "#,
+        )
+        .bind(&now)
+        .bind(&now)
+        .bind(&now)
+        .bind(&now)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert accounts");
+
+        let state = setup_app_state(pool.clone());
+
+        assert_eq!(super::super::entry_account_id(&state, None).await, Ok(1));
+        assert_eq!(
+            super::super::entry_account_id(&state, Some("2".to_string())).await,
+            Ok(2)
+        );
+        assert!(
+            super::super::entry_account_id(&state, Some("b".to_string()))
+                .await
+                .is_err()
+        );
+
+        // The active account's client isn't connected, so there is none to clone
+        let active = super::super::entry_account_client(&state, &pool, 1).await;
+        assert!(matches!(
+            active,
+            Err(crate::miniflux::MinifluxError::NotConnected)
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_entries_list_returns_content_preview_only() {
         let pool = setup_test_db().await;
@@ -538,7 +580,7 @@ mod tests {
     async fn test_get_entry_not_found() {
         let pool = setup_test_db().await;
 
-        let result = super::super::get_entry_from_db(&pool, 999).await;
+        let result = super::super::get_entry_from_db(&pool, 1, 999).await;
 
         assert!(result.is_err(), "Should error when entry not found");
     }
@@ -571,7 +613,7 @@ mod tests {
         )
         .await;
 
-        let entry = super::super::get_entry_from_db(&pool, 1)
+        let entry = super::super::get_entry_from_db(&pool, 1, 1)
             .await
             .expect("get_entry_from_db should not error");
 
@@ -617,7 +659,7 @@ mod tests {
             .await
             .expect("update_entry_content_in_db should not error");
 
-        let entry = super::super::get_entry_from_db(&pool, 1)
+        let entry = super::super::get_entry_from_db(&pool, 1, 1)
             .await
             .expect("get_entry_from_db should not error");
 
@@ -790,6 +832,9 @@ mod tests {
             Some("The Go language"),
         )
         .await;
+        crate::database::fts::refresh_entries_fts(&pool, 1, &[1, 2])
+            .await
+            .expect("Failed to index entries");
 
         let filters = EntryFilters {
             search: Some("Rust".to_string()),
@@ -803,6 +848,20 @@ mod tests {
         let entries = response.entries.expect("Should have entries");
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].title, "Rust Article");
+
+        let filters = EntryFilters {
+            search: Some("** \"\" -".to_string()),
+            ..EntryFilters::default()
+        };
+
+        let response = super::super::get_entries_from_db(&pool, &filters, 1)
+            .await
+            .expect("get_entries_from_db should not error");
+
+        assert_eq!(
+            response.total, 0,
+            "A search with nothing searchable should match nothing"
+        );
     }
 
     #[tokio::test]
@@ -895,7 +954,7 @@ mod tests {
         )
         .await;
 
-        super::super::mark_entries_read_in_db(&pool, &[1])
+        super::super::mark_entries_read_in_db(&pool, 1, &[1])
             .await
             .expect("mark_entries_read_in_db should not error");
 
@@ -945,7 +1004,7 @@ mod tests {
         .await;
         insert_entry(&pool, 1, 1, "Article", "unread", false, &now, None).await;
 
-        super::super::mark_entries_read_in_db(&pool, &[1])
+        super::super::mark_entries_read_in_db(&pool, 1, &[1])
             .await
             .expect("first mark should not error");
         let first: Option<String> =
@@ -954,7 +1013,7 @@ mod tests {
                 .await
                 .expect("Failed to read back entry");
 
-        super::super::mark_entries_read_in_db(&pool, &[1])
+        super::super::mark_entries_read_in_db(&pool, 1, &[1])
             .await
             .expect("second mark should not error");
         let second: Option<String> =
@@ -968,4 +1027,169 @@ mod tests {
             "Re-marking a read entry must not move it back to the top of History"
         );
     }
+
+    // ==================== mark_scope_read_in_db tests ====================
+
+    #[tokio::test]
+    async fn test_mark_scope_read_by_feed_and_category() {
+        let pool = setup_test_db().await;
+        let now = Utc::now().to_rfc3339();
+
+        insert_category(&pool, 1, "Technology", &now).await;
+        insert_category(&pool, 2, "News", &now).await;
+        insert_feed(
+            &pool,
+            1,
+            "Tech News",
+            "https://tech.example.com",
+            "https://tech.example.com/rss",
+            1,
+            &now,
+        )
+        .await;
+        insert_feed(
+            &pool,
+            2,
+            "World News",
+            "https://news.example.com",
+            "https://news.example.com/rss",
+            2,
+            &now,
+        )
+        .await;
+        insert_entry(&pool, 1, 1, "Tech 1", "unread", false, &now, None).await;
+        insert_entry(&pool, 2, 1, "Tech 2", "read", false, &now, None).await;
+        insert_entry(&pool, 3, 2, "News 1", "unread", false, &now, None).await;
+
+        let mut ids = super::super::mark_scope_read_in_db(
+            &pool,
+            1,
+            super::super::MarkReadScope::Feed(1),
+            None,
+        )
+        .await
+        .expect("mark feed read should not error");
+        ids.sort();
+        assert_eq!(
+            ids,
+            vec![1],
+            "Only unread entries of the feed should change"
+        );
+
+        let ids = super::super::mark_scope_read_in_db(
+            &pool,
+            1,
+            super::super::MarkReadScope::Category(2),
+            None,
+        )
+        .await
+        .expect("mark category read should not error");
+        assert_eq!(ids, vec![3]);
+
+        let unread: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE status = 'unread'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(unread, 0);
+
+        let stamped: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM entries WHERE id IN (1, 3) AND changed_at IS NOT NULL",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(stamped, 2, "changed_at should be stamped for History");
+    }
+
+    #[tokio::test]
+    async fn test_mark_scope_read_before_date_only_touches_older_entries() {
+        let pool = setup_test_db().await;
+        let now = Utc::now();
+        let now_str = now.to_rfc3339();
+        let old_publish = (now - Duration::days(10)).to_rfc3339();
+        let cutoff = (now - Duration::days(5)).to_rfc3339();
+
+        insert_category(&pool, 1, "Technology", &now_str).await;
+        insert_feed(
+            &pool,
+            1,
+            "Tech News",
+            "https://tech.example.com",
+            "https://tech.example.com/rss",
+            1,
+            &now_str,
+        )
+        .await;
+        insert_entry(&pool, 1, 1, "Old", "unread", false, &old_publish, None).await;
+        insert_entry(&pool, 2, 1, "New", "unread", false, &now_str, None).await;
+
+        let ids = super::super::mark_scope_read_in_db(
+            &pool,
+            1,
+            super::super::MarkReadScope::All,
+            Some(&cutoff),
+        )
+        .await
+        .expect("mark read before should not error");
+        assert_eq!(ids, vec![1]);
+
+        let status: String = sqlx::query_scalar("SELECT status FROM entries WHERE id = 2")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(status, "unread");
+    }
+
+    #[tokio::test]
+    async fn test_mark_scope_read_before_compares_instants_across_offsets() {
+        let pool = setup_test_db().await;
+        let now = Utc::now().to_rfc3339();
+
+        insert_category(&pool, 1, "Technology", &now).await;
+        insert_feed(
+            &pool,
+            1,
+            "Tech News",
+            "https://tech.example.com",
+            "https://tech.example.com/rss",
+            1,
+            &now,
+        )
+        .await;
+        // 15:00 UTC, after the cutoff even though its text sorts first
+        insert_entry(
+            &pool,
+            1,
+            1,
+            "Later",
+            "unread",
+            false,
+            "2026-10-16T10:00:00-05:00",
+            None,
+        )
+        .await;
+        // 09:00 UTC, before the cutoff even though its text sorts last
+        insert_entry(
+            &pool,
+            2,
+            1,
+            "Earlier",
+            "unread",
+            false,
+            "2026-10-16T18:00:00+09:00",
+            None,
+        )
+        .await;
+
+        let ids = super::super::mark_scope_read_in_db(
+            &pool,
+            1,
+            super::super::MarkReadScope::All,
+            Some("2026-10-16T12:00:00+00:00"),
+        )
+        .await
+        .expect("mark read before should not error");
+        assert_eq!(ids, vec![2]);
+    }
 }
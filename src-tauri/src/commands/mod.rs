@@ -4,11 +4,14 @@
 //! Import specific commands via their submodule (e.g., `commands::preferences::greet`).
 
 pub mod accounts;
+pub mod cloud_snapshots;
 pub mod cloud_sync;
 pub mod counters;
 pub mod data;
 pub mod downloads;
+pub mod filter_rules;
 pub mod in_app_browser;
+pub mod library_sync;
 pub mod miniflux;
 pub mod notifications;
 #[allow(clippy::unused_unit)]
@@ -18,8 +21,13 @@ pub mod preferences;
 pub mod quick_pane;
 pub mod reading_state;
 pub mod recovery;
+pub mod retention;
+pub mod search;
+pub mod smart_folders;
 pub mod summarize;
 pub mod sync;
+pub mod sync_history;
+pub mod tags;
 pub mod translation;
 pub mod translation_cache;
 pub mod tray;
@@ -2,7 +2,7 @@
 //!
 //! Provides cross-platform native notification support using the Tauri notification plugin.
 
-use tauri::AppHandle;
+use tauri::{AppHandle, Runtime};
 
 /// Sends a native system notification.
 /// On mobile platforms, returns an error as notifications are not yet supported.
@@ -14,7 +14,15 @@ pub async fn send_native_notification(
     body: Option<String>,
 ) -> Result<(), String> {
     log::info!("Sending native notification: {title}");
+    show_notification(&app, title, body)
+}
 
+/// Shows a native notification from backend code.
+pub(crate) fn show_notification<R: Runtime>(
+    app: &AppHandle<R>,
+    title: String,
+    body: Option<String>,
+) -> Result<(), String> {
     #[cfg(not(mobile))]
     {
         use tauri_plugin_notification::NotificationExt;
@@ -39,7 +47,7 @@ pub async fn send_native_notification(
 
     #[cfg(mobile)]
     {
-        let _ = (app, body);
+        let _ = (app, title, body);
         log::warn!("Native notifications not supported on mobile");
         Err("Native notifications not supported on mobile".to_string())
     }
@@ -1,11 +1,27 @@
 //! Podcast playback and feed settings management
-
+//!
+//! Playback progress is mirrored to the `media_progression` of the episode's
+//! enclosure on Miniflux: saves are queued and pushed after a short debounce,
+//! and each sync pulls the server's progression of episodes in progress back
+//! in.
+
+use crate::commands::miniflux::get_active_account_id;
+use crate::commands::sync::{
+    complete_sync_operations, enqueue_sync_operation, push_queued_operation,
+};
 use crate::miniflux::types::{CleanupResult, PodcastFeedSettings, PodcastProgress};
+use crate::miniflux::MinifluxClient;
 use crate::AppState;
-use chrono::Utc;
-use sqlx::Row;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool};
+use std::collections::HashSet;
+use std::time::Duration;
 use tauri::Manager;
 
+/// Quiet period after a progress save before it is pushed to Miniflux, so a
+/// burst of saves (seek, pause, dismiss) results in a single request
+const PROGRESS_PUSH_DELAY: Duration = Duration::from_secs(5);
+
 /// Get the entry_id for an enclosure by its URL (for linking downloads to player)
 #[tauri::command]
 #[specta::specta]
@@ -22,11 +38,15 @@ pub async fn get_entry_id_by_enclosure_url(
         .ok_or("Database not initialized")?
         .clone();
 
-    let row = sqlx::query("SELECT entry_id FROM enclosures WHERE url = ? LIMIT 1")
-        .bind(&url)
-        .fetch_optional(&pool)
-        .await
-        .map_err(|e| format!("{e}"))?;
+    let account_id = get_active_account_id(&state).await?;
+
+    let row =
+        sqlx::query("SELECT entry_id FROM enclosures WHERE account_id = ? AND url = ? LIMIT 1")
+            .bind(account_id)
+            .bind(&url)
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e| format!("{e}"))?;
 
     match row {
         Some(r) => {
@@ -138,18 +158,20 @@ pub async fn save_podcast_progress(
         .as_ref()
         .ok_or("Database not initialized")?
         .clone();
+    let account_id = get_active_account_id(&state).await?;
     let now = Utc::now().to_rfc3339();
 
     sqlx::query(
         r#"
-        INSERT INTO podcast_progress (entry_id, "current_time", total_time, completed, last_played_at)
-        VALUES (?, ?, ?, FALSE, ?)
-        ON CONFLICT(entry_id) DO UPDATE SET
+        INSERT INTO podcast_progress (account_id, entry_id, "current_time", total_time, completed, last_played_at)
+        VALUES (?, ?, ?, ?, FALSE, ?)
+        ON CONFLICT(account_id, entry_id) DO UPDATE SET
             "current_time" = excluded."current_time",
             total_time = excluded.total_time,
             last_played_at = excluded.last_played_at
         "#,
     )
+    .bind(account_id)
     .bind(entry_id)
     .bind(current_time)
     .bind(total_time)
@@ -158,9 +180,303 @@ pub async fn save_podcast_progress(
     .await
     .map_err(|e| format!("{e}"))?;
 
+    let Some(queue_id) = queue_progress_push(&pool, account_id, entry_id, current_time).await?
+    else {
+        return Ok(());
+    };
+
+    // A later save supersedes this queue row, so only the last save of a burst
+    // is still pending when its delay runs out
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(PROGRESS_PUSH_DELAY).await;
+
+        let state: tauri::State<'_, AppState> = app.state();
+        if !state
+            .sync_scheduler
+            .online
+            .load(std::sync::atomic::Ordering::Acquire)
+        {
+            return;
+        }
+        // After an account switch the connected client belongs to another
+        // server; the next sync of this account pushes the change instead
+        if get_active_account_id(&state).await != Ok(account_id) {
+            return;
+        }
+        let Some(client) = state.miniflux.client.lock().await.clone() else {
+            return;
+        };
+
+        if let Err(e) = push_queued_operation(&pool, &client, queue_id).await {
+            log::warn!("Failed to push podcast progress for entry {entry_id}: {e}");
+        }
+    });
+
+    Ok(())
+}
+
+/// The enclosure whose `media_progression` tracks an entry's playback
+async fn find_media_enclosure(
+    pool: &SqlitePool,
+    account_id: i64,
+    entry_id: i64,
+) -> Result<Option<i64>, String> {
+    sqlx::query_scalar(
+        "SELECT id FROM enclosures WHERE account_id = ? AND entry_id = ? AND (mime_type LIKE 'audio/%' OR mime_type LIKE 'video/%') ORDER BY id LIMIT 1",
+    )
+    .bind(account_id)
+    .bind(entry_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to look up enclosure: {e}"))
+}
+
+/// Queues the playback position for the entry's enclosure. Returns `None` when
+/// the entry has no media enclosure to sync it to.
+pub(crate) async fn queue_progress_push(
+    pool: &SqlitePool,
+    account_id: i64,
+    entry_id: i64,
+    current_time: i32,
+) -> Result<Option<i64>, String> {
+    let Some(enclosure_id) = find_media_enclosure(pool, account_id, entry_id).await? else {
+        return Ok(None);
+    };
+
+    let payload = serde_json::json!({ "media_progression": current_time }).to_string();
+    let queue_id = enqueue_sync_operation(
+        pool,
+        account_id,
+        "enclosure",
+        enclosure_id,
+        "set_media_progression",
+        &payload,
+    )
+    .await
+    .map_err(|e| format!("Failed to queue podcast progress: {e}"))?;
+
+    Ok(Some(queue_id))
+}
+
+/// Remembers what the server holds after a successful push, so the next pull
+/// can tell whether another device has moved the position since.
+pub(crate) async fn record_remote_progression(
+    pool: &SqlitePool,
+    account_id: i64,
+    enclosure_id: i64,
+    media_progression: i64,
+) -> Result<(), String> {
+    sqlx::query("UPDATE enclosures SET media_progression = ? WHERE account_id = ? AND id = ?")
+        .bind(media_progression)
+        .bind(account_id)
+        .bind(enclosure_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to record enclosure progression: {e}"))?;
+
+    sqlx::query(
+        "UPDATE podcast_progress SET remote_time = ? WHERE account_id = ? AND entry_id = (SELECT entry_id FROM enclosures WHERE account_id = ? AND id = ?)",
+    )
+    .bind(media_progression)
+    .bind(account_id)
+    .bind(account_id)
+    .bind(enclosure_id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to record remote podcast progress: {e}"))?;
+
     Ok(())
 }
 
+fn is_media_mime_type(mime_type: &str) -> bool {
+    mime_type.starts_with("audio/") || mime_type.starts_with("video/")
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+/// An episode's playback position as read from the server
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ServerProgression {
+    pub entry_id: i64,
+    pub enclosure_id: i64,
+    pub media_progression: i64,
+}
+
+/// The server positions of the media enclosures in pulled entries
+pub(crate) fn pulled_progressions(entries: &[crate::miniflux::Entry]) -> Vec<ServerProgression> {
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let enclosure = entry
+                .enclosures
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .filter(|enclosure| is_media_mime_type(&enclosure.mime_type))
+                .min_by_key(|enclosure| enclosure.id)?;
+            Some(ServerProgression {
+                entry_id: entry.id,
+                enclosure_id: enclosure.id,
+                media_progression: enclosure.media_progression,
+            })
+        })
+        .collect()
+}
+
+/// Reads the server position of every unfinished episode of the account that
+/// has local progress and merges it in. Episodes whose entries are in
+/// `pulled_entry_ids` are skipped, as their position came with the entry.
+///
+/// Miniflux doesn't touch an entry's `changed_at` when only the progression
+/// moves, so incremental entry pulls never see another device's position.
+pub(crate) async fn pull_episode_progressions(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    account_id: i64,
+    pulled_entry_ids: &HashSet<i64>,
+) -> Result<u32, String> {
+    let episodes: Vec<(i64, i64)> = sqlx::query_as(
+        r#"
+        SELECT p.entry_id, MIN(enc.id)
+        FROM podcast_progress p
+        JOIN enclosures enc ON enc.account_id = p.account_id AND enc.entry_id = p.entry_id
+        WHERE p.account_id = ? AND p.completed = FALSE
+          AND (enc.mime_type LIKE 'audio/%' OR enc.mime_type LIKE 'video/%')
+        GROUP BY p.entry_id
+        "#,
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to load episodes in progress: {e}"))?;
+
+    let mut progressions = Vec::with_capacity(episodes.len());
+    for (entry_id, enclosure_id) in episodes {
+        if pulled_entry_ids.contains(&entry_id) {
+            continue;
+        }
+        match client.get_enclosure(enclosure_id).await {
+            Ok(enclosure) => progressions.push(ServerProgression {
+                entry_id,
+                enclosure_id,
+                media_progression: enclosure.media_progression,
+            }),
+            Err(e) => log::warn!("Failed to fetch progression of enclosure {enclosure_id}: {e}"),
+        }
+    }
+
+    merge_remote_progression(pool, account_id, &progressions).await
+}
+
+/// Merges server positions into `podcast_progress`.
+///
+/// `remote_time` is the position the server held at the last push or pull. A
+/// server position equal to it means the server hasn't moved, and a local
+/// save (if any) is still to be pushed. Otherwise another device moved it: the
+/// server position is taken over unless a local save is still queued. When
+/// both moved, the server change was made after the account's last completed
+/// sync saw it unchanged, so the local save wins if it was played after that
+/// (last writer wins on `last_played_at`).
+pub(crate) async fn merge_remote_progression(
+    pool: &SqlitePool,
+    account_id: i64,
+    progressions: &[ServerProgression],
+) -> Result<u32, String> {
+    let now = Utc::now().to_rfc3339();
+    let last_sync_at: Option<String> =
+        sqlx::query_scalar("SELECT last_sync_at FROM sync_state WHERE account_id = ?")
+            .bind(account_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("Failed to load sync state: {e}"))?
+            .flatten();
+    let server_unchanged_at = last_sync_at.as_deref().and_then(parse_timestamp);
+    let mut merged = 0;
+
+    for progression in progressions {
+        let remote_time = progression.media_progression;
+
+        let local = sqlx::query(
+            r#"SELECT "current_time", last_played_at, remote_time FROM podcast_progress WHERE account_id = ? AND entry_id = ?"#,
+        )
+        .bind(account_id)
+        .bind(progression.entry_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("Failed to load podcast progress: {e}"))?;
+
+        let Some(local) = local else {
+            if remote_time > 0 {
+                sqlx::query(
+                    r#"INSERT INTO podcast_progress (account_id, entry_id, "current_time", total_time, completed, last_played_at, remote_time) VALUES (?, ?, ?, 0, FALSE, ?, ?)"#,
+                )
+                .bind(account_id)
+                .bind(progression.entry_id)
+                .bind(remote_time)
+                .bind(&now)
+                .bind(remote_time)
+                .execute(pool)
+                .await
+                .map_err(|e| format!("Failed to insert podcast progress: {e}"))?;
+                merged += 1;
+            }
+            continue;
+        };
+
+        let local_time: i64 = local.get("current_time");
+        let baseline: Option<i64> = local.get("remote_time");
+        if baseline == Some(remote_time) {
+            continue;
+        }
+        if local_time == remote_time {
+            record_remote_progression(pool, account_id, progression.enclosure_id, remote_time)
+                .await?;
+            continue;
+        }
+
+        let pending: Option<i64> = sqlx::query_scalar(
+            "SELECT id FROM sync_queue WHERE account_id = ? AND entity_type = 'enclosure' AND entity_id = ? AND operation_type = 'set_media_progression' AND status = 'pending'",
+        )
+        .bind(account_id)
+        .bind(progression.enclosure_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("Failed to check queued podcast progress: {e}"))?;
+
+        let local_played_at = parse_timestamp(&local.get::<String, _>("last_played_at"));
+        let local_wins = pending.is_some()
+            && local_played_at.is_some_and(|played| {
+                server_unchanged_at.is_none_or(|unchanged| played > unchanged)
+            });
+        if local_wins {
+            continue;
+        }
+
+        sqlx::query(
+            r#"UPDATE podcast_progress SET "current_time" = ?, last_played_at = ?, remote_time = ?, completed = FALSE WHERE account_id = ? AND entry_id = ?"#,
+        )
+        .bind(remote_time)
+        .bind(&now)
+        .bind(remote_time)
+        .bind(account_id)
+        .bind(progression.entry_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to update podcast progress: {e}"))?;
+
+        if let Some(queue_id) = pending {
+            complete_sync_operations(pool, &[queue_id]).await?;
+        }
+        merged += 1;
+    }
+
+    Ok(merged)
+}
+
 /// Get podcast progress for a single entry
 #[tauri::command]
 #[specta::specta]
@@ -176,10 +492,12 @@ pub async fn get_podcast_progress(
         .as_ref()
         .ok_or("Database not initialized")?
         .clone();
+    let account_id = get_active_account_id(&state).await?;
 
     let row = sqlx::query(
-        r#"SELECT entry_id, "current_time", total_time, completed, last_played_at FROM podcast_progress WHERE entry_id = ?"#,
+        r#"SELECT entry_id, "current_time", total_time, completed, last_played_at FROM podcast_progress WHERE account_id = ? AND entry_id = ?"#,
     )
+    .bind(account_id)
     .bind(entry_id)
     .fetch_optional(&pool)
     .await
@@ -216,14 +534,15 @@ pub async fn get_podcast_progress_batch(
         .as_ref()
         .ok_or("Database not initialized")?
         .clone();
+    let account_id = get_active_account_id(&state).await?;
 
     let placeholders: Vec<String> = entry_ids.iter().map(|_| "?".to_string()).collect();
     let query_str = format!(
-        r#"SELECT entry_id, "current_time", total_time, completed, last_played_at FROM podcast_progress WHERE entry_id IN ({})"#,
+        r#"SELECT entry_id, "current_time", total_time, completed, last_played_at FROM podcast_progress WHERE account_id = ? AND entry_id IN ({})"#,
         placeholders.join(",")
     );
 
-    let mut query = sqlx::query(&query_str);
+    let mut query = sqlx::query(&query_str).bind(account_id);
     for id in &entry_ids {
         query = query.bind(id);
     }
@@ -255,12 +574,14 @@ pub async fn mark_episode_completed(app: tauri::AppHandle, entry_id: i64) -> Res
         .as_ref()
         .ok_or("Database not initialized")?
         .clone();
+    let account_id = get_active_account_id(&state).await?;
     let now = Utc::now().to_rfc3339();
 
     sqlx::query(
-        "UPDATE podcast_progress SET completed = TRUE, last_played_at = ? WHERE entry_id = ?",
+        "UPDATE podcast_progress SET completed = TRUE, last_played_at = ? WHERE account_id = ? AND entry_id = ?",
     )
     .bind(&now)
+    .bind(account_id)
     .bind(entry_id)
     .execute(&pool)
     .await
@@ -285,6 +606,7 @@ pub async fn seed_e2e_test_data(app: tauri::AppHandle, entry_ids: Vec<i64>) -> R
         .as_ref()
         .ok_or("Database not initialized")?
         .clone();
+    let account_id = get_active_account_id(&state).await.unwrap_or(1);
     let now = Utc::now().to_rfc3339();
 
     // Clear stale test data that may have incorrect column types from previous runs
@@ -299,8 +621,9 @@ pub async fn seed_e2e_test_data(app: tauri::AppHandle, entry_ids: Vec<i64>) -> R
 
     // Create test category
     sqlx::query(
-        "INSERT OR IGNORE INTO categories (id, title, user_id, hide_globally, created_at, updated_at) VALUES (1, 'Test', 1, FALSE, ?, ?)",
+        "INSERT OR IGNORE INTO categories (account_id, id, title, user_id, hide_globally, created_at, updated_at) VALUES (?, 1, 'Test', 1, FALSE, ?, ?)",
     )
+    .bind(account_id)
     .bind(&now)
     .bind(&now)
     .execute(&pool)
@@ -309,9 +632,10 @@ pub async fn seed_e2e_test_data(app: tauri::AppHandle, entry_ids: Vec<i64>) -> R
 
     // Create test feed
     sqlx::query(
-        r#"INSERT OR IGNORE INTO feeds (id, user_id, feed_url, site_url, title, checked_at, category_id, created_at, updated_at)
-           VALUES (1, 1, 'https://test.example.com/feed.xml', 'https://test.example.com', 'Test Feed', ?, 1, ?, ?)"#,
+        r#"INSERT OR IGNORE INTO feeds (account_id, id, user_id, feed_url, site_url, title, checked_at, category_id, created_at, updated_at)
+           VALUES (?, 1, 1, 'https://test.example.com/feed.xml', 'https://test.example.com', 'Test Feed', ?, 1, ?, ?)"#,
     )
+    .bind(account_id)
     .bind(&now)
     .bind(&now)
     .bind(&now)
@@ -322,9 +646,10 @@ pub async fn seed_e2e_test_data(app: tauri::AppHandle, entry_ids: Vec<i64>) -> R
     // Create test entries
     for entry_id in &entry_ids {
         sqlx::query(
-            r#"INSERT OR IGNORE INTO entries (id, user_id, feed_id, status, hash, title, url, published_at, created_at, changed_at, content)
-               VALUES (?, 1, 1, 'read', ?, 'Test Entry', 'https://test.example.com/entry', ?, ?, ?, '')"#,
+            r#"INSERT OR IGNORE INTO entries (account_id, id, user_id, feed_id, status, hash, title, url, published_at, created_at, changed_at, content)
+               VALUES (?, ?, 1, 1, 'read', ?, 'Test Entry', 'https://test.example.com/entry', ?, ?, ?, '')"#,
         )
+        .bind(account_id)
         .bind(entry_id)
         .bind(format!("hash-{entry_id}"))
         .bind(&now)
@@ -354,10 +679,10 @@ pub async fn cleanup_played_episodes(app: tauri::AppHandle) -> Result<CleanupRes
     // Find completed episodes past their cleanup threshold
     let rows = sqlx::query(
         r#"
-        SELECT e.local_path, e.entry_id
+        SELECT e.local_path, e.account_id, e.entry_id
         FROM enclosures e
-        JOIN podcast_progress pp ON e.entry_id = pp.entry_id
-        JOIN entries ent ON e.entry_id = ent.id
+        JOIN podcast_progress pp ON pp.account_id = e.account_id AND pp.entry_id = e.entry_id
+        JOIN entries ent ON ent.account_id = e.account_id AND ent.id = e.entry_id
         LEFT JOIN podcast_feed_settings pfs ON ent.feed_id = pfs.feed_id
         WHERE pp.completed = TRUE
           AND e.downloaded = TRUE
@@ -375,6 +700,7 @@ pub async fn cleanup_played_episodes(app: tauri::AppHandle) -> Result<CleanupRes
 
     for row in &rows {
         let local_path: Option<String> = row.get("local_path");
+        let account_id: i64 = row.get("account_id");
         let entry_id: i64 = row.get("entry_id");
 
         if let Some(path) = local_path {
@@ -385,8 +711,9 @@ pub async fn cleanup_played_episodes(app: tauri::AppHandle) -> Result<CleanupRes
         }
 
         let _ = sqlx::query(
-            "UPDATE enclosures SET downloaded = FALSE, local_path = NULL, download_progress = 0 WHERE entry_id = ? AND mime_type LIKE 'audio/%'",
+            "UPDATE enclosures SET downloaded = FALSE, local_path = NULL, download_progress = 0 WHERE account_id = ? AND entry_id = ? AND mime_type LIKE 'audio/%'",
         )
+        .bind(account_id)
         .bind(entry_id)
         .execute(&pool)
         .await;
@@ -401,3 +728,7 @@ pub async fn cleanup_played_episodes(app: tauri::AppHandle) -> Result<CleanupRes
         freed_bytes,
     })
 }
+
+#[cfg(test)]
+#[path = "podcast.test.rs"]
+mod tests;
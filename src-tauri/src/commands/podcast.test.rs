@@ -0,0 +1,255 @@
+#[cfg(test)]
+mod tests {
+    use crate::commands::podcast::{
+        merge_remote_progression, pulled_progressions, queue_progress_push,
+        record_remote_progression, ServerProgression,
+    };
+    use crate::database::migrations::run_migrations;
+    use sqlx::{Row, SqlitePool};
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+        sqlx::query(
+            "INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (1, 100, 1, 'Feed', 'https://example.com', 'https://example.com/rss', '2026-02-11T00:00:00Z', '2026-02-11T00:00:00Z')",
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to insert feed");
+        pool
+    }
+
+    async fn insert_episode(pool: &SqlitePool, entry_id: i64, enclosure_id: i64) {
+        sqlx::query(
+            "INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (1, ?, 1, 100, 'Episode', 'https://example.com/episode', ?, '2026-02-11T00:00:00Z', '2026-02-11T00:00:00Z', 'unread')",
+        )
+        .bind(entry_id)
+        .bind(format!("hash-{entry_id}"))
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO enclosures (account_id, id, entry_id, url, mime_type, created_at) VALUES (1, ?, ?, 'https://example.com/episode.mp3', 'audio/mpeg', '2026-02-11T00:00:00Z')",
+        )
+        .bind(enclosure_id)
+        .bind(entry_id)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn insert_progress(pool: &SqlitePool, entry_id: i64, current_time: i64, played_at: &str) {
+        sqlx::query(
+            r#"INSERT INTO podcast_progress (account_id, entry_id, "current_time", total_time, completed, last_played_at) VALUES (1, ?, ?, 3600, FALSE, ?)"#,
+        )
+        .bind(entry_id)
+        .bind(current_time)
+        .bind(played_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn local_time(pool: &SqlitePool, entry_id: i64) -> Option<i64> {
+        sqlx::query(
+            r#"SELECT "current_time" FROM podcast_progress WHERE account_id = 1 AND entry_id = ?"#,
+        )
+        .bind(entry_id)
+        .fetch_optional(pool)
+        .await
+        .unwrap()
+        .map(|row| row.get("current_time"))
+    }
+
+    async fn pending_pushes(pool: &SqlitePool) -> i64 {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM sync_queue WHERE operation_type = 'set_media_progression' AND status = 'pending'",
+        )
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    async fn set_last_sync(pool: &SqlitePool, last_sync_at: &str) {
+        sqlx::query(
+            "INSERT INTO sync_state (account_id, last_sync_at) VALUES (1, ?) ON CONFLICT(account_id) DO UPDATE SET last_sync_at = excluded.last_sync_at",
+        )
+        .bind(last_sync_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    fn server_position(
+        entry_id: i64,
+        enclosure_id: i64,
+        media_progression: i64,
+    ) -> ServerProgression {
+        ServerProgression {
+            entry_id,
+            enclosure_id,
+            media_progression,
+        }
+    }
+
+    fn server_episode(
+        entry_id: i64,
+        enclosure_id: i64,
+        media_progression: i64,
+    ) -> crate::miniflux::Entry {
+        serde_json::from_value(serde_json::json!({
+            "id": entry_id,
+            "user_id": 1,
+            "feed_id": 100,
+            "title": "Episode",
+            "url": "https://example.com/episode",
+            "hash": format!("hash-{entry_id}"),
+            "published_at": "2026-02-11T00:00:00Z",
+            "changed_at": "2026-02-11T09:00:00Z",
+            "status": "unread",
+            "enclosures": [{
+                "id": enclosure_id,
+                "entry_id": entry_id,
+                "url": "https://example.com/episode.mp3",
+                "mime_type": "audio/mpeg",
+                "media_progression": media_progression
+            }],
+            "feed": {
+                "id": 100,
+                "user_id": 1,
+                "title": "Feed",
+                "site_url": "https://example.com",
+                "feed_url": "https://example.com/rss"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_queue_progress_push_supersedes_earlier_saves() {
+        let pool = setup_test_db().await;
+        insert_episode(&pool, 1, 10).await;
+
+        let first = queue_progress_push(&pool, 1, 1, 30).await.unwrap();
+        let second = queue_progress_push(&pool, 1, 1, 45).await.unwrap();
+
+        assert!(first.is_some() && second.is_some());
+        assert_eq!(pending_pushes(&pool).await, 1);
+
+        let payload: String = sqlx::query_scalar("SELECT payload FROM sync_queue")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(payload, r#"{"media_progression":45}"#);
+    }
+
+    #[tokio::test]
+    async fn test_queue_progress_push_skips_entries_without_media() {
+        let pool = setup_test_db().await;
+
+        let queued = queue_progress_push(&pool, 1, 1, 30).await.unwrap();
+
+        assert_eq!(queued, None);
+        assert_eq!(pending_pushes(&pool).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_merge_takes_position_moved_on_another_device() {
+        let pool = setup_test_db().await;
+        insert_episode(&pool, 1, 10).await;
+        insert_progress(&pool, 1, 120, "2026-02-11T08:00:00Z").await;
+        record_remote_progression(&pool, 1, 10, 120).await.unwrap();
+
+        let merged = merge_remote_progression(&pool, 1, &[server_position(1, 10, 900)])
+            .await
+            .unwrap();
+
+        assert_eq!(merged, 1);
+        assert_eq!(local_time(&pool, 1).await, Some(900));
+    }
+
+    #[tokio::test]
+    async fn test_merge_keeps_position_when_server_is_unchanged() {
+        let pool = setup_test_db().await;
+        insert_episode(&pool, 1, 10).await;
+        insert_progress(&pool, 1, 300, "2026-02-11T08:00:00Z").await;
+        sqlx::query("UPDATE podcast_progress SET remote_time = 120")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        merge_remote_progression(&pool, 1, &[server_position(1, 10, 120)])
+            .await
+            .unwrap();
+
+        assert_eq!(local_time(&pool, 1).await, Some(300));
+    }
+
+    #[tokio::test]
+    async fn test_merge_last_writer_wins_when_both_sides_moved() {
+        let pool = setup_test_db().await;
+        insert_episode(&pool, 1, 10).await;
+        insert_episode(&pool, 2, 20).await;
+        insert_progress(&pool, 1, 300, "2026-02-11T10:00:00Z").await;
+        insert_progress(&pool, 2, 300, "2026-02-11T08:00:00Z").await;
+        record_remote_progression(&pool, 1, 10, 120).await.unwrap();
+        record_remote_progression(&pool, 1, 20, 120).await.unwrap();
+        queue_progress_push(&pool, 1, 1, 300).await.unwrap();
+        queue_progress_push(&pool, 1, 2, 300).await.unwrap();
+        // The last sync still saw the server at the recorded baseline
+        set_last_sync(&pool, "2026-02-11T09:00:00Z").await;
+
+        merge_remote_progression(
+            &pool,
+            1,
+            &[server_position(1, 10, 900), server_position(2, 20, 900)],
+        )
+        .await
+        .unwrap();
+
+        // Entry 1 was played locally after the server could have moved, entry 2 before it
+        assert_eq!(local_time(&pool, 1).await, Some(300));
+        assert_eq!(local_time(&pool, 2).await, Some(900));
+        assert_eq!(pending_pushes(&pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_merge_creates_progress_started_elsewhere() {
+        let pool = setup_test_db().await;
+        insert_episode(&pool, 1, 10).await;
+        insert_episode(&pool, 2, 20).await;
+
+        let pulled = pulled_progressions(&[server_episode(1, 10, 600), server_episode(2, 20, 0)]);
+        merge_remote_progression(&pool, 1, &pulled).await.unwrap();
+
+        assert_eq!(local_time(&pool, 1).await, Some(600));
+        assert_eq!(local_time(&pool, 2).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_merge_keeps_queued_save_when_server_is_at_baseline() {
+        let pool = setup_test_db().await;
+        insert_episode(&pool, 1, 10).await;
+        insert_progress(&pool, 1, 120, "2026-02-11T08:00:00Z").await;
+        record_remote_progression(&pool, 1, 10, 120).await.unwrap();
+        set_last_sync(&pool, "2026-02-11T09:00:00Z").await;
+        // Played on, but the save is still waiting for its push
+        sqlx::query(r#"UPDATE podcast_progress SET "current_time" = 500"#)
+            .execute(&pool)
+            .await
+            .unwrap();
+        queue_progress_push(&pool, 1, 1, 500).await.unwrap();
+
+        let merged = merge_remote_progression(&pool, 1, &[server_position(1, 10, 120)])
+            .await
+            .unwrap();
+
+        assert_eq!(merged, 0);
+        assert_eq!(local_time(&pool, 1).await, Some(500));
+        assert_eq!(pending_pushes(&pool).await, 1);
+    }
+}
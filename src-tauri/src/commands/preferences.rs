@@ -3,9 +3,12 @@
 //! Handles loading and saving user preferences to disk.
 
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Manager, Runtime};
 use tokio::io::AsyncWriteExt;
 
+use crate::cloud_sync::snapshots::MAX_SNAPSHOT_RETENTION;
+use crate::cloud_sync::state::record_local_changes;
+use crate::cloud_sync::Remote;
 use crate::types::{
     validate_chinese_conversion_mode, validate_custom_chinese_conversions, validate_download_path,
     validate_language, validate_reader_code_theme, validate_reader_settings, validate_reader_theme,
@@ -14,7 +17,7 @@ use crate::types::{
 };
 
 /// Gets the path to the preferences file.
-pub(crate) fn get_preferences_path(app: &AppHandle) -> Result<PathBuf, String> {
+pub(crate) fn get_preferences_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
     let app_data_dir = app
         .path()
         .app_data_dir()
@@ -36,7 +39,7 @@ pub fn load_quick_pane_shortcut(app: &AppHandle) -> Option<String> {
 
 /// Load preferences synchronously for use during app initialization.
 /// Returns None on any failure.
-pub fn load_preferences_sync(app: &AppHandle) -> Option<AppPreferences> {
+pub fn load_preferences_sync<R: Runtime>(app: &AppHandle<R>) -> Option<AppPreferences> {
     let path = get_preferences_path(app).ok()?;
     if !path.exists() {
         return None;
@@ -179,8 +182,15 @@ pub async fn save_preferences(app: AppHandle, preferences: AppPreferences) -> Re
         _ => return Err(format!("Invalid log level: {}", preferences.log_level)),
     }
 
+    if preferences.cloud_sync_snapshot_retention > MAX_SNAPSHOT_RETENTION {
+        return Err(format!(
+            "Cloud sync can keep at most {MAX_SNAPSHOT_RETENTION} snapshots"
+        ));
+    }
+
     log::debug!("Saving preferences to disk: {preferences:?}");
     let prefs_path = get_preferences_path(&app)?;
+    let previous = load_preferences_sync(&app).unwrap_or_default();
 
     let json_content = serde_json::to_string_pretty(&preferences).map_err(|e| {
         log::error!("Failed to serialize preferences: {e}");
@@ -206,19 +216,24 @@ pub async fn save_preferences(app: AppHandle, preferences: AppPreferences) -> Re
 
     log::info!("Successfully saved preferences to {prefs_path:?}");
 
+    // Stamp what changed so cloud sync merges know which device changed it last
+    if let Err(e) = record_local_changes(&app, &previous, &preferences) {
+        log::warn!("Failed to record preference changes for cloud sync: {e}");
+    }
+
     // Grant asset protocol access for user-configured paths
     grant_asset_scope_for_preferences(&app, &preferences);
 
     // Notify the debounce worker to push after 5s of inactivity
-    let cloud_sync_configured = match preferences.cloud_sync_protocol.as_str() {
-        "webdav" => preferences.cloud_sync_webdav_url.is_some(),
-        _ => preferences.cloud_sync_endpoint.is_some() && preferences.cloud_sync_bucket.is_some(),
-    };
+    let cloud_sync_configured = Remote::from_preferences(&preferences).is_ok();
+    let state: tauri::State<'_, crate::AppState> = app.state();
     if preferences.cloud_sync_enabled && cloud_sync_configured {
-        let state: tauri::State<'_, crate::AppState> = app.state();
         state.cloud_sync_notify.notify_one();
     }
 
+    // Let the background sync scheduler pick up a changed sync_interval
+    state.sync_scheduler.notify.notify_one();
+
     Ok(())
 }
 
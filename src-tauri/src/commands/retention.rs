@@ -0,0 +1,367 @@
+//! Retention policies bounding how many entries the local cache keeps.
+//!
+//! A policy either belongs to one feed or, without a feed, is the account
+//! default for every feed that has no policy of its own. Starred and tagged
+//! entries, entries with downloaded enclosures and entries with local changes
+//! still waiting to be pushed are never pruned.
+
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{QueryBuilder, Row, SqlitePool};
+use tauri::State;
+
+use crate::commands::miniflux::get_active_account_id;
+use crate::utils::serde_helpers::{
+    deserialize_option_i64_from_string_or_number, serialize_option_i64_as_string,
+};
+use crate::AppState;
+
+/// Limits applied when pruning a feed's cached entries
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct RetentionPolicyInput {
+    /// Prune entries published more than this many days ago
+    pub max_age_days: Option<u32>,
+    /// Keep at most this many of the newest entries per feed
+    pub max_entries_per_feed: Option<u32>,
+    /// Never prune unread entries
+    #[serde(default = "default_keep_unread")]
+    pub keep_unread: bool,
+}
+
+fn default_keep_unread() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct RetentionPolicy {
+    /// `None` for the account default
+    #[serde(
+        default,
+        serialize_with = "serialize_option_i64_as_string",
+        deserialize_with = "deserialize_option_i64_from_string_or_number"
+    )]
+    #[specta(type = Option<String>)]
+    pub feed_id: Option<i64>,
+    pub max_age_days: Option<u32>,
+    pub max_entries_per_feed: Option<u32>,
+    pub keep_unread: bool,
+    pub updated_at: String,
+}
+
+fn build_policy_from_row(row: &sqlx::sqlite::SqliteRow) -> RetentionPolicy {
+    RetentionPolicy {
+        feed_id: row.get("feed_id"),
+        max_age_days: row
+            .get::<Option<i64>, _>("max_age_days")
+            .map(|days| days as u32),
+        max_entries_per_feed: row
+            .get::<Option<i64>, _>("max_entries_per_feed")
+            .map(|count| count as u32),
+        keep_unread: row.get("keep_unread"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+fn parse_feed_id(feed_id: Option<String>) -> Result<Option<i64>, String> {
+    feed_id
+        .map(|id| {
+            id.parse::<i64>()
+                .map_err(|e| format!("Invalid feed ID: {}", e))
+        })
+        .transpose()
+}
+
+pub async fn list_retention_policies_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+) -> Result<Vec<RetentionPolicy>, String> {
+    let rows = sqlx::query(
+        "SELECT feed_id, max_age_days, max_entries_per_feed, keep_unread, updated_at FROM retention_policies WHERE account_id = ? ORDER BY feed_id IS NOT NULL, feed_id",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch retention policies: {e}"))?;
+
+    Ok(rows.iter().map(build_policy_from_row).collect())
+}
+
+pub async fn set_retention_policy_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    feed_id: Option<i64>,
+    input: RetentionPolicyInput,
+) -> Result<RetentionPolicy, String> {
+    if input.max_entries_per_feed == Some(0) {
+        return Err("Maximum entries per feed must be at least 1".to_string());
+    }
+
+    let now = Utc::now().to_rfc3339();
+
+    // The scope index is on an expression, which ON CONFLICT can't target
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {e}"))?;
+
+    sqlx::query("DELETE FROM retention_policies WHERE account_id = ? AND feed_id IS ?")
+        .bind(account_id)
+        .bind(feed_id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| format!("Failed to replace retention policy: {e}"))?;
+
+    sqlx::query(
+        "INSERT INTO retention_policies (account_id, feed_id, max_age_days, max_entries_per_feed, keep_unread, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(feed_id)
+    .bind(input.max_age_days.map(i64::from))
+    .bind(input.max_entries_per_feed.map(i64::from))
+    .bind(input.keep_unread)
+    .bind(&now)
+    .bind(&now)
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("Failed to save retention policy: {e}"))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit retention policy: {e}"))?;
+
+    Ok(RetentionPolicy {
+        feed_id,
+        max_age_days: input.max_age_days,
+        max_entries_per_feed: input.max_entries_per_feed,
+        keep_unread: input.keep_unread,
+        updated_at: now,
+    })
+}
+
+pub async fn delete_retention_policy_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    feed_id: Option<i64>,
+) -> Result<(), String> {
+    sqlx::query("DELETE FROM retention_policies WHERE account_id = ? AND feed_id IS ?")
+        .bind(account_id)
+        .bind(feed_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to delete retention policy: {e}"))?;
+
+    Ok(())
+}
+
+/// Ids of the feed's entries the policy allows pruning.
+async fn select_prunable_entries(
+    pool: &SqlitePool,
+    account_id: i64,
+    feed_id: i64,
+    policy: &RetentionPolicy,
+) -> Result<Vec<i64>, String> {
+    if policy.max_age_days.is_none() && policy.max_entries_per_feed.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let mut query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        "SELECT id FROM (SELECT e.id, e.status, e.starred, e.sync_status, e.published_at, ROW_NUMBER() OVER (ORDER BY e.published_at DESC, e.id DESC) AS position FROM entries e WHERE e.account_id = ",
+    );
+    query.push_bind(account_id);
+    query.push(" AND e.feed_id = ");
+    query.push_bind(feed_id);
+    query.push(") ranked WHERE (0");
+
+    if let Some(days) = policy.max_age_days {
+        let cutoff = (Utc::now() - Duration::days(i64::from(days))).to_rfc3339();
+        query.push(" OR julianday(ranked.published_at) < julianday(");
+        query.push_bind(cutoff);
+        query.push(")");
+    }
+    if let Some(count) = policy.max_entries_per_feed {
+        query.push(" OR ranked.position > ");
+        query.push_bind(i64::from(count));
+    }
+
+    query.push(
+        ") AND ranked.starred = 0 AND COALESCE(ranked.sync_status, 'synced') != 'pending' AND NOT EXISTS (SELECT 1 FROM tags t WHERE t.account_id = ",
+    );
+    query.push_bind(account_id);
+    query.push(
+        " AND t.entry_id = ranked.id) AND NOT EXISTS (SELECT 1 FROM enclosures n WHERE n.account_id = ",
+    );
+    query.push_bind(account_id);
+    query.push(" AND n.entry_id = ranked.id AND (n.downloaded = 1 OR n.local_path IS NOT NULL))");
+    if policy.keep_unread {
+        query.push(" AND ranked.status != 'unread'");
+    }
+
+    query
+        .build_query_scalar()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to select entries to prune for feed {feed_id}: {e}"))
+}
+
+async fn delete_entries(
+    pool: &SqlitePool,
+    account_id: i64,
+    entry_ids: &[i64],
+) -> Result<(), String> {
+    for chunk in entry_ids.chunks(500) {
+        let mut transaction = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start prune transaction: {e}"))?;
+
+        for table in ["enclosures", "podcast_progress", "entries"] {
+            let column = if table == "entries" { "id" } else { "entry_id" };
+            let mut delete: QueryBuilder<sqlx::Sqlite> =
+                QueryBuilder::new(format!("DELETE FROM {table} WHERE account_id = "));
+            delete.push_bind(account_id);
+            delete.push(format!(" AND {column} IN ("));
+            let mut separated = delete.separated(",");
+            for id in chunk {
+                separated.push_bind(id);
+            }
+            delete.push(")");
+            delete
+                .build()
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| format!("Failed to prune {table}: {e}"))?;
+        }
+
+        transaction
+            .commit()
+            .await
+            .map_err(|e| format!("Failed to commit prune transaction: {e}"))?;
+    }
+
+    Ok(())
+}
+
+/// Applies the account's retention policies to its cached entries and returns
+/// how many entries were removed.
+pub async fn prune_entries(pool: &SqlitePool, account_id: i64) -> Result<u32, String> {
+    let policies = list_retention_policies_from_db(pool, account_id).await?;
+    if policies.is_empty() {
+        return Ok(0);
+    }
+
+    let default_policy = policies.iter().find(|policy| policy.feed_id.is_none());
+    let feed_policies: HashMap<i64, &RetentionPolicy> = policies
+        .iter()
+        .filter_map(|policy| policy.feed_id.map(|feed_id| (feed_id, policy)))
+        .collect();
+
+    let feed_ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM feeds WHERE account_id = ?")
+        .bind(account_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to fetch feeds: {e}"))?;
+
+    let mut entry_ids = Vec::new();
+    for feed_id in feed_ids {
+        let Some(policy) = feed_policies.get(&feed_id).copied().or(default_policy) else {
+            continue;
+        };
+        entry_ids.extend(select_prunable_entries(pool, account_id, feed_id, policy).await?);
+    }
+
+    delete_entries(pool, account_id, &entry_ids).await?;
+
+    if !entry_ids.is_empty() {
+        log::info!(
+            "Pruned {} entries for account {} under retention policies",
+            entry_ids.len(),
+            account_id
+        );
+    }
+
+    Ok(entry_ids.len() as u32)
+}
+
+/// List the active account's retention policies, account default first
+#[tauri::command]
+#[specta::specta]
+pub async fn get_retention_policies(
+    state: State<'_, AppState>,
+) -> Result<Vec<RetentionPolicy>, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    list_retention_policies_from_db(&pool, account_id).await
+}
+
+/// Set the retention policy for a feed, or the account default when `feed_id` is omitted
+#[tauri::command]
+#[specta::specta]
+pub async fn set_retention_policy(
+    state: State<'_, AppState>,
+    feed_id: Option<String>,
+    policy: RetentionPolicyInput,
+) -> Result<RetentionPolicy, String> {
+    let feed_id = parse_feed_id(feed_id)?;
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    set_retention_policy_in_db(&pool, account_id, feed_id, policy).await
+}
+
+/// Remove a feed's retention policy so it falls back to the account default
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_retention_policy(
+    state: State<'_, AppState>,
+    feed_id: Option<String>,
+) -> Result<(), String> {
+    let feed_id = parse_feed_id(feed_id)?;
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    delete_retention_policy_from_db(&pool, account_id, feed_id).await
+}
+
+/// Prune the active account's cached entries now instead of after the next sync
+#[tauri::command]
+#[specta::specta]
+pub async fn apply_retention_policies(state: State<'_, AppState>) -> Result<u32, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    prune_entries(&pool, account_id).await
+}
+
+#[cfg(test)]
+#[path = "retention.test.rs"]
+mod tests;
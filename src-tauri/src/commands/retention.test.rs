@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod tests {
+    use crate::commands::retention::{
+        delete_retention_policy_from_db, list_retention_policies_from_db, prune_entries,
+        set_retention_policy_in_db, RetentionPolicyInput,
+    };
+    use crate::database::migrations::run_migrations;
+    use chrono::{Duration, Utc};
+    use sqlx::SqlitePool;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+        pool
+    }
+
+    async fn insert_feed(pool: &SqlitePool, feed_id: i64) {
+        let now = "2026-02-12T00:00:00Z";
+        sqlx::query(
+            r#"
+            INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at)
+            VALUES (1, ?, 1, 'Feed', 'https://example.com', ?, ?, ?)
+            "#,
+        )
+        .bind(feed_id)
+        .bind(format!("https://example.com/{feed_id}.xml"))
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .expect("Failed to insert feed");
+    }
+
+    async fn insert_entry(pool: &SqlitePool, id: i64, feed_id: i64, days_old: i64, status: &str) {
+        let published_at = (Utc::now() - Duration::days(days_old)).to_rfc3339();
+        sqlx::query(
+            r#"
+            INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status)
+            VALUES (1, ?, 1, ?, ?, 'https://example.com/article', ?, ?, ?, ?)
+            "#,
+        )
+        .bind(id)
+        .bind(feed_id)
+        .bind(format!("Entry {id}"))
+        .bind(format!("hash-{id}"))
+        .bind(&published_at)
+        .bind(&published_at)
+        .bind(status)
+        .execute(pool)
+        .await
+        .expect("Failed to insert entry");
+    }
+
+    async fn remaining_ids(pool: &SqlitePool) -> Vec<i64> {
+        sqlx::query_scalar("SELECT id FROM entries ORDER BY id")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    fn policy(
+        max_age_days: Option<u32>,
+        max_entries_per_feed: Option<u32>,
+        keep_unread: bool,
+    ) -> RetentionPolicyInput {
+        RetentionPolicyInput {
+            max_age_days,
+            max_entries_per_feed,
+            keep_unread,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_replaces_policy_for_same_scope() {
+        let pool = setup_test_db().await;
+
+        set_retention_policy_in_db(&pool, 1, None, policy(Some(30), None, true))
+            .await
+            .unwrap();
+        set_retention_policy_in_db(&pool, 1, None, policy(Some(7), None, false))
+            .await
+            .unwrap();
+        set_retention_policy_in_db(&pool, 1, Some(5), policy(None, Some(10), true))
+            .await
+            .unwrap();
+
+        let policies = list_retention_policies_from_db(&pool, 1).await.unwrap();
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies[0].feed_id, None);
+        assert_eq!(policies[0].max_age_days, Some(7));
+        assert!(!policies[0].keep_unread);
+        assert_eq!(policies[1].feed_id, Some(5));
+        assert_eq!(policies[1].max_entries_per_feed, Some(10));
+
+        delete_retention_policy_from_db(&pool, 1, Some(5))
+            .await
+            .unwrap();
+        let policies = list_retention_policies_from_db(&pool, 1).await.unwrap();
+        assert_eq!(policies.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_zero_max_entries_is_rejected() {
+        let pool = setup_test_db().await;
+
+        let result = set_retention_policy_in_db(&pool, 1, None, policy(None, Some(0), true)).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_prune_by_age_keeps_unread_and_protected_entries() {
+        let pool = setup_test_db().await;
+        insert_feed(&pool, 1).await;
+        insert_entry(&pool, 1, 1, 60, "read").await;
+        insert_entry(&pool, 2, 1, 60, "unread").await;
+        insert_entry(&pool, 3, 1, 60, "read").await;
+        insert_entry(&pool, 4, 1, 60, "read").await;
+        insert_entry(&pool, 5, 1, 60, "read").await;
+        insert_entry(&pool, 6, 1, 1, "read").await;
+
+        sqlx::query("UPDATE entries SET starred = 1 WHERE id = 3")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO tags (account_id, entry_id, tag, created_at) VALUES (1, 4, 'keep', '2026-02-12T00:00:00Z')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO enclosures (account_id, id, entry_id, url, mime_type, downloaded, local_path, created_at) VALUES (1, 1, 5, 'https://example.com/a.mp3', 'audio/mpeg', 1, '/tmp/a.mp3', '2026-02-12T00:00:00Z')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        set_retention_policy_in_db(&pool, 1, None, policy(Some(30), None, true))
+            .await
+            .unwrap();
+
+        let pruned = prune_entries(&pool, 1).await.unwrap();
+
+        assert_eq!(pruned, 1);
+        assert_eq!(remaining_ids(&pool).await, vec![2, 3, 4, 5, 6]);
+    }
+
+    #[tokio::test]
+    async fn test_feed_policy_overrides_account_default() {
+        let pool = setup_test_db().await;
+        insert_feed(&pool, 1).await;
+        insert_feed(&pool, 2).await;
+        for (id, days_old) in [(1, 3), (2, 2), (3, 1)] {
+            insert_entry(&pool, id, 1, days_old, "unread").await;
+        }
+        for (id, days_old) in [(4, 3), (5, 2), (6, 1)] {
+            insert_entry(&pool, id, 2, days_old, "unread").await;
+        }
+
+        set_retention_policy_in_db(&pool, 1, None, policy(None, Some(1), false))
+            .await
+            .unwrap();
+        set_retention_policy_in_db(&pool, 1, Some(2), policy(None, Some(2), false))
+            .await
+            .unwrap();
+
+        let pruned = prune_entries(&pool, 1).await.unwrap();
+
+        assert_eq!(pruned, 3);
+        assert_eq!(remaining_ids(&pool).await, vec![3, 5, 6]);
+    }
+
+    #[tokio::test]
+    async fn test_policies_of_another_account_are_ignored() {
+        let pool = setup_test_db().await;
+        insert_feed(&pool, 1).await;
+        insert_entry(&pool, 1, 1, 400, "read").await;
+
+        set_retention_policy_in_db(&pool, 2, Some(1), policy(Some(30), None, false))
+            .await
+            .unwrap();
+
+        let pruned = prune_entries(&pool, 1).await.unwrap();
+
+        assert_eq!(pruned, 0);
+        assert_eq!(remaining_ids(&pool).await, vec![1]);
+        assert!(list_retention_policies_from_db(&pool, 1)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prune_without_policies_keeps_everything() {
+        let pool = setup_test_db().await;
+        insert_feed(&pool, 1).await;
+        insert_entry(&pool, 1, 1, 400, "read").await;
+
+        let pruned = prune_entries(&pool, 1).await.unwrap();
+
+        assert_eq!(pruned, 0);
+        assert_eq!(remaining_ids(&pool).await, vec![1]);
+    }
+}
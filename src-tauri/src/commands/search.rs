@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use tauri::State;
+
+use crate::commands::miniflux::get_active_account_id;
+use crate::database::fts::build_fts_query;
+use crate::utils::serde_helpers::{deserialize_i64_from_string_or_number, serialize_i64_as_string};
+use crate::AppState;
+
+const DEFAULT_SEARCH_LIMIT: u32 = 50;
+const MAX_SEARCH_LIMIT: u32 = 500;
+
+// Private-use characters mark matches in FTS5 output; they are swapped for
+// `<mark>` tags after the surrounding text has been HTML-escaped.
+const MATCH_START: &str = "\u{E000}";
+const MATCH_END: &str = "\u{E001}";
+
+/// A full-text search result, best match first.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, FromRow)]
+pub struct EntrySearchHit {
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub entry_id: i64,
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub feed_id: i64,
+    pub feed_title: Option<String>,
+    pub title: String,
+    /// Title as HTML with matched terms wrapped in `<mark>`.
+    pub title_highlight: String,
+    pub author: Option<String>,
+    pub url: String,
+    pub published_at: String,
+    pub status: String,
+    pub starred: bool,
+    /// Content excerpt around the best match, as HTML with `<mark>` tags.
+    pub snippet: String,
+    /// Relevance score (negated BM25); higher is better.
+    pub score: f64,
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_highlight(text: &str) -> String {
+    escape_html(text)
+        .replace(MATCH_START, "<mark>")
+        .replace(MATCH_END, "</mark>")
+}
+
+/// Runs a ranked full-text search over the account's cached entries.
+///
+/// Title matches weigh most, then author, feed title and content.
+pub async fn search_entries_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    query: &str,
+    limit: u32,
+    offset: u32,
+) -> Result<Vec<EntrySearchHit>, String> {
+    let Some(match_query) = build_fts_query(query) else {
+        return Ok(Vec::new());
+    };
+
+    let mut hits: Vec<EntrySearchHit> = sqlx::query_as(
+        r#"
+        SELECT
+            e.id AS entry_id,
+            e.feed_id,
+            f.title AS feed_title,
+            e.title,
+            highlight(entries_fts, 0, ?, ?) AS title_highlight,
+            e.author,
+            e.url,
+            e.published_at,
+            e.status,
+            COALESCE(e.starred, 0) AS starred,
+            snippet(entries_fts, 2, ?, ?, '…', 24) AS snippet,
+            -bm25(entries_fts, 10.0, 5.0, 1.0, 2.0) AS score
+        FROM entries_fts
+        JOIN entries e ON e.local_id = entries_fts.rowid
+        LEFT JOIN feeds f ON f.account_id = e.account_id AND f.id = e.feed_id
+        WHERE entries_fts MATCH ? AND e.account_id = ? AND e.hidden_by_rule IS NULL
+        ORDER BY score DESC, e.published_at DESC
+        LIMIT ? OFFSET ?
+        "#,
+    )
+    .bind(MATCH_START)
+    .bind(MATCH_END)
+    .bind(MATCH_START)
+    .bind(MATCH_END)
+    .bind(&match_query)
+    .bind(account_id)
+    .bind(i64::from(limit.min(MAX_SEARCH_LIMIT)))
+    .bind(i64::from(offset))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to search entries: {e}"))?;
+
+    for hit in &mut hits {
+        hit.title_highlight = render_highlight(&hit.title_highlight);
+        hit.snippet = render_highlight(&hit.snippet);
+    }
+
+    Ok(hits)
+}
+
+/// Full-text search over cached entries.
+///
+/// Supports `"exact phrases"`, `prefix*` and `feed:`/`author:`/`title:`
+/// qualifiers; see `build_fts_query`.
+#[tauri::command]
+#[specta::specta]
+pub async fn search_entries(
+    state: State<'_, AppState>,
+    query: String,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Result<Vec<EntrySearchHit>, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+
+    search_entries_in_db(
+        &pool,
+        account_id,
+        &query,
+        limit.unwrap_or(DEFAULT_SEARCH_LIMIT),
+        offset.unwrap_or(0),
+    )
+    .await
+}
+
+#[cfg(test)]
+#[path = "search.test.rs"]
+mod tests;
@@ -0,0 +1,209 @@
+#[cfg(test)]
+mod tests {
+    use crate::database::fts::refresh_entries_fts;
+    use crate::database::migrations::run_migrations;
+    use sqlx::SqlitePool;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+        pool
+    }
+
+    async fn insert_feed(pool: &SqlitePool, account_id: i64, id: i64, title: &str) {
+        let now = "2026-02-12T00:00:00Z";
+
+        sqlx::query(
+            r#"
+            INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(account_id)
+        .bind(id)
+        .bind(account_id)
+        .bind(title)
+        .bind(format!("https://feed{id}.example.com"))
+        .bind(format!("https://feed{id}.example.com/rss"))
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await
+        .expect("Failed to insert feed");
+    }
+
+    async fn insert_entry(
+        pool: &SqlitePool,
+        account_id: i64,
+        id: i64,
+        feed_id: i64,
+        title: &str,
+        author: Option<&str>,
+        content: &str,
+    ) {
+        sqlx::query(
+            r#"
+            INSERT INTO entries (account_id, id, user_id, feed_id, title, url, author, content, hash, published_at, created_at, status)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'unread')
+            "#,
+        )
+        .bind(account_id)
+        .bind(id)
+        .bind(account_id)
+        .bind(feed_id)
+        .bind(title)
+        .bind(format!("https://example.com/{id}"))
+        .bind(author)
+        .bind(content)
+        .bind(format!("hash-{id}"))
+        .bind("2026-02-12T00:00:00Z")
+        .bind("2026-02-12T00:00:00Z")
+        .execute(pool)
+        .await
+        .expect("Failed to insert entry");
+    }
+
+    async fn seed(pool: &SqlitePool) {
+        insert_feed(pool, 1, 1, "Rust Blog").await;
+        insert_feed(pool, 1, 2, "Hacker News").await;
+        // Another server that hands out the same feed and entry IDs
+        insert_feed(pool, 2, 1, "Rust Blog").await;
+        insert_entry(
+            pool,
+            1,
+            1,
+            1,
+            "Async closures are stable",
+            Some("Jane Doe"),
+            "<p>The <b>borrow checker</b> now understands async closures.</p><script>track()</script>",
+        )
+        .await;
+        insert_entry(
+            pool,
+            2,
+            1,
+            2,
+            "Show HN: a tiny database",
+            Some("John Smith"),
+            "<p>Written in Rust, with a checker for borrow rules.</p>",
+        )
+        .await;
+        insert_entry(
+            pool,
+            2,
+            1,
+            1,
+            "Another account's borrow checker post",
+            None,
+            "<p>borrow checker</p>",
+        )
+        .await;
+        refresh_entries_fts(pool, 1, &[1, 2])
+            .await
+            .expect("Failed to index entries");
+        refresh_entries_fts(pool, 2, &[1])
+            .await
+            .expect("Failed to index entries");
+    }
+
+    #[tokio::test]
+    async fn test_search_ranks_and_highlights_matches() {
+        let pool = setup_test_db().await;
+        seed(&pool).await;
+
+        let hits = super::super::search_entries_in_db(&pool, 1, "borrow", 50, 0)
+            .await
+            .expect("Search should succeed");
+
+        // The other account's entry 1 is not a hit
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].entry_id, 1);
+        assert_eq!(hits[0].feed_title.as_deref(), Some("Rust Blog"));
+        assert!(hits[0].snippet.contains("<mark>borrow</mark>"));
+        assert!(!hits[0].snippet.contains("track"));
+        assert!(hits[0].score >= hits[1].score);
+    }
+
+    #[tokio::test]
+    async fn test_search_supports_phrases_prefixes_and_qualifiers() {
+        let pool = setup_test_db().await;
+        seed(&pool).await;
+
+        let phrase = super::super::search_entries_in_db(&pool, 1, "\"borrow checker\"", 50, 0)
+            .await
+            .expect("Phrase search should succeed");
+        assert_eq!(phrase.len(), 1);
+        assert_eq!(phrase[0].entry_id, 1);
+
+        let prefix = super::super::search_entries_in_db(&pool, 1, "datab*", 50, 0)
+            .await
+            .expect("Prefix search should succeed");
+        assert_eq!(prefix.len(), 1);
+        assert_eq!(prefix[0].entry_id, 2);
+        assert_eq!(
+            prefix[0].title_highlight,
+            "Show HN: a tiny <mark>database</mark>"
+        );
+
+        let by_feed =
+            super::super::search_entries_in_db(&pool, 1, "feed:\"hacker news\" rust", 50, 0)
+                .await
+                .expect("Feed search should succeed");
+        assert_eq!(by_feed.len(), 1);
+        assert_eq!(by_feed[0].entry_id, 2);
+
+        let by_author = super::super::search_entries_in_db(&pool, 1, "author:jane", 50, 0)
+            .await
+            .expect("Author search should succeed");
+        assert_eq!(by_author.len(), 1);
+        assert_eq!(by_author[0].entry_id, 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_index_follows_entry_deletes_and_handles_empty_queries() {
+        let pool = setup_test_db().await;
+        seed(&pool).await;
+
+        sqlx::query("DELETE FROM entries WHERE account_id = 1 AND id = 1")
+            .execute(&pool)
+            .await
+            .expect("Failed to delete entry");
+
+        let hits = super::super::search_entries_in_db(&pool, 1, "borrow", 50, 0)
+            .await
+            .expect("Search should succeed");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].entry_id, 2);
+
+        let none = super::super::search_entries_in_db(&pool, 1, "  \"\" ", 50, 0)
+            .await
+            .expect("Empty search should succeed");
+        assert!(none.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_index_follows_feed_renames() {
+        let pool = setup_test_db().await;
+        seed(&pool).await;
+
+        sqlx::query("UPDATE feeds SET title = 'Ferris Weekly' WHERE account_id = 1 AND id = 1")
+            .execute(&pool)
+            .await
+            .expect("Failed to rename feed");
+
+        let renamed = super::super::search_entries_in_db(&pool, 1, "feed:ferris", 50, 0)
+            .await
+            .expect("Feed search should succeed");
+        assert_eq!(renamed.len(), 1);
+        assert_eq!(renamed[0].entry_id, 1);
+
+        let old_name = super::super::search_entries_in_db(&pool, 1, "feed:\"rust blog\"", 50, 0)
+            .await
+            .expect("Feed search should succeed");
+        assert!(old_name.is_empty());
+    }
+}
@@ -0,0 +1,340 @@
+//! Smart folders: named, saved `EntryFilters` evaluated against the local database.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqliteConnection, SqlitePool};
+use tauri::State;
+
+use crate::commands::miniflux::{entry_count_query, get_active_account_id};
+use crate::commands::tags::normalize_tag;
+use crate::miniflux::counters::SmartFolderUnread;
+use crate::miniflux::EntryFilters;
+use crate::utils::serde_helpers::{deserialize_i64_from_string_or_number, serialize_i64_as_string};
+use crate::AppState;
+
+/// A saved entry filter shown as a folder
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct SmartFolder {
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub id: i64,
+    pub name: String,
+    pub filters: EntryFilters,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Drops paging and sorting, which belong to the view rather than the folder,
+/// and normalizes tags.
+fn sanitize_filters(filters: EntryFilters) -> Result<EntryFilters, String> {
+    let tags = match filters.tags {
+        Some(tags) if !tags.is_empty() => Some(
+            tags.iter()
+                .map(|tag| normalize_tag(tag))
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        _ => None,
+    };
+    let search = filters.search.filter(|search| !search.trim().is_empty());
+
+    Ok(EntryFilters {
+        offset: None,
+        limit: None,
+        order: None,
+        direction: None,
+        tags,
+        search,
+        ..filters
+    })
+}
+
+fn normalize_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Smart folder name cannot be empty".to_string());
+    }
+    Ok(name.to_string())
+}
+
+fn map_write_error(e: sqlx::Error, name: &str) -> String {
+    match e.as_database_error() {
+        Some(db_error) if db_error.is_unique_violation() => {
+            format!("A smart folder named \"{name}\" already exists")
+        }
+        _ => format!("Failed to save smart folder: {e}"),
+    }
+}
+
+fn build_smart_folder_from_row(row: &sqlx::sqlite::SqliteRow) -> Result<SmartFolder, String> {
+    let id: i64 = row.get("id");
+    let filters: String = row.get("filters");
+    let filters = serde_json::from_str(&filters)
+        .map_err(|e| format!("Invalid filters for smart folder {id}: {e}"))?;
+
+    Ok(SmartFolder {
+        id,
+        name: row.get("name"),
+        filters,
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub async fn list_smart_folders_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+) -> Result<Vec<SmartFolder>, String> {
+    let rows = sqlx::query(
+        "SELECT id, name, filters, created_at, updated_at FROM smart_folders WHERE account_id = ? ORDER BY name COLLATE NOCASE",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch smart folders: {e}"))?;
+
+    rows.iter().map(build_smart_folder_from_row).collect()
+}
+
+pub async fn create_smart_folder_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    name: &str,
+    filters: EntryFilters,
+) -> Result<SmartFolder, String> {
+    let name = normalize_name(name)?;
+    let filters = sanitize_filters(filters)?;
+    let filters_json =
+        serde_json::to_string(&filters).map_err(|e| format!("Failed to serialize filters: {e}"))?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let id: i64 = sqlx::query_scalar(
+        "INSERT INTO smart_folders (account_id, name, filters, created_at, updated_at) VALUES (?, ?, ?, ?, ?) RETURNING id",
+    )
+    .bind(account_id)
+    .bind(&name)
+    .bind(&filters_json)
+    .bind(&now)
+    .bind(&now)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| map_write_error(e, &name))?;
+
+    Ok(SmartFolder {
+        id,
+        name,
+        filters,
+        created_at: now.clone(),
+        updated_at: now,
+    })
+}
+
+pub async fn update_smart_folder_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    id: i64,
+    name: &str,
+    filters: EntryFilters,
+) -> Result<SmartFolder, String> {
+    let name = normalize_name(name)?;
+    let filters = sanitize_filters(filters)?;
+    let filters_json =
+        serde_json::to_string(&filters).map_err(|e| format!("Failed to serialize filters: {e}"))?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let row = sqlx::query(
+        "UPDATE smart_folders SET name = ?, filters = ?, updated_at = ? WHERE id = ? AND account_id = ? RETURNING id, name, filters, created_at, updated_at",
+    )
+    .bind(&name)
+    .bind(&filters_json)
+    .bind(&now)
+    .bind(id)
+    .bind(account_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| map_write_error(e, &name))?
+    .ok_or_else(|| format!("Smart folder with id {id} not found"))?;
+
+    build_smart_folder_from_row(&row)
+}
+
+pub async fn delete_smart_folder_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    id: i64,
+) -> Result<(), String> {
+    let result = sqlx::query("DELETE FROM smart_folders WHERE id = ? AND account_id = ?")
+        .bind(id)
+        .bind(account_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to delete smart folder: {e}"))?;
+
+    if result.rows_affected() == 0 {
+        return Err(format!("Smart folder with id {id} not found"));
+    }
+
+    Ok(())
+}
+
+/// Rewrites tag filters after a tag rename, inside the rename's transaction.
+pub(crate) async fn rename_tag_in_smart_folders(
+    conn: &mut SqliteConnection,
+    account_id: i64,
+    from: &str,
+    to: &str,
+) -> Result<(), String> {
+    let rows: Vec<(i64, String)> =
+        sqlx::query_as("SELECT id, filters FROM smart_folders WHERE account_id = ?")
+            .bind(account_id)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to fetch smart folders: {e}"))?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+
+    for (id, filters_json) in rows {
+        let Ok(mut filters) = serde_json::from_str::<EntryFilters>(&filters_json) else {
+            continue;
+        };
+        let Some(tags) = filters.tags.as_mut() else {
+            continue;
+        };
+        if !tags.iter().any(|tag| tag == from) {
+            continue;
+        }
+
+        tags.retain(|tag| tag != from && tag != to);
+        tags.push(to.to_string());
+
+        let filters_json = serde_json::to_string(&filters)
+            .map_err(|e| format!("Failed to serialize filters: {e}"))?;
+        sqlx::query("UPDATE smart_folders SET filters = ?, updated_at = ? WHERE id = ?")
+            .bind(filters_json)
+            .bind(&now)
+            .bind(id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| format!("Failed to update smart folder: {e}"))?;
+    }
+
+    Ok(())
+}
+
+/// Live unread count for each of the account's smart folders, over its
+/// entries or every account's with `all_accounts`.
+pub async fn count_smart_folder_unread(
+    pool: &SqlitePool,
+    account_id: i64,
+    all_accounts: bool,
+) -> Result<Vec<SmartFolderUnread>, String> {
+    let folders = list_smart_folders_from_db(pool, account_id).await?;
+    let mut counts = Vec::with_capacity(folders.len());
+
+    for mut folder in folders {
+        if all_accounts {
+            folder.filters.all_accounts = Some(true);
+        }
+        let mut query = entry_count_query(&folder.filters, account_id);
+        query.push(" AND e.status = 'unread'");
+
+        let unread_count: i64 = query
+            .build_query_scalar()
+            .fetch_one(pool)
+            .await
+            .map_err(|e| format!("Failed to count smart folder \"{}\": {e}", folder.name))?;
+
+        counts.push(SmartFolderUnread {
+            smart_folder_id: folder.id,
+            unread_count,
+        });
+    }
+
+    Ok(counts)
+}
+
+/// List the active account's smart folders
+#[tauri::command]
+#[specta::specta]
+pub async fn list_smart_folders(state: State<'_, AppState>) -> Result<Vec<SmartFolder>, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    list_smart_folders_from_db(&pool, account_id).await
+}
+
+/// Save a named entry filter as a smart folder
+#[tauri::command]
+#[specta::specta]
+pub async fn create_smart_folder(
+    state: State<'_, AppState>,
+    name: String,
+    filters: EntryFilters,
+) -> Result<SmartFolder, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    create_smart_folder_in_db(&pool, account_id, &name, filters).await
+}
+
+/// Rename a smart folder or replace its filters
+#[tauri::command]
+#[specta::specta]
+pub async fn update_smart_folder(
+    state: State<'_, AppState>,
+    id: String,
+    name: String,
+    filters: EntryFilters,
+) -> Result<SmartFolder, String> {
+    let id = id
+        .parse::<i64>()
+        .map_err(|e| format!("Invalid smart folder ID: {}", e))?;
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    update_smart_folder_in_db(&pool, account_id, id, &name, filters).await
+}
+
+/// Delete a smart folder
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_smart_folder(state: State<'_, AppState>, id: String) -> Result<(), String> {
+    let id = id
+        .parse::<i64>()
+        .map_err(|e| format!("Invalid smart folder ID: {}", e))?;
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+    delete_smart_folder_from_db(&pool, account_id, id).await
+}
+
+#[cfg(test)]
+#[path = "smart_folders.test.rs"]
+mod tests;
@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use crate::commands::tags::{add_tags_to_entries, rename_tag_in_db};
+    use crate::database::migrations::run_migrations;
+    use crate::miniflux::EntryFilters;
+    use sqlx::SqlitePool;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+        pool
+    }
+
+    async fn insert_entries(pool: &SqlitePool) {
+        let now = "2026-02-12T00:00:00Z";
+
+        for feed_id in [1, 2] {
+            sqlx::query(
+                r#"
+                INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at)
+                VALUES (1, ?, 1, 'Feed', 'https://example.com', ?, ?, ?)
+                "#,
+            )
+            .bind(feed_id)
+            .bind(format!("https://example.com/{feed_id}.xml"))
+            .bind(now)
+            .bind(now)
+            .execute(pool)
+            .await
+            .expect("Failed to insert feed");
+        }
+
+        for (id, feed_id, status, starred) in [
+            (1, 1, "unread", true),
+            (2, 1, "read", true),
+            (3, 2, "unread", true),
+            (4, 2, "unread", false),
+        ] {
+            sqlx::query(
+                r#"
+                INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status, starred)
+                VALUES (1, ?, 1, ?, ?, 'https://example.com/article', ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(id)
+            .bind(feed_id)
+            .bind(format!("Entry {id}"))
+            .bind(format!("hash-{id}"))
+            .bind(now)
+            .bind(now)
+            .bind(status)
+            .bind(starred)
+            .execute(pool)
+            .await
+            .expect("Failed to insert entry");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_smart_folder_crud_strips_paging_and_rejects_duplicates() {
+        let pool = setup_test_db().await;
+
+        let filters = EntryFilters {
+            starred: Some(true),
+            limit: Some(50),
+            offset: Some(100),
+            ..EntryFilters::default()
+        };
+        let folder = super::super::create_smart_folder_in_db(&pool, 1, " Starred ", filters)
+            .await
+            .expect("Create should succeed");
+        assert_eq!(folder.name, "Starred");
+        assert_eq!(folder.filters.limit, None);
+        assert_eq!(folder.filters.offset, None);
+
+        let duplicate =
+            super::super::create_smart_folder_in_db(&pool, 1, "Starred", EntryFilters::default())
+                .await;
+        assert!(duplicate.unwrap_err().contains("already exists"));
+
+        let updated = super::super::update_smart_folder_in_db(
+            &pool,
+            1,
+            folder.id,
+            "Feed 2",
+            EntryFilters {
+                feed_id: Some(2),
+                ..EntryFilters::default()
+            },
+        )
+        .await
+        .expect("Update should succeed");
+        assert_eq!(updated.filters.feed_id, Some(2));
+        assert_eq!(updated.filters.starred, None);
+
+        let listed = super::super::list_smart_folders_from_db(&pool, 1)
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].name, "Feed 2");
+
+        super::super::delete_smart_folder_from_db(&pool, 1, folder.id)
+            .await
+            .expect("Delete should succeed");
+        assert!(
+            super::super::delete_smart_folder_from_db(&pool, 1, folder.id)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_smart_folders_are_scoped_to_their_account() {
+        let pool = setup_test_db().await;
+
+        let folder = super::super::create_smart_folder_in_db(
+            &pool,
+            1,
+            "Feed 2",
+            EntryFilters {
+                feed_id: Some(2),
+                ..EntryFilters::default()
+            },
+        )
+        .await
+        .unwrap();
+        super::super::create_smart_folder_in_db(&pool, 2, "Feed 2", EntryFilters::default())
+            .await
+            .expect("Another account can reuse the name");
+
+        assert!(
+            super::super::delete_smart_folder_from_db(&pool, 2, folder.id)
+                .await
+                .is_err()
+        );
+        let listed = super::super::list_smart_folders_from_db(&pool, 2)
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].filters.feed_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_smart_folder_unread_counts_follow_filters_and_tag_renames() {
+        let pool = setup_test_db().await;
+        insert_entries(&pool).await;
+
+        let starred = super::super::create_smart_folder_in_db(
+            &pool,
+            1,
+            "Starred",
+            EntryFilters {
+                starred: Some(true),
+                ..EntryFilters::default()
+            },
+        )
+        .await
+        .unwrap();
+        let tagged = super::super::create_smart_folder_in_db(
+            &pool,
+            1,
+            "Research",
+            EntryFilters {
+                tags: Some(vec!["ml".to_string()]),
+                feed_id: Some(2),
+                ..EntryFilters::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        add_tags_to_entries(&pool, 1, &[1, 3, 4], &["ml".to_string()])
+            .await
+            .unwrap();
+        rename_tag_in_db(&pool, 1, "ml", "machine learning")
+            .await
+            .unwrap();
+
+        let counts = super::super::count_smart_folder_unread(&pool, 1, false)
+            .await
+            .expect("Counting should succeed");
+        let count_for = |id: i64| {
+            counts
+                .iter()
+                .find(|count| count.smart_folder_id == id)
+                .map(|count| count.unread_count)
+        };
+
+        // Entries 1 and 3 are starred and unread
+        assert_eq!(count_for(starred.id), Some(2));
+        // Entries 3 and 4 are tagged, unread and in feed 2
+        assert_eq!(count_for(tagged.id), Some(2));
+
+        let folders = super::super::list_smart_folders_from_db(&pool, 1)
+            .await
+            .unwrap();
+        let research = folders
+            .iter()
+            .find(|folder| folder.id == tagged.id)
+            .unwrap();
+        assert_eq!(
+            research.filters.tags,
+            Some(vec!["machine learning".to_string()])
+        );
+    }
+}
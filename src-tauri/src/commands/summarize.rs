@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager};
 
+use crate::commands::miniflux::entry_account_id;
 use crate::commands::preferences::load_preferences_sync;
 use crate::types::ReaderTranslationProviderSettings;
 use crate::utils::llm_stream;
@@ -1288,6 +1289,7 @@ pub struct ArticleSummaryRecord {
 pub async fn get_article_summary(
     app: AppHandle,
     entry_id: String,
+    account_id: Option<String>,
 ) -> Result<Option<ArticleSummaryRecord>, String> {
     let state: tauri::State<'_, crate::AppState> = app.state();
     let pool = state
@@ -1298,9 +1300,12 @@ pub async fn get_article_summary(
         .ok_or("Database not initialized")?
         .clone();
 
+    let account_id = entry_account_id(&state, account_id).await?;
+
     let row = sqlx::query(
-        "SELECT entry_id, summary, provider_used, model_used FROM article_summaries WHERE entry_id = ?",
+        "SELECT entry_id, summary, provider_used, model_used FROM article_summaries WHERE account_id = ? AND entry_id = ?",
     )
+    .bind(account_id)
     .bind(&entry_id)
     .fetch_optional(&pool)
     .await
@@ -1325,6 +1330,7 @@ pub async fn save_article_summary(
     summary: String,
     provider_used: Option<String>,
     model_used: Option<String>,
+    account_id: Option<String>,
 ) -> Result<(), String> {
     let state: tauri::State<'_, crate::AppState> = app.state();
     let pool = state
@@ -1335,19 +1341,21 @@ pub async fn save_article_summary(
         .ok_or("Database not initialized")?
         .clone();
 
+    let account_id = entry_account_id(&state, account_id).await?;
     let now = Utc::now().to_rfc3339();
 
     sqlx::query(
         r#"
-        INSERT INTO article_summaries (entry_id, summary, provider_used, model_used, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?)
-        ON CONFLICT(entry_id) DO UPDATE SET
+        INSERT INTO article_summaries (account_id, entry_id, summary, provider_used, model_used, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(account_id, entry_id) DO UPDATE SET
             summary = excluded.summary,
             provider_used = excluded.provider_used,
             model_used = excluded.model_used,
             updated_at = excluded.updated_at
         "#,
     )
+    .bind(account_id)
     .bind(&entry_id)
     .bind(&summary)
     .bind(&provider_used)
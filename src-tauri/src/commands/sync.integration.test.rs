@@ -0,0 +1,718 @@
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeInclusive;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use crate::commands::miniflux::get_entries_from_db;
+    use crate::commands::sync::{
+        get_or_create_sync_state, sync_accounts_impl, sync_miniflux_impl, sync_scope_impl,
+        ScopePolling, SyncScope,
+    };
+    use crate::commands::sync_history::get_sync_history_from_db;
+    use crate::database::migrations::run_migrations;
+    use crate::miniflux::{EntryFilters, MinifluxClient, RetryPolicy};
+    use miniflux_mock::{
+        Fault, MockCategory, MockEnclosure, MockEntry, MockFeed, MockIcon, MockMiniflux, MockUser,
+    };
+    use sqlx::SqlitePool;
+    use tauri::test::mock_app;
+    use tauri::Listener;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+        pool
+    }
+
+    /// A server with one category and one feed holding `entry_ids`
+    async fn seeded_server(
+        user_id: i64,
+        feed_id: i64,
+        entry_ids: RangeInclusive<i64>,
+    ) -> MockMiniflux {
+        let server = MockMiniflux::start(MockUser::new(user_id, format!("user-{user_id}"))).await;
+        server.add_category(MockCategory::new(feed_id, format!("Category {feed_id}")));
+        server.add_feed(MockFeed::new(feed_id, feed_id));
+        server.add_entries(entry_ids.map(|id| MockEntry::new(id, feed_id)));
+        server
+    }
+
+    fn client_for(server: &MockMiniflux) -> MinifluxClient {
+        MinifluxClient::new(server.url())
+            .with_token(server.token())
+            .with_retry_policy(RetryPolicy::none())
+    }
+
+    /// A `changed_at` safely after any sync started by the test
+    fn later() -> i64 {
+        chrono::Utc::now().timestamp() + 60
+    }
+
+    async fn entry_ids(pool: &SqlitePool, user_id: i64) -> Vec<i64> {
+        sqlx::query_scalar("SELECT id FROM entries WHERE user_id = ? ORDER BY id")
+            .bind(user_id)
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    async fn entry_state(pool: &SqlitePool, id: i64) -> (String, bool) {
+        sqlx::query_as("SELECT status, starred FROM entries WHERE id = ?")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_full_sync_mirrors_server() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = MockMiniflux::start(MockUser::new(1, "reader")).await;
+        server.add_category(MockCategory::new(1, "News"));
+        server.add_category(MockCategory::new(2, "Podcasts"));
+        server.add_feed(MockFeed::new(10, 1));
+        server.add_feed(MockFeed::new(20, 2).icon(MockIcon::new(7, "image/png", "aWNvbg==")));
+        server.add_entries((1..=119).map(|id| MockEntry::new(id, 10)));
+        server.add_entries([MockEntry::new(120, 20)
+            .status("read")
+            .starred(true)
+            .enclosure(MockEnclosure::new(900).media_progression(42))]);
+
+        let summary = sync_miniflux_impl(&pool, &client_for(&server), app.handle(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.categories_pulled, 2);
+        assert_eq!(summary.feeds_pulled, 2);
+        assert_eq!(summary.entries_pulled, 120);
+        assert_eq!(entry_ids(&pool, 1).await, (1..=120).collect::<Vec<_>>());
+        assert_eq!(entry_state(&pool, 120).await, ("read".to_string(), true));
+
+        let progression: i64 =
+            sqlx::query_scalar("SELECT media_progression FROM enclosures WHERE id = 900")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(progression, 42);
+
+        // 50 entries, then a doubled page of 100
+        assert_eq!(server.request_count("GET", "/v1/entries"), 2);
+
+        let state = get_or_create_sync_state(&pool, 1).await.unwrap();
+        assert!(state.last_full_sync_at.is_some());
+        assert!(!state.sync_in_progress);
+        assert_eq!(state.entries_synced, 120);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_sync_pulls_only_changes() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = seeded_server(1, 10, 1..=60).await;
+        let client = client_for(&server);
+
+        sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+
+        let changed_at = later();
+        server.update_entry(5, |entry| {
+            entry.status = "read".to_string();
+            entry.changed_at = changed_at;
+        });
+        server.add_entries([MockEntry::new(61, 10).changed_at(changed_at)]);
+
+        let summary = sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.entries_pulled, 2);
+        assert_eq!(entry_ids(&pool, 1).await.len(), 61);
+        assert_eq!(entry_state(&pool, 5).await, ("read".to_string(), false));
+
+        let last_request = server
+            .requests()
+            .into_iter()
+            .rfind(|request| request.path == "/v1/entries")
+            .unwrap();
+        assert!(last_request
+            .query
+            .iter()
+            .any(|(name, _)| name == "changed_after"));
+    }
+
+    #[tokio::test]
+    async fn test_sync_reads_progress_only_of_episodes_not_pulled() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = MockMiniflux::start(MockUser::new(1, "reader")).await;
+        server.add_category(MockCategory::new(1, "Podcasts"));
+        server.add_feed(MockFeed::new(10, 1));
+        server.add_entries([
+            MockEntry::new(1, 10).enclosure(MockEnclosure::new(901).media_progression(30)),
+            MockEntry::new(2, 10).enclosure(MockEnclosure::new(902).media_progression(30)),
+        ]);
+        let client = client_for(&server);
+
+        sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+        assert_eq!(server.request_count("GET", "/v1/enclosures/901"), 0);
+        assert_eq!(server.request_count("GET", "/v1/enclosures/902"), 0);
+
+        // Entry 1 changes and comes with its position; entry 2's position
+        // moves without touching the entry
+        let changed_at = later();
+        server.update_entry(1, |entry| {
+            entry.changed_at = changed_at;
+            entry.enclosures[0].media_progression = 90;
+        });
+        server.update_entry(2, |entry| entry.enclosures[0].media_progression = 60);
+
+        sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(server.request_count("GET", "/v1/enclosures/901"), 0);
+        assert_eq!(server.request_count("GET", "/v1/enclosures/902"), 1);
+        let positions: Vec<(i64, i64)> = sqlx::query_as(
+            r#"SELECT entry_id, "current_time" FROM podcast_progress ORDER BY entry_id"#,
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(positions, vec![(1, 90), (2, 60)]);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_sync_recovers_after_interruption() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = seeded_server(1, 10, 1..=10).await;
+        let client = client_for(&server);
+
+        sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+
+        let changed_at = later();
+        server.add_entries((11..=130).map(|id| MockEntry::new(id, 10).changed_at(changed_at)));
+        server.inject(
+            Fault::status(500)
+                .on("GET", "/v1/entries")
+                .after(1)
+                .times(1),
+        );
+
+        let error = sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap_err();
+        assert!(error.contains("500"), "unexpected error: {error}");
+        assert_eq!(entry_ids(&pool, 1).await.len(), 60);
+        assert!(
+            get_or_create_sync_state(&pool, 1)
+                .await
+                .unwrap()
+                .sync_in_progress
+        );
+
+        let summary = sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.entries_pulled, 120);
+        assert_eq!(entry_ids(&pool, 1).await, (1..=130).collect::<Vec<_>>());
+        assert!(
+            !get_or_create_sync_state(&pool, 1)
+                .await
+                .unwrap()
+                .sync_in_progress
+        );
+    }
+
+    fn entries_offsets(server: &MockMiniflux) -> Vec<String> {
+        server
+            .requests()
+            .into_iter()
+            .filter(|request| request.method == "GET" && request.path == "/v1/entries")
+            .filter_map(|request| {
+                request
+                    .query
+                    .into_iter()
+                    .find(|(name, _)| name == "offset")
+                    .map(|(_, value)| value)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_full_sync_resumes_from_checkpoint() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = seeded_server(1, 10, 1..=120).await;
+        let client = client_for(&server);
+        server.inject(
+            Fault::status(500)
+                .on("GET", "/v1/entries")
+                .after(1)
+                .times(1),
+        );
+
+        sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap_err();
+
+        let state = get_or_create_sync_state(&pool, 1).await.unwrap();
+        assert!(state.last_full_sync_at.is_none());
+        assert_eq!(state.entries_offset, 50);
+        assert_eq!(state.entries_total, 120);
+        let started_at = state.sync_started_at.expect("checkpoint start time");
+
+        let summary = sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.entries_pulled, 120);
+        assert_eq!(entry_ids(&pool, 1).await, (1..=120).collect::<Vec<_>>());
+        // First page, the failed second page, then the resumed run picks up at 50
+        assert_eq!(entries_offsets(&server), vec!["0", "50", "50", "100"]);
+
+        let state = get_or_create_sync_state(&pool, 1).await.unwrap();
+        assert_eq!(
+            state.last_full_sync_at.as_deref(),
+            Some(started_at.as_str())
+        );
+        assert_eq!(state.last_sync_at.as_deref(), Some(started_at.as_str()));
+        assert_eq!(state.entries_offset, 0);
+        assert!(state.sync_started_at.is_none());
+
+        let stale: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE sync_status = 'stale'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(stale, 0);
+    }
+
+    #[tokio::test]
+    async fn test_entry_pages_are_fetched_concurrently_and_written_in_order() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = seeded_server(1, 10, 1..=1000).await;
+        // Slow pages after the first keep several requests open at once
+        server.inject(
+            Fault::latency(Duration::from_millis(100))
+                .on("GET", "/v1/entries")
+                .after(1),
+        );
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let recorded = progress.clone();
+        app.listen("sync-progress", move |event| {
+            let event: serde_json::Value = serde_json::from_str(event.payload()).unwrap();
+            if event["event"] == "EntriesProgress" {
+                recorded.lock().unwrap().push((
+                    event["pulled"].as_u64().unwrap(),
+                    event["total"].as_u64().unwrap(),
+                    event["percentage"].as_f64().unwrap(),
+                ));
+            }
+        });
+
+        let summary = sync_miniflux_impl(&pool, &client_for(&server), app.handle(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.entries_pulled, 1000);
+        assert_eq!(entry_ids(&pool, 1).await, (1..=1000).collect::<Vec<_>>());
+        assert!(
+            server.peak_concurrency() > 1,
+            "entry pages were fetched one at a time"
+        );
+
+        // Progress only ever moves forward and ends at the total
+        let progress = progress.lock().unwrap().clone();
+        assert!(progress.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(progress.iter().all(|(_, total, _)| *total == 1000));
+        let (pulled, _, percentage) = *progress.last().unwrap();
+        assert_eq!(pulled, 1000);
+        assert_eq!(percentage, 100.0);
+    }
+
+    /// Two categories with a feed each: 1/10 holds entries 1-20, 2/20 holds 21-40
+    async fn two_feed_server() -> MockMiniflux {
+        let server = MockMiniflux::start(MockUser::new(1, "reader")).await;
+        server.add_category(MockCategory::new(1, "News"));
+        server.add_category(MockCategory::new(2, "Podcasts"));
+        server.add_feed(MockFeed::new(10, 1));
+        server.add_feed(MockFeed::new(20, 2));
+        server.add_entries((1..=20).map(|id| MockEntry::new(id, 10)));
+        server.add_entries((21..=40).map(|id| MockEntry::new(id, 20)));
+        server
+    }
+
+    #[tokio::test]
+    async fn test_sync_feed_pulls_only_that_feed() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = two_feed_server().await;
+        let client = client_for(&server);
+
+        sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+        let last_sync_at = get_or_create_sync_state(&pool, 1)
+            .await
+            .unwrap()
+            .last_sync_at;
+
+        let changed_at = later();
+        server.add_entries([
+            MockEntry::new(41, 10).changed_at(changed_at),
+            MockEntry::new(42, 20).changed_at(changed_at),
+        ]);
+
+        let summary = sync_scope_impl(
+            &pool,
+            &client,
+            app.handle(),
+            1,
+            SyncScope::Feed { id: 10 },
+            ScopePolling::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.entries_pulled, 1);
+        assert_eq!(summary.feeds_pulled, 1);
+        let ids = entry_ids(&pool, 1).await;
+        assert!(ids.contains(&41));
+        assert!(!ids.contains(&42));
+        assert_eq!(server.request_count("PUT", "/v1/feeds/10/refresh"), 1);
+
+        let last_request = server
+            .requests()
+            .into_iter()
+            .rfind(|request| request.path == "/v1/entries")
+            .unwrap();
+        for name in ["feed_id", "changed_after"] {
+            assert!(last_request.query.iter().any(|(param, _)| param == name));
+        }
+
+        // The account's own sync bookkeeping is untouched
+        let state = get_or_create_sync_state(&pool, 1).await.unwrap();
+        assert_eq!(state.last_sync_at, last_sync_at);
+        assert_eq!(state.entries_offset, 0);
+    }
+
+    #[tokio::test]
+    async fn test_sync_category_waits_for_background_refresh() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = two_feed_server().await;
+        let client = client_for(&server);
+
+        sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+
+        let polling = ScopePolling {
+            retries: 10,
+            interval: Duration::from_millis(30),
+        };
+        let sync = sync_scope_impl(
+            &pool,
+            &client,
+            app.handle(),
+            1,
+            SyncScope::Category { id: 2 },
+            polling,
+        );
+        // The server's re-crawl lands a little after the refresh request
+        let crawl = async {
+            tokio::time::sleep(Duration::from_millis(80)).await;
+            server.add_entries((43..=45).map(|id| MockEntry::new(id, 20).changed_at(later())));
+        };
+        let (summary, ()) = tokio::join!(sync, crawl);
+
+        assert_eq!(summary.unwrap().entries_pulled, 3);
+        assert_eq!(entry_ids(&pool, 1).await.len(), 43);
+        assert_eq!(server.request_count("PUT", "/v1/categories/2/refresh"), 1);
+        // The full sync, at least one empty poll, then the pull that found them
+        assert!(server.request_count("GET", "/v1/entries") > 2);
+    }
+
+    #[tokio::test]
+    async fn test_sync_category_is_not_ended_by_older_changes() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = two_feed_server().await;
+        let client = client_for(&server);
+
+        sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+        // Entry 46 changed after the last sync but before the refresh
+        let last_sync_at = chrono::Utc::now() - chrono::Duration::minutes(2);
+        sqlx::query("UPDATE sync_state SET last_sync_at = ? WHERE account_id = 1")
+            .bind(last_sync_at.to_rfc3339())
+            .execute(&pool)
+            .await
+            .unwrap();
+        server.add_entries([MockEntry::new(46, 20).changed_at(last_sync_at.timestamp() + 60)]);
+
+        let polling = ScopePolling {
+            retries: 10,
+            interval: Duration::from_millis(30),
+        };
+        let sync = sync_scope_impl(
+            &pool,
+            &client,
+            app.handle(),
+            1,
+            SyncScope::Category { id: 2 },
+            polling,
+        );
+        let crawl = async {
+            tokio::time::sleep(Duration::from_millis(80)).await;
+            server.add_entries((43..=45).map(|id| MockEntry::new(id, 20).changed_at(later())));
+        };
+        let (summary, ()) = tokio::join!(sync, crawl);
+
+        assert_eq!(summary.unwrap().entries_pulled, 4);
+        let ids = entry_ids(&pool, 1).await;
+        for id in 43..=46 {
+            assert!(ids.contains(&id), "entry {id} was not pulled");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_switching_accounts_keeps_data_separate() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let first = seeded_server(1, 10, 1..=30).await;
+        let second = seeded_server(2, 20, 101..=140).await;
+
+        sync_miniflux_impl(&pool, &client_for(&first), app.handle(), 1)
+            .await
+            .unwrap();
+        sync_miniflux_impl(&pool, &client_for(&second), app.handle(), 2)
+            .await
+            .unwrap();
+        // Back to the first account, now incrementally
+        sync_miniflux_impl(&pool, &client_for(&first), app.handle(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(entry_ids(&pool, 1).await, (1..=30).collect::<Vec<_>>());
+        assert_eq!(entry_ids(&pool, 2).await, (101..=140).collect::<Vec<_>>());
+
+        let feeds: Vec<(i64, i64)> = sqlx::query_as("SELECT id, user_id FROM feeds ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(feeds, vec![(10, 1), (20, 2)]);
+
+        for account_id in [1, 2] {
+            let state = get_or_create_sync_state(&pool, account_id).await.unwrap();
+            assert!(state.last_full_sync_at.is_some());
+            assert!(state.sync_error.is_none());
+        }
+    }
+
+    async fn insert_account(pool: &SqlitePool, id: i64, server: &MockMiniflux, is_active: bool) {
+        sqlx::query(
+            "INSERT INTO miniflux_connections (id, username, server_url, auth_method, is_active, created_at, updated_at) VALUES (?, ?, ?, 'token', ?, '', '')",
+        )
+        .bind(id)
+        .bind(format!("user-{id}"))
+        .bind(server.url())
+        .bind(is_active)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_all_accounts_sync_side_by_side() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let work = seeded_server(1, 10, 1..=30).await;
+        let personal = seeded_server(2, 20, 101..=120).await;
+        insert_account(&pool, 1, &work, true).await;
+        insert_account(&pool, 2, &personal, false).await;
+
+        let background = Arc::new(Mutex::new(Vec::new()));
+        let recorded = background.clone();
+        app.listen("account-sync-progress", move |event| {
+            let event: serde_json::Value = serde_json::from_str(event.payload()).unwrap();
+            recorded
+                .lock()
+                .unwrap()
+                .push(event["account_id"].as_str().unwrap().to_string());
+        });
+
+        let results = sync_accounts_impl(
+            &pool,
+            app.handle(),
+            vec![(1, client_for(&work)), (2, client_for(&personal))],
+        )
+        .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|result| result.error.is_none()));
+        for account_id in [1, 2] {
+            let state = get_or_create_sync_state(&pool, account_id).await.unwrap();
+            assert!(state.last_full_sync_at.is_some());
+        }
+
+        let per_account: Vec<(i64, i64)> = sqlx::query_as(
+            "SELECT account_id, COUNT(*) FROM entries GROUP BY account_id ORDER BY account_id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(per_account, vec![(1, 30), (2, 20)]);
+
+        // Only the background account reports on its own channel
+        let background = background.lock().unwrap().clone();
+        assert!(!background.is_empty());
+        assert!(background.iter().all(|account_id| account_id == "2"));
+
+        // The all-accounts view lists both, badged with their account
+        let all_accounts = EntryFilters {
+            all_accounts: Some(true),
+            ..EntryFilters::default()
+        };
+        let response = get_entries_from_db(&pool, &all_accounts, 1).await.unwrap();
+        assert_eq!(response.total, 50);
+        let entries = response.entries.unwrap();
+        assert!(entries
+            .iter()
+            .all(|entry| entry.account_id == Some(if entry.id > 100 { 2 } else { 1 })));
+
+        let active_only = get_entries_from_db(&pool, &EntryFilters::default(), 1)
+            .await
+            .unwrap();
+        assert_eq!(active_only.total, 30);
+    }
+
+    #[tokio::test]
+    async fn test_accounts_with_overlapping_ids_keep_their_own_rows() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        // Two servers that hand out the same user, feed and entry IDs
+        let work = seeded_server(1, 10, 1..=30).await;
+        let personal = seeded_server(1, 10, 1..=20).await;
+        insert_account(&pool, 1, &work, true).await;
+        insert_account(&pool, 2, &personal, false).await;
+
+        let results = sync_accounts_impl(
+            &pool,
+            app.handle(),
+            vec![(1, client_for(&work)), (2, client_for(&personal))],
+        )
+        .await;
+        assert!(results.iter().all(|result| result.error.is_none()));
+
+        // A full sync of the first account only prunes its own rows
+        sync_miniflux_impl(&pool, &client_for(&work), app.handle(), 1)
+            .await
+            .unwrap();
+
+        let per_account: Vec<(i64, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT e.account_id, COUNT(*), COUNT(DISTINCT f.account_id)
+            FROM entries e
+            JOIN feeds f ON f.account_id = e.account_id AND f.id = e.feed_id
+            GROUP BY e.account_id
+            ORDER BY e.account_id
+            "#,
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(per_account, vec![(1, 30, 1), (2, 20, 1)]);
+
+        let all_accounts = EntryFilters {
+            all_accounts: Some(true),
+            ..EntryFilters::default()
+        };
+        let response = get_entries_from_db(&pool, &all_accounts, 1).await.unwrap();
+        assert_eq!(response.total, 50);
+        let active_only = get_entries_from_db(&pool, &EntryFilters::default(), 2)
+            .await
+            .unwrap();
+        assert_eq!(active_only.total, 20);
+    }
+
+    #[tokio::test]
+    async fn test_transient_failures_are_retried() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = seeded_server(1, 10, 1..=5).await;
+        server.inject(Fault::latency(Duration::from_millis(50)).on("GET", "/v1/me"));
+        server.inject(Fault::status(503).on("GET", "/v1/feeds").times(1));
+        server.inject(Fault::rate_limited(0).on("GET", "/v1/entries").times(1));
+        let client = MinifluxClient::new(server.url())
+            .with_token(server.token())
+            .with_retry_policy(RetryPolicy {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(10),
+                max_delay: Duration::from_millis(50),
+                deadline: Duration::from_secs(10),
+            });
+
+        let summary = sync_miniflux_impl(&pool, &client, app.handle(), 1)
+            .await
+            .unwrap();
+
+        assert_eq!(summary.entries_pulled, 5);
+        assert_eq!(server.request_count("GET", "/v1/feeds"), 2);
+        assert_eq!(server.request_count("GET", "/v1/entries"), 2);
+
+        let run = get_sync_history_from_db(&pool, Some(1), 1)
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(run.sync_type, "full");
+        assert_eq!(run.status, "succeeded");
+        assert_eq!(run.entries_pulled, 5);
+        assert_eq!(run.pages, 1);
+        assert_eq!(run.failures, 2);
+        assert_eq!(run.error_chain.len(), 2);
+        assert!(run.requests >= 6);
+        assert!(run.bytes_transferred > 0);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_response_fails_sync() {
+        let pool = setup_test_db().await;
+        let app = mock_app();
+        let server = seeded_server(1, 10, 1..=5).await;
+        server.inject(Fault::malformed_json().on("GET", "/v1/entries"));
+
+        let error = sync_miniflux_impl(&pool, &client_for(&server), app.handle(), 1)
+            .await
+            .unwrap_err();
+
+        assert!(
+            error.starts_with("Parse error"),
+            "unexpected error: {error}"
+        );
+        assert!(entry_ids(&pool, 1).await.is_empty());
+
+        let run = get_sync_history_from_db(&pool, Some(1), 1)
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(run.status, "failed");
+        assert_eq!(run.error_chain.last(), Some(&error));
+    }
+}
@@ -1,9 +1,29 @@
 use chrono::{TimeZone, Utc};
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
-use sqlx::{FromRow, QueryBuilder, SqlitePool};
-use tauri::{AppHandle, Emitter, State};
-
-use crate::miniflux::{EntryFilters, MinifluxClient};
+use sqlx::{FromRow, QueryBuilder, SqliteConnection, SqlitePool};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Runtime, State};
+use tokio::task::JoinSet;
+
+use crate::commands::accounts::stored_auth_config;
+use crate::commands::filter_rules::{
+    apply_filter_rules, load_enabled_filter_rules, send_rule_notifications, CompiledFilterRule,
+};
+use crate::commands::miniflux::get_active_account_id;
+use crate::commands::podcast::{
+    merge_remote_progression, pull_episode_progressions, pulled_progressions,
+    record_remote_progression,
+};
+use crate::commands::preferences::load_preferences_sync;
+use crate::commands::retention::prune_entries;
+use crate::commands::sync_history::{finish_sync_run, start_sync_run, SyncRunMetrics, SyncRunType};
+use crate::miniflux::{EntryFilters, EntryResponse, MinifluxClient, MinifluxError, TransferStats};
+use crate::types::SyncConflictRule;
+use crate::utils::serde_helpers::{deserialize_i64_from_string_or_number, serialize_i64_as_string};
 use crate::AppState;
 
 /// Sync progress events for granular progress tracking
@@ -27,6 +47,89 @@ pub enum SyncProgressEvent {
     EntriesCompleted,
     CleanupStarted,
     CleanupCompleted,
+    /// Entries removed from the local cache by retention policies
+    EntriesPruned {
+        count: u32,
+    },
+}
+
+/// Progress of an account synced in the background by `sync_all_accounts`,
+/// emitted as `account-sync-progress` so the active account's
+/// `sync-progress` stays its own
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct AccountSyncProgressEvent {
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub account_id: i64,
+    pub progress: SyncProgressEvent,
+}
+
+/// Reports an account sync's progress to the channel its account listens on
+struct SyncProgress<'a, R: Runtime> {
+    app_handle: &'a AppHandle<R>,
+    /// Set when the account isn't the active one
+    background_account: Option<i64>,
+}
+
+impl<R: Runtime> SyncProgress<'_, R> {
+    fn emit(&self, progress: SyncProgressEvent) {
+        let _ = match self.background_account {
+            Some(account_id) => self.app_handle.emit(
+                "account-sync-progress",
+                &AccountSyncProgressEvent {
+                    account_id,
+                    progress,
+                },
+            ),
+            None => self.app_handle.emit("sync-progress", &progress),
+        };
+    }
+}
+
+/// The part of an account an on-demand sync covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SyncScope {
+    Feed {
+        #[serde(
+            serialize_with = "serialize_i64_as_string",
+            deserialize_with = "deserialize_i64_from_string_or_number"
+        )]
+        #[specta(type = String)]
+        id: i64,
+    },
+    Category {
+        #[serde(
+            serialize_with = "serialize_i64_as_string",
+            deserialize_with = "deserialize_i64_from_string_or_number"
+        )]
+        #[specta(type = String)]
+        id: i64,
+    },
+}
+
+/// Progress of a `sync_feed` / `sync_category` run, emitted as
+/// `scope-sync-progress` so it doesn't disturb the account sync's progress
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "event")]
+pub enum ScopeSyncProgressEvent {
+    /// The server was asked to re-crawl the scope
+    Refreshing { scope: SyncScope },
+    /// Nothing new yet; checking again after a pause
+    Waiting { scope: SyncScope, attempt: u32 },
+    EntriesProgress {
+        scope: SyncScope,
+        pulled: u32,
+        total: u32,
+        percentage: f32,
+    },
+    Completed {
+        scope: SyncScope,
+        entries_pulled: u32,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
@@ -37,6 +140,19 @@ pub struct SyncSummary {
     pub categories_pulled: u32,
 }
 
+/// How one account's run in `sync_all_accounts` went
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct AccountSyncResult {
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub account_id: i64,
+    pub summary: Option<SyncSummary>,
+    pub error: Option<String>,
+}
+
 /// Persisted sync status returned to the frontend for display
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 pub struct SyncStatus {
@@ -46,6 +162,35 @@ pub struct SyncStatus {
     pub entries_synced: u32,
 }
 
+/// A pull that disagreed with a pending local change, and how it was resolved
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, FromRow)]
+pub struct SyncConflict {
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub id: i64,
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub entry_id: i64,
+    pub entry_title: Option<String>,
+    /// "status" or "starred"
+    pub field: String,
+    pub local_value: String,
+    pub server_value: String,
+    pub local_changed_at: Option<String>,
+    pub server_changed_at: Option<String>,
+    /// Rule in effect when the conflict was resolved
+    pub rule: String,
+    /// "local" or "server"
+    pub resolution: String,
+    pub created_at: String,
+}
+
 #[derive(Debug, Clone, FromRow)]
 #[allow(dead_code)]
 pub struct SyncState {
@@ -62,8 +207,28 @@ pub struct SyncState {
     pub categories_synced: i64,
     pub feeds_synced: i64,
     pub entries_synced: i64,
+    pub sync_started_at: Option<String>,
 }
 
+/// Bounds for the number of entries asked for per page
+const MIN_PAGE_SIZE: i64 = 25;
+const MAX_PAGE_SIZE: i64 = 500;
+
+/// How many entry pages are fetched at once after the first one
+const MAX_CONCURRENT_PAGES: usize = 4;
+
+/// Pages slower or larger than this shrink the page size; pages well under
+/// both grow it
+const TARGET_PAGE_LATENCY: Duration = Duration::from_secs(2);
+const TARGET_PAGE_BYTES: usize = 2 * 1024 * 1024;
+
+/// Held while a sync writes entries in multi-statement transactions. Accounts
+/// sync concurrently, and two SQLite transactions that both read before
+/// writing can't be settled by waiting for the lock, so they take turns.
+static ENTRY_WRITES: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Page size for the entries pull, adapted to how quickly the server answers
+/// and how heavy its entries are
 #[derive(Debug, Clone, Copy)]
 struct SyncWindow {
     limit: i64,
@@ -76,9 +241,18 @@ impl Default for SyncWindow {
 }
 
 impl SyncWindow {
-    fn grow_batch_size(&mut self) {
-        if self.limit < 500 {
-            self.limit = (self.limit * 2).min(500);
+    /// Takes a fetched page into account. `bytes` is scaled to the current
+    /// limit so a short last page doesn't look cheap.
+    fn record_page(&mut self, latency: Duration, entries: usize, bytes: usize) {
+        if entries == 0 {
+            return;
+        }
+        let projected_bytes = bytes / entries * self.limit as usize;
+
+        if latency > TARGET_PAGE_LATENCY || projected_bytes > TARGET_PAGE_BYTES {
+            self.limit = (self.limit / 2).max(MIN_PAGE_SIZE);
+        } else if latency < TARGET_PAGE_LATENCY / 2 && projected_bytes < TARGET_PAGE_BYTES / 2 {
+            self.limit = (self.limit * 2).min(MAX_PAGE_SIZE);
         }
     }
 }
@@ -89,7 +263,7 @@ pub async fn get_or_create_sync_state(
     account_id: i64,
 ) -> Result<SyncState, String> {
     if let Some(row) = sqlx::query_as::<_, SyncState>(
-        "SELECT id, last_sync_at, last_full_sync_at, sync_in_progress, sync_error, sync_version, entries_offset, entries_pulled, entries_total, account_id, categories_synced, feeds_synced, entries_synced FROM sync_state WHERE account_id = ? LIMIT 1",
+        "SELECT id, last_sync_at, last_full_sync_at, sync_in_progress, sync_error, sync_version, entries_offset, entries_pulled, entries_total, account_id, categories_synced, feeds_synced, entries_synced, sync_started_at FROM sync_state WHERE account_id = ? LIMIT 1",
     )
     .bind(account_id)
     .fetch_optional(pool)
@@ -108,7 +282,7 @@ pub async fn get_or_create_sync_state(
     .map_err(|e| format!("{e}"))?;
 
     sqlx::query_as::<_, SyncState>(
-        "SELECT id, last_sync_at, last_full_sync_at, sync_in_progress, sync_error, sync_version, entries_offset, entries_pulled, entries_total, account_id, categories_synced, feeds_synced, entries_synced FROM sync_state WHERE account_id = ? LIMIT 1",
+        "SELECT id, last_sync_at, last_full_sync_at, sync_in_progress, sync_error, sync_version, entries_offset, entries_pulled, entries_total, account_id, categories_synced, feeds_synced, entries_synced, sync_started_at FROM sync_state WHERE account_id = ? LIMIT 1",
     )
     .bind(account_id)
     .fetch_one(pool)
@@ -116,43 +290,754 @@ pub async fn get_or_create_sync_state(
     .map_err(|e| format!("{e}"))
 }
 
-#[allow(dead_code)]
-pub async fn enqueue_sync_operation(
-    pool: &SqlitePool,
-    entity_type: &str,
-    entity_id: i64,
-    operation_type: &str,
-    payload: &str,
-) -> Result<i64, String> {
-    let now = Utc::now().to_rfc3339();
-    let result = sqlx::query(
-        r#"
-        INSERT INTO sync_queue (operation_type, entity_type, entity_id, payload, retry_count, status, created_at, updated_at)
-        VALUES (?, ?, ?, ?, 0, 'pending', ?, ?)
-        "#,
-    )
-    .bind(operation_type)
-    .bind(entity_type)
-    .bind(entity_id)
-    .bind(payload)
-    .bind(&now)
-    .bind(&now)
-    .execute(pool)
-    .await
-    .map_err(|e| format!("{e}"))?;
+/// Maximum number of failed replays before a queued operation is parked as `failed`.
+const MAX_QUEUE_RETRIES: i64 = 8;
+
+/// A local mutation waiting to be pushed to Miniflux.
+#[derive(Debug, Clone, FromRow)]
+struct QueuedOperation {
+    id: i64,
+    account_id: i64,
+    operation_type: String,
+    entity_type: String,
+    entity_id: i64,
+    payload: String,
+    retry_count: i64,
+    updated_at: String,
+}
+
+/// Records a local mutation in `sync_queue` so it survives offline periods and
+/// app restarts. Any pending operation of the same type for the same entity is
+/// superseded: only the latest intent needs to reach the server.
+pub async fn enqueue_sync_operation(
+    pool: &SqlitePool,
+    account_id: i64,
+    entity_type: &str,
+    entity_id: i64,
+    operation_type: &str,
+    payload: &str,
+) -> Result<i64, String> {
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| format!("Failed to acquire connection: {e}"))?;
+
+    enqueue_with_connection(
+        &mut *conn,
+        account_id,
+        entity_type,
+        entity_id,
+        operation_type,
+        payload,
+    )
+    .await
+}
+
+async fn enqueue_with_connection(
+    conn: &mut SqliteConnection,
+    account_id: i64,
+    entity_type: &str,
+    entity_id: i64,
+    operation_type: &str,
+    payload: &str,
+) -> Result<i64, String> {
+    let now = Utc::now().to_rfc3339();
+
+    sqlx::query(
+        "DELETE FROM sync_queue WHERE account_id = ? AND entity_type = ? AND entity_id = ? AND operation_type = ? AND status = 'pending'",
+    )
+    .bind(account_id)
+    .bind(entity_type)
+    .bind(entity_id)
+    .bind(operation_type)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| format!("{e}"))?;
+
+    let result = sqlx::query(
+        r#"
+        INSERT INTO sync_queue (account_id, operation_type, entity_type, entity_id, payload, retry_count, status, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, 0, 'pending', ?, ?)
+        "#,
+    )
+    .bind(account_id)
+    .bind(operation_type)
+    .bind(entity_type)
+    .bind(entity_id)
+    .bind(payload)
+    .bind(&now)
+    .bind(&now)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| format!("{e}"))?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Queues a read/unread change for each entry. Returns the queue row IDs so the
+/// caller can complete them right away if the immediate API call succeeds.
+pub async fn queue_entry_status_change(
+    pool: &SqlitePool,
+    account_id: i64,
+    entry_ids: &[i64],
+    status: &str,
+) -> Result<Vec<i64>, String> {
+    let payload = serde_json::json!({ "status": status }).to_string();
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start sync queue transaction: {e}"))?;
+
+    let mut queue_ids = Vec::with_capacity(entry_ids.len());
+    for entry_id in entry_ids {
+        let queue_id = enqueue_with_connection(
+            &mut *transaction,
+            account_id,
+            "entry",
+            *entry_id,
+            "update_status",
+            &payload,
+        )
+        .await
+        .map_err(|e| format!("Failed to queue entry status change: {e}"))?;
+        queue_ids.push(queue_id);
+
+        sqlx::query("UPDATE entries SET sync_status = 'pending' WHERE account_id = ? AND id = ?")
+            .bind(account_id)
+            .bind(entry_id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| format!("Failed to mark entry pending: {e}"))?;
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit sync queue transaction: {e}"))?;
+
+    Ok(queue_ids)
+}
+
+/// Queues the desired starred state for an entry. The absolute value is stored
+/// (not a toggle) so replaying it is idempotent.
+pub async fn queue_entry_starred_change(
+    pool: &SqlitePool,
+    account_id: i64,
+    entry_id: i64,
+    starred: bool,
+) -> Result<i64, String> {
+    let payload = serde_json::json!({ "starred": starred }).to_string();
+    let queue_id =
+        enqueue_sync_operation(pool, account_id, "entry", entry_id, "set_starred", &payload)
+            .await
+            .map_err(|e| format!("Failed to queue entry starred change: {e}"))?;
+
+    sqlx::query("UPDATE entries SET sync_status = 'pending' WHERE account_id = ? AND id = ?")
+        .bind(account_id)
+        .bind(entry_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to mark entry pending: {e}"))?;
+
+    Ok(queue_id)
+}
+
+/// Removes queue rows whose operations have reached the server (or no longer
+/// need to) and releases entries that have nothing left to push.
+pub async fn complete_sync_operations(pool: &SqlitePool, queue_ids: &[i64]) -> Result<(), String> {
+    if queue_ids.is_empty() {
+        return Ok(());
+    }
+
+    for chunk in queue_ids.chunks(500) {
+        let mut builder: QueryBuilder<sqlx::Sqlite> =
+            QueryBuilder::new("DELETE FROM sync_queue WHERE id IN (");
+        let mut separated = builder.separated(",");
+        for id in chunk {
+            separated.push_bind(id);
+        }
+        builder.push(") RETURNING account_id, entity_type, entity_id");
+
+        let removed: Vec<(i64, String, i64)> = builder
+            .build_query_as()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("Failed to complete sync queue operations: {e}"))?;
+
+        let mut entry_ids_by_account: HashMap<i64, Vec<i64>> = HashMap::new();
+        for (account_id, entity_type, entity_id) in removed {
+            if entity_type == "entry" {
+                entry_ids_by_account
+                    .entry(account_id)
+                    .or_default()
+                    .push(entity_id);
+            }
+        }
+        for (account_id, entry_ids) in entry_ids_by_account {
+            release_pending_entries(pool, account_id, &entry_ids).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Flips `entries.sync_status` back to `synced` for entries without any
+/// remaining pending queue operation.
+async fn release_pending_entries(
+    pool: &SqlitePool,
+    account_id: i64,
+    entry_ids: &[i64],
+) -> Result<(), String> {
+    if entry_ids.is_empty() {
+        return Ok(());
+    }
+
+    let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        "UPDATE entries SET sync_status = 'synced' WHERE sync_status = 'pending' AND NOT EXISTS (SELECT 1 FROM sync_queue q WHERE q.account_id = entries.account_id AND q.entity_type = 'entry' AND q.entity_id = entries.id AND q.status = 'pending') AND account_id = ",
+    );
+    builder.push_bind(account_id);
+    builder.push(" AND id IN (");
+    let mut separated = builder.separated(",");
+    for id in entry_ids {
+        separated.push_bind(id);
+    }
+    builder.push(")");
+
+    builder
+        .build()
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to release synced entries: {e}"))?;
+
+    Ok(())
+}
+
+/// Delay before a failed operation is retried: 30s doubling per attempt, capped at one hour.
+fn queue_retry_delay(retry_count: i64) -> chrono::Duration {
+    let exponent = retry_count.saturating_sub(1).clamp(0, 7) as u32;
+    let seconds = (30_i64 * 2_i64.pow(exponent)).min(3600);
+    chrono::Duration::seconds(seconds)
+}
+
+fn is_queue_operation_due(operation: &QueuedOperation, now: chrono::DateTime<Utc>) -> bool {
+    if operation.retry_count == 0 {
+        return true;
+    }
+
+    match chrono::DateTime::parse_from_rfc3339(&operation.updated_at) {
+        Ok(updated_at) => {
+            updated_at.with_timezone(&Utc) + queue_retry_delay(operation.retry_count) <= now
+        }
+        Err(_) => true,
+    }
+}
+
+async fn record_queue_failure(
+    pool: &SqlitePool,
+    operation: &QueuedOperation,
+    error: &str,
+) -> Result<(), String> {
+    let retry_count = operation.retry_count + 1;
+    let status = if retry_count >= MAX_QUEUE_RETRIES {
+        "failed"
+    } else {
+        "pending"
+    };
+
+    sqlx::query(
+        "UPDATE sync_queue SET retry_count = ?, status = ?, error_message = ?, updated_at = ? WHERE id = ?",
+    )
+    .bind(retry_count)
+    .bind(status)
+    .bind(error)
+    .bind(Utc::now().to_rfc3339())
+    .bind(operation.id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to record sync queue failure: {e}"))?;
+
+    if status == "failed" && operation.entity_type == "entry" {
+        release_pending_entries(pool, operation.account_id, &[operation.entity_id]).await?;
+    }
+
+    Ok(())
+}
+
+/// Pushes one run of consecutive status changes with a single API call.
+async fn push_status_batch(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    status: &str,
+    batch: &[QueuedOperation],
+) -> Result<u32, String> {
+    if batch.is_empty() {
+        return Ok(0);
+    }
+
+    let entry_ids: Vec<i64> = batch.iter().map(|op| op.entity_id).collect();
+    let queue_ids: Vec<i64> = batch.iter().map(|op| op.id).collect();
+
+    match client.update_entries(entry_ids, status.to_string()).await {
+        Ok(()) => {
+            complete_sync_operations(pool, &queue_ids).await?;
+            Ok(batch.len() as u32)
+        }
+        Err(error) => {
+            log::warn!(
+                "Failed to push {} queued status changes: {}",
+                batch.len(),
+                error
+            );
+            let error = error.to_string();
+            for operation in batch {
+                record_queue_failure(pool, operation, &error).await?;
+            }
+            Ok(0)
+        }
+    }
+}
+
+/// Sets the starred state of an entry on the server. Miniflux only exposes a
+/// bookmark toggle, so the server state is read first to keep this idempotent.
+pub async fn set_entry_starred_remote(
+    client: &MinifluxClient,
+    entry_id: i64,
+    starred: bool,
+) -> Result<(), MinifluxError> {
+    let entry = client.get_entry(entry_id).await?;
+    if entry.starred == starred {
+        return Ok(());
+    }
+    client.toggle_bookmark(entry_id).await
+}
+
+async fn push_starred_operation(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    operation: &QueuedOperation,
+    starred: bool,
+) -> Result<u32, String> {
+    match set_entry_starred_remote(client, operation.entity_id, starred).await {
+        Ok(()) => {
+            complete_sync_operations(pool, &[operation.id]).await?;
+            Ok(1)
+        }
+        Err(error) if error.is_not_found() => {
+            log::info!(
+                "Dropping queued starred change for entry {} (not found on server)",
+                operation.entity_id
+            );
+            complete_sync_operations(pool, &[operation.id]).await?;
+            Ok(0)
+        }
+        Err(error) => {
+            log::warn!(
+                "Failed to push queued starred change for entry {}: {}",
+                operation.entity_id,
+                error
+            );
+            record_queue_failure(pool, operation, &error.to_string()).await?;
+            Ok(0)
+        }
+    }
+}
+
+async fn push_media_progression_operation(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    operation: &QueuedOperation,
+    media_progression: i64,
+) -> Result<u32, String> {
+    match client
+        .update_enclosure_progression(operation.entity_id, media_progression)
+        .await
+    {
+        Ok(()) => {
+            complete_sync_operations(pool, &[operation.id]).await?;
+            record_remote_progression(
+                pool,
+                operation.account_id,
+                operation.entity_id,
+                media_progression,
+            )
+            .await?;
+            Ok(1)
+        }
+        Err(error) if error.is_not_found() => {
+            log::info!(
+                "Dropping queued progression for enclosure {} (not found on server)",
+                operation.entity_id
+            );
+            complete_sync_operations(pool, &[operation.id]).await?;
+            Ok(0)
+        }
+        Err(error) => {
+            log::warn!(
+                "Failed to push queued progression for enclosure {}: {}",
+                operation.entity_id,
+                error
+            );
+            record_queue_failure(pool, operation, &error.to_string()).await?;
+            Ok(0)
+        }
+    }
+}
+
+/// Pushes one queued operation on its own. Status changes go through
+/// `push_status_batch` as a batch of one.
+async fn push_single_operation(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    operation: &QueuedOperation,
+) -> Result<u32, String> {
+    let payload: serde_json::Value =
+        serde_json::from_str(&operation.payload).unwrap_or(serde_json::Value::Null);
+
+    match (
+        operation.entity_type.as_str(),
+        operation.operation_type.as_str(),
+    ) {
+        ("entry", "update_status") => {
+            let Some(status) = payload.get("status").and_then(|v| v.as_str()) else {
+                record_queue_failure(pool, operation, "Missing status in payload").await?;
+                return Ok(0);
+            };
+            push_status_batch(pool, client, status, std::slice::from_ref(operation)).await
+        }
+        ("entry", "set_starred") => {
+            let Some(starred) = payload.get("starred").and_then(|v| v.as_bool()) else {
+                record_queue_failure(pool, operation, "Missing starred in payload").await?;
+                return Ok(0);
+            };
+            push_starred_operation(pool, client, operation, starred).await
+        }
+        ("enclosure", "set_media_progression") => {
+            let Some(media_progression) = payload.get("media_progression").and_then(|v| v.as_i64())
+            else {
+                record_queue_failure(pool, operation, "Missing media_progression in payload")
+                    .await?;
+                return Ok(0);
+            };
+            push_media_progression_operation(pool, client, operation, media_progression).await
+        }
+        (entity_type, operation_type) => {
+            let error = format!("Unsupported sync operation: {entity_type}/{operation_type}");
+            log::warn!("{}", error);
+            record_queue_failure(pool, operation, &error).await?;
+            Ok(0)
+        }
+    }
+}
+
+/// Pushes a single queued operation right away, e.g. once a debounce window
+/// has passed. Returns 0 when the operation was superseded or already pushed.
+pub async fn push_queued_operation(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    queue_id: i64,
+) -> Result<u32, String> {
+    let operation = sqlx::query_as::<_, QueuedOperation>(
+        "SELECT id, account_id, operation_type, entity_type, entity_id, payload, retry_count, updated_at FROM sync_queue WHERE id = ? AND status = 'pending'",
+    )
+    .bind(queue_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Failed to load sync queue operation: {e}"))?;
+
+    match operation {
+        Some(operation) => push_single_operation(pool, client, &operation).await,
+        None => Ok(0),
+    }
+}
+
+/// Replays pending `sync_queue` operations in the order they were recorded.
+///
+/// Consecutive status changes with the same target status are sent in one
+/// request. Operations that fail stay queued with an increasing backoff and are
+/// parked as `failed` after `MAX_QUEUE_RETRIES` attempts. Returns the number of
+/// operations that reached the server.
+///
+/// Only operations queued in `account_id` are replayed, so each account's
+/// client pushes its own changes.
+pub async fn push_pending_operations(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    account_id: i64,
+) -> Result<u32, String> {
+    let operations = sqlx::query_as::<_, QueuedOperation>(
+        r#"
+        SELECT id, account_id, operation_type, entity_type, entity_id, payload, retry_count, updated_at
+        FROM sync_queue
+        WHERE status = 'pending' AND account_id = ?
+        ORDER BY id
+        "#,
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to load sync queue: {e}"))?;
+
+    let now = Utc::now();
+    let mut pushed = 0_u32;
+    let mut status_batch: Vec<QueuedOperation> = Vec::new();
+    let mut batch_status = String::new();
+
+    for operation in operations
+        .into_iter()
+        .filter(|operation| is_queue_operation_due(operation, now))
+    {
+        if operation.entity_type != "entry" || operation.operation_type != "update_status" {
+            pushed += push_status_batch(pool, client, &batch_status, &status_batch).await?;
+            status_batch.clear();
+            pushed += push_single_operation(pool, client, &operation).await?;
+            continue;
+        }
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&operation.payload).unwrap_or(serde_json::Value::Null);
+        let Some(status) = payload.get("status").and_then(|v| v.as_str()) else {
+            record_queue_failure(pool, &operation, "Missing status in payload").await?;
+            continue;
+        };
+
+        if status != batch_status {
+            pushed += push_status_batch(pool, client, &batch_status, &status_batch).await?;
+            status_batch.clear();
+            batch_status = status.to_string();
+        }
+        status_batch.push(operation);
+    }
+
+    pushed += push_status_batch(pool, client, &batch_status, &status_batch).await?;
+
+    Ok(pushed)
+}
+
+/// A pending queue operation joined to an entry that still has unpushed local edits.
+#[derive(Debug, Clone, FromRow)]
+struct PendingEntryChange {
+    queue_id: i64,
+    entry_id: i64,
+    operation_type: String,
+    payload: String,
+    queued_at: String,
+}
+
+/// Decides whether the local value survives a conflict under `rule`.
+fn local_change_wins(
+    rule: SyncConflictRule,
+    local_changed_at: &str,
+    server_changed_at: Option<&str>,
+) -> bool {
+    match rule {
+        SyncConflictRule::LocalWins => true,
+        SyncConflictRule::ServerWins => false,
+        SyncConflictRule::NewestWins => {
+            let local = chrono::DateTime::parse_from_rfc3339(local_changed_at).ok();
+            let server = server_changed_at
+                .and_then(|value| chrono::DateTime::parse_from_rfc3339(value).ok());
+            match (local, server) {
+                (Some(local), Some(server)) => local >= server,
+                // Without a server timestamp the local edit is the only dated change
+                (_, None) => true,
+                (None, Some(_)) => false,
+            }
+        }
+    }
+}
+
+/// Reconciles a page of server entries with entries whose `sync_status` is
+/// `pending` before the page is upserted.
+///
+/// When the server already matches the local change, the queued operation is
+/// settled. Otherwise the conflict is recorded in `sync_conflicts` and `rule`
+/// picks a side: a local win rewrites the server entry so the upsert keeps the
+/// local value, a server win drops the queued operation. Returns the number of
+/// conflicts recorded.
+async fn reconcile_pending_entries(
+    pool: &SqlitePool,
+    account_id: i64,
+    entries: &mut [crate::miniflux::Entry],
+    rule: SyncConflictRule,
+) -> Result<u32, String> {
+    if entries.is_empty() {
+        return Ok(0);
+    }
+
+    let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        "SELECT q.id AS queue_id, q.entity_id AS entry_id, q.operation_type, q.payload, q.created_at AS queued_at FROM sync_queue q JOIN entries e ON e.account_id = q.account_id AND e.id = q.entity_id WHERE q.account_id = ",
+    );
+    builder.push_bind(account_id);
+    builder.push(" AND q.entity_type = 'entry' AND q.status = 'pending' AND e.sync_status = 'pending' AND q.entity_id IN (");
+    let mut separated = builder.separated(",");
+    for entry in entries.iter() {
+        separated.push_bind(entry.id);
+    }
+    builder.push(") ORDER BY q.id");
+
+    let pending: Vec<PendingEntryChange> = builder
+        .build_query_as()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to load pending entry changes: {e}"))?;
+
+    if pending.is_empty() {
+        return Ok(0);
+    }
+
+    let mut changes_by_entry: HashMap<i64, Vec<PendingEntryChange>> = HashMap::new();
+    for change in pending {
+        changes_by_entry
+            .entry(change.entry_id)
+            .or_default()
+            .push(change);
+    }
+
+    let now = Utc::now().to_rfc3339();
+    let mut settled_queue_ids = Vec::new();
+    let mut conflicts = 0_u32;
+
+    for entry in entries.iter_mut() {
+        let Some(changes) = changes_by_entry.get(&entry.id) else {
+            continue;
+        };
+
+        for change in changes {
+            let payload: serde_json::Value =
+                serde_json::from_str(&change.payload).unwrap_or(serde_json::Value::Null);
+
+            let (field, local_value, server_value) = match change.operation_type.as_str() {
+                "update_status" => match payload.get("status").and_then(|v| v.as_str()) {
+                    Some(status) => ("status", status.to_string(), entry.status.clone()),
+                    None => continue,
+                },
+                "set_starred" => match payload.get("starred").and_then(|v| v.as_bool()) {
+                    Some(starred) => ("starred", starred.to_string(), entry.starred.to_string()),
+                    None => continue,
+                },
+                _ => continue,
+            };
+
+            if local_value == server_value {
+                // The server already reflects the local change
+                settled_queue_ids.push(change.queue_id);
+                continue;
+            }
+
+            let server_changed_at = entry.changed_at.clone();
+            let local_wins =
+                local_change_wins(rule, &change.queued_at, server_changed_at.as_deref());
+
+            if local_wins {
+                if field == "status" {
+                    entry.status = local_value.clone();
+                    entry.changed_at = Some(change.queued_at.clone());
+                } else {
+                    entry.starred = local_value == "true";
+                }
+            } else {
+                settled_queue_ids.push(change.queue_id);
+            }
+
+            sqlx::query(
+                "INSERT INTO sync_conflicts (account_id, user_id, entry_id, field, local_value, server_value, local_changed_at, server_changed_at, rule, resolution, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(account_id)
+            .bind(entry.user_id)
+            .bind(entry.id)
+            .bind(field)
+            .bind(&local_value)
+            .bind(&server_value)
+            .bind(&change.queued_at)
+            .bind(server_changed_at.as_deref())
+            .bind(rule.as_str())
+            .bind(if local_wins { "local" } else { "server" })
+            .bind(&now)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("Failed to record sync conflict: {e}"))?;
+
+            log::info!(
+                "Sync conflict on entry {} {}: local={} server={} -> {} wins ({})",
+                entry.id,
+                field,
+                local_value,
+                server_value,
+                if local_wins { "local" } else { "server" },
+                rule.as_str()
+            );
+            conflicts += 1;
+        }
+    }
+
+    complete_sync_operations(pool, &settled_queue_ids).await?;
+
+    Ok(conflicts)
+}
+
+pub async fn sync_miniflux_impl<R: Runtime>(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    app_handle: &AppHandle<R>,
+    account_id: i64,
+) -> Result<SyncSummary, String> {
+    sync_and_record(pool, client, app_handle, account_id, false).await
+}
+
+/// Syncs an account and records the run in `sync_runs`. `forced` marks a
+/// full sync the user asked for. Failing to record is only logged; it
+/// doesn't fail the sync.
+async fn sync_and_record<R: Runtime>(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    app_handle: &AppHandle<R>,
+    account_id: i64,
+    forced: bool,
+) -> Result<SyncSummary, String> {
+    let stats = Arc::new(TransferStats::default());
+    let client = client.clone().with_stats(stats.clone());
+
+    let planned_type = if forced {
+        SyncRunType::Forced
+    } else {
+        match get_or_create_sync_state(pool, account_id).await {
+            Ok(state) if state.last_full_sync_at.is_some() => SyncRunType::Incremental,
+            _ => SyncRunType::Full,
+        }
+    };
+    let run_id = start_sync_run(pool, account_id, planned_type)
+        .await
+        .inspect_err(|e| log::warn!("Failed to record sync run: {e}"))
+        .ok();
+
+    let mut metrics = SyncRunMetrics {
+        full_sync: planned_type != SyncRunType::Incremental,
+        ..SyncRunMetrics::default()
+    };
+    let result = run_account_sync(pool, &client, app_handle, account_id, &mut metrics).await;
 
-    Ok(result.last_insert_rowid())
+    if let Some(run_id) = run_id {
+        let sync_type = match (forced, metrics.full_sync) {
+            (true, _) => SyncRunType::Forced,
+            (false, true) => SyncRunType::Full,
+            (false, false) => SyncRunType::Incremental,
+        };
+        if let Err(e) = finish_sync_run(pool, run_id, sync_type, &result, &metrics, &stats).await {
+            log::warn!("Failed to record sync run: {e}");
+        }
+    }
+
+    result
 }
 
-pub async fn sync_miniflux_impl(
+async fn run_account_sync<R: Runtime>(
     pool: &SqlitePool,
     client: &MinifluxClient,
-    app_handle: &AppHandle,
+    app_handle: &AppHandle<R>,
     account_id: i64,
+    metrics: &mut SyncRunMetrics,
 ) -> Result<SyncSummary, String> {
-    let window = SyncWindow::default();
     let now = Utc::now().to_rfc3339();
-    let sync_started_at = now.clone();
     let mut summary = SyncSummary {
         entries_pulled: 0,
         entries_pushed: 0,
@@ -162,41 +1047,67 @@ pub async fn sync_miniflux_impl(
 
     let mut sync_state = get_or_create_sync_state(pool, account_id).await?;
     let mut is_full_sync = sync_state.last_full_sync_at.is_none();
-    let was_in_progress = sync_state.sync_in_progress;
 
-    // If a previous sync was interrupted (app crashed), it's now a stale in_progress flag
-    // Reset it so we don't think we're resuming indefinitely
-    if was_in_progress {
-        log::info!("Detected interrupted sync from previous run, resetting in_progress flag");
-        sqlx::query("UPDATE sync_state SET sync_in_progress = 0, sync_error = 'Sync interrupted by app closure' WHERE account_id = ?")
+    // An account without a connection row is treated as the active one
+    let is_active: bool =
+        sqlx::query_scalar("SELECT is_active FROM miniflux_connections WHERE id = ?")
             .bind(account_id)
-            .execute(pool)
+            .fetch_optional(pool)
             .await
-            .map_err(|e| format!("Failed to clear interrupted sync state: {e}"))?;
+            .map_err(|e| format!("Failed to query account: {e}"))?
+            .unwrap_or(true);
+    let progress = SyncProgress {
+        app_handle,
+        background_account: (!is_active).then_some(account_id),
+    };
 
-        // Reload state after clearing in_progress
-        sync_state = get_or_create_sync_state(pool, account_id).await?;
+    // A run that stopped part way through the entries (app closed, network
+    // lost) left a checkpoint behind. Carry on from it rather than starting
+    // over, and keep its start time so the next incremental sync still
+    // covers everything that changed since then.
+    let resuming = sync_state.entries_offset > 0 && sync_state.sync_started_at.is_some();
+    let sync_started_at = match &sync_state.sync_started_at {
+        Some(started_at) if resuming => started_at.clone(),
+        _ => now.clone(),
+    };
+    if resuming {
+        log::info!(
+            "Resuming interrupted {} sync at entry {} of {}",
+            if is_full_sync { "full" } else { "incremental" },
+            sync_state.entries_offset,
+            sync_state.entries_total
+        );
     }
 
     sqlx::query(
-        "UPDATE sync_state SET sync_in_progress = 1, sync_error = NULL WHERE account_id = ?",
+        "UPDATE sync_state SET sync_in_progress = 1, sync_error = NULL, sync_started_at = ? WHERE account_id = ?",
     )
+    .bind(&sync_started_at)
     .bind(account_id)
     .execute(pool)
     .await
     .map_err(|e| format!("Failed to mark sync in progress: {e}"))?;
 
-    let user = client.get_current_user().await?;
-    let user_id = user.id;
+    // Push queued local changes before pulling so the server state we are about
+    // to download already reflects them.
+    summary.entries_pushed = push_pending_operations(pool, client, account_id).await?;
+    metrics.entries_pushed = summary.entries_pushed;
+    if summary.entries_pushed > 0 {
+        log::info!("Pushed {} queued local changes", summary.entries_pushed);
+    }
+
+    // Confirms the credentials still work before any cached rows are touched
+    client.get_current_user().await?;
 
     // Recover from orphaned sync state: if last_full_sync_at is NULL but entries
-    // already exist in the DB for this user, the sync_state was lost (e.g. migration
+    // already exist in the DB for this account, the sync_state was lost (e.g. migration
     // assigned it to a different account). Recover by switching to incremental sync
-    // using the latest entry timestamp as the baseline.
-    if is_full_sync {
+    // using the latest entry timestamp as the baseline. A resumed full sync has
+    // entries from its earlier pages, which is not the same thing.
+    if is_full_sync && !resuming {
         let existing_count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE user_id = ?")
-                .bind(user_id)
+            sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE account_id = ?")
+                .bind(account_id)
                 .fetch_one(pool)
                 .await
                 .map_err(|e| format!("Failed to count existing entries: {e}"))?;
@@ -204,8 +1115,8 @@ pub async fn sync_miniflux_impl(
         if existing_count > 0 {
             // Entries exist but sync_state is blank — recover the baseline timestamp
             let latest_changed_at: Option<String> =
-                sqlx::query_scalar("SELECT MAX(changed_at) FROM entries WHERE user_id = ?")
-                    .bind(user_id)
+                sqlx::query_scalar("SELECT MAX(changed_at) FROM entries WHERE account_id = ?")
+                    .bind(account_id)
                     .fetch_one(pool)
                     .await
                     .map_err(|e| format!("Failed to query latest entry timestamp: {e}"))?;
@@ -213,10 +1124,10 @@ pub async fn sync_miniflux_impl(
             let recovery_timestamp = latest_changed_at.unwrap_or_else(|| now.clone());
 
             log::info!(
-                "Sync state recovery: found {} existing entries for user {}. \
+                "Sync state recovery: found {} existing entries for account {}. \
                  Setting last_full_sync_at and last_sync_at to {} for incremental sync.",
                 existing_count,
-                user_id,
+                account_id,
                 recovery_timestamp
             );
 
@@ -236,87 +1147,149 @@ pub async fn sync_miniflux_impl(
         }
     }
 
-    if is_full_sync {
-        mark_categories_stale(pool, user_id).await?;
-        mark_feeds_stale(pool, user_id).await?;
-        mark_entries_stale(pool, user_id).await?;
+    metrics.full_sync = is_full_sync;
+
+    // A resumed full sync marked everything stale when it first started
+    if is_full_sync && !resuming {
+        mark_categories_stale(pool, account_id).await?;
+        mark_feeds_stale(pool, account_id).await?;
+        mark_entries_stale(pool, account_id).await?;
     }
 
-    let _ = app_handle.emit("sync-progress", &SyncProgressEvent::CategoriesStarted);
+    progress.emit(SyncProgressEvent::CategoriesStarted);
 
     let categories = client.get_categories().await?;
     let category_ids: Vec<i64> = categories.iter().map(|category| category.id).collect();
-    upsert_categories(pool, &categories, &now).await?;
+    upsert_categories(pool, account_id, &categories, &now).await?;
     summary.categories_pulled = categories.len() as u32;
+    metrics.categories_pulled = summary.categories_pulled;
 
-    let _ = app_handle.emit(
-        "sync-progress",
-        &SyncProgressEvent::CategoriesCompleted {
-            count: summary.categories_pulled,
-        },
-    );
+    progress.emit(SyncProgressEvent::CategoriesCompleted {
+        count: summary.categories_pulled,
+    });
 
-    let _ = app_handle.emit("sync-progress", &SyncProgressEvent::FeedsStarted);
+    progress.emit(SyncProgressEvent::FeedsStarted);
 
     let feeds = client.get_feeds().await?;
     let feed_ids: Vec<i64> = feeds.iter().map(|feed| feed.id).collect();
-    upsert_feeds(pool, &feeds, &now).await?;
+    upsert_feeds(pool, account_id, &feeds, &now).await?;
     summary.feeds_pulled = feeds.len() as u32;
+    metrics.feeds_pulled = summary.feeds_pulled;
 
-    let _ = app_handle.emit(
-        "sync-progress",
-        &SyncProgressEvent::FeedsCompleted {
-            count: summary.feeds_pulled,
-        },
-    );
+    progress.emit(SyncProgressEvent::FeedsCompleted {
+        count: summary.feeds_pulled,
+    });
 
-    let _ = app_handle.emit("sync-progress", &SyncProgressEvent::EntriesStarted);
+    progress.emit(SyncProgressEvent::EntriesStarted);
 
-    // Reset resume fields if this is not a resumed sync (i.e., sync was not in progress)
-    if !sync_state.sync_in_progress {
-        sqlx::query(
-            "UPDATE sync_state SET entries_offset = 0, entries_pulled = 0, entries_total = 0 WHERE account_id = ?",
-        )
-        .bind(account_id)
-        .execute(pool)
+    let resume_offset = if resuming {
+        sync_state.entries_offset
+    } else {
+        let mut conn = pool
+            .acquire()
+            .await
+            .map_err(|e| format!("Failed to acquire connection: {e}"))?;
+        save_entries_checkpoint(&mut conn, account_id, 0, 0).await?;
+        0
+    };
+
+    let conflict_rule = load_preferences_sync(app_handle)
+        .map(|preferences| preferences.sync_conflict_rule)
+        .unwrap_or_default();
+    let filter_rules = load_enabled_filter_rules(pool, account_id)
         .await
-        .map_err(|e| format!("Failed to reset sync resume state: {e}"))?;
-    }
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to load filter rules: {e}");
+            Vec::new()
+        });
 
-    if is_full_sync {
-        sync_full_entries(pool, client, &window, &mut summary, app_handle).await?;
+    // Only entries changed since the last completed sync, unless this is the first one
+    let changed_after = if is_full_sync {
+        None
     } else {
-        sync_incremental_entries(
-            pool,
-            client,
-            &window,
-            &mut summary,
-            &sync_state,
-            app_handle,
-            account_id,
-        )
-        .await?;
+        sync_state
+            .last_sync_at
+            .as_ref()
+            .and_then(|timestamp| parse_rfc3339_to_epoch(timestamp).ok())
+    };
+
+    let mut pulled_entry_ids = HashSet::new();
+    let pull = EntriesPull {
+        filters: EntryFilters {
+            changed_after,
+            ..EntryFilters::default()
+        },
+        account_id,
+        checkpoint: true,
+        resume_offset,
+        pulled_entry_ids: Some(&mut pulled_entry_ids),
+    };
+    summary.entries_pulled = sync_entries(
+        pool,
+        client,
+        app_handle,
+        pull,
+        conflict_rule,
+        &filter_rules,
+        |pulled, total, percentage| {
+            metrics.pages += 1;
+            metrics.entries_pulled = pulled;
+            progress.emit(SyncProgressEvent::EntriesProgress {
+                pulled,
+                total,
+                percentage,
+            });
+        },
+    )
+    .await?;
+    metrics.entries_pulled = summary.entries_pulled;
+
+    if let Err(e) = pull_episode_progressions(pool, client, account_id, &pulled_entry_ids).await {
+        log::warn!("Failed to pull podcast progress from Miniflux: {e}");
+    }
+
+    // Push read/star changes made by filter rules now rather than next sync
+    if !filter_rules.is_empty() {
+        match push_pending_operations(pool, client, account_id).await {
+            Ok(pushed) => {
+                summary.entries_pushed = summary.entries_pushed.saturating_add(pushed);
+                metrics.entries_pushed = summary.entries_pushed;
+            }
+            Err(e) => log::warn!("Failed to push filter rule changes: {e}"),
+        }
     }
 
-    let _ = app_handle.emit("sync-progress", &SyncProgressEvent::EntriesCompleted);
+    progress.emit(SyncProgressEvent::EntriesCompleted);
 
-    let _ = app_handle.emit("sync-progress", &SyncProgressEvent::CleanupStarted);
+    progress.emit(SyncProgressEvent::CleanupStarted);
 
     if is_full_sync {
-        delete_stale_entries(pool, user_id).await?;
-        delete_stale_icons(pool, user_id).await?;
-        delete_stale_feeds(pool, user_id).await?;
-        delete_stale_categories(pool, user_id).await?;
+        metrics.entries_deleted += delete_stale_entries(pool, account_id).await?;
+        delete_stale_icons(pool, account_id).await?;
+        delete_stale_feeds(pool, account_id).await?;
+        delete_stale_categories(pool, account_id).await?;
     } else {
-        delete_removed_entries(pool, user_id, &sync_state).await?;
-        delete_removed_feeds(pool, user_id, &feed_ids).await?;
-        delete_removed_categories(pool, user_id, &category_ids).await?;
+        metrics.entries_deleted += delete_removed_entries(pool, account_id, &sync_state).await?;
+        delete_removed_feeds(pool, account_id, &feed_ids).await?;
+        delete_removed_categories(pool, account_id, &category_ids).await?;
     }
 
-    let _ = app_handle.emit("sync-progress", &SyncProgressEvent::CleanupCompleted);
+    progress.emit(SyncProgressEvent::CleanupCompleted);
+
+    let pruned = {
+        let _writes = ENTRY_WRITES.lock().await;
+        prune_entries(pool, account_id).await
+    };
+    match pruned {
+        Ok(count) => {
+            metrics.entries_deleted += count;
+            progress.emit(SyncProgressEvent::EntriesPruned { count });
+        }
+        Err(e) => log::warn!("Failed to apply retention policies: {e}"),
+    }
 
     sqlx::query(
-        "UPDATE sync_state SET last_sync_at = ?, last_full_sync_at = COALESCE(last_full_sync_at, ?), sync_in_progress = 0, sync_error = NULL, entries_offset = 0, entries_pulled = 0, entries_total = 0, categories_synced = ?, feeds_synced = ?, entries_synced = ? WHERE account_id = ?",
+        "UPDATE sync_state SET last_sync_at = ?, last_full_sync_at = COALESCE(last_full_sync_at, ?), sync_in_progress = 0, sync_error = NULL, entries_offset = 0, entries_pulled = 0, entries_total = 0, sync_started_at = NULL, categories_synced = ?, feeds_synced = ?, entries_synced = ? WHERE account_id = ?",
     )
     .bind(&sync_started_at)
     .bind(&sync_started_at)
@@ -328,11 +1301,30 @@ pub async fn sync_miniflux_impl(
     .await
     .map_err(|e| format!("Failed to update sync state: {e}"))?;
 
-    sync_state.last_sync_at = Some(now);
-
     Ok(summary)
 }
 
+/// Syncs an account, recording a failure in its `sync_state`
+async fn sync_account<R: Runtime>(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    app_handle: &AppHandle<R>,
+    account_id: i64,
+    forced: bool,
+) -> Result<SyncSummary, String> {
+    let result = sync_and_record(pool, client, app_handle, account_id, forced).await;
+    if let Err(error) = &result {
+        let _ = sqlx::query(
+            "UPDATE sync_state SET sync_in_progress = 0, sync_error = ? WHERE account_id = ?",
+        )
+        .bind(error)
+        .bind(account_id)
+        .execute(pool)
+        .await;
+    }
+    result
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn sync_miniflux(
@@ -341,6 +1333,11 @@ pub async fn sync_miniflux(
 ) -> Result<SyncSummary, String> {
     log::info!("Starting Miniflux sync");
 
+    let _sync_guard = state
+        .sync_scheduler
+        .try_begin_sync()
+        .ok_or("Sync already in progress")?;
+
     if let Err(e) = app_handle.emit("sync-started", ()) {
         log::error!("Failed to emit sync-started event: {e}");
     }
@@ -369,20 +1366,7 @@ pub async fn sync_miniflux(
             .map_err(|e| format!("Failed to query active account: {e}"))?
             .ok_or("No active account found")?;
 
-    let summary = match sync_miniflux_impl(&pool, &client, &app_handle, account_id).await {
-        Ok(summary) => summary,
-        Err(error) => {
-            let _ = sqlx::query(
-                "UPDATE sync_state SET sync_in_progress = 0, sync_error = ? WHERE account_id = ?",
-            )
-            .bind(&error)
-            .bind(account_id)
-            .execute(&pool)
-            .await;
-
-            return Err(error);
-        }
-    };
+    let summary = sync_account(&pool, &client, &app_handle, account_id, false).await?;
 
     if let Err(e) = app_handle.emit("sync-completed", &summary) {
         log::error!("Failed to emit sync-completed event: {e}");
@@ -405,10 +1389,234 @@ pub async fn force_full_sync(
 ) -> Result<SyncSummary, String> {
     log::info!("Starting forced full sync");
 
+    let _sync_guard = state
+        .sync_scheduler
+        .try_begin_sync()
+        .ok_or("Sync already in progress")?;
+
+    if let Err(e) = app_handle.emit("sync-started", ()) {
+        log::error!("Failed to emit sync-started event: {e}");
+    }
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let client = {
+        let client_guard = state.miniflux.client.lock().await;
+        client_guard
+            .as_ref()
+            .ok_or("Not connected to Miniflux server")?
+            .clone()
+    };
+
+    let account_id: i64 =
+        sqlx::query_scalar("SELECT id FROM miniflux_connections WHERE is_active = 1 LIMIT 1")
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e| format!("Failed to query active account: {e}"))?
+            .ok_or("No active account found")?;
+
+    // Reset last_full_sync_at to NULL to trigger a full sync, dropping any
+    // checkpoint so it starts from the first page
+    sqlx::query("UPDATE sync_state SET last_full_sync_at = NULL, entries_offset = 0, entries_pulled = 0, entries_total = 0, sync_started_at = NULL WHERE account_id = ?")
+        .bind(account_id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to reset full sync state: {e}"))?;
+
+    let summary = sync_account(&pool, &client, &app_handle, account_id, true).await?;
+
+    if let Err(e) = app_handle.emit("sync-completed", &summary) {
+        log::error!("Failed to emit sync-completed event: {e}");
+    }
+
+    log::info!(
+        "Full sync completed: {} entries pulled, {} pushed",
+        summary.entries_pulled,
+        summary.entries_pushed
+    );
+
+    Ok(summary)
+}
+
+/// Syncs every saved account at once, each with its own client and
+/// `sync_state`. The active account reports on `sync-progress` and
+/// `sync-completed` as usual; the others on `account-sync-progress` and
+/// `account-sync-completed`.
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_all_accounts(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<AccountSyncResult>, String> {
+    log::info!("Starting Miniflux sync of all accounts");
+
+    let _sync_guard = state
+        .sync_scheduler
+        .try_begin_sync()
+        .ok_or("Sync already in progress")?;
+
     if let Err(e) = app_handle.emit("sync-started", ()) {
         log::error!("Failed to emit sync-started event: {e}");
     }
 
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let active_client = state.miniflux.client.lock().await.clone();
+
+    let accounts: Vec<(i64, bool)> =
+        sqlx::query_as("SELECT id, is_active FROM miniflux_connections ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| format!("Failed to query accounts: {e}"))?;
+
+    let active_account = accounts
+        .iter()
+        .find(|(_, is_active)| *is_active)
+        .map(|(account_id, _)| *account_id);
+
+    // The active account reuses the connected client; the others get one
+    // built from their stored credentials
+    let mut clients = Vec::with_capacity(accounts.len());
+    let mut results = Vec::new();
+    for (account_id, is_active) in accounts {
+        let client = match active_client.clone().filter(|_| is_active) {
+            Some(client) => Ok(client),
+            None => stored_account_client(&pool, account_id).await,
+        };
+        match client {
+            Ok(client) => clients.push((account_id, client)),
+            Err(error) => {
+                log::warn!("Skipping sync of account {account_id}: {error}");
+                results.push(AccountSyncResult {
+                    account_id,
+                    summary: None,
+                    error: Some(error),
+                });
+            }
+        }
+    }
+
+    results.extend(sync_accounts_impl(&pool, &app_handle, clients).await);
+
+    for result in &results {
+        let event = if Some(result.account_id) == active_account {
+            match &result.summary {
+                Some(summary) => app_handle.emit("sync-completed", summary),
+                None => continue,
+            }
+        } else {
+            app_handle.emit("account-sync-completed", result)
+        };
+        if let Err(e) = event {
+            log::error!("Failed to emit sync completion event: {e}");
+        }
+    }
+
+    log::info!(
+        "Sync of {} accounts completed, {} failed",
+        results.len(),
+        results
+            .iter()
+            .filter(|result| result.error.is_some())
+            .count()
+    );
+
+    Ok(results)
+}
+
+/// Builds a client for a saved account from its keyring credentials
+pub(crate) async fn stored_account_client(
+    pool: &SqlitePool,
+    account_id: i64,
+) -> Result<MinifluxClient, String> {
+    let config = stored_auth_config(pool, account_id)
+        .await
+        .map_err(|e| format!("Failed to load credentials: {e}"))?;
+    MinifluxClient::from_auth_config(&config).map_err(|e| e.to_string())
+}
+
+/// Syncs each account with its client concurrently. A failing account is
+/// recorded in its result and `sync_state` without stopping the others.
+pub async fn sync_accounts_impl<R: Runtime>(
+    pool: &SqlitePool,
+    app_handle: &AppHandle<R>,
+    clients: Vec<(i64, MinifluxClient)>,
+) -> Vec<AccountSyncResult> {
+    let runs = clients.into_iter().map(|(account_id, client)| async move {
+        match sync_account(pool, &client, app_handle, account_id, false).await {
+            Ok(summary) => AccountSyncResult {
+                account_id,
+                summary: Some(summary),
+                error: None,
+            },
+            Err(error) => {
+                log::warn!("Sync of account {account_id} failed: {error}");
+                AccountSyncResult {
+                    account_id,
+                    summary: None,
+                    error: Some(error),
+                }
+            }
+        }
+    });
+
+    join_all(runs).await
+}
+
+/// Re-crawls one feed on the server and pulls just its changed entries
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_feed(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+    feed_id: String,
+) -> Result<SyncSummary, String> {
+    let id = feed_id
+        .parse::<i64>()
+        .map_err(|e| format!("Invalid feed ID: {e}"))?;
+
+    sync_scope(app_handle, state, SyncScope::Feed { id }).await
+}
+
+/// Re-crawls a category's feeds on the server and pulls just their changed entries
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_category(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+    category_id: String,
+) -> Result<SyncSummary, String> {
+    let id = category_id
+        .parse::<i64>()
+        .map_err(|e| format!("Invalid category ID: {e}"))?;
+
+    sync_scope(app_handle, state, SyncScope::Category { id }).await
+}
+
+async fn sync_scope(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+    scope: SyncScope,
+) -> Result<SyncSummary, String> {
+    log::info!("Starting on-demand sync of {scope:?}");
+
+    let _sync_guard = state
+        .sync_scheduler
+        .try_begin_sync()
+        .ok_or("Sync already in progress")?;
+
     let pool = state
         .db_pool
         .lock()
@@ -432,36 +1640,210 @@ pub async fn force_full_sync(
             .map_err(|e| format!("Failed to query active account: {e}"))?
             .ok_or("No active account found")?;
 
-    // Reset last_full_sync_at to NULL to trigger a full sync
-    sqlx::query("UPDATE sync_state SET last_full_sync_at = NULL WHERE account_id = ?")
-        .bind(account_id)
-        .execute(&pool)
+    let summary = sync_scope_impl(
+        &pool,
+        &client,
+        &app_handle,
+        account_id,
+        scope,
+        ScopePolling::default(),
+    )
+    .await?;
+
+    log::info!(
+        "On-demand sync of {scope:?} completed: {} entries pulled",
+        summary.entries_pulled
+    );
+
+    Ok(summary)
+}
+
+/// How long an on-demand category sync waits for the server's background
+/// re-crawl to produce entries
+#[derive(Debug, Clone, Copy)]
+pub struct ScopePolling {
+    pub retries: u32,
+    pub interval: Duration,
+}
+
+impl Default for ScopePolling {
+    fn default() -> Self {
+        Self {
+            retries: 3,
+            interval: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Refreshes `scope` on the server, then pulls the entries in it that changed
+/// since the account's last sync (all of them if it never synced). The
+/// account's sync state is left alone; the next full or incremental sync
+/// simply sees these entries again.
+///
+/// Miniflux re-crawls a single feed before answering, so a feed is pulled
+/// once. A category is refreshed in the background, so while nothing new has
+/// arrived the pull is retried per `polling`.
+pub async fn sync_scope_impl<R: Runtime>(
+    pool: &SqlitePool,
+    client: &MinifluxClient,
+    app_handle: &AppHandle<R>,
+    account_id: i64,
+    scope: SyncScope,
+    polling: ScopePolling,
+) -> Result<SyncSummary, String> {
+    let now = Utc::now().to_rfc3339();
+    let _ = app_handle.emit(
+        "scope-sync-progress",
+        &ScopeSyncProgressEvent::Refreshing { scope },
+    );
+
+    // Entries the refresh produces change after this second
+    let refreshed_at = Utc::now().timestamp();
+    // A feed is re-crawled before the refresh returns; a category is re-crawled
+    // in the background, so its new entries are polled for
+    let (feeds, filters, poll_retries) = match scope {
+        SyncScope::Feed { id } => {
+            client.refresh_feed(id).await?;
+            let feed = client.get_feed(id).await?;
+            let filters = EntryFilters {
+                feed_id: Some(id),
+                ..EntryFilters::default()
+            };
+            (vec![feed], filters, None)
+        }
+        SyncScope::Category { id } => {
+            client.refresh_category(id).await?;
+            let feeds = client.get_category_feeds(id).await?;
+            let filters = EntryFilters {
+                category_id: Some(id),
+                ..EntryFilters::default()
+            };
+            (feeds, filters, Some(polling.retries))
+        }
+    };
+
+    if feeds.is_empty() {
+        // An empty category has no entries to pull
+        let _ = app_handle.emit(
+            "scope-sync-progress",
+            &ScopeSyncProgressEvent::Completed {
+                scope,
+                entries_pulled: 0,
+            },
+        );
+        return Ok(SyncSummary {
+            entries_pulled: 0,
+            entries_pushed: 0,
+            feeds_pulled: 0,
+            categories_pulled: 0,
+        });
+    }
+
+    let mut categories: Vec<crate::miniflux::Category> = Vec::new();
+    for category in feeds.iter().filter_map(|feed| feed.category.clone()) {
+        if !categories.iter().any(|known| known.id == category.id) {
+            categories.push(category);
+        }
+    }
+    if !categories.is_empty() {
+        upsert_categories(pool, account_id, &categories, &now).await?;
+    }
+    upsert_feeds(pool, account_id, &feeds, &now).await?;
+
+    let mut summary = SyncSummary {
+        entries_pulled: 0,
+        entries_pushed: 0,
+        feeds_pulled: feeds.len() as u32,
+        categories_pulled: categories.len() as u32,
+    };
+
+    let sync_state = get_or_create_sync_state(pool, account_id).await?;
+    let mut since_last_sync = filters.clone();
+    since_last_sync.changed_after = sync_state
+        .last_sync_at
+        .as_ref()
+        .and_then(|timestamp| parse_rfc3339_to_epoch(timestamp).ok());
+
+    let conflict_rule = load_preferences_sync(app_handle)
+        .map(|preferences| preferences.sync_conflict_rule)
+        .unwrap_or_default();
+    let filter_rules = load_enabled_filter_rules(pool, account_id)
         .await
-        .map_err(|e| format!("Failed to reset full sync state: {e}"))?;
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to load filter rules: {e}");
+            Vec::new()
+        });
 
-    let summary = match sync_miniflux_impl(&pool, &client, &app_handle, account_id).await {
-        Ok(summary) => summary,
-        Err(error) => {
-            let _ = sqlx::query(
-                "UPDATE sync_state SET sync_in_progress = 0, sync_error = ? WHERE account_id = ?",
-            )
-            .bind(&error)
-            .bind(account_id)
-            .execute(&pool)
-            .await;
+    let pull_entries = |filters: EntryFilters| {
+        let pull = EntriesPull {
+            filters,
+            account_id,
+            checkpoint: false,
+            resume_offset: 0,
+            pulled_entry_ids: None,
+        };
+        sync_entries(
+            pool,
+            client,
+            app_handle,
+            pull,
+            conflict_rule,
+            &filter_rules,
+            move |pulled, total, percentage| {
+                let _ = app_handle.emit(
+                    "scope-sync-progress",
+                    &ScopeSyncProgressEvent::EntriesProgress {
+                        scope,
+                        pulled,
+                        total,
+                        percentage,
+                    },
+                );
+            },
+        )
+    };
 
-            return Err(error);
+    if let Some(retries) = poll_retries {
+        let refreshed = EntryFilters {
+            changed_after: Some(refreshed_at),
+            ..filters.clone()
+        };
+        for attempt in 0..=retries {
+            if attempt > 0 {
+                let _ = app_handle.emit(
+                    "scope-sync-progress",
+                    &ScopeSyncProgressEvent::Waiting { scope, attempt },
+                );
+                tokio::time::sleep(polling.interval).await;
+            }
+
+            summary.entries_pulled = pull_entries(refreshed.clone()).await?;
+            if summary.entries_pulled > 0 {
+                break;
+            }
         }
-    };
 
-    if let Err(e) = app_handle.emit("sync-completed", &summary) {
-        log::error!("Failed to emit sync-completed event: {e}");
+        // Changes from before the refresh are pulled on their own, so they
+        // can't end the wait before the re-crawl has produced anything
+        since_last_sync.changed_before = Some(refreshed_at + 1);
     }
 
-    log::info!(
-        "Full sync completed: {} entries pulled, {} pushed",
-        summary.entries_pulled,
-        summary.entries_pushed
+    summary.entries_pulled += pull_entries(since_last_sync).await?;
+
+    // Push read/star changes made by filter rules now rather than next sync
+    if !filter_rules.is_empty() {
+        match push_pending_operations(pool, client, account_id).await {
+            Ok(pushed) => summary.entries_pushed = pushed,
+            Err(e) => log::warn!("Failed to push filter rule changes: {e}"),
+        }
+    }
+
+    let _ = app_handle.emit(
+        "scope-sync-progress",
+        &ScopeSyncProgressEvent::Completed {
+            scope,
+            entries_pulled: summary.entries_pulled,
+        },
     );
 
     Ok(summary)
@@ -509,18 +1891,108 @@ pub async fn get_sync_status(
     )
 }
 
+/// Reports the network status seen by the webview (`navigator.onLine` and the
+/// Network Information API) so the background scheduler can pause while
+/// offline or on a metered connection.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_network_status(
+    state: State<'_, AppState>,
+    online: bool,
+    metered: bool,
+) -> Result<(), String> {
+    let scheduler = &state.sync_scheduler;
+    let was_online = scheduler.online.swap(online, Ordering::AcqRel);
+    let was_metered = scheduler.metered.swap(metered, Ordering::AcqRel);
+
+    if was_online != online || was_metered != metered {
+        log::info!("Network status changed: online={online}, metered={metered}");
+        scheduler.notify.notify_one();
+    }
+
+    Ok(())
+}
+
+/// Returns recently recorded sync conflicts for the active account, newest first
+#[tauri::command]
+#[specta::specta]
+pub async fn get_sync_conflicts(
+    state: State<'_, AppState>,
+    limit: Option<u32>,
+) -> Result<Vec<SyncConflict>, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+
+    get_sync_conflicts_from_db(&pool, account_id, limit.unwrap_or(100)).await
+}
+
+pub async fn get_sync_conflicts_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    limit: u32,
+) -> Result<Vec<SyncConflict>, String> {
+    sqlx::query_as::<_, SyncConflict>(
+        r#"
+        SELECT c.id, c.entry_id, e.title AS entry_title, c.field, c.local_value, c.server_value,
+               c.local_changed_at, c.server_changed_at, c.rule, c.resolution, c.created_at
+        FROM sync_conflicts c
+        LEFT JOIN entries e ON e.account_id = c.account_id AND e.id = c.entry_id
+        WHERE c.account_id = ?
+        ORDER BY c.created_at DESC, c.id DESC
+        LIMIT ?
+        "#,
+    )
+    .bind(account_id)
+    .bind(limit as i64)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to query sync conflicts: {e}"))
+}
+
+/// Clears the recorded sync conflicts for the active account
+#[tauri::command]
+#[specta::specta]
+pub async fn clear_sync_conflicts(state: State<'_, AppState>) -> Result<(), String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = get_active_account_id(&state).await?;
+
+    sqlx::query("DELETE FROM sync_conflicts WHERE account_id = ?")
+        .bind(account_id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to clear sync conflicts: {e}"))?;
+
+    Ok(())
+}
+
 async fn upsert_categories(
     pool: &SqlitePool,
+    account_id: i64,
     categories: &[crate::miniflux::Category],
     now: &str,
 ) -> Result<(), String> {
     log::debug!("Upserting {} categories...", categories.len());
     let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-        "INSERT INTO categories (id, user_id, title, hide_globally, created_at, updated_at, sync_status)",
+        "INSERT INTO categories (account_id, id, user_id, title, hide_globally, created_at, updated_at, sync_status)",
     );
 
     builder.push_values(categories, |mut row, category| {
-        row.push_bind(category.id)
+        row.push_bind(account_id)
+            .push_bind(category.id)
             .push_bind(category.user_id)
             .push_bind(&category.title)
             .push_bind(category.hide_globally)
@@ -530,7 +2002,7 @@ async fn upsert_categories(
     });
 
     builder.push(
-        " ON CONFLICT(id) DO UPDATE SET user_id = excluded.user_id, title = excluded.title, hide_globally = excluded.hide_globally, created_at = excluded.created_at, updated_at = excluded.updated_at, sync_status = 'synced'",
+        " ON CONFLICT(account_id, id) DO UPDATE SET user_id = excluded.user_id, title = excluded.title, hide_globally = excluded.hide_globally, created_at = excluded.created_at, updated_at = excluded.updated_at, sync_status = 'synced'",
     );
 
     builder
@@ -542,9 +2014,9 @@ async fn upsert_categories(
     Ok(())
 }
 
-async fn mark_categories_stale(pool: &SqlitePool, user_id: i64) -> Result<(), String> {
-    sqlx::query("UPDATE categories SET sync_status = 'stale' WHERE user_id = ?")
-        .bind(user_id)
+async fn mark_categories_stale(pool: &SqlitePool, account_id: i64) -> Result<(), String> {
+    sqlx::query("UPDATE categories SET sync_status = 'stale' WHERE account_id = ?")
+        .bind(account_id)
         .execute(pool)
         .await
         .map_err(|e| format!("Failed to mark categories stale: {e}"))?;
@@ -553,16 +2025,18 @@ async fn mark_categories_stale(pool: &SqlitePool, user_id: i64) -> Result<(), St
 
 async fn upsert_feeds(
     pool: &SqlitePool,
+    account_id: i64,
     feeds: &[crate::miniflux::Feed],
     now: &str,
 ) -> Result<(), String> {
     log::debug!("Upserting {} feeds...", feeds.len());
     let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-        "INSERT INTO feeds (id, user_id, title, site_url, feed_url, category_id, checked_at, etag_header, last_modified_header, parsing_error_message, parsing_error_count, scraper_rules, rewrite_rules, crawler, blocklist_rules, keeplist_rules, user_agent, username, password, disabled, ignore_http_cache, fetch_via_proxy, no_media_player, allow_self_signed_certificates, urlrewrite_rules, cookie, apprise_service_urls, hide_globally, created_at, updated_at, sync_status)",
+        "INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, category_id, checked_at, etag_header, last_modified_header, parsing_error_message, parsing_error_count, scraper_rules, rewrite_rules, crawler, blocklist_rules, keeplist_rules, user_agent, username, password, disabled, ignore_http_cache, fetch_via_proxy, no_media_player, allow_self_signed_certificates, urlrewrite_rules, cookie, apprise_service_urls, hide_globally, created_at, updated_at, sync_status)",
     );
 
     builder.push_values(feeds, |mut row, feed| {
-        row.push_bind(feed.id)
+        row.push_bind(account_id)
+            .push_bind(feed.id)
             .push_bind(feed.user_id)
             .push_bind(&feed.title)
             .push_bind(&feed.site_url)
@@ -596,7 +2070,7 @@ async fn upsert_feeds(
     });
 
     builder.push(
-        " ON CONFLICT(id) DO UPDATE SET user_id = excluded.user_id, title = excluded.title, site_url = excluded.site_url, feed_url = excluded.feed_url, category_id = excluded.category_id, checked_at = excluded.checked_at, etag_header = excluded.etag_header, last_modified_header = excluded.last_modified_header, parsing_error_message = excluded.parsing_error_message, parsing_error_count = excluded.parsing_error_count, scraper_rules = excluded.scraper_rules, rewrite_rules = excluded.rewrite_rules, crawler = excluded.crawler, blocklist_rules = excluded.blocklist_rules, keeplist_rules = excluded.keeplist_rules, user_agent = excluded.user_agent, username = excluded.username, password = excluded.password, disabled = excluded.disabled, ignore_http_cache = excluded.ignore_http_cache, fetch_via_proxy = excluded.fetch_via_proxy, no_media_player = excluded.no_media_player, allow_self_signed_certificates = excluded.allow_self_signed_certificates, urlrewrite_rules = excluded.urlrewrite_rules, cookie = excluded.cookie, apprise_service_urls = excluded.apprise_service_urls, hide_globally = excluded.hide_globally, created_at = excluded.created_at, updated_at = excluded.updated_at, sync_status = 'synced'",
+        " ON CONFLICT(account_id, id) DO UPDATE SET user_id = excluded.user_id, title = excluded.title, site_url = excluded.site_url, feed_url = excluded.feed_url, category_id = excluded.category_id, checked_at = excluded.checked_at, etag_header = excluded.etag_header, last_modified_header = excluded.last_modified_header, parsing_error_message = excluded.parsing_error_message, parsing_error_count = excluded.parsing_error_count, scraper_rules = excluded.scraper_rules, rewrite_rules = excluded.rewrite_rules, crawler = excluded.crawler, blocklist_rules = excluded.blocklist_rules, keeplist_rules = excluded.keeplist_rules, user_agent = excluded.user_agent, username = excluded.username, password = excluded.password, disabled = excluded.disabled, ignore_http_cache = excluded.ignore_http_cache, fetch_via_proxy = excluded.fetch_via_proxy, no_media_player = excluded.no_media_player, allow_self_signed_certificates = excluded.allow_self_signed_certificates, urlrewrite_rules = excluded.urlrewrite_rules, cookie = excluded.cookie, apprise_service_urls = excluded.apprise_service_urls, hide_globally = excluded.hide_globally, created_at = excluded.created_at, updated_at = excluded.updated_at, sync_status = 'synced'",
     );
 
     builder
@@ -608,313 +2082,408 @@ async fn upsert_feeds(
     Ok(())
 }
 
-async fn mark_feeds_stale(pool: &SqlitePool, user_id: i64) -> Result<(), String> {
-    sqlx::query("UPDATE feeds SET sync_status = 'stale' WHERE user_id = ?")
-        .bind(user_id)
+async fn mark_feeds_stale(pool: &SqlitePool, account_id: i64) -> Result<(), String> {
+    sqlx::query("UPDATE feeds SET sync_status = 'stale' WHERE account_id = ?")
+        .bind(account_id)
         .execute(pool)
         .await
         .map_err(|e| format!("Failed to mark feeds stale: {e}"))?;
     Ok(())
 }
 
+/// Upserts entries with their enclosures and search index rows on `conn`,
+/// one prepared statement per row, stamped with the account they came from.
+/// Returns the IDs that weren't cached before.
 async fn upsert_entries(
-    pool: &SqlitePool,
+    conn: &mut SqliteConnection,
     entries: &[crate::miniflux::Entry],
+    account_id: i64,
     now: &str,
-) -> Result<(), String> {
-    if entries.is_empty() {
-        return Ok(());
+) -> Result<Vec<i64>, String> {
+    let mut new_entry_ids = Vec::new();
+
+    for entry in entries {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM entries WHERE account_id = ? AND id = ?)",
+        )
+        .bind(account_id)
+        .bind(entry.id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| format!("Failed to look up cached entries: {e}"))?;
+        if !exists {
+            new_entry_ids.push(entry.id);
+        }
+
+        sqlx::query(
+            "INSERT INTO entries (id, user_id, feed_id, title, url, comments_url, author, content, hash, published_at, created_at, changed_at, status, share_code, starred, reading_time, account_id, sync_status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'synced') ON CONFLICT(account_id, id) DO UPDATE SET user_id = excluded.user_id, feed_id = excluded.feed_id, title = excluded.title, url = excluded.url, comments_url = excluded.comments_url, author = excluded.author, content = excluded.content, hash = excluded.hash, published_at = excluded.published_at, created_at = excluded.created_at, changed_at = excluded.changed_at, status = excluded.status, share_code = excluded.share_code, starred = excluded.starred, reading_time = excluded.reading_time, sync_status = CASE WHEN entries.sync_status = 'pending' THEN 'pending' ELSE 'synced' END",
+        )
+        .bind(entry.id)
+        .bind(entry.user_id)
+        .bind(entry.feed_id)
+        .bind(&entry.title)
+        .bind(&entry.url)
+        .bind(entry.comments_url.as_deref())
+        .bind(entry.author.as_deref())
+        .bind(entry.content.as_deref())
+        .bind(&entry.hash)
+        .bind(&entry.published_at)
+        .bind(entry.created_at.as_deref().unwrap_or(now))
+        .bind(entry.changed_at.as_deref())
+        .bind(&entry.status)
+        .bind(entry.share_code.as_deref())
+        .bind(entry.starred)
+        .bind(entry.reading_time)
+        .bind(account_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Failed to upsert entries: {e}"))?;
     }
 
-    let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-        "INSERT INTO entries (id, user_id, feed_id, title, url, comments_url, author, content, hash, published_at, created_at, changed_at, status, share_code, starred, reading_time, sync_status)",
-    );
+    upsert_enclosures(conn, account_id, entries, now).await?;
 
-    builder.push_values(entries, |mut row, entry| {
-        row.push_bind(entry.id)
-            .push_bind(entry.user_id)
-            .push_bind(entry.feed_id)
-            .push_bind(&entry.title)
-            .push_bind(&entry.url)
-            .push_bind(entry.comments_url.as_deref())
-            .push_bind(entry.author.as_deref())
-            .push_bind(entry.content.as_deref())
-            .push_bind(&entry.hash)
-            .push_bind(&entry.published_at)
-            .push_bind(entry.created_at.as_deref().unwrap_or(now))
-            .push_bind(entry.changed_at.as_deref())
-            .push_bind(&entry.status)
-            .push_bind(entry.share_code.as_deref())
-            .push_bind(entry.starred)
-            .push_bind(entry.reading_time)
-            .push_bind("synced");
-    });
+    let entry_ids: Vec<i64> = entries.iter().map(|entry| entry.id).collect();
+    crate::database::fts::refresh_entries_fts_on(conn, account_id, &entry_ids)
+        .await
+        .map_err(|e| format!("Failed to update search index: {e}"))?;
 
-    builder.push(
-        " ON CONFLICT(id) DO UPDATE SET user_id = excluded.user_id, feed_id = excluded.feed_id, title = excluded.title, url = excluded.url, comments_url = excluded.comments_url, author = excluded.author, content = excluded.content, hash = excluded.hash, published_at = excluded.published_at, created_at = excluded.created_at, changed_at = excluded.changed_at, status = excluded.status, share_code = excluded.share_code, starred = excluded.starred, reading_time = excluded.reading_time, sync_status = 'synced'",
-    );
+    Ok(new_entry_ids)
+}
 
-    builder
-        .build()
-        .execute(pool)
+/// Writes one page of pulled entries, and the checkpoint (offset, total)
+/// after it if there is one, in a single transaction so a page is either
+/// fully cached and counted or not at all.
+async fn write_entries_page(
+    pool: &SqlitePool,
+    entries: &[crate::miniflux::Entry],
+    account_id: i64,
+    checkpoint: Option<(i64, i64)>,
+) -> Result<Vec<i64>, String> {
+    let mut transaction = pool
+        .begin()
         .await
-        .map_err(|e| format!("Failed to upsert entries: {e}"))?;
+        .map_err(|e| format!("Failed to start entries transaction: {e}"))?;
 
-    // Upsert enclosures from entries that have them
-    upsert_enclosures(pool, entries, now).await?;
+    let new_entry_ids = upsert_entries(
+        &mut transaction,
+        entries,
+        account_id,
+        &Utc::now().to_rfc3339(),
+    )
+    .await?;
+    if let Some((offset, total)) = checkpoint {
+        save_entries_checkpoint(&mut transaction, account_id, offset, total).await?;
+    }
 
-    Ok(())
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit entries transaction: {e}"))?;
+
+    if let Err(e) = merge_remote_progression(pool, account_id, &pulled_progressions(entries)).await
+    {
+        log::warn!("Failed to merge podcast progress from Miniflux: {e}");
+    }
+
+    Ok(new_entry_ids)
 }
 
-async fn upsert_enclosures(
+/// Runs the user's filter rules over entries cached for the first time.
+/// Rule failures are logged rather than failing the sync.
+async fn run_filter_rules_on_new_entries<R: Runtime>(
     pool: &SqlitePool,
+    app_handle: &AppHandle<R>,
+    account_id: i64,
+    rules: &[CompiledFilterRule],
     entries: &[crate::miniflux::Entry],
-    now: &str,
-) -> Result<(), String> {
-    let enclosures: Vec<_> = entries
+    new_entry_ids: &[i64],
+) {
+    if rules.is_empty() || new_entry_ids.is_empty() {
+        return;
+    }
+
+    let new_entries: Vec<crate::miniflux::Entry> = entries
         .iter()
-        .flat_map(|e| e.enclosures.as_deref().unwrap_or_default())
+        .filter(|entry| new_entry_ids.contains(&entry.id))
+        .cloned()
         .collect();
 
-    if enclosures.is_empty() {
-        return Ok(());
+    match apply_filter_rules(pool, account_id, rules, &new_entries).await {
+        Ok(outcome) => {
+            if outcome.matched_entries > 0 {
+                log::debug!(
+                    "Filter rules matched {} of {} new entries",
+                    outcome.matched_entries,
+                    new_entries.len()
+                );
+            }
+            send_rule_notifications(app_handle, outcome.notifications).await;
+        }
+        Err(e) => log::warn!("Failed to apply filter rules: {e}"),
     }
+}
 
-    // SQLite has a variable limit; batch in chunks of 500
-    for chunk in enclosures.chunks(500) {
-        let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-            "INSERT INTO enclosures (id, entry_id, url, mime_type, length, position, created_at)",
-        );
-
-        builder.push_values(chunk, |mut row, enc| {
-            row.push_bind(enc.id)
-                .push_bind(enc.entry_id)
-                .push_bind(&enc.url)
-                .push_bind(&enc.mime_type)
-                .push_bind(enc.length)
-                .push_bind(enc.position)
-                .push_bind(now);
-        });
-
-        builder.push(
-            " ON CONFLICT(id) DO UPDATE SET url = excluded.url, mime_type = excluded.mime_type, length = excluded.length, position = excluded.position",
-        );
+async fn upsert_enclosures(
+    conn: &mut SqliteConnection,
+    account_id: i64,
+    entries: &[crate::miniflux::Entry],
+    now: &str,
+) -> Result<(), String> {
+    let enclosures = entries
+        .iter()
+        .flat_map(|e| e.enclosures.as_deref().unwrap_or_default());
 
-        builder
-            .build()
-            .execute(pool)
-            .await
-            .map_err(|e| format!("Failed to upsert enclosures: {e}"))?;
+    for enc in enclosures {
+        sqlx::query(
+            "INSERT INTO enclosures (account_id, id, entry_id, url, mime_type, length, position, media_progression, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?) ON CONFLICT(account_id, id) DO UPDATE SET url = excluded.url, mime_type = excluded.mime_type, length = excluded.length, position = excluded.position, media_progression = excluded.media_progression",
+        )
+        .bind(account_id)
+        .bind(enc.id)
+        .bind(enc.entry_id)
+        .bind(&enc.url)
+        .bind(&enc.mime_type)
+        .bind(enc.length)
+        .bind(enc.position)
+        .bind(enc.media_progression)
+        .bind(now)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Failed to upsert enclosures: {e}"))?;
     }
 
     Ok(())
 }
 
-async fn mark_entries_stale(pool: &SqlitePool, user_id: i64) -> Result<(), String> {
-    sqlx::query("UPDATE entries SET sync_status = 'stale' WHERE user_id = ?")
-        .bind(user_id)
+async fn mark_entries_stale(pool: &SqlitePool, account_id: i64) -> Result<(), String> {
+    // Entries with unpushed local changes are kept out of the stale sweep
+    sqlx::query(
+        "UPDATE entries SET sync_status = 'stale' WHERE account_id = ? AND (sync_status IS NULL OR sync_status != 'pending')",
+    )
+        .bind(account_id)
         .execute(pool)
         .await
         .map_err(|e| format!("Failed to mark entries stale: {e}"))?;
     Ok(())
 }
 
-async fn sync_full_entries(
-    pool: &SqlitePool,
-    client: &MinifluxClient,
-    window: &SyncWindow,
-    summary: &mut SyncSummary,
-    app_handle: &AppHandle,
+/// Records how far the entry pull got. Called once a page is written, so a
+/// run interrupted after this point resumes with the next page.
+async fn save_entries_checkpoint(
+    conn: &mut SqliteConnection,
+    account_id: i64,
+    offset: i64,
+    total: i64,
 ) -> Result<(), String> {
-    let mut window = *window;
-    let mut offset = 0;
-    let mut total_seen = 0;
-
-    loop {
-        let filters = EntryFilters {
-            offset: Some(offset),
-            limit: Some(window.limit),
-            order: Some("published_at".to_string()),
-            direction: Some("desc".to_string()),
-            ..EntryFilters::default()
-        };
-
-        let response = client.get_entries(&filters).await?;
-        let entries = response.entries.unwrap_or_default();
-        let count = entries.len();
-
-        if count == 0 {
-            break;
-        }
-
-        upsert_entries(pool, &entries, &Utc::now().to_rfc3339()).await?;
-        summary.entries_pulled = summary.entries_pulled.saturating_add(count as u32);
-        total_seen += count as i64;
-
-        let percentage = if response.total > 0 {
-            (total_seen as f32 / response.total as f32) * 100.0
-        } else {
-            100.0
-        };
-
-        let _ = app_handle.emit(
-            "sync-progress",
-            &SyncProgressEvent::EntriesProgress {
-                pulled: summary.entries_pulled,
-                total: response.total as u32,
-                percentage,
-            },
-        );
+    sqlx::query(
+        "UPDATE sync_state SET entries_offset = ?, entries_pulled = ?, entries_total = ? WHERE account_id = ?",
+    )
+    .bind(offset)
+    .bind(offset)
+    .bind(total)
+    .bind(account_id)
+    .execute(conn)
+    .await
+    .map_err(|e| format!("Failed to save sync checkpoint: {e}"))?;
+    Ok(())
+}
 
-        if total_seen >= response.total {
-            break;
-        }
+/// An entries page back from the server, waiting for its turn to be written
+struct FetchedPage {
+    offset: i64,
+    response: EntryResponse,
+    latency: Duration,
+    bytes: usize,
+}
 
-        offset += window.limit;
-        window.grow_batch_size();
-    }
+async fn fetch_entries_page(
+    client: MinifluxClient,
+    filters: EntryFilters,
+) -> Result<FetchedPage, String> {
+    let started = Instant::now();
+    let (response, bytes) = client.get_entries_with_size(&filters).await?;
+
+    Ok(FetchedPage {
+        offset: filters.offset.unwrap_or_default(),
+        response,
+        latency: started.elapsed(),
+        bytes,
+    })
+}
 
-    Ok(())
+/// What an entries pull covers and whether it keeps a checkpoint
+struct EntriesPull<'a> {
+    /// Shared by every page: the scope and `changed_after`. Paging and order
+    /// are filled in per page.
+    filters: EntryFilters,
+    /// Account the entries are stamped with
+    account_id: i64,
+    /// Whether the account's `sync_state` checkpoint follows the pull.
+    /// On-demand pulls of a single feed or category don't keep one.
+    checkpoint: bool,
+    resume_offset: i64,
+    /// Collects the IDs of the entries written, if given
+    pulled_entry_ids: Option<&'a mut HashSet<i64>>,
 }
 
-async fn sync_incremental_entries(
+/// Pulls entries newest first, starting at `resume_offset`, and returns how
+/// many are now pulled including those before it. Without `changed_after`
+/// this is the full pull of a first sync.
+///
+/// The first page is fetched on its own to learn the total. After that up to
+/// [`MAX_CONCURRENT_PAGES`] pages are in flight while earlier ones are
+/// written, and pages are always written in the order they were requested,
+/// so the checkpoint and `on_progress` only ever cover a contiguous run.
+async fn sync_entries<R: Runtime>(
     pool: &SqlitePool,
     client: &MinifluxClient,
-    window: &SyncWindow,
-    summary: &mut SyncSummary,
-    state: &SyncState,
-    app_handle: &AppHandle,
-    account_id: i64,
-) -> Result<(), String> {
-    let mut window = *window;
-    let mut offset = if state.sync_in_progress {
-        state.entries_offset
-    } else {
-        0
-    };
-    let mut total_seen = if state.sync_in_progress {
-        state.entries_pulled
-    } else {
-        0
-    };
-
-    // Restore pulled count if resuming
-    if state.sync_in_progress {
-        summary.entries_pulled = state.entries_pulled as u32;
-    }
-
-    let changed_after = state
-        .last_sync_at
-        .as_ref()
-        .and_then(|timestamp| parse_rfc3339_to_epoch(timestamp).ok());
+    app_handle: &AppHandle<R>,
+    mut pull: EntriesPull<'_>,
+    conflict_rule: SyncConflictRule,
+    filter_rules: &[CompiledFilterRule],
+    mut on_progress: impl FnMut(u32, u32, f32),
+) -> Result<u32, String> {
+    let mut window = SyncWindow::default();
+
+    // Pages before the checkpoint count towards this run's progress
+    let mut pulled = pull.resume_offset as u32;
+
+    // Dropping the set on an error aborts the fetches still running
+    let mut in_flight = JoinSet::new();
+    let mut fetched: BTreeMap<usize, FetchedPage> = BTreeMap::new();
+    let mut next_offset = pull.resume_offset;
+    let mut requested = 0_usize;
+    let mut written = 0_usize;
+    let mut total: Option<i64> = None;
+    let mut exhausted = false;
 
     loop {
-        let filters = EntryFilters {
-            offset: Some(offset),
-            limit: Some(window.limit),
-            order: Some("published_at".to_string()),
-            direction: Some("desc".to_string()),
-            changed_after,
-            ..EntryFilters::default()
+        let parallelism = if total.is_some() {
+            MAX_CONCURRENT_PAGES
+        } else {
+            1
         };
+        while !exhausted
+            && requested - written < parallelism
+            && total.is_none_or(|total| next_offset < total)
+        {
+            let filters = EntryFilters {
+                offset: Some(next_offset),
+                limit: Some(window.limit),
+                order: Some("published_at".to_string()),
+                direction: Some("desc".to_string()),
+                ..pull.filters.clone()
+            };
+            let sequence = requested;
+            let client = client.clone();
+            in_flight.spawn(async move { (sequence, fetch_entries_page(client, filters).await) });
+            next_offset += window.limit;
+            requested += 1;
+        }
+
+        let Some(page) = fetched.remove(&written) else {
+            match in_flight.join_next().await {
+                Some(joined) => {
+                    let (sequence, page) =
+                        joined.map_err(|e| format!("Entries page fetch failed: {e}"))?;
+                    fetched.insert(sequence, page?);
+                    continue;
+                }
+                None => break,
+            }
+        };
+        written += 1;
 
-        let response = client.get_entries(&filters).await?;
-        let entries = response.entries.unwrap_or_default();
+        let mut entries = page.response.entries.unwrap_or_default();
         let count = entries.len();
+        total = Some(page.response.total);
+        window.record_page(page.latency, count, page.bytes);
 
         if count == 0 {
-            break;
+            // The list shrank under us; pages already requested still get written
+            exhausted = true;
+            continue;
         }
 
-        upsert_entries(pool, &entries, &Utc::now().to_rfc3339()).await?;
-        summary.entries_pulled = summary.entries_pulled.saturating_add(count as u32);
-        total_seen += count as i64;
+        let offset = page.offset + count as i64;
+        let writes = ENTRY_WRITES.lock().await;
+        reconcile_pending_entries(pool, pull.account_id, &mut entries, conflict_rule).await?;
+        let checkpoint = pull.checkpoint.then_some((offset, page.response.total));
+        let new_entry_ids = write_entries_page(pool, &entries, pull.account_id, checkpoint).await?;
+        run_filter_rules_on_new_entries(
+            pool,
+            app_handle,
+            pull.account_id,
+            filter_rules,
+            &entries,
+            &new_entry_ids,
+        )
+        .await;
+        drop(writes);
+        if let Some(ids) = pull.pulled_entry_ids.as_deref_mut() {
+            ids.extend(entries.iter().map(|entry| entry.id));
+        }
+        pulled = pulled.saturating_add(count as u32);
 
-        let percentage = if response.total > 0 {
-            (total_seen as f32 / response.total as f32) * 100.0
+        let percentage = if page.response.total > 0 {
+            (offset as f32 / page.response.total as f32 * 100.0).min(100.0)
         } else {
             100.0
         };
 
-        // Persist progress every 10% to support resume
-        if percentage as i32 % 10 == 0 || count < window.limit as usize {
-            let _ = sqlx::query(
-                "UPDATE sync_state SET entries_offset = ?, entries_pulled = ?, entries_total = ? WHERE account_id = ?",
-            )
-            .bind(offset)
-            .bind(total_seen)
-            .bind(response.total)
-            .bind(account_id)
-            .execute(pool)
-            .await;
-        }
-
-        let _ = app_handle.emit(
-            "sync-progress",
-            &SyncProgressEvent::EntriesProgress {
-                pulled: summary.entries_pulled,
-                total: response.total as u32,
-                percentage,
-            },
-        );
-
-        if total_seen >= response.total {
-            break;
-        }
-
-        offset += window.limit;
-        window.grow_batch_size();
+        on_progress(pulled, page.response.total as u32, percentage);
     }
 
-    Ok(())
+    Ok(pulled)
 }
 
-async fn delete_stale_categories(pool: &SqlitePool, user_id: i64) -> Result<(), String> {
-    sqlx::query("DELETE FROM categories WHERE user_id = ? AND sync_status = 'stale'")
-        .bind(user_id)
+async fn delete_stale_categories(pool: &SqlitePool, account_id: i64) -> Result<(), String> {
+    sqlx::query("DELETE FROM categories WHERE account_id = ? AND sync_status = 'stale'")
+        .bind(account_id)
         .execute(pool)
         .await
         .map_err(|e| format!("Failed to delete stale categories: {e}"))?;
     Ok(())
 }
 
-async fn delete_stale_feeds(pool: &SqlitePool, user_id: i64) -> Result<(), String> {
-    sqlx::query("DELETE FROM feeds WHERE user_id = ? AND sync_status = 'stale'")
-        .bind(user_id)
+async fn delete_stale_feeds(pool: &SqlitePool, account_id: i64) -> Result<(), String> {
+    sqlx::query("DELETE FROM feeds WHERE account_id = ? AND sync_status = 'stale'")
+        .bind(account_id)
         .execute(pool)
         .await
         .map_err(|e| format!("Failed to delete stale feeds: {e}"))?;
     Ok(())
 }
 
-async fn delete_stale_icons(pool: &SqlitePool, user_id: i64) -> Result<(), String> {
+async fn delete_stale_icons(pool: &SqlitePool, account_id: i64) -> Result<(), String> {
     sqlx::query(
-        "DELETE FROM icons WHERE feed_id IN (SELECT id FROM feeds WHERE user_id = ? AND sync_status = 'stale')",
+        "DELETE FROM icons WHERE account_id = ? AND feed_id IN (SELECT id FROM feeds WHERE account_id = ? AND sync_status = 'stale')",
     )
-    .bind(user_id)
+    .bind(account_id)
+    .bind(account_id)
     .execute(pool)
     .await
     .map_err(|e| format!("Failed to delete stale icons: {e}"))?;
     Ok(())
 }
 
-async fn delete_stale_entries(pool: &SqlitePool, user_id: i64) -> Result<(), String> {
-    sqlx::query("DELETE FROM entries WHERE user_id = ? AND sync_status = 'stale'")
-        .bind(user_id)
+/// Returns the number of entries deleted
+async fn delete_stale_entries(pool: &SqlitePool, account_id: i64) -> Result<u32, String> {
+    let result = sqlx::query("DELETE FROM entries WHERE account_id = ? AND sync_status = 'stale'")
+        .bind(account_id)
         .execute(pool)
         .await
         .map_err(|e| format!("Failed to delete stale entries: {e}"))?;
-    Ok(())
+    Ok(result.rows_affected() as u32)
 }
 
 async fn delete_removed_categories(
     pool: &SqlitePool,
-    user_id: i64,
+    account_id: i64,
     category_ids: &[i64],
 ) -> Result<(), String> {
     if category_ids.is_empty() {
-        sqlx::query("DELETE FROM categories WHERE user_id = ?")
-            .bind(user_id)
+        sqlx::query("DELETE FROM categories WHERE account_id = ?")
+            .bind(account_id)
             .execute(pool)
             .await
             .map_err(|e| format!("Failed to clear categories: {e}"))?;
@@ -922,8 +2491,8 @@ async fn delete_removed_categories(
     }
 
     let mut builder: QueryBuilder<sqlx::Sqlite> =
-        QueryBuilder::new("DELETE FROM categories WHERE user_id = ");
-    builder.push_bind(user_id);
+        QueryBuilder::new("DELETE FROM categories WHERE account_id = ");
+    builder.push_bind(account_id);
     builder.push(" AND id NOT IN (");
     let mut separated = builder.separated(",");
     for id in category_ids {
@@ -941,7 +2510,7 @@ async fn delete_removed_categories(
 
 async fn delete_removed_feeds(
     pool: &SqlitePool,
-    user_id: i64,
+    account_id: i64,
     feed_ids: &[i64],
 ) -> Result<(), String> {
     let mut transaction = pool
@@ -950,20 +2519,20 @@ async fn delete_removed_feeds(
         .map_err(|e| format!("Failed to start removed feed cleanup transaction: {e}"))?;
 
     if feed_ids.is_empty() {
-        sqlx::query("DELETE FROM entries WHERE user_id = ?")
-            .bind(user_id)
+        sqlx::query("DELETE FROM entries WHERE account_id = ?")
+            .bind(account_id)
             .execute(&mut *transaction)
             .await
             .map_err(|e| format!("Failed to clear entries for removed feeds: {e}"))?;
 
-        sqlx::query("DELETE FROM icons WHERE feed_id IN (SELECT id FROM feeds WHERE user_id = ?)")
-            .bind(user_id)
+        sqlx::query("DELETE FROM icons WHERE account_id = ?")
+            .bind(account_id)
             .execute(&mut *transaction)
             .await
             .map_err(|e| format!("Failed to clear icons for removed feeds: {e}"))?;
 
-        sqlx::query("DELETE FROM feeds WHERE user_id = ?")
-            .bind(user_id)
+        sqlx::query("DELETE FROM feeds WHERE account_id = ?")
+            .bind(account_id)
             .execute(&mut *transaction)
             .await
             .map_err(|e| format!("Failed to clear feeds: {e}"))?;
@@ -977,8 +2546,8 @@ async fn delete_removed_feeds(
     }
 
     let mut entries_builder: QueryBuilder<sqlx::Sqlite> =
-        QueryBuilder::new("DELETE FROM entries WHERE user_id = ");
-    entries_builder.push_bind(user_id);
+        QueryBuilder::new("DELETE FROM entries WHERE account_id = ");
+    entries_builder.push_bind(account_id);
     entries_builder.push(" AND feed_id NOT IN (");
     let mut entries_separated = entries_builder.separated(",");
     for id in feed_ids {
@@ -992,16 +2561,15 @@ async fn delete_removed_feeds(
         .await
         .map_err(|e| format!("Failed to delete entries for removed feeds: {e}"))?;
 
-    let mut icons_builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-        "DELETE FROM icons WHERE feed_id IN (SELECT id FROM feeds WHERE user_id = ",
-    );
-    icons_builder.push_bind(user_id);
-    icons_builder.push(" AND id NOT IN (");
+    let mut icons_builder: QueryBuilder<sqlx::Sqlite> =
+        QueryBuilder::new("DELETE FROM icons WHERE account_id = ");
+    icons_builder.push_bind(account_id);
+    icons_builder.push(" AND feed_id NOT IN (");
     let mut icons_separated = icons_builder.separated(",");
     for id in feed_ids {
         icons_separated.push_bind(id);
     }
-    icons_builder.push("))");
+    icons_builder.push(")");
 
     icons_builder
         .build()
@@ -1010,8 +2578,8 @@ async fn delete_removed_feeds(
         .map_err(|e| format!("Failed to delete icons for removed feeds: {e}"))?;
 
     let mut builder: QueryBuilder<sqlx::Sqlite> =
-        QueryBuilder::new("DELETE FROM feeds WHERE user_id = ");
-    builder.push_bind(user_id);
+        QueryBuilder::new("DELETE FROM feeds WHERE account_id = ");
+    builder.push_bind(account_id);
     builder.push(" AND id NOT IN (");
     let mut separated = builder.separated(",");
     for id in feed_ids {
@@ -1033,11 +2601,12 @@ async fn delete_removed_feeds(
     Ok(())
 }
 
+/// Returns the number of entries deleted
 async fn delete_removed_entries(
     pool: &SqlitePool,
-    user_id: i64,
+    account_id: i64,
     state: &SyncState,
-) -> Result<(), String> {
+) -> Result<u32, String> {
     let changed_after = state
         .last_sync_at
         .as_ref()
@@ -1050,17 +2619,18 @@ async fn delete_removed_entries(
             .ok_or("Failed to convert changed_after timestamp".to_string())?
             .to_rfc3339();
 
-        sqlx::query(
-            "DELETE FROM entries WHERE user_id = ? AND status = 'removed' AND (changed_at IS NOT NULL AND changed_at >= ?)",
+        let result = sqlx::query(
+            "DELETE FROM entries WHERE account_id = ? AND status = 'removed' AND (changed_at IS NOT NULL AND changed_at >= ?)",
         )
-        .bind(user_id)
+        .bind(account_id)
         .bind(&threshold)
         .execute(pool)
         .await
         .map_err(|e| format!("Failed to delete removed entries: {e}"))?;
+        return Ok(result.rows_affected() as u32);
     }
 
-    Ok(())
+    Ok(0)
 }
 
 fn parse_rfc3339_to_epoch(value: &str) -> Result<i64, String> {
@@ -1072,3 +2642,7 @@ fn parse_rfc3339_to_epoch(value: &str) -> Result<i64, String> {
 #[cfg(test)]
 #[path = "sync.test.rs"]
 mod tests;
+
+#[cfg(test)]
+#[path = "sync.integration.test.rs"]
+mod integration_tests;
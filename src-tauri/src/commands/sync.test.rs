@@ -1,9 +1,17 @@
 #[cfg(test)]
 mod tests {
-    use super::super::delete_removed_feeds;
-    use crate::commands::sync::{enqueue_sync_operation, get_or_create_sync_state};
+    use super::super::{
+        delete_removed_feeds, is_queue_operation_due, local_change_wins, queue_retry_delay,
+        reconcile_pending_entries, QueuedOperation, SyncWindow, MAX_PAGE_SIZE, MIN_PAGE_SIZE,
+    };
+    use crate::commands::sync::{
+        complete_sync_operations, enqueue_sync_operation, get_or_create_sync_state,
+        queue_entry_starred_change, queue_entry_status_change,
+    };
     use crate::database::migrations::run_migrations;
+    use crate::types::SyncConflictRule;
     use sqlx::SqlitePool;
+    use std::time::Duration;
 
     async fn setup_test_db() -> SqlitePool {
         let pool = SqlitePool::connect("sqlite::memory:")
@@ -15,11 +23,56 @@ mod tests {
         pool
     }
 
+    async fn insert_entry(pool: &SqlitePool, id: i64, status: &str) {
+        sqlx::query(
+            "INSERT OR IGNORE INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (1, 100, 1, 'Feed', 'https://example.com', 'https://example.com/rss', '2026-02-11T00:00:00Z', '2026-02-11T00:00:00Z')",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (1, ?, 1, 100, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id)
+        .bind(format!("Entry {id}"))
+        .bind(format!("https://example.com/{id}"))
+        .bind(format!("hash-{id}"))
+        .bind("2026-02-11T00:00:00Z")
+        .bind("2026-02-11T00:00:00Z")
+        .bind(status)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    fn server_entry(id: i64, status: &str, changed_at: &str) -> crate::miniflux::Entry {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "user_id": 1,
+            "feed_id": 100,
+            "title": format!("Entry {id}"),
+            "url": format!("https://example.com/{id}"),
+            "hash": format!("hash-{id}"),
+            "published_at": "2026-02-11T00:00:00Z",
+            "changed_at": changed_at,
+            "status": status,
+            "starred": false,
+            "feed": {
+                "id": 100,
+                "user_id": 1,
+                "title": "Feed",
+                "site_url": "https://example.com",
+                "feed_url": "https://example.com/rss"
+            }
+        }))
+        .unwrap()
+    }
+
     #[tokio::test]
     async fn test_enqueue_sync_operation_persists_queue_row() {
         let pool = setup_test_db().await;
         let payload = serde_json::json!({"status": "read"}).to_string();
-        let id = enqueue_sync_operation(&pool, "entry", 123, "mark_read", &payload)
+        let id = enqueue_sync_operation(&pool, 1, "entry", 123, "mark_read", &payload)
             .await
             .unwrap();
 
@@ -61,7 +114,7 @@ mod tests {
         let pool = setup_test_db().await;
         let payload = serde_json::json!({"status": "read"}).to_string();
 
-        let id = enqueue_sync_operation(&pool, "entry", 123, "mark_read", &payload)
+        let id = enqueue_sync_operation(&pool, 1, "entry", 123, "mark_read", &payload)
             .await
             .unwrap();
 
@@ -79,14 +132,314 @@ mod tests {
         assert!(!row.3.is_empty(), "updated_at should be set");
     }
 
+    #[tokio::test]
+    async fn test_enqueue_sync_operation_supersedes_pending_operation_for_same_entity() {
+        let pool = setup_test_db().await;
+
+        queue_entry_starred_change(&pool, 1, 42, true)
+            .await
+            .unwrap();
+        let latest = queue_entry_starred_change(&pool, 1, 42, false)
+            .await
+            .unwrap();
+        queue_entry_starred_change(&pool, 1, 43, true)
+            .await
+            .unwrap();
+
+        let rows: Vec<(i64, i64, String)> = sqlx::query_as(
+            "SELECT id, entity_id, payload FROM sync_queue WHERE operation_type = 'set_starred' ORDER BY id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(
+            rows.len(),
+            2,
+            "Older pending change for entry 42 should be replaced"
+        );
+        assert_eq!(rows[0].0, latest);
+        assert_eq!(rows[0].1, 42);
+        assert_eq!(rows[0].2, serde_json::json!({"starred": false}).to_string());
+        assert_eq!(rows[1].1, 43);
+    }
+
+    #[tokio::test]
+    async fn test_queued_changes_keep_their_account() {
+        let pool = setup_test_db().await;
+
+        // Both servers know an entry 42; neither change replaces the other
+        queue_entry_starred_change(&pool, 1, 42, true)
+            .await
+            .unwrap();
+        queue_entry_starred_change(&pool, 2, 42, false)
+            .await
+            .unwrap();
+
+        let rows: Vec<(i64, i64, String)> = sqlx::query_as(
+            "SELECT account_id, entity_id, payload FROM sync_queue WHERE status = 'pending' ORDER BY account_id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                (1, 42, serde_json::json!({"starred": true}).to_string()),
+                (2, 42, serde_json::json!({"starred": false}).to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_queue_entry_status_change_and_complete() {
+        let pool = setup_test_db().await;
+
+        let queue_ids = queue_entry_status_change(&pool, 1, &[1, 2, 3], "read")
+            .await
+            .unwrap();
+        assert_eq!(queue_ids.len(), 3);
+
+        // Status and starred changes for the same entry are independent
+        queue_entry_starred_change(&pool, 1, 1, true).await.unwrap();
+
+        complete_sync_operations(&pool, &queue_ids[..2])
+            .await
+            .unwrap();
+
+        let remaining: Vec<(i64, String)> = sqlx::query_as(
+            "SELECT entity_id, operation_type FROM sync_queue WHERE status = 'pending' ORDER BY id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(
+            remaining,
+            vec![
+                (3, "update_status".to_string()),
+                (1, "set_starred".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_queue_retry_delay_grows_and_caps() {
+        assert_eq!(queue_retry_delay(1).num_seconds(), 30);
+        assert_eq!(queue_retry_delay(2).num_seconds(), 60);
+        assert_eq!(queue_retry_delay(3).num_seconds(), 120);
+        assert_eq!(queue_retry_delay(20).num_seconds(), 3600);
+    }
+
+    #[test]
+    fn test_sync_window_adapts_to_latency_and_payload() {
+        let mut window = SyncWindow::default();
+        let fast = Duration::from_millis(200);
+
+        window.record_page(fast, 50, 50 * 1024);
+        assert_eq!(window.limit, 100);
+
+        // A short page of the same entry size is no reason to shrink
+        window.record_page(fast, 10, 10 * 1024);
+        assert_eq!(window.limit, 200);
+
+        window.record_page(Duration::from_secs(5), 200, 200 * 1024);
+        assert_eq!(window.limit, 100);
+
+        // 100 entries of 30 KB each is over the payload target
+        window.record_page(fast, 100, 100 * 30 * 1024);
+        assert_eq!(window.limit, 50);
+
+        // Between the halves of both targets the size holds
+        window.record_page(Duration::from_millis(1500), 50, 50 * 1024);
+        assert_eq!(window.limit, 50);
+    }
+
+    #[test]
+    fn test_sync_window_stays_within_bounds() {
+        let mut window = SyncWindow::default();
+        for _ in 0..10 {
+            window.record_page(Duration::from_millis(10), 1, 100);
+        }
+        assert_eq!(window.limit, MAX_PAGE_SIZE);
+
+        for _ in 0..10 {
+            window.record_page(Duration::from_secs(10), 1, 100);
+        }
+        assert_eq!(window.limit, MIN_PAGE_SIZE);
+
+        // An empty page says nothing about the server
+        window.record_page(Duration::from_secs(10), 0, 0);
+        assert_eq!(window.limit, MIN_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_queue_operation_due_respects_backoff() {
+        let now = chrono::Utc::now();
+        let mut operation = QueuedOperation {
+            id: 1,
+            account_id: 1,
+            operation_type: "update_status".to_string(),
+            entity_type: "entry".to_string(),
+            entity_id: 1,
+            payload: "{}".to_string(),
+            retry_count: 0,
+            updated_at: now.to_rfc3339(),
+        };
+        assert!(is_queue_operation_due(&operation, now));
+
+        operation.retry_count = 2;
+        assert!(!is_queue_operation_due(&operation, now));
+
+        operation.updated_at = (now - chrono::Duration::seconds(61)).to_rfc3339();
+        assert!(is_queue_operation_due(&operation, now));
+    }
+
+    #[test]
+    fn test_local_change_wins_by_rule() {
+        let older = "2026-02-11T00:00:00Z";
+        let newer = "2026-02-12T00:00:00Z";
+
+        assert!(local_change_wins(
+            SyncConflictRule::LocalWins,
+            older,
+            Some(newer)
+        ));
+        assert!(!local_change_wins(
+            SyncConflictRule::ServerWins,
+            newer,
+            Some(older)
+        ));
+        assert!(local_change_wins(
+            SyncConflictRule::NewestWins,
+            newer,
+            Some(older)
+        ));
+        assert!(!local_change_wins(
+            SyncConflictRule::NewestWins,
+            older,
+            Some(newer)
+        ));
+        assert!(local_change_wins(SyncConflictRule::NewestWins, older, None));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_keeps_newer_local_change_and_records_conflict() {
+        let pool = setup_test_db().await;
+        insert_entry(&pool, 1, "read").await;
+        queue_entry_status_change(&pool, 1, &[1], "read")
+            .await
+            .unwrap();
+
+        let mut entries = vec![server_entry(1, "unread", "2020-01-01T00:00:00Z")];
+        let conflicts =
+            reconcile_pending_entries(&pool, 1, &mut entries, SyncConflictRule::NewestWins)
+                .await
+                .unwrap();
+
+        assert_eq!(conflicts, 1);
+        assert_eq!(
+            entries[0].status, "read",
+            "Local change should survive the pull"
+        );
+
+        let pending: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM sync_queue WHERE status = 'pending'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            pending, 1,
+            "Local change should stay queued for the next push"
+        );
+
+        let conflict: (String, String, String, String) = sqlx::query_as(
+            "SELECT field, local_value, server_value, resolution FROM sync_conflicts WHERE entry_id = 1",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            conflict,
+            (
+                "status".to_string(),
+                "read".to_string(),
+                "unread".to_string(),
+                "local".to_string()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_server_wins_drops_queued_change() {
+        let pool = setup_test_db().await;
+        insert_entry(&pool, 1, "read").await;
+        queue_entry_status_change(&pool, 1, &[1], "read")
+            .await
+            .unwrap();
+
+        let mut entries = vec![server_entry(1, "unread", "2020-01-01T00:00:00Z")];
+        let conflicts =
+            reconcile_pending_entries(&pool, 1, &mut entries, SyncConflictRule::ServerWins)
+                .await
+                .unwrap();
+
+        assert_eq!(conflicts, 1);
+        assert_eq!(entries[0].status, "unread");
+
+        let pending: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sync_queue")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(pending, 0);
+
+        let sync_status: String =
+            sqlx::query_scalar("SELECT sync_status FROM entries WHERE id = 1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(sync_status, "synced");
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_settles_change_already_on_server() {
+        let pool = setup_test_db().await;
+        insert_entry(&pool, 1, "read").await;
+        queue_entry_status_change(&pool, 1, &[1], "read")
+            .await
+            .unwrap();
+
+        let mut entries = vec![server_entry(1, "read", "2026-02-12T00:00:00Z")];
+        let conflicts =
+            reconcile_pending_entries(&pool, 1, &mut entries, SyncConflictRule::NewestWins)
+                .await
+                .unwrap();
+
+        assert_eq!(conflicts, 0);
+
+        let queued: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sync_queue")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(queued, 0);
+
+        let recorded: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sync_conflicts")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(recorded, 0);
+    }
+
     #[tokio::test]
     async fn test_delete_removed_feeds_cleans_dependent_rows_before_deleting_feed() {
         let pool = setup_test_db().await;
         let now = "2026-02-11T00:00:00Z";
 
         sqlx::query(
-            "INSERT INTO feeds (id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
         )
+        .bind(1_i64)
         .bind(100_i64)
         .bind(1_i64)
         .bind("Keep Feed")
@@ -99,8 +452,9 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO feeds (id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
         )
+        .bind(1_i64)
         .bind(200_i64)
         .bind(1_i64)
         .bind("Removed Feed")
@@ -113,8 +467,9 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO entries (id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
+        .bind(1_i64)
         .bind(1_000_i64)
         .bind(1_i64)
         .bind(100_i64)
@@ -129,8 +484,9 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO entries (id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
+        .bind(1_i64)
         .bind(2_000_i64)
         .bind(1_i64)
         .bind(200_i64)
@@ -145,9 +501,10 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO icons (id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO icons (id, account_id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(1_i64)
+        .bind(1_i64)
         .bind(100_i64)
         .bind(10_i64)
         .bind("aGVsbG8=")
@@ -158,9 +515,10 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO icons (id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO icons (id, account_id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(2_i64)
+        .bind(1_i64)
         .bind(200_i64)
         .bind(20_i64)
         .bind("d29ybGQ=")
@@ -173,17 +531,18 @@ mod tests {
         delete_removed_feeds(&pool, 1, &[100]).await.unwrap();
 
         let removed_feed_count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM feeds WHERE id = 200 AND user_id = 1")
+            sqlx::query_scalar("SELECT COUNT(*) FROM feeds WHERE account_id = 1 AND id = 200")
                 .fetch_one(&pool)
                 .await
                 .unwrap();
         assert_eq!(removed_feed_count, 0);
 
-        let removed_entry_count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE feed_id = 200 AND user_id = 1")
-                .fetch_one(&pool)
-                .await
-                .unwrap();
+        let removed_entry_count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM entries WHERE account_id = 1 AND feed_id = 200",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
         assert_eq!(removed_entry_count, 0);
 
         let removed_icon_count: i64 =
@@ -194,7 +553,7 @@ mod tests {
         assert_eq!(removed_icon_count, 0);
 
         let kept_feed_count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM feeds WHERE id = 100 AND user_id = 1")
+            sqlx::query_scalar("SELECT COUNT(*) FROM feeds WHERE account_id = 1 AND id = 100")
                 .fetch_one(&pool)
                 .await
                 .unwrap();
@@ -202,16 +561,17 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_delete_removed_feeds_with_empty_remote_list_only_clears_current_user_data() {
+    async fn test_delete_removed_feeds_with_empty_remote_list_only_clears_current_account_data() {
         let pool = setup_test_db().await;
         let now = "2026-02-11T00:00:00Z";
 
         sqlx::query(
-            "INSERT INTO feeds (id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
         )
+        .bind(1_i64)
         .bind(300_i64)
         .bind(1_i64)
-        .bind("User1 Feed")
+        .bind("Account1 Feed")
         .bind("https://u1.example.com")
         .bind("https://u1.example.com/rss")
         .bind(now)
@@ -221,11 +581,12 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO feeds (id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
         )
+        .bind(2_i64)
         .bind(400_i64)
         .bind(2_i64)
-        .bind("User2 Feed")
+        .bind("Account2 Feed")
         .bind("https://u2.example.com")
         .bind("https://u2.example.com/rss")
         .bind(now)
@@ -235,12 +596,13 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO entries (id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
+        .bind(1_i64)
         .bind(3_000_i64)
         .bind(1_i64)
         .bind(300_i64)
-        .bind("User1 Entry")
+        .bind("Account1 Entry")
         .bind("https://u1.example.com/1")
         .bind("u1-hash")
         .bind(now)
@@ -251,12 +613,13 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO entries (id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
+        .bind(2_i64)
         .bind(4_000_i64)
         .bind(2_i64)
         .bind(400_i64)
-        .bind("User2 Entry")
+        .bind("Account2 Entry")
         .bind("https://u2.example.com/1")
         .bind("u2-hash")
         .bind(now)
@@ -267,9 +630,10 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO icons (id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO icons (id, account_id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(3_i64)
+        .bind(1_i64)
         .bind(300_i64)
         .bind(30_i64)
         .bind("dXNlcjE=")
@@ -280,9 +644,10 @@ mod tests {
         .unwrap();
 
         sqlx::query(
-            "INSERT INTO icons (id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO icons (id, account_id, feed_id, icon_id, data, mime_type, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(4_i64)
+        .bind(2_i64)
         .bind(400_i64)
         .bind(40_i64)
         .bind("dXNlcjI=")
@@ -294,46 +659,46 @@ mod tests {
 
         delete_removed_feeds(&pool, 1, &[]).await.unwrap();
 
-        let user1_feed_count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM feeds WHERE user_id = 1")
+        let account1_feed_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM feeds WHERE account_id = 1")
                 .fetch_one(&pool)
                 .await
                 .unwrap();
-        assert_eq!(user1_feed_count, 0);
+        assert_eq!(account1_feed_count, 0);
 
-        let user1_entry_count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE user_id = 1")
+        let account1_entry_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE account_id = 1")
                 .fetch_one(&pool)
                 .await
                 .unwrap();
-        assert_eq!(user1_entry_count, 0);
+        assert_eq!(account1_entry_count, 0);
 
-        let user1_icon_count: i64 =
+        let account1_icon_count: i64 =
             sqlx::query_scalar("SELECT COUNT(*) FROM icons WHERE feed_id = 300")
                 .fetch_one(&pool)
                 .await
                 .unwrap();
-        assert_eq!(user1_icon_count, 0);
+        assert_eq!(account1_icon_count, 0);
 
-        let user2_feed_count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM feeds WHERE user_id = 2")
+        let account2_feed_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM feeds WHERE account_id = 2")
                 .fetch_one(&pool)
                 .await
                 .unwrap();
-        assert_eq!(user2_feed_count, 1);
+        assert_eq!(account2_feed_count, 1);
 
-        let user2_entry_count: i64 =
-            sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE user_id = 2")
+        let account2_entry_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM entries WHERE account_id = 2")
                 .fetch_one(&pool)
                 .await
                 .unwrap();
-        assert_eq!(user2_entry_count, 1);
+        assert_eq!(account2_entry_count, 1);
 
-        let user2_icon_count: i64 =
+        let account2_icon_count: i64 =
             sqlx::query_scalar("SELECT COUNT(*) FROM icons WHERE feed_id = 400")
                 .fetch_one(&pool)
                 .await
                 .unwrap();
-        assert_eq!(user2_icon_count, 1);
+        assert_eq!(account2_icon_count, 1);
     }
 }
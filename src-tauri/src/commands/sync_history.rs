@@ -0,0 +1,253 @@
+//! History of Miniflux sync runs.
+//!
+//! Every account sync records a `sync_runs` row when it starts and fills in
+//! its counts, transfer metrics and errors when it ends, so slow or failing
+//! syncs can be looked at after the fact. Only the newest [`MAX_SYNC_RUNS`]
+//! rows are kept.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use tauri::State;
+
+use crate::commands::sync::SyncSummary;
+use crate::miniflux::TransferStats;
+use crate::utils::serde_helpers::{
+    deserialize_i64_from_string_or_number, deserialize_option_i64_from_string_or_number,
+    serialize_i64_as_string, serialize_option_i64_as_string,
+};
+use crate::AppState;
+
+/// Runs kept across all accounts; older ones are dropped as new ones finish
+pub const MAX_SYNC_RUNS: i64 = 1000;
+
+const DEFAULT_HISTORY_LIMIT: u32 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncRunType {
+    Full,
+    Incremental,
+    /// A full sync the user asked for with `force_full_sync`
+    Forced,
+}
+
+impl SyncRunType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Incremental => "incremental",
+            Self::Forced => "forced",
+        }
+    }
+}
+
+/// What a run did, filled in as it goes so a failed run still reports how
+/// far it got
+#[derive(Debug, Default)]
+pub struct SyncRunMetrics {
+    /// Whether the run pulled everything rather than changes since the last sync
+    pub full_sync: bool,
+    pub entries_pulled: u32,
+    pub entries_pushed: u32,
+    pub entries_deleted: u32,
+    pub feeds_pulled: u32,
+    pub categories_pulled: u32,
+    /// Non-empty entry pages written
+    pub pages: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct SyncRun {
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub id: i64,
+    #[serde(
+        default,
+        serialize_with = "serialize_option_i64_as_string",
+        deserialize_with = "deserialize_option_i64_from_string_or_number"
+    )]
+    #[specta(type = Option<String>)]
+    pub account_id: Option<i64>,
+    /// "full", "incremental" or "forced"
+    pub sync_type: String,
+    /// "running", "succeeded", "failed" or "interrupted"
+    pub status: String,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub entries_pulled: u32,
+    pub entries_pushed: u32,
+    /// Entries deleted locally: gone from the server or pruned by retention
+    pub entries_deleted: u32,
+    pub feeds_pulled: u32,
+    pub categories_pulled: u32,
+    /// Response bytes received from the server
+    pub bytes_transferred: u64,
+    pub pages: u32,
+    /// Requests sent, retries included
+    pub requests: u32,
+    /// Failed attempts, whether or not a retry then succeeded
+    pub failures: u32,
+    /// Failed attempts in order, then the error that ended the run
+    pub error_chain: Vec<String>,
+}
+
+fn build_run_from_row(row: &sqlx::sqlite::SqliteRow) -> SyncRun {
+    let count = |column: &str| row.get::<i64, _>(column) as u32;
+    SyncRun {
+        id: row.get("id"),
+        account_id: row.get("account_id"),
+        sync_type: row.get("sync_type"),
+        status: row.get("status"),
+        started_at: row.get("started_at"),
+        finished_at: row.get("finished_at"),
+        entries_pulled: count("entries_pulled"),
+        entries_pushed: count("entries_pushed"),
+        entries_deleted: count("entries_deleted"),
+        feeds_pulled: count("feeds_pulled"),
+        categories_pulled: count("categories_pulled"),
+        bytes_transferred: row.get::<i64, _>("bytes_transferred") as u64,
+        pages: count("pages"),
+        requests: count("requests"),
+        failures: count("failures"),
+        error_chain: row
+            .get::<Option<String>, _>("error_chain")
+            .and_then(|chain| serde_json::from_str(&chain).ok())
+            .unwrap_or_default(),
+    }
+}
+
+/// Records the start of a run and returns its ID. Runs of the account still
+/// marked running never finished (the app quit mid-sync) and become
+/// `interrupted`.
+pub async fn start_sync_run(
+    pool: &SqlitePool,
+    account_id: i64,
+    sync_type: SyncRunType,
+) -> Result<i64, String> {
+    sqlx::query(
+        "UPDATE sync_runs SET status = 'interrupted' WHERE account_id = ? AND status = 'running'",
+    )
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to close interrupted sync runs: {e}"))?;
+
+    let result = sqlx::query(
+        "INSERT INTO sync_runs (account_id, sync_type, status, started_at) VALUES (?, ?, 'running', ?)",
+    )
+    .bind(account_id)
+    .bind(sync_type.as_str())
+    .bind(Utc::now().to_rfc3339())
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to record sync run: {e}"))?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Fills in a run's outcome and drops the runs beyond [`MAX_SYNC_RUNS`].
+/// `sync_type` replaces the one guessed at the start, which a recovered sync
+/// state can turn from full to incremental.
+pub async fn finish_sync_run(
+    pool: &SqlitePool,
+    run_id: i64,
+    sync_type: SyncRunType,
+    result: &Result<SyncSummary, String>,
+    metrics: &SyncRunMetrics,
+    stats: &TransferStats,
+) -> Result<(), String> {
+    let mut error_chain = stats.errors();
+    let status = match result {
+        Ok(_) => "succeeded",
+        Err(error) => {
+            error_chain.push(error.clone());
+            "failed"
+        }
+    };
+    let error_chain = (!error_chain.is_empty())
+        .then(|| serde_json::to_string(&error_chain))
+        .transpose()
+        .map_err(|e| format!("Failed to serialize error chain: {e}"))?;
+
+    sqlx::query(
+        "UPDATE sync_runs SET sync_type = ?, status = ?, finished_at = ?, entries_pulled = ?, entries_pushed = ?, entries_deleted = ?, feeds_pulled = ?, categories_pulled = ?, bytes_transferred = ?, pages = ?, requests = ?, failures = ?, error_chain = ? WHERE id = ?",
+    )
+    .bind(sync_type.as_str())
+    .bind(status)
+    .bind(Utc::now().to_rfc3339())
+    .bind(metrics.entries_pulled as i64)
+    .bind(metrics.entries_pushed as i64)
+    .bind(metrics.entries_deleted as i64)
+    .bind(metrics.feeds_pulled as i64)
+    .bind(metrics.categories_pulled as i64)
+    .bind(stats.bytes_received() as i64)
+    .bind(metrics.pages as i64)
+    .bind(stats.requests() as i64)
+    .bind(stats.failed_attempts() as i64)
+    .bind(error_chain)
+    .bind(run_id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to record sync run: {e}"))?;
+
+    sqlx::query(
+        "DELETE FROM sync_runs WHERE id NOT IN (SELECT id FROM sync_runs ORDER BY id DESC LIMIT ?)",
+    )
+    .bind(MAX_SYNC_RUNS)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to trim sync history: {e}"))?;
+
+    Ok(())
+}
+
+/// Returns recorded sync runs, newest first, for one account or all of them
+#[tauri::command]
+#[specta::specta]
+pub async fn get_sync_history(
+    state: State<'_, AppState>,
+    account_id: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<SyncRun>, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+
+    let account_id = account_id
+        .map(|id| {
+            id.parse::<i64>()
+                .map_err(|e| format!("Invalid account ID: {e}"))
+        })
+        .transpose()?;
+
+    get_sync_history_from_db(&pool, account_id, limit.unwrap_or(DEFAULT_HISTORY_LIMIT)).await
+}
+
+pub async fn get_sync_history_from_db(
+    pool: &SqlitePool,
+    account_id: Option<i64>,
+    limit: u32,
+) -> Result<Vec<SyncRun>, String> {
+    let rows = sqlx::query(
+        "SELECT * FROM sync_runs WHERE (? IS NULL OR account_id = ?) ORDER BY started_at DESC, id DESC LIMIT ?",
+    )
+    .bind(account_id)
+    .bind(account_id)
+    .bind(limit as i64)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to query sync history: {e}"))?;
+
+    Ok(rows.iter().map(build_run_from_row).collect())
+}
+
+#[cfg(test)]
+#[path = "sync_history.test.rs"]
+mod tests;
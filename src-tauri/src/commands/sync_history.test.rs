@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use crate::commands::sync::SyncSummary;
+    use crate::commands::sync_history::{
+        finish_sync_run, get_sync_history_from_db, start_sync_run, SyncRunMetrics, SyncRunType,
+        MAX_SYNC_RUNS,
+    };
+    use crate::database::migrations::run_migrations;
+    use crate::miniflux::TransferStats;
+    use sqlx::SqlitePool;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+        pool
+    }
+
+    fn summary() -> SyncSummary {
+        SyncSummary {
+            entries_pulled: 120,
+            entries_pushed: 3,
+            feeds_pulled: 4,
+            categories_pulled: 2,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_successful_run() {
+        let pool = setup_test_db().await;
+        let stats = TransferStats::default();
+        stats.record_request();
+        stats.record_bytes(4096);
+        let metrics = SyncRunMetrics {
+            full_sync: true,
+            entries_pulled: 120,
+            entries_pushed: 3,
+            entries_deleted: 5,
+            feeds_pulled: 4,
+            categories_pulled: 2,
+            pages: 2,
+        };
+
+        let run_id = start_sync_run(&pool, 1, SyncRunType::Full).await.unwrap();
+        finish_sync_run(
+            &pool,
+            run_id,
+            SyncRunType::Full,
+            &Ok(summary()),
+            &metrics,
+            &stats,
+        )
+        .await
+        .unwrap();
+
+        let runs = get_sync_history_from_db(&pool, Some(1), 10).await.unwrap();
+        assert_eq!(runs.len(), 1);
+        let run = &runs[0];
+        assert_eq!(run.sync_type, "full");
+        assert_eq!(run.status, "succeeded");
+        assert!(run.finished_at.is_some());
+        assert_eq!(run.entries_pulled, 120);
+        assert_eq!(run.entries_pushed, 3);
+        assert_eq!(run.entries_deleted, 5);
+        assert_eq!(run.bytes_transferred, 4096);
+        assert_eq!(run.pages, 2);
+        assert_eq!(run.requests, 1);
+        assert!(run.error_chain.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_run_keeps_error_chain() {
+        let pool = setup_test_db().await;
+        let stats = TransferStats::default();
+        stats.record_request();
+        stats.record_failure("/v1/entries", &"HTTP 503");
+        stats.record_request();
+
+        let run_id = start_sync_run(&pool, 1, SyncRunType::Incremental)
+            .await
+            .unwrap();
+        let result = Err("Parse error: expected value".to_string());
+        // Failed on the third entries page, after feeds and categories
+        let metrics = SyncRunMetrics {
+            entries_pulled: 200,
+            entries_pushed: 1,
+            feeds_pulled: 4,
+            categories_pulled: 2,
+            pages: 2,
+            ..SyncRunMetrics::default()
+        };
+        finish_sync_run(
+            &pool,
+            run_id,
+            SyncRunType::Incremental,
+            &result,
+            &metrics,
+            &stats,
+        )
+        .await
+        .unwrap();
+
+        let run = get_sync_history_from_db(&pool, None, 10)
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(run.status, "failed");
+        assert_eq!(run.entries_pulled, 200);
+        assert_eq!(run.entries_pushed, 1);
+        assert_eq!(run.feeds_pulled, 4);
+        assert_eq!(run.categories_pulled, 2);
+        assert_eq!(run.pages, 2);
+        assert_eq!(run.requests, 2);
+        assert_eq!(run.failures, 1);
+        assert_eq!(
+            run.error_chain,
+            vec![
+                "/v1/entries: HTTP 503".to_string(),
+                "Parse error: expected value".to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unfinished_run_becomes_interrupted() {
+        let pool = setup_test_db().await;
+
+        let abandoned = start_sync_run(&pool, 1, SyncRunType::Full).await.unwrap();
+        let other_account = start_sync_run(&pool, 2, SyncRunType::Full).await.unwrap();
+        start_sync_run(&pool, 1, SyncRunType::Full).await.unwrap();
+
+        let runs = get_sync_history_from_db(&pool, None, 10).await.unwrap();
+        let status_of = |id: i64| {
+            runs.iter()
+                .find(|run| run.id == id)
+                .map(|run| run.status.as_str())
+        };
+        assert_eq!(status_of(abandoned), Some("interrupted"));
+        assert_eq!(status_of(other_account), Some("running"));
+    }
+
+    #[tokio::test]
+    async fn test_history_is_capped() {
+        let pool = setup_test_db().await;
+        sqlx::query(
+            r#"
+            WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < ?)
+            INSERT INTO sync_runs (account_id, sync_type, status, started_at)
+            SELECT 1, 'incremental', 'succeeded', '2026-01-01T00:00:00+00:00' FROM n
+            "#,
+        )
+        .bind(MAX_SYNC_RUNS)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let run_id = start_sync_run(&pool, 1, SyncRunType::Incremental)
+            .await
+            .unwrap();
+        finish_sync_run(
+            &pool,
+            run_id,
+            SyncRunType::Incremental,
+            &Ok(summary()),
+            &SyncRunMetrics::default(),
+            &TransferStats::default(),
+        )
+        .await
+        .unwrap();
+
+        let (count, oldest): (i64, i64) = sqlx::query_as("SELECT COUNT(*), MIN(id) FROM sync_runs")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, MAX_SYNC_RUNS);
+        assert_eq!(oldest, 2);
+
+        let newest = get_sync_history_from_db(&pool, Some(1), 1).await.unwrap();
+        assert_eq!(newest[0].id, run_id);
+    }
+}
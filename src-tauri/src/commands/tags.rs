@@ -0,0 +1,391 @@
+//! Local entry tags.
+//!
+//! Tags live only in the local `tags` table; Miniflux has no API for them.
+//! Every query is scoped to the active account through the tagged entry.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, QueryBuilder, Row, SqlitePool};
+use tauri::State;
+
+use crate::commands::miniflux::get_active_account_id;
+use crate::AppState;
+
+const MAX_TAG_LENGTH: usize = 64;
+
+/// A tag with the number of entries carrying it
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, FromRow)]
+pub struct TagSummary {
+    pub tag: String,
+    pub entry_count: i64,
+    pub unread_count: i64,
+}
+
+/// Trims a tag and collapses inner whitespace. Rejects empty or overlong tags.
+pub fn normalize_tag(tag: &str) -> Result<String, String> {
+    let normalized = tag.split_whitespace().collect::<Vec<_>>().join(" ");
+    if normalized.is_empty() {
+        return Err("Tag cannot be empty".to_string());
+    }
+    if normalized.chars().count() > MAX_TAG_LENGTH {
+        return Err(format!(
+            "Tag cannot be longer than {MAX_TAG_LENGTH} characters"
+        ));
+    }
+    Ok(normalized)
+}
+
+fn normalize_tags(tags: &[String]) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = normalize_tag(tag)?;
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    Ok(normalized)
+}
+
+fn parse_entry_ids(ids: &[String]) -> Result<Vec<i64>, String> {
+    ids.iter()
+        .map(|id| {
+            id.parse::<i64>()
+                .map_err(|e| format!("Invalid entry ID: {}", e))
+        })
+        .collect()
+}
+
+pub async fn list_tags_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+) -> Result<Vec<TagSummary>, String> {
+    sqlx::query_as(
+        r#"
+        SELECT t.tag,
+               COUNT(*) AS entry_count,
+               SUM(CASE WHEN e.status = 'unread' THEN 1 ELSE 0 END) AS unread_count
+        FROM tags t
+        JOIN entries e ON e.account_id = t.account_id AND e.id = t.entry_id
+        WHERE t.account_id = ?
+        GROUP BY t.tag
+        ORDER BY t.tag COLLATE NOCASE
+        "#,
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch tags: {e}"))
+}
+
+/// Tags the given entries. Entries of other accounts are ignored and existing
+/// tags are kept. Returns the number of tags added.
+pub async fn add_tags_to_entries(
+    pool: &SqlitePool,
+    account_id: i64,
+    entry_ids: &[i64],
+    tags: &[String],
+) -> Result<u64, String> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {e}"))?;
+    let mut added = 0;
+
+    for chunk in entry_ids.chunks(500) {
+        for tag in tags {
+            let mut query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+                "INSERT OR IGNORE INTO tags (account_id, entry_id, tag, created_at) SELECT account_id, id, ",
+            );
+            query.push_bind(tag);
+            query.push(", ");
+            query.push_bind(&now);
+            query.push(" FROM entries WHERE account_id = ");
+            query.push_bind(account_id);
+            query.push(" AND id IN (");
+            let mut separated = query.separated(", ");
+            for id in chunk {
+                separated.push_bind(id);
+            }
+            separated.push_unseparated(")");
+
+            added += query
+                .build()
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| format!("Failed to add tags: {e}"))?
+                .rows_affected();
+        }
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit tags: {e}"))?;
+
+    Ok(added)
+}
+
+/// Removes the given tags from the given entries. Returns the number of tags removed.
+pub async fn remove_tags_from_entries(
+    pool: &SqlitePool,
+    account_id: i64,
+    entry_ids: &[i64],
+    tags: &[String],
+) -> Result<u64, String> {
+    if tags.is_empty() {
+        return Ok(0);
+    }
+
+    let mut removed = 0;
+
+    for chunk in entry_ids.chunks(500) {
+        let mut query: QueryBuilder<sqlx::Sqlite> =
+            QueryBuilder::new("DELETE FROM tags WHERE tag IN (");
+        let mut separated = query.separated(", ");
+        for tag in tags {
+            separated.push_bind(tag);
+        }
+        separated.push_unseparated(")");
+        query.push(" AND account_id = ");
+        query.push_bind(account_id);
+        query.push(" AND entry_id IN (");
+        let mut separated = query.separated(", ");
+        for id in chunk {
+            separated.push_bind(id);
+        }
+        separated.push_unseparated(")");
+
+        removed += query
+            .build()
+            .execute(pool)
+            .await
+            .map_err(|e| format!("Failed to remove tags: {e}"))?
+            .rows_affected();
+    }
+
+    Ok(removed)
+}
+
+/// Renames a tag on all of the account's entries, merging into `to` if it
+/// already exists. The account's smart folders filtering on the old tag are
+/// updated too. Returns the number of entries that carried the old tag.
+pub async fn rename_tag_in_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    from: &str,
+    to: &str,
+) -> Result<u64, String> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Failed to start transaction: {e}"))?;
+
+    sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO tags (account_id, entry_id, tag, created_at)
+        SELECT account_id, entry_id, ?, created_at
+        FROM tags
+        WHERE tag = ? AND account_id = ?
+        "#,
+    )
+    .bind(to)
+    .bind(from)
+    .bind(account_id)
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("Failed to rename tag: {e}"))?;
+
+    let renamed = sqlx::query("DELETE FROM tags WHERE tag = ? AND account_id = ?")
+        .bind(from)
+        .bind(account_id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| format!("Failed to rename tag: {e}"))?
+        .rows_affected();
+
+    crate::commands::smart_folders::rename_tag_in_smart_folders(
+        &mut *transaction,
+        account_id,
+        from,
+        to,
+    )
+    .await?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit tag rename: {e}"))?;
+
+    Ok(renamed)
+}
+
+/// Removes a tag from all of the account's entries. Returns the number of entries untagged.
+pub async fn delete_tag_from_db(
+    pool: &SqlitePool,
+    account_id: i64,
+    tag: &str,
+) -> Result<u64, String> {
+    sqlx::query("DELETE FROM tags WHERE tag = ? AND account_id = ?")
+        .bind(tag)
+        .bind(account_id)
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected())
+        .map_err(|e| format!("Failed to delete tag: {e}"))
+}
+
+/// Fills `Entry.tags` with local tags, in a single batch query.
+pub(crate) async fn load_tags_for_entries(
+    pool: &SqlitePool,
+    entries: &mut [crate::miniflux::Entry],
+) -> Result<(), String> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let mut query: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        "SELECT account_id, entry_id, tag FROM tags WHERE (account_id, entry_id) IN (VALUES ",
+    );
+    let mut separated = query.separated(", ");
+    for entry in entries.iter() {
+        separated.push("(");
+        separated.push_bind_unseparated(entry.account_id);
+        separated.push_unseparated(", ");
+        separated.push_bind_unseparated(entry.id);
+        separated.push_unseparated(")");
+    }
+    query.push(") ORDER BY tag COLLATE NOCASE");
+
+    let rows = query
+        .build()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to fetch entry tags: {e}"))?;
+
+    let mut tag_map: std::collections::HashMap<(Option<i64>, i64), Vec<String>> =
+        std::collections::HashMap::new();
+    for row in &rows {
+        tag_map
+            .entry((row.get("account_id"), row.get("entry_id")))
+            .or_default()
+            .push(row.get("tag"));
+    }
+
+    for entry in entries.iter_mut() {
+        if let Some(tags) = tag_map.remove(&(entry.account_id, entry.id)) {
+            entry.tags = Some(tags);
+        }
+    }
+
+    Ok(())
+}
+
+/// List the active account's tags with entry counts
+#[tauri::command]
+#[specta::specta]
+pub async fn list_tags(state: State<'_, AppState>) -> Result<Vec<TagSummary>, String> {
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let account_id = get_active_account_id(&state).await?;
+    list_tags_from_db(&pool, account_id).await
+}
+
+/// Add tags to one or more entries
+#[tauri::command]
+#[specta::specta]
+pub async fn add_entry_tags(
+    state: State<'_, AppState>,
+    entry_ids: Vec<String>,
+    tags: Vec<String>,
+) -> Result<u32, String> {
+    let entry_ids = parse_entry_ids(&entry_ids)?;
+    let tags = normalize_tags(&tags)?;
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let account_id = get_active_account_id(&state).await?;
+
+    let added = add_tags_to_entries(&pool, account_id, &entry_ids, &tags).await?;
+    Ok(added as u32)
+}
+
+/// Remove tags from one or more entries
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_entry_tags(
+    state: State<'_, AppState>,
+    entry_ids: Vec<String>,
+    tags: Vec<String>,
+) -> Result<u32, String> {
+    let entry_ids = parse_entry_ids(&entry_ids)?;
+    let tags = normalize_tags(&tags)?;
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let account_id = get_active_account_id(&state).await?;
+
+    let removed = remove_tags_from_entries(&pool, account_id, &entry_ids, &tags).await?;
+    Ok(removed as u32)
+}
+
+/// Rename a tag across all entries
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_tag(
+    state: State<'_, AppState>,
+    from: String,
+    to: String,
+) -> Result<u32, String> {
+    let from = normalize_tag(&from)?;
+    let to = normalize_tag(&to)?;
+    if from == to {
+        return Ok(0);
+    }
+
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let account_id = get_active_account_id(&state).await?;
+
+    let renamed = rename_tag_in_db(&pool, account_id, &from, &to).await?;
+    Ok(renamed as u32)
+}
+
+/// Remove a tag from all entries
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_tag(state: State<'_, AppState>, tag: String) -> Result<u32, String> {
+    let tag = normalize_tag(&tag)?;
+    let pool = state
+        .db_pool
+        .lock()
+        .await
+        .as_ref()
+        .ok_or("Database not initialized")?
+        .clone();
+    let account_id = get_active_account_id(&state).await?;
+
+    let deleted = delete_tag_from_db(&pool, account_id, &tag).await?;
+    Ok(deleted as u32)
+}
+
+#[cfg(test)]
+#[path = "tags.test.rs"]
+mod tests;
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use crate::commands::miniflux::get_entries_from_db;
+    use crate::database::migrations::run_migrations;
+    use crate::miniflux::EntryFilters;
+    use sqlx::SqlitePool;
+
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .expect("Failed to create in-memory database");
+        run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+        pool
+    }
+
+    async fn insert_entries(pool: &SqlitePool) {
+        let now = "2026-02-12T00:00:00Z";
+
+        for account_id in [1, 2] {
+            sqlx::query(
+                r#"
+                INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at)
+                VALUES (?, 1, ?, 'Feed', 'https://example.com', 'https://example.com/rss', ?, ?)
+                "#,
+            )
+            .bind(account_id)
+            .bind(account_id)
+            .bind(now)
+            .bind(now)
+            .execute(pool)
+            .await
+            .expect("Failed to insert feed");
+        }
+
+        for (id, account_id, status) in [
+            (1, 1, "unread"),
+            (2, 1, "read"),
+            (3, 1, "unread"),
+            (4, 2, "unread"),
+        ] {
+            sqlx::query(
+                r#"
+                INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status)
+                VALUES (?, ?, ?, 1, ?, 'https://example.com/article', ?, ?, ?, ?)
+                "#,
+            )
+            .bind(account_id)
+            .bind(id)
+            .bind(account_id)
+            .bind(format!("Entry {id}"))
+            .bind(format!("hash-{id}"))
+            .bind(now)
+            .bind(now)
+            .bind(status)
+            .execute(pool)
+            .await
+            .expect("Failed to insert entry");
+        }
+    }
+
+    fn tags(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(
+            super::super::normalize_tag("  machine   learning ").unwrap(),
+            "machine learning"
+        );
+        assert!(super::super::normalize_tag("   ").is_err());
+        assert!(super::super::normalize_tag(&"x".repeat(65)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_tagging_is_scoped_to_account_and_idempotent() {
+        let pool = setup_test_db().await;
+        insert_entries(&pool).await;
+
+        let added =
+            super::super::add_tags_to_entries(&pool, 1, &[1, 2, 4], &tags(&["research", "ml"]))
+                .await
+                .expect("Tagging should succeed");
+        // Entry 4 belongs to another account
+        assert_eq!(added, 4);
+
+        let again = super::super::add_tags_to_entries(&pool, 1, &[1], &tags(&["research"]))
+            .await
+            .expect("Tagging should succeed");
+        assert_eq!(again, 0);
+
+        let summaries = super::super::list_tags_from_db(&pool, 1)
+            .await
+            .expect("Listing tags should succeed");
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].tag, "ml");
+        assert_eq!(summaries[0].entry_count, 2);
+        assert_eq!(summaries[0].unread_count, 1);
+
+        let removed = super::super::remove_tags_from_entries(&pool, 1, &[2], &tags(&["ml"]))
+            .await
+            .expect("Removing tags should succeed");
+        assert_eq!(removed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_rename_tag_merges_into_existing_tag() {
+        let pool = setup_test_db().await;
+        insert_entries(&pool).await;
+
+        super::super::add_tags_to_entries(&pool, 1, &[1, 2], &tags(&["ml"]))
+            .await
+            .unwrap();
+        super::super::add_tags_to_entries(&pool, 1, &[1], &tags(&["machine learning"]))
+            .await
+            .unwrap();
+
+        let renamed = super::super::rename_tag_in_db(&pool, 1, "ml", "machine learning")
+            .await
+            .expect("Rename should succeed");
+        assert_eq!(renamed, 2);
+
+        let summaries = super::super::list_tags_from_db(&pool, 1).await.unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].tag, "machine learning");
+        assert_eq!(summaries[0].entry_count, 2);
+
+        let deleted = super::super::delete_tag_from_db(&pool, 1, "machine learning")
+            .await
+            .unwrap();
+        assert_eq!(deleted, 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_entries_filters_by_all_tags_and_loads_tags() {
+        let pool = setup_test_db().await;
+        insert_entries(&pool).await;
+
+        super::super::add_tags_to_entries(&pool, 1, &[1, 3], &tags(&["research"]))
+            .await
+            .unwrap();
+        super::super::add_tags_to_entries(&pool, 1, &[3], &tags(&["to-cite"]))
+            .await
+            .unwrap();
+
+        let filters = EntryFilters {
+            tags: Some(tags(&["research", "to-cite"])),
+            ..EntryFilters::default()
+        };
+        let response = get_entries_from_db(&pool, &filters, 1)
+            .await
+            .expect("get_entries_from_db should not error");
+
+        assert_eq!(response.total, 1);
+        let entries = response.entries.expect("Should have entries");
+        assert_eq!(entries[0].id, 3);
+        assert_eq!(entries[0].tags, Some(tags(&["research", "to-cite"])));
+    }
+}
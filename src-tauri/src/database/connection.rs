@@ -2,7 +2,7 @@ use sqlx::sqlite::SqlitePool;
 use std::path::PathBuf;
 use tauri::{AppHandle, Manager};
 
-use super::migrations::run_migrations;
+use super::migrations::{rollback_to, run_migrations, MigrationError};
 
 pub fn get_db_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
     let app_dir = app_handle.path().app_data_dir().map_err(|e| {
@@ -26,12 +26,31 @@ pub fn get_db_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
     Ok(db_path)
 }
 
-pub async fn init_database_pool(app_handle: &AppHandle) -> Result<SqlitePool, sqlx::Error> {
+/// Environment variable naming a schema version to revert the database to.
+///
+/// Going back to an older Minikyu build needs the schema it knows: start this
+/// build once with the variable set to that build's schema version, and it
+/// reverts the newer migrations and quits instead of opening the app.
+pub const ROLLBACK_ENV_VAR: &str = "MINIKYU_ROLLBACK_SCHEMA_TO";
+
+/// The schema version requested through [`ROLLBACK_ENV_VAR`], if any.
+pub fn requested_rollback() -> Option<i32> {
+    let value = std::env::var(ROLLBACK_ENV_VAR).ok()?;
+    match value.trim().parse() {
+        Ok(version) => Some(version),
+        Err(e) => {
+            log::error!("Ignoring {ROLLBACK_ENV_VAR}={value}: {e}");
+            None
+        }
+    }
+}
+
+async fn open_pool(app_handle: &AppHandle) -> Result<SqlitePool, MigrationError> {
     let db_path = match get_db_path(app_handle) {
         Ok(path) => path,
         Err(e) => {
             log::error!("Failed to get database path: {}", e);
-            return Err(sqlx::Error::Io(std::io::Error::other(e)));
+            return Err(sqlx::Error::Io(std::io::Error::other(e)).into());
         }
     };
 
@@ -46,7 +65,7 @@ pub async fn init_database_pool(app_handle: &AppHandle) -> Result<SqlitePool, sq
                 db_path,
                 e
             );
-            return Err(e);
+            return Err(e.into());
         }
     };
 
@@ -60,12 +79,32 @@ pub async fn init_database_pool(app_handle: &AppHandle) -> Result<SqlitePool, sq
     .execute(&pool)
     .await?;
 
+    Ok(pool)
+}
+
+pub async fn init_database_pool(app_handle: &AppHandle) -> Result<SqlitePool, MigrationError> {
+    let pool = open_pool(app_handle).await?;
+
     run_migrations(&pool).await?;
-    log::info!("Database initialized successfully at {:?}", db_path);
+    log::info!("Database initialized successfully");
 
     Ok(pool)
 }
 
+/// Reverts the database to `target_version` (see [`ROLLBACK_ENV_VAR`]).
+pub async fn rollback_database(
+    app_handle: &AppHandle,
+    target_version: i32,
+) -> Result<(), MigrationError> {
+    let pool = open_pool(app_handle).await?;
+
+    rollback_to(&pool, target_version).await?;
+    pool.close().await;
+    log::info!("Database schema rolled back to version {target_version}");
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,252 @@
+//! Full-text search index over cached entries.
+//!
+//! `entries_fts` is a standalone FTS5 table keyed by the entry's `local_id`
+//! (rowid) holding the title, author, tag-stripped content and feed title of
+//! each entry. Miniflux entry IDs repeat across accounts, so they can't be the
+//! key. Rows are written from Rust (so HTML can be stripped) and removed by an
+//! `AFTER DELETE` trigger on `entries`.
+
+use regex::Regex;
+use sqlx::{QueryBuilder, SqliteConnection, SqlitePool};
+use std::sync::LazyLock;
+
+static SCRIPT_STYLE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?is)<(script|style)\b.*?</(script|style)\s*>")
+        .expect("Failed to compile script/style regex pattern")
+});
+
+static TAG_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<[^>]*>").expect("Failed to compile tag regex pattern"));
+
+static WHITESPACE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s+").expect("Failed to compile whitespace regex pattern"));
+
+/// Column qualifiers accepted in search queries (`feed:rust`, `author:"Jane Doe"`).
+const QUERY_QUALIFIERS: &[&str] = &["title", "author", "feed", "content"];
+
+/// Reduces HTML to plain text for indexing: drops script/style blocks and
+/// tags, decodes the common entities and collapses whitespace.
+pub fn strip_html(html: &str) -> String {
+    let without_scripts = SCRIPT_STYLE_PATTERN.replace_all(html, " ");
+    let without_tags = TAG_PATTERN.replace_all(&without_scripts, " ");
+    let decoded = without_tags
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+    WHITESPACE_PATTERN
+        .replace_all(&decoded, " ")
+        .trim()
+        .to_string()
+}
+
+fn quote_fts_term(term: &str) -> String {
+    format!("\"{}\"", term.replace('"', "\"\""))
+}
+
+/// Translates user search syntax into an FTS5 `MATCH` expression.
+///
+/// Supported syntax, combined with implicit AND:
+/// - `word` matches the token, `word*` matches it as a prefix
+/// - `"exact phrase"` matches the phrase
+/// - `feed:`, `author:`, `title:` and `content:` restrict a word or phrase to
+///   that column, e.g. `feed:"hacker news"` or `author:jo*`
+///
+/// Every term is quoted, so FTS5 operators typed by the user are treated as
+/// text and never produce a syntax error. Returns `None` if nothing searchable
+/// remains.
+pub fn build_fts_query(input: &str) -> Option<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut terms = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        if chars[index].is_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        // Optional `qualifier:` prefix
+        let mut column = None;
+        let word_end = chars[index..]
+            .iter()
+            .position(|c| c.is_whitespace() || *c == ':' || *c == '"')
+            .map_or(chars.len(), |offset| index + offset);
+        if word_end < chars.len() && chars[word_end] == ':' {
+            let qualifier: String = chars[index..word_end].iter().collect::<String>();
+            let qualifier = qualifier.to_lowercase();
+            if QUERY_QUALIFIERS.contains(&qualifier.as_str()) {
+                column = Some(qualifier);
+                index = word_end + 1;
+            }
+        }
+
+        let (text, is_prefix) = if index < chars.len() && chars[index] == '"' {
+            let start = index + 1;
+            let end = chars[start..]
+                .iter()
+                .position(|c| *c == '"')
+                .map_or(chars.len(), |offset| start + offset);
+            index = (end + 1).min(chars.len());
+            (chars[start..end].iter().collect::<String>(), false)
+        } else {
+            let start = index;
+            let end = chars[start..]
+                .iter()
+                .position(|c| c.is_whitespace())
+                .map_or(chars.len(), |offset| start + offset);
+            index = end;
+            let word: String = chars[start..end].iter().collect();
+            match word.strip_suffix('*') {
+                Some(stem) => (stem.to_string(), true),
+                None => (word, false),
+            }
+        };
+
+        let text = text.trim();
+        if !text.chars().any(char::is_alphanumeric) {
+            continue;
+        }
+
+        let mut term = quote_fts_term(text);
+        if is_prefix {
+            term.push('*');
+        }
+        if let Some(column) = column {
+            term = format!("{column} : {term}");
+        }
+        terms.push(term);
+    }
+
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Rewrites the index rows for the given entries of an account from their
+/// current `entries` and `feeds` rows.
+pub async fn refresh_entries_fts(
+    pool: &SqlitePool,
+    account_id: i64,
+    entry_ids: &[i64],
+) -> Result<(), sqlx::Error> {
+    for chunk in entry_ids.chunks(500) {
+        let mut transaction = pool.begin().await?;
+        refresh_entries_fts_chunk(&mut transaction, account_id, chunk).await?;
+        transaction.commit().await?;
+    }
+
+    Ok(())
+}
+
+/// Same as [`refresh_entries_fts`], on a connection the caller already holds
+/// (e.g. inside a sync transaction).
+pub async fn refresh_entries_fts_on(
+    conn: &mut SqliteConnection,
+    account_id: i64,
+    entry_ids: &[i64],
+) -> Result<(), sqlx::Error> {
+    for chunk in entry_ids.chunks(500) {
+        refresh_entries_fts_chunk(conn, account_id, chunk).await?;
+    }
+
+    Ok(())
+}
+
+async fn refresh_entries_fts_chunk(
+    conn: &mut SqliteConnection,
+    account_id: i64,
+    chunk: &[i64],
+) -> Result<(), sqlx::Error> {
+    let mut select: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        "SELECT e.local_id, e.title, e.author, e.content, f.title FROM entries e LEFT JOIN feeds f ON f.account_id = e.account_id AND f.id = e.feed_id WHERE e.account_id = ",
+    );
+    select.push_bind(account_id);
+    select.push(" AND e.id IN (");
+    let mut separated = select.separated(",");
+    for id in chunk {
+        separated.push_bind(id);
+    }
+    select.push(")");
+
+    let rows: Vec<(i64, String, Option<String>, Option<String>, Option<String>)> =
+        select.build_query_as().fetch_all(&mut *conn).await?;
+
+    for (local_id, title, author, content, feed_title) in rows {
+        write_index_row(
+            conn,
+            local_id,
+            &title,
+            author.as_deref(),
+            content.as_deref(),
+            feed_title.as_deref(),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the index row `rowid` with the given entry fields.
+pub(crate) async fn write_index_row(
+    conn: &mut SqliteConnection,
+    rowid: i64,
+    title: &str,
+    author: Option<&str>,
+    content: Option<&str>,
+    feed_title: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM entries_fts WHERE rowid = ?")
+        .bind(rowid)
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query(
+        "INSERT INTO entries_fts (rowid, title, author, content, feed) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(rowid)
+    .bind(title)
+    .bind(author.unwrap_or_default())
+    .bind(strip_html(content.unwrap_or_default()))
+    .bind(feed_title.unwrap_or_default())
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_html_removes_tags_scripts_and_entities() {
+        let html = "<p>Hello&nbsp;<b>world</b> &amp; friends</p><script>var x = 1;</script>\n<style>p{}</style>";
+        assert_eq!(strip_html(html), "Hello world & friends");
+    }
+
+    #[test]
+    fn build_fts_query_quotes_plain_words() {
+        assert_eq!(
+            build_fts_query("rust async").as_deref(),
+            Some("\"rust\" \"async\"")
+        );
+    }
+
+    #[test]
+    fn build_fts_query_supports_phrases_prefixes_and_qualifiers() {
+        assert_eq!(
+            build_fts_query("\"borrow checker\" tok* feed:\"hacker news\" author:jo*").as_deref(),
+            Some("\"borrow checker\" \"tok\"* feed : \"hacker news\" author : \"jo\"*")
+        );
+    }
+
+    #[test]
+    fn build_fts_query_neutralizes_operators_and_unknown_qualifiers() {
+        assert_eq!(
+            build_fts_query("NOT \"a\"\"b https://x.io").as_deref(),
+            Some("\"NOT\" \"a\" \"b https://x.io\"")
+        );
+        assert_eq!(build_fts_query("  ** \"\" "), None);
+    }
+}
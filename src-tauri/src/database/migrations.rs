@@ -1,139 +1,91 @@
-use chrono::Utc;
-use sqlx::sqlite::SqlitePool;
-
-pub async fn run_migrations(pool: &SqlitePool) -> Result<(), sqlx::Error> {
-    create_schema_versions_table(pool).await?;
-
-    let applied_migrations = get_applied_migrations(pool).await?;
-
-    if !applied_migrations.contains(&1) {
-        apply_initial_schema(pool).await?;
-        record_migration(pool, 1, "initial_schema").await?;
-    }
-
-    if !applied_migrations.contains(&2) {
-        apply_composite_index_migration(pool).await?;
-        record_migration(pool, 2, "add_composite_index").await?;
-    }
-
-    if !applied_migrations.contains(&3) {
-        apply_entries_sort_index_migration(pool).await?;
-        record_migration(pool, 3, "add_entries_status_published_at_index").await?;
-    }
-
-    if !applied_migrations.contains(&4) {
-        apply_podcast_settings_migration(pool).await?;
-        record_migration(pool, 4, "podcast_feed_settings_and_duration").await?;
-    }
-
-    if !applied_migrations.contains(&5) {
-        apply_local_cache_migration(pool).await?;
-        record_migration(pool, 5, "article_summaries_and_translation_cache").await?;
-    }
-
-    if !applied_migrations.contains(&6) {
-        apply_per_account_sync_state_migration(pool).await?;
-        record_migration(pool, 6, "per_account_sync_state").await?;
-    }
+//! Ordered schema migrations.
+//!
+//! Every schema change is a [`Migration`] in [`MIGRATIONS`]. Pending
+//! migrations run in version order, each inside its own transaction, and are
+//! recorded in `schema_versions` together with a checksum of their steps and
+//! the app version that applied them. A database migrated by a newer app
+//! version is refused rather than half-used.
 
-    if !applied_migrations.contains(&7) {
-        apply_sync_state_stats_migration(pool).await?;
-        record_migration(pool, 7, "sync_state_completion_stats").await?;
-    }
-
-    if !applied_migrations.contains(&8) {
-        apply_downloads_media_type_migration(pool).await?;
-        record_migration(pool, 8, "downloads_media_type").await?;
-    }
-
-    if !applied_migrations.contains(&9) {
-        apply_account_is_admin_migration(pool).await?;
-        record_migration(pool, 9, "account_is_admin").await?;
-    }
-
-    if !applied_migrations.contains(&10) {
-        apply_account_miniflux_user_id_migration(pool).await?;
-        record_migration(pool, 10, "account_miniflux_user_id").await?;
-    }
-
-    Ok(())
+use chrono::Utc;
+use futures_util::future::BoxFuture;
+use sqlx::sqlite::{SqliteConnection, SqlitePool};
+
+/// Data migration written in Rust, run on the migration's transaction.
+pub(crate) type DataStep =
+    for<'c> fn(&'c mut SqliteConnection) -> BoxFuture<'c, Result<(), sqlx::Error>>;
+
+pub(crate) enum Step {
+    Sql(&'static str),
+    /// `ALTER TABLE ... ADD COLUMN`, skipped when the column already exists
+    AddColumn {
+        table: &'static str,
+        column: &'static str,
+        definition: &'static str,
+    },
+    /// `name` stands in for the code in the checksum
+    Data {
+        name: &'static str,
+        run: DataStep,
+    },
 }
 
-pub(crate) async fn create_schema_versions_table(pool: &SqlitePool) -> Result<(), sqlx::Error> {
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS schema_versions (
-            version INTEGER PRIMARY KEY,
-            migration_name TEXT UNIQUE NOT NULL,
-            applied_at TEXT NOT NULL
-        )
-        "#,
-    )
-    .execute(pool)
-    .await?;
-
-    Ok(())
+pub(crate) struct Migration {
+    pub version: i32,
+    pub name: &'static str,
+    pub up: &'static [Step],
+    /// Steps undoing `up`; `None` when the migration can't be reverted
+    pub down: Option<&'static [Step]>,
 }
 
-async fn get_applied_migrations(pool: &SqlitePool) -> Result<Vec<i32>, sqlx::Error> {
-    let versions: Vec<i32> =
-        sqlx::query_scalar("SELECT version FROM schema_versions ORDER BY version")
-            .fetch_all(pool)
-            .await?;
-
-    Ok(versions)
+#[derive(Debug, thiserror::Error)]
+pub enum MigrationError {
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+
+    #[error("Migration {version} ({name}) failed: {source}")]
+    Failed {
+        version: i32,
+        name: &'static str,
+        source: sqlx::Error,
+    },
+
+    #[error(
+        "Database schema version {found} was written by Minikyu {app_version} and is newer than this version supports ({supported}). Update Minikyu to open it."
+    )]
+    SchemaTooNew {
+        found: i32,
+        supported: i32,
+        app_version: String,
+    },
+
+    #[error("Migration {version} ({name}) cannot be reverted")]
+    Irreversible { version: i32, name: &'static str },
+
+    #[error(
+        "Migration {version} ({name}) changed after it was applied (recorded checksum {recorded}, current {current}). Add a new migration instead of editing an applied one."
+    )]
+    ChecksumMismatch {
+        version: i32,
+        name: &'static str,
+        recorded: String,
+        current: String,
+    },
 }
 
-async fn record_migration(
-    pool: &SqlitePool,
+#[derive(Debug, sqlx::FromRow)]
+struct AppliedMigration {
     version: i32,
-    migration_name: &str,
-) -> Result<(), sqlx::Error> {
-    let applied_at = Utc::now().to_rfc3339();
-
-    sqlx::query(
-        r#"
-        INSERT INTO schema_versions (version, migration_name, applied_at)
-        VALUES (?, ?, ?)
-        ON CONFLICT(version) DO NOTHING
-        "#,
-    )
-    .bind(version)
-    .bind(migration_name)
-    .bind(applied_at)
-    .execute(pool)
-    .await?;
-
-    Ok(())
-}
-
-pub(crate) async fn apply_composite_index_migration(pool: &SqlitePool) -> Result<(), sqlx::Error> {
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_entries_feed_status ON entries(feed_id, status)")
-        .execute(pool)
-        .await?;
-
-    log::info!("Composite index migration applied (version 2)");
-
-    Ok(())
-}
-
-pub(crate) async fn apply_entries_sort_index_migration(
-    pool: &SqlitePool,
-) -> Result<(), sqlx::Error> {
-    sqlx::query(
-        "CREATE INDEX IF NOT EXISTS idx_entries_status_published_at ON entries(status, published_at DESC)",
-    )
-    .execute(pool)
-    .await?;
-
-    log::info!("Entries sort index migration applied (version 3)");
-
-    Ok(())
+    checksum: Option<String>,
+    app_version: Option<String>,
 }
 
-pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::Error> {
-    sqlx::query(
-        r#"
+pub(crate) static MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial_schema",
+        up: &[
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS categories (
             id INTEGER PRIMARY KEY,
             user_id INTEGER NOT NULL,
@@ -145,12 +97,9 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             UNIQUE(id, user_id)
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS feeds (
             id INTEGER PRIMARY KEY,
             user_id INTEGER NOT NULL,
@@ -187,12 +136,9 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             UNIQUE(id, user_id)
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS icons (
             id INTEGER PRIMARY KEY,
             feed_id INTEGER NOT NULL,
@@ -204,12 +150,9 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             UNIQUE(feed_id)
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS entries (
             id INTEGER PRIMARY KEY,
             user_id INTEGER NOT NULL,
@@ -232,12 +175,9 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             UNIQUE(id, user_id)
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS enclosures (
             id INTEGER PRIMARY KEY,
             entry_id INTEGER NOT NULL,
@@ -253,12 +193,9 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             FOREIGN KEY (entry_id) REFERENCES entries(id) ON DELETE CASCADE
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS tags (
             id INTEGER PRIMARY KEY,
             entry_id INTEGER NOT NULL,
@@ -268,12 +205,9 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             UNIQUE(entry_id, tag)
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS miniflux_connections (
             id INTEGER PRIMARY KEY,
             username TEXT NOT NULL,
@@ -284,12 +218,9 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             updated_at TEXT NOT NULL
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS downloads (
             id INTEGER PRIMARY KEY,
             url TEXT NOT NULL,
@@ -304,20 +235,11 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             updated_at TEXT NOT NULL
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_downloads_status ON downloads(status)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_downloads_url ON downloads(url)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_downloads_status ON downloads(status)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_downloads_url ON downloads(url)"),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS sync_state (
             id INTEGER PRIMARY KEY,
             last_sync_at TEXT,
@@ -330,12 +252,9 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             entries_total INTEGER DEFAULT 0
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS sync_queue (
             id INTEGER PRIMARY KEY,
             operation_type TEXT NOT NULL,
@@ -349,12 +268,9 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             updated_at TEXT NOT NULL
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS podcast_progress (
             id INTEGER PRIMARY KEY,
             entry_id INTEGER NOT NULL UNIQUE,
@@ -365,64 +281,47 @@ pub(crate) async fn apply_initial_schema(pool: &SqlitePool) -> Result<(), sqlx::
             FOREIGN KEY (entry_id) REFERENCES entries(id) ON DELETE CASCADE
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_entries_feed_id ON entries(feed_id)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_entries_status ON entries(status)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_entries_starred ON entries(starred)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query(
-        "CREATE INDEX IF NOT EXISTS idx_entries_published_at ON entries(published_at DESC)",
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_entries_user_status ON entries(user_id, status)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_enclosures_entry_id ON enclosures(entry_id)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_enclosures_media_type ON enclosures(media_type)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_sync_queue_status ON sync_queue(status)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_feeds_category_id ON feeds(category_id)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_feeds_user_id ON feeds(user_id)")
-        .execute(pool)
-        .await?;
-
-    sqlx::query("CREATE INDEX IF NOT EXISTS idx_categories_user_id ON categories(user_id)")
-        .execute(pool)
-        .await?;
-
-    log::info!("Initial schema migration applied (version 1)");
-
-    Ok(())
-}
-
-pub(crate) async fn apply_podcast_settings_migration(pool: &SqlitePool) -> Result<(), sqlx::Error> {
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_feed_id ON entries(feed_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_status ON entries(status)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_starred ON entries(starred)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_published_at ON entries(published_at DESC)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_user_status ON entries(user_id, status)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_enclosures_entry_id ON enclosures(entry_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_enclosures_media_type ON enclosures(media_type)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_sync_queue_status ON sync_queue(status)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_feeds_category_id ON feeds(category_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_feeds_user_id ON feeds(user_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_categories_user_id ON categories(user_id)"),
+        ],
+        down: None,
+    },
+    Migration {
+        version: 2,
+        name: "add_composite_index",
+        up: &[
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_feed_status ON entries(feed_id, status)"),
+        ],
+        down: Some(&[
+            Step::Sql("DROP INDEX IF EXISTS idx_entries_feed_status"),
+        ]),
+    },
+    Migration {
+        version: 3,
+        name: "add_entries_status_published_at_index",
+        up: &[
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_status_published_at ON entries(status, published_at DESC)"),
+        ],
+        down: Some(&[
+            Step::Sql("DROP INDEX IF EXISTS idx_entries_status_published_at"),
+        ]),
+    },
+    Migration {
+        version: 4,
+        name: "podcast_feed_settings_and_duration",
+        up: &[
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS podcast_feed_settings (
             feed_id INTEGER PRIMARY KEY,
             auto_download_count INTEGER DEFAULT 3,
@@ -432,22 +331,24 @@ pub(crate) async fn apply_podcast_settings_migration(pool: &SqlitePool) -> Resul
             updated_at TEXT NOT NULL
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query("ALTER TABLE enclosures ADD COLUMN duration_seconds INTEGER")
-        .execute(pool)
-        .await
-        .ok(); // OK if column already exists
-
-    log::info!("Podcast settings migration applied (version 4)");
-    Ok(())
-}
-
-pub(crate) async fn apply_local_cache_migration(pool: &SqlitePool) -> Result<(), sqlx::Error> {
-    sqlx::query(
-        r#"
+            ),
+            Step::AddColumn {
+                table: "enclosures",
+                column: "duration_seconds",
+                definition: "INTEGER",
+            },
+        ],
+        down: Some(&[
+            Step::Sql("ALTER TABLE enclosures DROP COLUMN duration_seconds"),
+            Step::Sql("DROP TABLE IF EXISTS podcast_feed_settings"),
+        ]),
+    },
+    Migration {
+        version: 5,
+        name: "article_summaries_and_translation_cache",
+        up: &[
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS article_summaries (
             entry_id TEXT NOT NULL PRIMARY KEY,
             summary TEXT NOT NULL,
@@ -457,12 +358,9 @@ pub(crate) async fn apply_local_cache_migration(pool: &SqlitePool) -> Result<(),
             updated_at TEXT NOT NULL
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql(
+                r#"
         CREATE TABLE IF NOT EXISTS translation_cache (
             cache_key TEXT NOT NULL PRIMARY KEY,
             translated_text TEXT NOT NULL,
@@ -470,121 +368,119 @@ pub(crate) async fn apply_local_cache_migration(pool: &SqlitePool) -> Result<(),
             cached_at INTEGER NOT NULL
         )
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    sqlx::query(
-        "CREATE INDEX IF NOT EXISTS idx_translation_cache_cached_at ON translation_cache(cached_at)",
-    )
-    .execute(pool)
-    .await?;
-
-    log::info!("Local cache migration applied (version 5)");
-    Ok(())
-}
-
-pub(crate) async fn apply_per_account_sync_state_migration(
-    pool: &SqlitePool,
-) -> Result<(), sqlx::Error> {
-    // Add account_id column (nullable for existing rows)
-    sqlx::query("ALTER TABLE sync_state ADD COLUMN account_id INTEGER")
-        .execute(pool)
-        .await
-        .ok(); // OK if column already exists
-
-    // Assign existing sync_state row to the active account
-    sqlx::query(
-        r#"
+            ),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_translation_cache_cached_at ON translation_cache(cached_at)"),
+        ],
+        down: Some(&[
+            Step::Sql("DROP TABLE IF EXISTS translation_cache"),
+            Step::Sql("DROP TABLE IF EXISTS article_summaries"),
+        ]),
+    },
+    Migration {
+        version: 6,
+        name: "per_account_sync_state",
+        up: &[
+            // account_id is nullable for rows created before accounts existed
+            Step::AddColumn {
+                table: "sync_state",
+                column: "account_id",
+                definition: "INTEGER",
+            },
+            // Assign the existing sync_state row to the active account
+            Step::Sql(
+                r#"
         UPDATE sync_state
         SET account_id = (SELECT id FROM miniflux_connections WHERE is_active = 1 LIMIT 1)
         WHERE account_id IS NULL
         "#,
-    )
-    .execute(pool)
-    .await?;
-
-    // Add completion stats columns
-    sqlx::query("ALTER TABLE sync_state ADD COLUMN categories_synced INTEGER DEFAULT 0")
-        .execute(pool)
-        .await
-        .ok();
-    sqlx::query("ALTER TABLE sync_state ADD COLUMN feeds_synced INTEGER DEFAULT 0")
-        .execute(pool)
-        .await
-        .ok();
-    sqlx::query("ALTER TABLE sync_state ADD COLUMN entries_synced INTEGER DEFAULT 0")
-        .execute(pool)
-        .await
-        .ok();
-
-    // Create unique index so each account has at most one sync_state row
-    sqlx::query(
-        "CREATE UNIQUE INDEX IF NOT EXISTS idx_sync_state_account_id ON sync_state(account_id)",
-    )
-    .execute(pool)
-    .await?;
-
-    log::info!("Per-account sync state migration applied (version 6)");
-    Ok(())
-}
-
-/// Ensures sync_state has completion stats columns.
-/// Needed for DBs that ran migration 6 before stats columns were added.
-pub(crate) async fn apply_sync_state_stats_migration(pool: &SqlitePool) -> Result<(), sqlx::Error> {
-    sqlx::query("ALTER TABLE sync_state ADD COLUMN categories_synced INTEGER DEFAULT 0")
-        .execute(pool)
-        .await
-        .ok(); // OK if column already exists
-    sqlx::query("ALTER TABLE sync_state ADD COLUMN feeds_synced INTEGER DEFAULT 0")
-        .execute(pool)
-        .await
-        .ok();
-    sqlx::query("ALTER TABLE sync_state ADD COLUMN entries_synced INTEGER DEFAULT 0")
-        .execute(pool)
-        .await
-        .ok();
-
-    log::info!("Sync state stats migration applied (version 7)");
-    Ok(())
-}
-
-pub(crate) async fn apply_downloads_media_type_migration(
-    pool: &SqlitePool,
-) -> Result<(), sqlx::Error> {
-    sqlx::query("ALTER TABLE downloads ADD COLUMN media_type TEXT")
-        .execute(pool)
-        .await
-        .ok(); // OK if column already exists
-
-    log::info!("Downloads media_type migration applied (version 8)");
-    Ok(())
-}
-
-pub(crate) async fn apply_account_is_admin_migration(pool: &SqlitePool) -> Result<(), sqlx::Error> {
-    sqlx::query("ALTER TABLE miniflux_connections ADD COLUMN is_admin INTEGER DEFAULT 0")
-        .execute(pool)
-        .await
-        .ok(); // OK if column already exists
-
-    log::info!("Account is_admin migration applied (version 9)");
-    Ok(())
-}
-
-pub(crate) async fn apply_account_miniflux_user_id_migration(
-    pool: &SqlitePool,
-) -> Result<(), sqlx::Error> {
-    sqlx::query("ALTER TABLE miniflux_connections ADD COLUMN miniflux_user_id INTEGER")
-        .execute(pool)
-        .await
-        .ok(); // OK if column already exists
-
-    // Backfill miniflux_user_id for accounts that have cached entries.
-    // Each account's entries are stored with the Miniflux user_id — use that
-    // to populate the column so offline mode works immediately without needing
-    // a fresh online connection.
-    sqlx::query(
-        r#"
+            ),
+            Step::AddColumn {
+                table: "sync_state",
+                column: "categories_synced",
+                definition: "INTEGER DEFAULT 0",
+            },
+            Step::AddColumn {
+                table: "sync_state",
+                column: "feeds_synced",
+                definition: "INTEGER DEFAULT 0",
+            },
+            Step::AddColumn {
+                table: "sync_state",
+                column: "entries_synced",
+                definition: "INTEGER DEFAULT 0",
+            },
+            // Each account has at most one sync_state row
+            Step::Sql("CREATE UNIQUE INDEX IF NOT EXISTS idx_sync_state_account_id ON sync_state(account_id)"),
+        ],
+        down: None,
+    },
+    Migration {
+        version: 7,
+        name: "sync_state_completion_stats",
+        up: &[
+            // Needed for databases that ran migration 6 before the stats columns were
+            // added to it
+            Step::AddColumn {
+                table: "sync_state",
+                column: "categories_synced",
+                definition: "INTEGER DEFAULT 0",
+            },
+            Step::AddColumn {
+                table: "sync_state",
+                column: "feeds_synced",
+                definition: "INTEGER DEFAULT 0",
+            },
+            Step::AddColumn {
+                table: "sync_state",
+                column: "entries_synced",
+                definition: "INTEGER DEFAULT 0",
+            },
+        ],
+        down: None,
+    },
+    Migration {
+        version: 8,
+        name: "downloads_media_type",
+        up: &[
+            Step::AddColumn {
+                table: "downloads",
+                column: "media_type",
+                definition: "TEXT",
+            },
+        ],
+        down: Some(&[
+            Step::Sql("ALTER TABLE downloads DROP COLUMN media_type"),
+        ]),
+    },
+    Migration {
+        version: 9,
+        name: "account_is_admin",
+        up: &[
+            Step::AddColumn {
+                table: "miniflux_connections",
+                column: "is_admin",
+                definition: "INTEGER DEFAULT 0",
+            },
+        ],
+        down: Some(&[
+            Step::Sql("ALTER TABLE miniflux_connections DROP COLUMN is_admin"),
+        ]),
+    },
+    Migration {
+        version: 10,
+        name: "account_miniflux_user_id",
+        up: &[
+            Step::AddColumn {
+                table: "miniflux_connections",
+                column: "miniflux_user_id",
+                definition: "INTEGER",
+            },
+            // Backfill miniflux_user_id for accounts that have cached entries.
+            // Each account's entries are stored with the Miniflux user_id — use that
+            // to populate the column so offline mode works immediately without needing
+            // a fresh online connection.
+            Step::Sql(
+                r#"
         UPDATE miniflux_connections
         SET miniflux_user_id = (
             SELECT user_id FROM entries WHERE user_id IS NOT NULL LIMIT 1
@@ -592,13 +488,1539 @@ pub(crate) async fn apply_account_miniflux_user_id_migration(
         WHERE miniflux_user_id IS NULL
           AND EXISTS (SELECT 1 FROM entries WHERE user_id IS NOT NULL LIMIT 1)
         "#,
-    )
-    .execute(pool)
-    .await
-    .ok();
-
-    log::info!("Account miniflux_user_id migration applied (version 10)");
-    Ok(())
+            ),
+        ],
+        down: Some(&[
+            Step::Sql("ALTER TABLE miniflux_connections DROP COLUMN miniflux_user_id"),
+        ]),
+    },
+    Migration {
+        version: 11,
+        name: "sync_conflicts",
+        up: &[
+            Step::Sql(
+                r#"
+        CREATE TABLE IF NOT EXISTS sync_conflicts (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            entry_id INTEGER NOT NULL,
+            field TEXT NOT NULL,
+            local_value TEXT NOT NULL,
+            server_value TEXT NOT NULL,
+            local_changed_at TEXT,
+            server_changed_at TEXT,
+            rule TEXT NOT NULL,
+            resolution TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        )
+        "#,
+            ),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_sync_conflicts_user_created ON sync_conflicts(user_id, created_at DESC)"),
+            // Replay and conflict checks look up pending operations per entry
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_sync_queue_entity ON sync_queue(entity_type, entity_id, status)"),
+        ],
+        down: Some(&[
+            Step::Sql("DROP INDEX IF EXISTS idx_sync_queue_entity"),
+            Step::Sql("DROP TABLE IF EXISTS sync_conflicts"),
+        ]),
+    },
+    Migration {
+        version: 12,
+        name: "entries_fts",
+        up: &[
+            Step::Sql(
+                r#"
+        CREATE VIRTUAL TABLE IF NOT EXISTS entries_fts USING fts5(
+            title,
+            author,
+            content,
+            feed,
+            tokenize = 'unicode61 remove_diacritics 2'
+        )
+        "#,
+            ),
+            // Index rows are written from Rust (content needs HTML stripping), but
+            // deletes happen in many places, so a trigger keeps them from going stale
+            Step::Sql(
+                r#"
+        CREATE TRIGGER IF NOT EXISTS entries_fts_after_delete
+        AFTER DELETE ON entries
+        BEGIN
+            DELETE FROM entries_fts WHERE rowid = old.id;
+        END
+        "#,
+            ),
+            Step::Data {
+                name: "backfill_entries_fts",
+                run: backfill_entries_fts,
+            },
+        ],
+        down: Some(&[
+            Step::Sql("DROP TRIGGER IF EXISTS entries_fts_after_delete"),
+            Step::Sql("DROP TABLE IF EXISTS entries_fts"),
+        ]),
+    },
+    Migration {
+        version: 13,
+        name: "smart_folders_and_tag_index",
+        up: &[
+            Step::Sql(
+                r#"
+        CREATE TABLE IF NOT EXISTS smart_folders (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            filters TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            UNIQUE(user_id, name)
+        )
+        "#,
+            ),
+            // Tag listing and tag filters look entries up by tag
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_tags_tag ON tags(tag, entry_id)"),
+        ],
+        down: Some(&[
+            Step::Sql("DROP INDEX IF EXISTS idx_tags_tag"),
+            Step::Sql("DROP TABLE IF EXISTS smart_folders"),
+        ]),
+    },
+    Migration {
+        version: 14,
+        name: "filter_rules",
+        up: &[
+            Step::Sql(
+                r#"
+        CREATE TABLE IF NOT EXISTS filter_rules (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            enabled INTEGER NOT NULL DEFAULT 1,
+            match_any INTEGER NOT NULL DEFAULT 0,
+            conditions TEXT NOT NULL,
+            actions TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+            ),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_filter_rules_user ON filter_rules(user_id)"),
+            // Id of the rule that hid the entry; NULL for visible entries
+            Step::AddColumn {
+                table: "entries",
+                column: "hidden_by_rule",
+                definition: "INTEGER",
+            },
+        ],
+        down: Some(&[
+            Step::Sql("ALTER TABLE entries DROP COLUMN hidden_by_rule"),
+            Step::Sql("DROP TABLE IF EXISTS filter_rules"),
+        ]),
+    },
+    Migration {
+        version: 15,
+        name: "retention_policies",
+        up: &[
+            // feed_id NULL is the account-wide default
+            Step::Sql(
+                r#"
+        CREATE TABLE IF NOT EXISTS retention_policies (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            feed_id INTEGER,
+            max_age_days INTEGER,
+            max_entries_per_feed INTEGER,
+            keep_unread INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+            ),
+            Step::Sql("CREATE UNIQUE INDEX IF NOT EXISTS idx_retention_policies_scope ON retention_policies(user_id, COALESCE(feed_id, 0))"),
+        ],
+        down: Some(&[
+            Step::Sql("DROP TABLE IF EXISTS retention_policies"),
+        ]),
+    },
+    Migration {
+        version: 16,
+        name: "enclosure_media_progression",
+        up: &[
+            Step::AddColumn {
+                table: "enclosures",
+                column: "media_progression",
+                definition: "INTEGER DEFAULT 0",
+            },
+            // Server-side progression as of the last push or pull, to tell
+            // which side moved since then
+            Step::AddColumn {
+                table: "podcast_progress",
+                column: "remote_time",
+                definition: "INTEGER",
+            },
+        ],
+        down: Some(&[
+            Step::Sql("ALTER TABLE podcast_progress DROP COLUMN remote_time"),
+            Step::Sql("ALTER TABLE enclosures DROP COLUMN media_progression"),
+        ]),
+    },
+    Migration {
+        version: 17,
+        name: "sync_checkpoint_started_at",
+        up: &[
+            // When the run owning the entries checkpoint began, so a resumed
+            // run still reports the original start as its sync time
+            Step::AddColumn {
+                table: "sync_state",
+                column: "sync_started_at",
+                definition: "TEXT",
+            },
+        ],
+        down: Some(&[Step::Sql(
+            "ALTER TABLE sync_state DROP COLUMN sync_started_at",
+        )]),
+    },
+    Migration {
+        version: 18,
+        name: "entry_account_id",
+        up: &[
+            // The miniflux_connections row an entry was synced from, so
+            // several accounts can be synced and listed side by side
+            Step::AddColumn {
+                table: "entries",
+                column: "account_id",
+                definition: "INTEGER",
+            },
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_account_id ON entries(account_id)"),
+            // Backfill from the Miniflux user ID where exactly one account has it
+            Step::Sql(
+                r#"
+        UPDATE entries
+        SET account_id = (
+            SELECT id FROM miniflux_connections
+            WHERE miniflux_user_id = entries.user_id
+        )
+        WHERE account_id IS NULL
+          AND (SELECT COUNT(*) FROM miniflux_connections WHERE miniflux_user_id = entries.user_id) = 1
+        "#,
+            ),
+        ],
+        down: Some(&[
+            Step::Sql("DROP INDEX IF EXISTS idx_entries_account_id"),
+            Step::Sql("ALTER TABLE entries DROP COLUMN account_id"),
+        ]),
+    },
+    Migration {
+        version: 19,
+        name: "account_scoped_cache",
+        up: &[
+            // Miniflux IDs are only unique per server, so cached rows are
+            // keyed by (account_id, id) instead of by the Miniflux ID alone
+            Step::Sql("DROP TRIGGER IF EXISTS entries_fts_after_delete"),
+            // Owner of rows cached before this migration: the account with that
+            // Miniflux user ID, else the active account
+            Step::Sql(
+                r#"
+        CREATE TEMP TABLE cache_owners AS
+        SELECT owners.user_id,
+               CASE
+                   WHEN (SELECT COUNT(*) FROM miniflux_connections WHERE miniflux_user_id = owners.user_id) = 1
+                   THEN (SELECT id FROM miniflux_connections WHERE miniflux_user_id = owners.user_id)
+                   ELSE (SELECT id FROM miniflux_connections ORDER BY is_active DESC, id LIMIT 1)
+               END AS account_id
+        FROM (
+            SELECT user_id FROM categories
+            UNION SELECT user_id FROM feeds
+            UNION SELECT user_id FROM entries
+        ) owners
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        UPDATE entries
+        SET account_id = (SELECT account_id FROM cache_owners WHERE cache_owners.user_id = entries.user_id)
+        WHERE account_id IS NULL
+        "#,
+            ),
+            // A feed or category shared by entries of several accounts is
+            // copied into each of them
+            Step::Sql(
+                r#"
+        CREATE TEMP TABLE feed_owners AS
+        SELECT o.account_id, f.id AS feed_id
+        FROM feeds f JOIN cache_owners o ON o.user_id = f.user_id
+        WHERE o.account_id IS NOT NULL
+        UNION
+        SELECT account_id, feed_id FROM entries WHERE account_id IS NOT NULL
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TEMP TABLE category_owners AS
+        SELECT o.account_id, c.id AS category_id
+        FROM categories c JOIN cache_owners o ON o.user_id = c.user_id
+        WHERE o.account_id IS NOT NULL
+        UNION
+        SELECT fo.account_id, f.category_id
+        FROM feed_owners fo JOIN feeds f ON f.id = fo.feed_id
+        WHERE f.category_id IS NOT NULL
+        "#,
+            ),
+            Step::Sql("ALTER TABLE podcast_progress RENAME TO podcast_progress_old"),
+            Step::Sql("ALTER TABLE tags RENAME TO tags_old"),
+            Step::Sql("ALTER TABLE enclosures RENAME TO enclosures_old"),
+            Step::Sql("ALTER TABLE entries RENAME TO entries_old"),
+            Step::Sql("ALTER TABLE icons RENAME TO icons_old"),
+            Step::Sql("ALTER TABLE feeds RENAME TO feeds_old"),
+            Step::Sql("ALTER TABLE categories RENAME TO categories_old"),
+            Step::Sql(
+                r#"
+        CREATE TABLE categories (
+            account_id INTEGER NOT NULL,
+            id INTEGER NOT NULL,
+            user_id INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            hide_globally BOOLEAN DEFAULT FALSE,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            sync_status TEXT DEFAULT 'synced',
+            PRIMARY KEY (account_id, id)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE feeds (
+            account_id INTEGER NOT NULL,
+            id INTEGER NOT NULL,
+            user_id INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            site_url TEXT NOT NULL,
+            feed_url TEXT NOT NULL,
+            category_id INTEGER,
+            checked_at TEXT,
+            etag_header TEXT,
+            last_modified_header TEXT,
+            parsing_error_message TEXT,
+            parsing_error_count INTEGER DEFAULT 0,
+            scraper_rules TEXT,
+            rewrite_rules TEXT,
+            crawler BOOLEAN DEFAULT FALSE,
+            blocklist_rules TEXT,
+            keeplist_rules TEXT,
+            user_agent TEXT,
+            username TEXT,
+            password TEXT,
+            disabled BOOLEAN DEFAULT FALSE,
+            ignore_http_cache BOOLEAN DEFAULT FALSE,
+            fetch_via_proxy BOOLEAN DEFAULT FALSE,
+            no_media_player BOOLEAN DEFAULT FALSE,
+            allow_self_signed_certificates BOOLEAN DEFAULT FALSE,
+            urlrewrite_rules TEXT,
+            cookie TEXT,
+            apprise_service_urls TEXT,
+            hide_globally BOOLEAN DEFAULT FALSE,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            sync_status TEXT DEFAULT 'synced',
+            PRIMARY KEY (account_id, id),
+            FOREIGN KEY (account_id, category_id) REFERENCES categories(account_id, id)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE icons (
+            id INTEGER PRIMARY KEY,
+            account_id INTEGER NOT NULL,
+            feed_id INTEGER NOT NULL,
+            icon_id INTEGER NOT NULL,
+            data TEXT NOT NULL,
+            mime_type TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            FOREIGN KEY (account_id, feed_id) REFERENCES feeds(account_id, id),
+            UNIQUE(account_id, feed_id)
+        )
+        "#,
+            ),
+            // local_id is the entries_fts rowid; declared as the rowid alias so
+            // VACUUM keeps it stable
+            Step::Sql(
+                r#"
+        CREATE TABLE entries (
+            local_id INTEGER PRIMARY KEY,
+            account_id INTEGER NOT NULL,
+            id INTEGER NOT NULL,
+            user_id INTEGER NOT NULL,
+            feed_id INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            url TEXT NOT NULL,
+            comments_url TEXT,
+            author TEXT,
+            content TEXT,
+            hash TEXT NOT NULL,
+            published_at TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            changed_at TEXT,
+            status TEXT NOT NULL,
+            share_code TEXT,
+            starred BOOLEAN DEFAULT FALSE,
+            reading_time INTEGER,
+            sync_status TEXT DEFAULT 'synced',
+            hidden_by_rule INTEGER,
+            FOREIGN KEY (account_id, feed_id) REFERENCES feeds(account_id, id),
+            UNIQUE(account_id, id)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE enclosures (
+            account_id INTEGER NOT NULL,
+            id INTEGER NOT NULL,
+            entry_id INTEGER NOT NULL,
+            url TEXT NOT NULL,
+            mime_type TEXT NOT NULL,
+            length INTEGER,
+            position INTEGER DEFAULT 0,
+            media_type TEXT,
+            downloaded BOOLEAN DEFAULT FALSE,
+            local_path TEXT,
+            download_progress INTEGER DEFAULT 0,
+            created_at TEXT NOT NULL,
+            duration_seconds INTEGER,
+            media_progression INTEGER DEFAULT 0,
+            PRIMARY KEY (account_id, id),
+            FOREIGN KEY (account_id, entry_id) REFERENCES entries(account_id, id) ON DELETE CASCADE
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE tags (
+            id INTEGER PRIMARY KEY,
+            account_id INTEGER NOT NULL,
+            entry_id INTEGER NOT NULL,
+            tag TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            FOREIGN KEY (account_id, entry_id) REFERENCES entries(account_id, id) ON DELETE CASCADE,
+            UNIQUE(account_id, entry_id, tag)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE podcast_progress (
+            id INTEGER PRIMARY KEY,
+            account_id INTEGER NOT NULL,
+            entry_id INTEGER NOT NULL,
+            "current_time" INTEGER NOT NULL,
+            total_time INTEGER NOT NULL,
+            completed BOOLEAN DEFAULT FALSE,
+            last_played_at TEXT NOT NULL,
+            remote_time INTEGER,
+            FOREIGN KEY (account_id, entry_id) REFERENCES entries(account_id, id) ON DELETE CASCADE,
+            UNIQUE(account_id, entry_id)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT INTO categories (account_id, id, user_id, title, hide_globally, created_at, updated_at, sync_status)
+        SELECT co.account_id, c.id, c.user_id, c.title, c.hide_globally, c.created_at, c.updated_at, c.sync_status
+        FROM category_owners co JOIN categories_old c ON c.id = co.category_id
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT INTO feeds (
+            account_id, id, user_id, title, site_url, feed_url, category_id, checked_at,
+            etag_header, last_modified_header, parsing_error_message, parsing_error_count,
+            scraper_rules, rewrite_rules, crawler, blocklist_rules, keeplist_rules, user_agent,
+            username, password, disabled, ignore_http_cache, fetch_via_proxy, no_media_player,
+            allow_self_signed_certificates, urlrewrite_rules, cookie, apprise_service_urls,
+            hide_globally, created_at, updated_at, sync_status
+        )
+        SELECT
+            fo.account_id, f.id, f.user_id, f.title, f.site_url, f.feed_url, f.category_id, f.checked_at,
+            f.etag_header, f.last_modified_header, f.parsing_error_message, f.parsing_error_count,
+            f.scraper_rules, f.rewrite_rules, f.crawler, f.blocklist_rules, f.keeplist_rules, f.user_agent,
+            f.username, f.password, f.disabled, f.ignore_http_cache, f.fetch_via_proxy, f.no_media_player,
+            f.allow_self_signed_certificates, f.urlrewrite_rules, f.cookie, f.apprise_service_urls,
+            f.hide_globally, f.created_at, f.updated_at, f.sync_status
+        FROM feed_owners fo JOIN feeds_old f ON f.id = fo.feed_id
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT INTO icons (account_id, feed_id, icon_id, data, mime_type, created_at)
+        SELECT fo.account_id, i.feed_id, i.icon_id, i.data, i.mime_type, i.created_at
+        FROM feed_owners fo JOIN icons_old i ON i.feed_id = fo.feed_id
+        "#,
+            ),
+            // The old entry ID becomes local_id, so the search index stays valid
+            Step::Sql(
+                r#"
+        INSERT INTO entries (
+            local_id, account_id, id, user_id, feed_id, title, url, comments_url, author,
+            content, hash, published_at, created_at, changed_at, status, share_code, starred,
+            reading_time, sync_status, hidden_by_rule
+        )
+        SELECT
+            id, account_id, id, user_id, feed_id, title, url, comments_url, author,
+            content, hash, published_at, created_at, changed_at, status, share_code, starred,
+            reading_time, sync_status, hidden_by_rule
+        FROM entries_old
+        WHERE account_id IS NOT NULL
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT INTO enclosures (
+            account_id, id, entry_id, url, mime_type, length, position, media_type,
+            downloaded, local_path, download_progress, created_at, duration_seconds,
+            media_progression
+        )
+        SELECT
+            e.account_id, en.id, en.entry_id, en.url, en.mime_type, en.length, en.position,
+            en.media_type, en.downloaded, en.local_path, en.download_progress, en.created_at,
+            en.duration_seconds, en.media_progression
+        FROM enclosures_old en JOIN entries_old e ON e.id = en.entry_id
+        WHERE e.account_id IS NOT NULL
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT INTO tags (id, account_id, entry_id, tag, created_at)
+        SELECT t.id, e.account_id, t.entry_id, t.tag, t.created_at
+        FROM tags_old t JOIN entries_old e ON e.id = t.entry_id
+        WHERE e.account_id IS NOT NULL
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT INTO podcast_progress (
+            id, account_id, entry_id, "current_time", total_time, completed, last_played_at, remote_time
+        )
+        SELECT p.id, e.account_id, p.entry_id, p."current_time", p.total_time, p.completed,
+               p.last_played_at, p.remote_time
+        FROM podcast_progress_old p JOIN entries_old e ON e.id = p.entry_id
+        WHERE e.account_id IS NOT NULL
+        "#,
+            ),
+            Step::Sql("DROP TABLE podcast_progress_old"),
+            Step::Sql("DROP TABLE tags_old"),
+            Step::Sql("DROP TABLE enclosures_old"),
+            Step::Sql("DROP TABLE entries_old"),
+            Step::Sql("DROP TABLE icons_old"),
+            Step::Sql("DROP TABLE feeds_old"),
+            Step::Sql("DROP TABLE categories_old"),
+            Step::Sql("DROP TABLE category_owners"),
+            Step::Sql("DROP TABLE feed_owners"),
+            Step::Sql("DROP TABLE cache_owners"),
+            // Entries that belonged to no account are gone
+            Step::Sql("DELETE FROM entries_fts WHERE rowid NOT IN (SELECT local_id FROM entries)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_categories_user_id ON categories(user_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_feeds_category_id ON feeds(account_id, category_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_feed_status ON entries(account_id, feed_id, status)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_account_status ON entries(account_id, status)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_starred ON entries(starred)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_published_at ON entries(published_at DESC)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_status_published_at ON entries(status, published_at DESC)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_enclosures_entry_id ON enclosures(account_id, entry_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_enclosures_media_type ON enclosures(media_type)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_tags_tag ON tags(tag, account_id, entry_id)"),
+            Step::Sql(
+                r#"
+        CREATE TRIGGER IF NOT EXISTS entries_fts_after_delete
+        AFTER DELETE ON entries
+        BEGIN
+            DELETE FROM entries_fts WHERE rowid = old.local_id;
+        END
+        "#,
+            ),
+            // Queued changes are pushed by the client of the account they
+            // were made in
+            Step::AddColumn {
+                table: "sync_queue",
+                column: "account_id",
+                definition: "INTEGER",
+            },
+            Step::Sql(
+                r#"
+        UPDATE sync_queue
+        SET account_id = COALESCE(
+            CASE entity_type
+                WHEN 'entry' THEN (
+                    SELECT account_id FROM entries WHERE id = sync_queue.entity_id
+                    ORDER BY account_id IS NOT (SELECT id FROM miniflux_connections WHERE is_active = 1), account_id
+                    LIMIT 1
+                )
+                WHEN 'enclosure' THEN (
+                    SELECT account_id FROM enclosures WHERE id = sync_queue.entity_id
+                    ORDER BY account_id IS NOT (SELECT id FROM miniflux_connections WHERE is_active = 1), account_id
+                    LIMIT 1
+                )
+            END,
+            (SELECT id FROM miniflux_connections WHERE is_active = 1)
+        )
+        WHERE account_id IS NULL
+        "#,
+            ),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_sync_queue_account ON sync_queue(account_id, status)"),
+            Step::AddColumn {
+                table: "sync_conflicts",
+                column: "account_id",
+                definition: "INTEGER",
+            },
+            Step::Sql(
+                r#"
+        UPDATE sync_conflicts
+        SET account_id = (
+            SELECT account_id FROM entries
+            WHERE entries.id = sync_conflicts.entry_id AND entries.user_id = sync_conflicts.user_id
+            ORDER BY account_id
+            LIMIT 1
+        )
+        WHERE account_id IS NULL
+        "#,
+            ),
+        ],
+        // Rows of other accounts whose IDs collide with the active account's
+        // are dropped on the way back
+        down: Some(&[
+            Step::Sql("ALTER TABLE sync_conflicts DROP COLUMN account_id"),
+            Step::Sql("DROP INDEX IF EXISTS idx_sync_queue_account"),
+            Step::Sql("ALTER TABLE sync_queue DROP COLUMN account_id"),
+            Step::Sql("DROP TRIGGER IF EXISTS entries_fts_after_delete"),
+            Step::Sql("ALTER TABLE podcast_progress RENAME TO podcast_progress_scoped"),
+            Step::Sql("ALTER TABLE tags RENAME TO tags_scoped"),
+            Step::Sql("ALTER TABLE enclosures RENAME TO enclosures_scoped"),
+            Step::Sql("ALTER TABLE entries RENAME TO entries_scoped"),
+            Step::Sql("ALTER TABLE icons RENAME TO icons_scoped"),
+            Step::Sql("ALTER TABLE feeds RENAME TO feeds_scoped"),
+            Step::Sql("ALTER TABLE categories RENAME TO categories_scoped"),
+            Step::Sql(
+                r#"
+        CREATE TABLE categories (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            hide_globally BOOLEAN DEFAULT FALSE,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            sync_status TEXT DEFAULT 'synced',
+            UNIQUE(id, user_id)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE feeds (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            site_url TEXT NOT NULL,
+            feed_url TEXT NOT NULL,
+            category_id INTEGER,
+            checked_at TEXT,
+            etag_header TEXT,
+            last_modified_header TEXT,
+            parsing_error_message TEXT,
+            parsing_error_count INTEGER DEFAULT 0,
+            scraper_rules TEXT,
+            rewrite_rules TEXT,
+            crawler BOOLEAN DEFAULT FALSE,
+            blocklist_rules TEXT,
+            keeplist_rules TEXT,
+            user_agent TEXT,
+            username TEXT,
+            password TEXT,
+            disabled BOOLEAN DEFAULT FALSE,
+            ignore_http_cache BOOLEAN DEFAULT FALSE,
+            fetch_via_proxy BOOLEAN DEFAULT FALSE,
+            no_media_player BOOLEAN DEFAULT FALSE,
+            allow_self_signed_certificates BOOLEAN DEFAULT FALSE,
+            urlrewrite_rules TEXT,
+            cookie TEXT,
+            apprise_service_urls TEXT,
+            hide_globally BOOLEAN DEFAULT FALSE,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            sync_status TEXT DEFAULT 'synced',
+            FOREIGN KEY (category_id) REFERENCES categories(id),
+            UNIQUE(id, user_id)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE icons (
+            id INTEGER PRIMARY KEY,
+            feed_id INTEGER NOT NULL,
+            icon_id INTEGER NOT NULL,
+            data TEXT NOT NULL,
+            mime_type TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            FOREIGN KEY (feed_id) REFERENCES feeds(id),
+            UNIQUE(feed_id)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE entries (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            feed_id INTEGER NOT NULL,
+            title TEXT NOT NULL,
+            url TEXT NOT NULL,
+            comments_url TEXT,
+            author TEXT,
+            content TEXT,
+            hash TEXT NOT NULL,
+            published_at TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            changed_at TEXT,
+            status TEXT NOT NULL,
+            share_code TEXT,
+            starred BOOLEAN DEFAULT FALSE,
+            reading_time INTEGER,
+            sync_status TEXT DEFAULT 'synced',
+            hidden_by_rule INTEGER,
+            account_id INTEGER,
+            FOREIGN KEY (feed_id) REFERENCES feeds(id),
+            UNIQUE(id, user_id)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE enclosures (
+            id INTEGER PRIMARY KEY,
+            entry_id INTEGER NOT NULL,
+            url TEXT NOT NULL,
+            mime_type TEXT NOT NULL,
+            length INTEGER,
+            position INTEGER DEFAULT 0,
+            media_type TEXT,
+            downloaded BOOLEAN DEFAULT FALSE,
+            local_path TEXT,
+            download_progress INTEGER DEFAULT 0,
+            created_at TEXT NOT NULL,
+            duration_seconds INTEGER,
+            media_progression INTEGER DEFAULT 0,
+            FOREIGN KEY (entry_id) REFERENCES entries(id) ON DELETE CASCADE
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE tags (
+            id INTEGER PRIMARY KEY,
+            entry_id INTEGER NOT NULL,
+            tag TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            FOREIGN KEY (entry_id) REFERENCES entries(id) ON DELETE CASCADE,
+            UNIQUE(entry_id, tag)
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        CREATE TABLE podcast_progress (
+            id INTEGER PRIMARY KEY,
+            entry_id INTEGER NOT NULL UNIQUE,
+            "current_time" INTEGER NOT NULL,
+            total_time INTEGER NOT NULL,
+            completed BOOLEAN DEFAULT FALSE,
+            last_played_at TEXT NOT NULL,
+            remote_time INTEGER,
+            FOREIGN KEY (entry_id) REFERENCES entries(id) ON DELETE CASCADE
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO categories (id, user_id, title, hide_globally, created_at, updated_at, sync_status)
+        SELECT id, user_id, title, hide_globally, created_at, updated_at, sync_status
+        FROM categories_scoped
+        ORDER BY account_id IS NOT (SELECT id FROM miniflux_connections WHERE is_active = 1), account_id
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO feeds (
+            id, user_id, title, site_url, feed_url, category_id, checked_at,
+            etag_header, last_modified_header, parsing_error_message, parsing_error_count,
+            scraper_rules, rewrite_rules, crawler, blocklist_rules, keeplist_rules, user_agent,
+            username, password, disabled, ignore_http_cache, fetch_via_proxy, no_media_player,
+            allow_self_signed_certificates, urlrewrite_rules, cookie, apprise_service_urls,
+            hide_globally, created_at, updated_at, sync_status
+        )
+        SELECT
+            id, user_id, title, site_url, feed_url, category_id, checked_at,
+            etag_header, last_modified_header, parsing_error_message, parsing_error_count,
+            scraper_rules, rewrite_rules, crawler, blocklist_rules, keeplist_rules, user_agent,
+            username, password, disabled, ignore_http_cache, fetch_via_proxy, no_media_player,
+            allow_self_signed_certificates, urlrewrite_rules, cookie, apprise_service_urls,
+            hide_globally, created_at, updated_at, sync_status
+        FROM feeds_scoped
+        ORDER BY account_id IS NOT (SELECT id FROM miniflux_connections WHERE is_active = 1), account_id
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO icons (feed_id, icon_id, data, mime_type, created_at)
+        SELECT feed_id, icon_id, data, mime_type, created_at
+        FROM icons_scoped
+        ORDER BY account_id IS NOT (SELECT id FROM miniflux_connections WHERE is_active = 1), account_id
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO entries (
+            id, user_id, feed_id, title, url, comments_url, author, content, hash,
+            published_at, created_at, changed_at, status, share_code, starred, reading_time,
+            sync_status, hidden_by_rule, account_id
+        )
+        SELECT
+            id, user_id, feed_id, title, url, comments_url, author, content, hash,
+            published_at, created_at, changed_at, status, share_code, starred, reading_time,
+            sync_status, hidden_by_rule, account_id
+        FROM entries_scoped
+        ORDER BY account_id IS NOT (SELECT id FROM miniflux_connections WHERE is_active = 1), account_id
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO enclosures (
+            id, entry_id, url, mime_type, length, position, media_type, downloaded,
+            local_path, download_progress, created_at, duration_seconds, media_progression
+        )
+        SELECT
+            en.id, en.entry_id, en.url, en.mime_type, en.length, en.position, en.media_type,
+            en.downloaded, en.local_path, en.download_progress, en.created_at,
+            en.duration_seconds, en.media_progression
+        FROM enclosures_scoped en
+        JOIN entries e ON e.id = en.entry_id AND e.account_id = en.account_id
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO tags (entry_id, tag, created_at)
+        SELECT t.entry_id, t.tag, t.created_at
+        FROM tags_scoped t
+        JOIN entries e ON e.id = t.entry_id AND e.account_id = t.account_id
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO podcast_progress (
+            entry_id, "current_time", total_time, completed, last_played_at, remote_time
+        )
+        SELECT p.entry_id, p."current_time", p.total_time, p.completed, p.last_played_at, p.remote_time
+        FROM podcast_progress_scoped p
+        JOIN entries e ON e.id = p.entry_id AND e.account_id = p.account_id
+        "#,
+            ),
+            Step::Sql("DROP TABLE podcast_progress_scoped"),
+            Step::Sql("DROP TABLE tags_scoped"),
+            Step::Sql("DROP TABLE enclosures_scoped"),
+            Step::Sql("DROP TABLE entries_scoped"),
+            Step::Sql("DROP TABLE icons_scoped"),
+            Step::Sql("DROP TABLE feeds_scoped"),
+            Step::Sql("DROP TABLE categories_scoped"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_feed_id ON entries(feed_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_status ON entries(status)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_starred ON entries(starred)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_published_at ON entries(published_at DESC)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_user_status ON entries(user_id, status)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_feed_status ON entries(feed_id, status)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_status_published_at ON entries(status, published_at DESC)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_entries_account_id ON entries(account_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_enclosures_entry_id ON enclosures(entry_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_enclosures_media_type ON enclosures(media_type)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_feeds_category_id ON feeds(category_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_feeds_user_id ON feeds(user_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_categories_user_id ON categories(user_id)"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_tags_tag ON tags(tag, entry_id)"),
+            Step::Sql(
+                r#"
+        CREATE TRIGGER IF NOT EXISTS entries_fts_after_delete
+        AFTER DELETE ON entries
+        BEGIN
+            DELETE FROM entries_fts WHERE rowid = old.id;
+        END
+        "#,
+            ),
+            // Index rows were keyed by local_id
+            Step::Sql("DELETE FROM entries_fts"),
+            Step::Data {
+                name: "backfill_entries_fts",
+                run: backfill_entries_fts,
+            },
+        ]),
+    },
+    Migration {
+        version: 20,
+        name: "sync_runs",
+        up: &[
+            // One row per account sync; error_chain is a JSON array of the
+            // failed attempts followed by the error that ended the run
+            Step::Sql(
+                r#"
+        CREATE TABLE IF NOT EXISTS sync_runs (
+            id INTEGER PRIMARY KEY,
+            account_id INTEGER,
+            sync_type TEXT NOT NULL,
+            status TEXT NOT NULL,
+            started_at TEXT NOT NULL,
+            finished_at TEXT,
+            entries_pulled INTEGER NOT NULL DEFAULT 0,
+            entries_pushed INTEGER NOT NULL DEFAULT 0,
+            entries_deleted INTEGER NOT NULL DEFAULT 0,
+            feeds_pulled INTEGER NOT NULL DEFAULT 0,
+            categories_pulled INTEGER NOT NULL DEFAULT 0,
+            bytes_transferred INTEGER NOT NULL DEFAULT 0,
+            pages INTEGER NOT NULL DEFAULT 0,
+            requests INTEGER NOT NULL DEFAULT 0,
+            failures INTEGER NOT NULL DEFAULT 0,
+            error_chain TEXT
+        )
+        "#,
+            ),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_sync_runs_account ON sync_runs(account_id, started_at)"),
+        ],
+        down: Some(&[
+            Step::Sql("DROP TABLE IF EXISTS sync_runs"),
+        ]),
+    },
+    Migration {
+        version: 21,
+        name: "entries_fts_feed_title",
+        up: &[
+            // The feed column holds a copy of the feed title; keep it current
+            // when a feed is renamed, however the rename reaches the cache
+            Step::Sql(
+                r#"
+        CREATE TRIGGER IF NOT EXISTS entries_fts_after_feed_rename
+        AFTER UPDATE OF title ON feeds
+        WHEN new.title IS NOT old.title
+        BEGIN
+            UPDATE entries_fts SET feed = new.title
+            WHERE rowid IN (
+                SELECT local_id FROM entries
+                WHERE account_id = new.account_id AND feed_id = new.id
+            );
+        END
+        "#,
+            ),
+        ],
+        down: Some(&[Step::Sql(
+            "DROP TRIGGER IF EXISTS entries_fts_after_feed_rename",
+        )]),
+    },
+    Migration {
+        version: 22,
+        name: "retention_policies_by_account",
+        up: &[
+            // A feed policy names a feed ID that is only unique per server, so
+            // policies belong to an account rather than a Miniflux user ID
+            Step::Sql("DROP INDEX IF EXISTS idx_retention_policies_scope"),
+            Step::Sql("ALTER TABLE retention_policies RENAME TO retention_policies_old"),
+            Step::Sql(
+                r#"
+        CREATE TABLE retention_policies (
+            id INTEGER PRIMARY KEY,
+            account_id INTEGER NOT NULL,
+            feed_id INTEGER,
+            max_age_days INTEGER,
+            max_entries_per_feed INTEGER,
+            keep_unread INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+            ),
+            // Owner: the account with that Miniflux user ID, else the active account
+            Step::Sql(
+                r#"
+        INSERT INTO retention_policies (
+            id, account_id, feed_id, max_age_days, max_entries_per_feed, keep_unread, created_at, updated_at
+        )
+        SELECT id, owner, feed_id, max_age_days, max_entries_per_feed, keep_unread, created_at, updated_at
+        FROM (
+            SELECT p.*,
+                   CASE
+                       WHEN (SELECT COUNT(*) FROM miniflux_connections WHERE miniflux_user_id = p.user_id) = 1
+                       THEN (SELECT id FROM miniflux_connections WHERE miniflux_user_id = p.user_id)
+                       ELSE (SELECT id FROM miniflux_connections ORDER BY is_active DESC, id LIMIT 1)
+                   END AS owner
+            FROM retention_policies_old p
+        )
+        WHERE owner IS NOT NULL
+        "#,
+            ),
+            Step::Sql("DROP TABLE retention_policies_old"),
+            Step::Sql("CREATE UNIQUE INDEX IF NOT EXISTS idx_retention_policies_scope ON retention_policies(account_id, COALESCE(feed_id, 0))"),
+        ],
+        down: Some(&[
+            Step::Sql("DROP INDEX IF EXISTS idx_retention_policies_scope"),
+            Step::Sql("ALTER TABLE retention_policies RENAME TO retention_policies_new"),
+            Step::Sql(
+                r#"
+        CREATE TABLE retention_policies (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            feed_id INTEGER,
+            max_age_days INTEGER,
+            max_entries_per_feed INTEGER,
+            keep_unread INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+            ),
+            Step::Sql("CREATE UNIQUE INDEX IF NOT EXISTS idx_retention_policies_scope ON retention_policies(user_id, COALESCE(feed_id, 0))"),
+            // Accounts sharing a Miniflux user ID collapse into one scope; the
+            // active account's policies win
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO retention_policies (
+            id, user_id, feed_id, max_age_days, max_entries_per_feed, keep_unread, created_at, updated_at
+        )
+        SELECT p.id, c.miniflux_user_id, p.feed_id, p.max_age_days, p.max_entries_per_feed,
+               p.keep_unread, p.created_at, p.updated_at
+        FROM retention_policies_new p
+        JOIN miniflux_connections c ON c.id = p.account_id
+        WHERE c.miniflux_user_id IS NOT NULL
+        ORDER BY c.is_active DESC, p.account_id
+        "#,
+            ),
+            Step::Sql("DROP TABLE retention_policies_new"),
+        ]),
+    },
+    Migration {
+        version: 23,
+        name: "smart_folders_by_account",
+        up: &[
+            // Folder filters name feed and category IDs that are only unique
+            // per server, so folders belong to an account
+            Step::Sql("ALTER TABLE smart_folders RENAME TO smart_folders_old"),
+            Step::Sql(
+                r#"
+        CREATE TABLE smart_folders (
+            id INTEGER PRIMARY KEY,
+            account_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            filters TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            UNIQUE(account_id, name)
+        )
+        "#,
+            ),
+            // Owner: the account with that Miniflux user ID, else the active account
+            Step::Sql(
+                r#"
+        INSERT INTO smart_folders (id, account_id, name, filters, created_at, updated_at)
+        SELECT id, owner, name, filters, created_at, updated_at
+        FROM (
+            SELECT f.*,
+                   CASE
+                       WHEN (SELECT COUNT(*) FROM miniflux_connections WHERE miniflux_user_id = f.user_id) = 1
+                       THEN (SELECT id FROM miniflux_connections WHERE miniflux_user_id = f.user_id)
+                       ELSE (SELECT id FROM miniflux_connections ORDER BY is_active DESC, id LIMIT 1)
+                   END AS owner
+            FROM smart_folders_old f
+        )
+        WHERE owner IS NOT NULL
+        "#,
+            ),
+            Step::Sql("DROP TABLE smart_folders_old"),
+        ],
+        down: Some(&[
+            Step::Sql("ALTER TABLE smart_folders RENAME TO smart_folders_new"),
+            Step::Sql(
+                r#"
+        CREATE TABLE smart_folders (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            filters TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            UNIQUE(user_id, name)
+        )
+        "#,
+            ),
+            // Accounts sharing a Miniflux user ID collapse into one scope; the
+            // active account's folders win
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO smart_folders (id, user_id, name, filters, created_at, updated_at)
+        SELECT f.id, c.miniflux_user_id, f.name, f.filters, f.created_at, f.updated_at
+        FROM smart_folders_new f
+        JOIN miniflux_connections c ON c.id = f.account_id
+        WHERE c.miniflux_user_id IS NOT NULL
+        ORDER BY c.is_active DESC, f.account_id
+        "#,
+            ),
+            Step::Sql("DROP TABLE smart_folders_new"),
+        ]),
+    },
+    Migration {
+        version: 24,
+        name: "filter_rules_by_account",
+        up: &[
+            // Rules run on one account's entries during its sync, so they
+            // belong to that account rather than a Miniflux user ID
+            Step::Sql("DROP INDEX IF EXISTS idx_filter_rules_user"),
+            Step::Sql("ALTER TABLE filter_rules RENAME TO filter_rules_old"),
+            Step::Sql(
+                r#"
+        CREATE TABLE filter_rules (
+            id INTEGER PRIMARY KEY,
+            account_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            enabled INTEGER NOT NULL DEFAULT 1,
+            match_any INTEGER NOT NULL DEFAULT 0,
+            conditions TEXT NOT NULL,
+            actions TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+            ),
+            // Owner: the account with that Miniflux user ID, else the active account
+            Step::Sql(
+                r#"
+        INSERT INTO filter_rules (
+            id, account_id, name, enabled, match_any, conditions, actions, created_at, updated_at
+        )
+        SELECT id, owner, name, enabled, match_any, conditions, actions, created_at, updated_at
+        FROM (
+            SELECT r.*,
+                   CASE
+                       WHEN (SELECT COUNT(*) FROM miniflux_connections WHERE miniflux_user_id = r.user_id) = 1
+                       THEN (SELECT id FROM miniflux_connections WHERE miniflux_user_id = r.user_id)
+                       ELSE (SELECT id FROM miniflux_connections ORDER BY is_active DESC, id LIMIT 1)
+                   END AS owner
+            FROM filter_rules_old r
+        )
+        WHERE owner IS NOT NULL
+        "#,
+            ),
+            Step::Sql("DROP TABLE filter_rules_old"),
+            // Entries hidden by a rule that had no account are shown again
+            Step::Sql(
+                r#"
+        UPDATE entries SET hidden_by_rule = NULL
+        WHERE hidden_by_rule IS NOT NULL
+          AND hidden_by_rule NOT IN (SELECT id FROM filter_rules WHERE account_id = entries.account_id)
+        "#,
+            ),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_filter_rules_account ON filter_rules(account_id)"),
+        ],
+        down: Some(&[
+            Step::Sql("DROP INDEX IF EXISTS idx_filter_rules_account"),
+            Step::Sql("ALTER TABLE filter_rules RENAME TO filter_rules_new"),
+            Step::Sql(
+                r#"
+        CREATE TABLE filter_rules (
+            id INTEGER PRIMARY KEY,
+            user_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            enabled INTEGER NOT NULL DEFAULT 1,
+            match_any INTEGER NOT NULL DEFAULT 0,
+            conditions TEXT NOT NULL,
+            actions TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+            ),
+            Step::Sql(
+                r#"
+        INSERT INTO filter_rules (
+            id, user_id, name, enabled, match_any, conditions, actions, created_at, updated_at
+        )
+        SELECT r.id, c.miniflux_user_id, r.name, r.enabled, r.match_any, r.conditions, r.actions,
+               r.created_at, r.updated_at
+        FROM filter_rules_new r
+        JOIN miniflux_connections c ON c.id = r.account_id
+        WHERE c.miniflux_user_id IS NOT NULL
+        "#,
+            ),
+            Step::Sql("DROP TABLE filter_rules_new"),
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_filter_rules_user ON filter_rules(user_id)"),
+        ]),
+    },
+    Migration {
+        version: 25,
+        name: "article_summaries_by_account",
+        up: &[
+            // Entry IDs are only unique per server
+            Step::Sql("ALTER TABLE article_summaries RENAME TO article_summaries_old"),
+            Step::Sql(
+                r#"
+        CREATE TABLE article_summaries (
+            account_id INTEGER NOT NULL,
+            entry_id TEXT NOT NULL,
+            summary TEXT NOT NULL,
+            provider_used TEXT,
+            model_used TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            PRIMARY KEY (account_id, entry_id)
+        )
+        "#,
+            ),
+            // Owner: the active account if it has the entry, else the first
+            // account that does, else the active account
+            Step::Sql(
+                r#"
+        INSERT INTO article_summaries (
+            account_id, entry_id, summary, provider_used, model_used, created_at, updated_at
+        )
+        SELECT owner, entry_id, summary, provider_used, model_used, created_at, updated_at
+        FROM (
+            SELECT s.*,
+                   COALESCE(
+                       (
+                           SELECT account_id FROM entries WHERE id = CAST(s.entry_id AS INTEGER)
+                           ORDER BY account_id IS NOT (SELECT id FROM miniflux_connections WHERE is_active = 1), account_id
+                           LIMIT 1
+                       ),
+                       (SELECT id FROM miniflux_connections ORDER BY is_active DESC, id LIMIT 1)
+                   ) AS owner
+            FROM article_summaries_old s
+        )
+        WHERE owner IS NOT NULL
+        "#,
+            ),
+            Step::Sql("DROP TABLE article_summaries_old"),
+        ],
+        down: Some(&[
+            Step::Sql("ALTER TABLE article_summaries RENAME TO article_summaries_new"),
+            Step::Sql(
+                r#"
+        CREATE TABLE article_summaries (
+            entry_id TEXT NOT NULL PRIMARY KEY,
+            summary TEXT NOT NULL,
+            provider_used TEXT,
+            model_used TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+            ),
+            // Summaries of the same entry ID on several accounts collapse into
+            // one; the active account's wins
+            Step::Sql(
+                r#"
+        INSERT OR IGNORE INTO article_summaries (
+            entry_id, summary, provider_used, model_used, created_at, updated_at
+        )
+        SELECT s.entry_id, s.summary, s.provider_used, s.model_used, s.created_at, s.updated_at
+        FROM article_summaries_new s
+        LEFT JOIN miniflux_connections c ON c.id = s.account_id
+        ORDER BY COALESCE(c.is_active, 0) DESC, s.account_id
+        "#,
+            ),
+            Step::Sql("DROP TABLE article_summaries_new"),
+        ]),
+    },
+];
+
+/// Highest schema version this build knows about.
+pub(crate) fn latest_version() -> i32 {
+    MIGRATIONS.last().map_or(0, |migration| migration.version)
+}
+
+pub async fn run_migrations(pool: &SqlitePool) -> Result<(), MigrationError> {
+    create_schema_versions_table(pool).await?;
+
+    let applied = get_applied_migrations(pool).await?;
+    ensure_schema_not_newer(&applied)?;
+
+    for migration in MIGRATIONS {
+        match applied.iter().find(|row| row.version == migration.version) {
+            Some(row) => verify_checksum(pool, migration, row).await?,
+            None => apply_migration(pool, migration).await?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Reverts applied migrations newer than `target_version`, newest first.
+/// Fails before touching anything if one of them has no down steps.
+pub async fn rollback_to(pool: &SqlitePool, target_version: i32) -> Result<(), MigrationError> {
+    create_schema_versions_table(pool).await?;
+
+    let applied = get_applied_migrations(pool).await?;
+    ensure_schema_not_newer(&applied)?;
+
+    let to_revert: Vec<&Migration> = MIGRATIONS
+        .iter()
+        .rev()
+        .filter(|migration| migration.version > target_version)
+        .filter(|migration| applied.iter().any(|row| row.version == migration.version))
+        .collect();
+
+    if let Some(migration) = to_revert.iter().find(|migration| migration.down.is_none()) {
+        return Err(MigrationError::Irreversible {
+            version: migration.version,
+            name: migration.name,
+        });
+    }
+
+    for migration in to_revert {
+        let steps = migration.down.unwrap_or_default();
+        let failed = |source| MigrationError::Failed {
+            version: migration.version,
+            name: migration.name,
+            source,
+        };
+
+        let mut transaction = pool.begin().await?;
+        for step in steps {
+            run_step(&mut transaction, step).await.map_err(failed)?;
+        }
+        sqlx::query("DELETE FROM schema_versions WHERE version = ?")
+            .bind(migration.version)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+
+        log::info!(
+            "Migration {} ({}) reverted",
+            migration.version,
+            migration.name
+        );
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn create_schema_versions_table(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_versions (
+            version INTEGER PRIMARY KEY,
+            migration_name TEXT UNIQUE NOT NULL,
+            applied_at TEXT NOT NULL,
+            checksum TEXT,
+            app_version TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Tables created before checksums were tracked
+    let mut conn = pool.acquire().await?;
+    add_column_if_missing(&mut conn, "schema_versions", "checksum", "TEXT").await?;
+    add_column_if_missing(&mut conn, "schema_versions", "app_version", "TEXT").await?;
+
+    Ok(())
+}
+
+async fn get_applied_migrations(pool: &SqlitePool) -> Result<Vec<AppliedMigration>, sqlx::Error> {
+    sqlx::query_as("SELECT version, checksum, app_version FROM schema_versions ORDER BY version")
+        .fetch_all(pool)
+        .await
+}
+
+fn ensure_schema_not_newer(applied: &[AppliedMigration]) -> Result<(), MigrationError> {
+    let supported = latest_version();
+    match applied.iter().max_by_key(|row| row.version) {
+        Some(newest) if newest.version > supported => Err(MigrationError::SchemaTooNew {
+            found: newest.version,
+            supported,
+            app_version: newest
+                .app_version
+                .clone()
+                .unwrap_or_else(|| "unknown".to_string()),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Records checksums for migrations applied before they were tracked and
+/// refuses migrations whose steps changed after being applied.
+async fn verify_checksum(
+    pool: &SqlitePool,
+    migration: &Migration,
+    applied: &AppliedMigration,
+) -> Result<(), MigrationError> {
+    let checksum = migration_checksum(migration);
+
+    match applied.checksum.as_deref() {
+        None => {
+            sqlx::query("UPDATE schema_versions SET checksum = ? WHERE version = ?")
+                .bind(&checksum)
+                .bind(migration.version)
+                .execute(pool)
+                .await?;
+        }
+        Some(recorded) if recorded != checksum => {
+            return Err(MigrationError::ChecksumMismatch {
+                version: migration.version,
+                name: migration.name,
+                recorded: recorded.to_string(),
+                current: checksum,
+            });
+        }
+        Some(_) => {}
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn apply_migration(
+    pool: &SqlitePool,
+    migration: &Migration,
+) -> Result<(), MigrationError> {
+    let failed = |source| MigrationError::Failed {
+        version: migration.version,
+        name: migration.name,
+        source,
+    };
+
+    let mut transaction = pool.begin().await?;
+
+    for step in migration.up {
+        run_step(&mut transaction, step).await.map_err(failed)?;
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO schema_versions (version, migration_name, applied_at, checksum, app_version)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(migration.version)
+    .bind(migration.name)
+    .bind(Utc::now().to_rfc3339())
+    .bind(migration_checksum(migration))
+    .bind(env!("CARGO_PKG_VERSION"))
+    .execute(&mut *transaction)
+    .await
+    .map_err(failed)?;
+
+    transaction.commit().await?;
+
+    log::info!(
+        "Migration {} ({}) applied",
+        migration.version,
+        migration.name
+    );
+    Ok(())
+}
+
+async fn run_step(conn: &mut SqliteConnection, step: &Step) -> Result<(), sqlx::Error> {
+    match step {
+        Step::Sql(sql) => {
+            sqlx::query(sql).execute(&mut *conn).await?;
+        }
+        Step::AddColumn {
+            table,
+            column,
+            definition,
+        } => {
+            add_column_if_missing(conn, table, column, definition).await?;
+        }
+        Step::Data { run, .. } => run(conn).await?,
+    }
+
+    Ok(())
+}
+
+async fn add_column_if_missing(
+    conn: &mut SqliteConnection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), sqlx::Error> {
+    let exists: bool =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM pragma_table_info(?) WHERE name = ?)")
+            .bind(table)
+            .bind(column)
+            .fetch_one(&mut *conn)
+            .await?;
+
+    if !exists {
+        sqlx::query(&format!(
+            "ALTER TABLE {table} ADD COLUMN {column} {definition}"
+        ))
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// FNV-1a over the migration's up steps with whitespace collapsed, so
+/// reformatting SQL doesn't count as a change.
+pub(crate) fn migration_checksum(migration: &Migration) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for step in migration.up {
+        let text = match step {
+            Step::Sql(sql) => sql.split_whitespace().collect::<Vec<_>>().join(" "),
+            Step::AddColumn {
+                table,
+                column,
+                definition,
+            } => format!("ADD COLUMN {table}.{column} {definition}"),
+            Step::Data { name, .. } => format!("DATA {name}"),
+        };
+
+        for byte in text.bytes().chain(std::iter::once(b'\n')) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    format!("{hash:016x}")
+}
+
+/// Indexes entries cached before the FTS table existed (version 12), and
+/// re-indexes them when version 21 is reverted. Reads the schema before
+/// version 21, where the entry ID is the index rowid.
+fn backfill_entries_fts(conn: &mut SqliteConnection) -> BoxFuture<'_, Result<(), sqlx::Error>> {
+    Box::pin(async move {
+        let mut last_id = i64::MIN;
+        loop {
+            let rows: Vec<(i64, String, Option<String>, Option<String>, Option<String>)> =
+                sqlx::query_as(
+                    "SELECT e.id, e.title, e.author, e.content, f.title FROM entries e LEFT JOIN feeds f ON f.id = e.feed_id WHERE e.id > ? ORDER BY e.id LIMIT 500",
+                )
+                .bind(last_id)
+                .fetch_all(&mut *conn)
+                .await?;
+            let Some(&(max_id, ..)) = rows.last() else {
+                break;
+            };
+            for (id, title, author, content, feed_title) in &rows {
+                super::fts::write_index_row(
+                    conn,
+                    *id,
+                    title,
+                    author.as_deref(),
+                    content.as_deref(),
+                    feed_title.as_deref(),
+                )
+                .await?;
+            }
+            last_id = max_id;
+        }
+
+        Ok(())
+    })
 }
 
 #[cfg(test)]
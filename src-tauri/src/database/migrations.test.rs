@@ -2,7 +2,10 @@
 mod tests {
     use sqlx::sqlite::SqlitePool;
 
-    use crate::database::migrations::run_migrations;
+    use crate::database::migrations::{
+        apply_migration, create_schema_versions_table, latest_version, migration_checksum,
+        rollback_to, run_migrations, MigrationError, MIGRATIONS,
+    };
 
     #[tokio::test]
     async fn test_schema_versions_table_creation() {
@@ -32,7 +35,7 @@ mod tests {
         .await
         .unwrap();
 
-        assert_eq!(columns.len(), 3, "Should have 3 columns");
+        assert_eq!(columns.len(), 5, "Should have 5 columns");
         assert_eq!(columns[0].0, "version", "First column should be version");
         assert_eq!(columns[0].1, "INTEGER", "version should be INTEGER type");
         assert_eq!(
@@ -45,6 +48,11 @@ mod tests {
             "Third column should be applied_at"
         );
         assert_eq!(columns[2].1, "TEXT", "applied_at should be TEXT type");
+        assert_eq!(columns[3].0, "checksum", "Fourth column should be checksum");
+        assert_eq!(
+            columns[4].0, "app_version",
+            "Fifth column should be app_version"
+        );
     }
 
     #[tokio::test]
@@ -69,10 +77,10 @@ mod tests {
             .await
             .unwrap();
 
-        // Should have exactly 10 migrations
+        // Should have exactly 25 migrations
         assert_eq!(
-            count, 10,
-            "Should have exactly 10 migration entries after running twice"
+            count, 25,
+            "Should have exactly 25 migration entries after running twice"
         );
     }
 
@@ -194,4 +202,278 @@ mod tests {
             "Migration 1 should be tracked"
         );
     }
+
+    #[tokio::test]
+    async fn test_migrations_record_checksum_and_app_version() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        let rows: Vec<(i32, Option<String>, Option<String>)> = sqlx::query_as(
+            "SELECT version, checksum, app_version FROM schema_versions ORDER BY version",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(rows.len(), MIGRATIONS.len());
+        for ((version, checksum, app_version), migration) in rows.iter().zip(MIGRATIONS) {
+            assert_eq!(*version, migration.version);
+            assert_eq!(
+                checksum.as_deref(),
+                Some(migration_checksum(migration).as_str())
+            );
+            assert_eq!(app_version.as_deref(), Some(env!("CARGO_PKG_VERSION")));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_missing_checksums_are_backfilled() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        // Simulate a database migrated before checksums were tracked
+        sqlx::query("UPDATE schema_versions SET checksum = NULL, app_version = NULL")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        run_migrations(&pool).await.unwrap();
+
+        let missing: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM schema_versions WHERE checksum IS NULL")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(missing, 0);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_migration_edited_after_it_was_applied() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        sqlx::query("UPDATE schema_versions SET checksum = 'edited' WHERE version = 3")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        match run_migrations(&pool).await {
+            Err(MigrationError::ChecksumMismatch {
+                version, recorded, ..
+            }) => {
+                assert_eq!(version, 3);
+                assert_eq!(recorded, "edited");
+            }
+            other => panic!("Expected ChecksumMismatch, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refuses_schema_from_newer_app_version() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        sqlx::query(
+            "INSERT INTO schema_versions (version, migration_name, applied_at, app_version) VALUES (?, 'from_the_future', '2030-01-01T00:00:00Z', '99.0.0')",
+        )
+        .bind(latest_version() + 1)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let result = run_migrations(&pool).await;
+
+        match result {
+            Err(MigrationError::SchemaTooNew {
+                found,
+                supported,
+                app_version,
+            }) => {
+                assert_eq!(found, latest_version() + 1);
+                assert_eq!(supported, latest_version());
+                assert_eq!(app_version, "99.0.0");
+            }
+            other => panic!("Expected SchemaTooNew, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_is_split_by_account() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        create_schema_versions_table(&pool).await.unwrap();
+        for migration in MIGRATIONS.iter().filter(|m| m.version <= 18) {
+            apply_migration(&pool, migration).await.unwrap();
+        }
+
+        for statement in [
+            "INSERT INTO miniflux_connections (id, username, server_url, auth_method, is_active, created_at, updated_at, miniflux_user_id) VALUES (1, 'a', 'https://a.example', 'token', 1, 'now', 'now', 7), (2, 'b', 'https://b.example', 'token', 0, 'now', 'now', 9)",
+            "INSERT INTO categories (id, user_id, title, created_at, updated_at) VALUES (1, 7, 'News', 'now', 'now')",
+            "INSERT INTO feeds (id, user_id, title, site_url, feed_url, category_id, created_at, updated_at) VALUES (10, 7, 'Feed', 'https://a.example', 'https://a.example/feed', 1, 'now', 'now')",
+            // Account 2's entry points at the feed account 1 overwrote
+            "INSERT INTO entries (id, user_id, feed_id, title, url, hash, published_at, created_at, status, account_id) VALUES (100, 7, 10, 'From a', 'https://a.example/1', 'h1', 'now', 'now', 'unread', NULL), (101, 9, 10, 'From b', 'https://b.example/1', 'h2', 'now', 'now', 'unread', 2)",
+            "INSERT INTO enclosures (id, entry_id, url, mime_type, created_at) VALUES (500, 101, 'https://b.example/1.mp3', 'audio/mpeg', 'now')",
+            "INSERT INTO entries_fts (rowid, title, author, content, feed) VALUES (100, 'From a', '', '', 'Feed'), (101, 'From b', '', '', 'Feed')",
+        ] {
+            sqlx::query(statement).execute(&pool).await.unwrap();
+        }
+
+        run_migrations(&pool).await.unwrap();
+
+        let feeds: Vec<(i64, i64, Option<i64>)> =
+            sqlx::query_as("SELECT account_id, id, category_id FROM feeds ORDER BY account_id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(feeds, vec![(1, 10, Some(1)), (2, 10, Some(1))]);
+        let categories: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM categories")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(categories, 2);
+
+        let entries: Vec<(i64, i64, i64)> =
+            sqlx::query_as("SELECT local_id, account_id, id FROM entries ORDER BY local_id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(entries, vec![(100, 1, 100), (101, 2, 101)]);
+        let enclosure_account: i64 =
+            sqlx::query_scalar("SELECT account_id FROM enclosures WHERE id = 500")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(enclosure_account, 2);
+
+        // The same Miniflux IDs can now be cached for another account
+        sqlx::query("INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (2, 100, 9, 10, 'Also b', 'https://b.example/2', 'h3', 'now', 'now', 'unread')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM entries WHERE account_id = 1 AND id = 100")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let indexed: Vec<i64> = sqlx::query_scalar("SELECT rowid FROM entries_fts ORDER BY rowid")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(indexed, vec![101]);
+    }
+
+    #[tokio::test]
+    async fn test_user_settings_move_to_account() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        create_schema_versions_table(&pool).await.unwrap();
+        for migration in MIGRATIONS.iter().filter(|m| m.version <= 21) {
+            apply_migration(&pool, migration).await.unwrap();
+        }
+
+        for statement in [
+            // Accounts 1 and 2 are both user 7 on different servers
+            "INSERT INTO miniflux_connections (id, username, server_url, auth_method, is_active, created_at, updated_at, miniflux_user_id) VALUES (1, 'a', 'https://a.example', 'token', 0, 'now', 'now', 7), (2, 'b', 'https://b.example', 'token', 1, 'now', 'now', 7), (3, 'c', 'https://c.example', 'token', 0, 'now', 'now', 9)",
+            "INSERT INTO retention_policies (user_id, feed_id, max_age_days, keep_unread, created_at, updated_at) VALUES (7, 10, 30, 1, 'now', 'now'), (9, NULL, 60, 1, 'now', 'now')",
+            "INSERT INTO smart_folders (user_id, name, filters, created_at, updated_at) VALUES (7, 'Feed 10', '{\"feed_id\":\"10\"}', 'now', 'now'), (9, 'Feed 10', '{}', 'now', 'now')",
+            "INSERT INTO filter_rules (id, user_id, name, conditions, actions, created_at, updated_at) VALUES (1, 9, 'Hide', '[]', '[]', 'now', 'now')",
+            "INSERT INTO feeds (account_id, id, user_id, title, site_url, feed_url, created_at, updated_at) VALUES (2, 10, 7, 'Feed', 'https://b.example', 'https://b.example/feed', 'now', 'now'), (3, 10, 9, 'Feed', 'https://c.example', 'https://c.example/feed', 'now', 'now')",
+            // Entry 100 is cached for accounts 2 and 3; the active account owns its summary
+            "INSERT INTO entries (account_id, id, user_id, feed_id, title, url, hash, published_at, created_at, status) VALUES (2, 100, 7, 10, 'B', 'https://b.example/1', 'h1', 'now', 'now', 'unread'), (3, 100, 9, 10, 'C', 'https://c.example/1', 'h2', 'now', 'now', 'unread'), (3, 101, 9, 10, 'C', 'https://c.example/2', 'h3', 'now', 'now', 'unread')",
+            "INSERT INTO article_summaries (entry_id, summary, created_at, updated_at) VALUES ('100', 'A', 'now', 'now'), ('101', 'C', 'now', 'now')",
+        ] {
+            sqlx::query(statement).execute(&pool).await.unwrap();
+        }
+
+        run_migrations(&pool).await.unwrap();
+
+        let policies: Vec<(i64, Option<i64>)> = sqlx::query_as(
+            "SELECT account_id, feed_id FROM retention_policies ORDER BY account_id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(policies, vec![(2, Some(10)), (3, None)]);
+
+        let folders: Vec<(i64, String)> =
+            sqlx::query_as("SELECT account_id, name FROM smart_folders ORDER BY account_id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            folders,
+            vec![(2, "Feed 10".to_string()), (3, "Feed 10".to_string())]
+        );
+
+        let rules: Vec<(i64, i64)> = sqlx::query_as("SELECT id, account_id FROM filter_rules")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(rules, vec![(1, 3)]);
+
+        let summaries: Vec<(i64, String)> =
+            sqlx::query_as("SELECT account_id, entry_id FROM article_summaries ORDER BY entry_id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            summaries,
+            vec![(2, "100".to_string()), (3, "101".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rollback_reverts_and_reapplies() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        rollback_to(&pool, 12).await.unwrap();
+
+        let tables: Vec<String> =
+            sqlx::query_scalar("SELECT name FROM sqlite_master WHERE type='table'")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert!(!tables.contains(&"filter_rules".to_string()));
+        assert!(!tables.contains(&"smart_folders".to_string()));
+
+        let hidden_by_rule: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM pragma_table_info('entries') WHERE name = 'hidden_by_rule')",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert!(!hidden_by_rule);
+
+        let newest: i32 = sqlx::query_scalar("SELECT MAX(version) FROM schema_versions")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(newest, 12);
+
+        run_migrations(&pool).await.unwrap();
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM schema_versions")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count as usize, MIGRATIONS.len());
+    }
+
+    #[tokio::test]
+    async fn test_rollback_past_irreversible_migration_fails() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        let result = rollback_to(&pool, 5).await;
+
+        assert!(matches!(
+            result,
+            Err(MigrationError::Irreversible { version: 7, .. })
+        ));
+
+        // Nothing is reverted when any step in the range is irreversible
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM schema_versions")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count as usize, MIGRATIONS.len());
+    }
 }
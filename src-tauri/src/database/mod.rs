@@ -1,4 +1,5 @@
 pub mod connection;
+pub mod fts;
 pub mod migrations;
 
-pub use connection::init_database_pool;
+pub use connection::{init_database_pool, requested_rollback, rollback_database};
@@ -10,6 +10,7 @@ mod cloud_sync;
 mod commands;
 mod database;
 mod miniflux;
+mod sync_scheduler;
 mod types;
 mod utils;
 
@@ -30,6 +31,7 @@ pub struct AppState {
     pub db_pool: Arc<Mutex<Option<sqlx::SqlitePool>>>,
     pub miniflux: MinifluxState,
     pub cloud_sync_notify: Arc<tokio::sync::Notify>,
+    pub sync_scheduler: sync_scheduler::SyncSchedulerState,
 }
 
 /// Application entry point. Sets up all plugins and initializes the app.
@@ -232,6 +234,7 @@ pub fn run() {
                 user_id: std::sync::Arc::new(tokio::sync::Mutex::new(None)),
             },
             cloud_sync_notify: Arc::new(tokio::sync::Notify::new()),
+            sync_scheduler: sync_scheduler::SyncSchedulerState::default(),
         })
         .setup(|app| {
             log::info!("Application starting up");
@@ -244,6 +247,19 @@ pub fn run() {
             let app_handle = app.handle().clone();
             let handle_clone = app.handle().clone();
             tauri::async_runtime::spawn(async move {
+                // Downgrade helper: revert the schema and quit without opening the app
+                if let Some(target) = database::requested_rollback() {
+                    let exit_code = match database::rollback_database(&app_handle, target).await {
+                        Ok(()) => 0,
+                        Err(e) => {
+                            log::error!("Failed to roll back database to version {target}: {e}");
+                            1
+                        }
+                    };
+                    app_handle.exit(exit_code);
+                    return;
+                }
+
                 match database::init_database_pool(&app_handle).await {
                     Ok(pool) => {
                         log::info!("Database initialized successfully");
@@ -363,6 +379,20 @@ pub fn run() {
                 );
             }
 
+            // Pull when another device's changes arrive through a sync folder
+            {
+                let app_handle = app.handle().clone();
+                tauri::async_runtime::spawn(cloud_sync::folder_watch::run_folder_watcher(
+                    app_handle,
+                ));
+            }
+
+            // Start background Miniflux sync on the configured interval
+            {
+                let app_handle = app.handle().clone();
+                tauri::async_runtime::spawn(sync_scheduler::run_sync_scheduler(app_handle));
+            }
+
             Ok(())
         })
         .invoke_handler(builder.invoke_handler())
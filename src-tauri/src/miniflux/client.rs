@@ -1,9 +1,17 @@
+use crate::miniflux::error::{parse_retry_after, MinifluxError};
+use crate::miniflux::retry::RetryPolicy;
+use crate::miniflux::stats::TransferStats;
 use crate::miniflux::types::*;
 use base64::{prelude::BASE64_STANDARD, Engine};
+use futures_util::{Stream, StreamExt};
 use reqwest::{header, Client};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Timeout for a single HTTP attempt
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 
 /// Miniflux API Client
 #[derive(Clone)]
@@ -13,13 +21,15 @@ pub struct MinifluxClient {
     username: Option<String>,
     password: Option<String>,
     http_client: Client,
+    retry_policy: RetryPolicy,
+    stats: Option<Arc<TransferStats>>,
 }
 
 impl MinifluxClient {
     /// Create a new Miniflux client
     pub fn new(base_url: String) -> Self {
         let http_client = Client::builder()
-            .timeout(Duration::from_secs(30))
+            .timeout(REQUEST_TIMEOUT)
             .build()
             .expect("Failed to create HTTP client");
 
@@ -29,6 +39,8 @@ impl MinifluxClient {
             username: None,
             password: None,
             http_client,
+            retry_policy: RetryPolicy::default(),
+            stats: None,
         }
     }
 
@@ -45,6 +57,31 @@ impl MinifluxClient {
         self
     }
 
+    /// Create a client for the server and credentials in `config`, preferring
+    /// the API token
+    pub fn from_auth_config(config: &AuthConfig) -> Result<Self, MinifluxError> {
+        let client = Self::new(config.server_url.clone());
+        if let Some(token) = &config.auth_token {
+            Ok(client.with_token(token.clone()))
+        } else if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            Ok(client.with_credentials(username.clone(), password.clone()))
+        } else {
+            Err(MinifluxError::MissingCredentials)
+        }
+    }
+
+    /// Set the retry policy for idempotent requests
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Count requests, failures and bytes into `stats`, shared with clones
+    pub fn with_stats(mut self, stats: Arc<TransferStats>) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
     /// Build request with authentication
     fn build_request(&self, path: &str) -> reqwest::RequestBuilder {
         let url = format!("{}/v1/{}", self.base_url, path);
@@ -133,33 +170,130 @@ impl MinifluxClient {
         request
     }
 
-    /// Execute GET request and parse response
-    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
-        let response = self
-            .build_request(path)
-            .send()
-            .await
-            .map_err(|e| format!("Network error: {}", e))?;
+    /// Turns a non-success response into a [`MinifluxError`].
+    async fn check_response(
+        response: reqwest::Response,
+        path: &str,
+    ) -> Result<reqwest::Response, MinifluxError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let headers = response.headers().clone();
+        let body = response.text().await.unwrap_or_default();
+        Err(MinifluxError::from_response(status, path, &headers, body))
+    }
+
+    /// Send an idempotent request, retrying transient failures under the
+    /// client's [`RetryPolicy`]. `build` is called again for every attempt.
+    async fn send_with_retry(
+        &self,
+        build: impl Fn() -> reqwest::RequestBuilder,
+        path: &str,
+    ) -> Result<reqwest::Response, MinifluxError> {
+        let policy = &self.retry_policy;
+        let started = Instant::now();
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            let remaining = policy.deadline.saturating_sub(started.elapsed());
+            let request = build().timeout(remaining.min(REQUEST_TIMEOUT));
+            if let Some(stats) = &self.stats {
+                stats.record_request();
+            }
+
+            let (error, retry_after) = match request.send().await {
+                Ok(response) => {
+                    let status = response.status();
+                    let retry_after = if RetryPolicy::honors_retry_after(status) {
+                        parse_retry_after(response.headers())
+                            .map(|secs| Duration::from_secs(u64::from(secs)))
+                    } else {
+                        None
+                    };
+
+                    match Self::check_response(response, path).await {
+                        Ok(response) => return Ok(response),
+                        Err(error) if RetryPolicy::is_retryable_status(status) => {
+                            (error, retry_after)
+                        }
+                        Err(error) => return Err(self.record_failure(path, error)),
+                    }
+                }
+                Err(error) if RetryPolicy::is_retryable_error(&error) => {
+                    (MinifluxError::from(error), None)
+                }
+                Err(error) => return Err(self.record_failure(path, MinifluxError::from(error))),
+            };
+            let error = self.record_failure(path, error);
+
+            let Some(delay) = policy.delay_after(attempt, retry_after) else {
+                return Err(error);
+            };
+            if started.elapsed() + delay >= policy.deadline {
+                log::warn!(
+                    "Giving up on {} after {} attempt(s): next retry in {:?} would pass the deadline",
+                    path,
+                    attempt,
+                    delay
+                );
+                return Err(error);
+            }
+
+            log::warn!(
+                "Request to {} failed (attempt {}/{}): {}; retrying in {:?}",
+                path,
+                attempt,
+                policy.max_attempts,
+                error,
+                delay
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let url = response.url().clone();
-            return Err(format!("API error: {} - {}", status, url));
+    /// Counts a failed attempt, handing the error back
+    fn record_failure(&self, path: &str, error: MinifluxError) -> MinifluxError {
+        if let Some(stats) = &self.stats {
+            stats.record_failure(path, &error);
         }
+        error
+    }
+
+    /// Execute GET request and parse response
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, MinifluxError> {
+        self.get_with_size(path).await.map(|(value, _)| value)
+    }
+
+    /// Execute GET request and parse response, also returning the body size in bytes
+    async fn get_with_size<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> Result<(T, usize), MinifluxError> {
+        let response = self
+            .send_with_retry(|| self.build_request(path), path)
+            .await?;
 
         // Log response for debugging
         let response_text = response.text().await.map_err(|e| {
             log::error!("Failed to read response body: {}", e);
-            format!("Failed to read response body: {}", e)
+            MinifluxError::from(e)
         })?;
 
         log::debug!("API Response from {}: {}", path, response_text);
+        if let Some(stats) = &self.stats {
+            stats.record_bytes(response_text.len());
+        }
 
-        serde_json::from_str(&response_text).map_err(|e| {
+        let value = serde_json::from_str(&response_text).map_err(|e| {
             log::error!("Failed to parse JSON from {}: {}", path, e);
             log::error!("Response body was: {}", response_text);
-            format!("Parse error: {}", e)
-        })
+            MinifluxError::from(e)
+        })?;
+
+        Ok((value, response_text.len()))
     }
 
     /// Execute POST request and parse response
@@ -167,28 +301,11 @@ impl MinifluxClient {
         &self,
         path: &str,
         body: &B,
-    ) -> Result<T, String> {
-        let response = self
-            .build_post_request(path)
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| format!("Network error: {}", e))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            // Try to get error details from response body
-            let body_text = response.text().await.unwrap_or_default();
-            if body_text.is_empty() {
-                return Err(format!("API error: {}", status));
-            }
-            return Err(format!("API error: {} - {}", status, body_text));
-        }
+    ) -> Result<T, MinifluxError> {
+        let response = self.build_post_request(path).json(body).send().await?;
+        let response = Self::check_response(response, path).await?;
 
-        response
-            .json()
-            .await
-            .map_err(|e| format!("Parse error: {}", e))
+        Ok(response.json().await?)
     }
 
     /// Execute PUT request and parse response
@@ -196,86 +313,44 @@ impl MinifluxClient {
         &self,
         path: &str,
         body: Option<&B>,
-    ) -> Result<T, String> {
-        let mut request = self.build_put_request(path);
-        if let Some(b) = body {
-            request = request.json(b);
-        }
-
-        let response = request
-            .send()
-            .await
-            .map_err(|e| format!("Network error: {}", e))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            // Try to get error details from response body
-            let body_text = response.text().await.unwrap_or_default();
-            if body_text.is_empty() {
-                return Err(format!("API error: {}", status));
+    ) -> Result<T, MinifluxError> {
+        let build = || {
+            let request = self.build_put_request(path);
+            match body {
+                Some(b) => request.json(b),
+                None => request,
             }
-            return Err(format!("API error: {} - {}", status, body_text));
-        }
+        };
 
-        response
-            .json()
-            .await
-            .map_err(|e| format!("Parse error: {}", e))
+        let response = self.send_with_retry(build, path).await?;
+
+        Ok(response.json().await?)
     }
 
     /// Execute PUT request with no request body and no response body (e.g. refresh endpoints → 204)
-    async fn put_no_body(&self, path: &str) -> Result<(), String> {
-        let response = self
-            .build_put_request(path)
-            .send()
-            .await
-            .map_err(|e| format!("Network error: {}", e))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body_text = response.text().await.unwrap_or_default();
-            if body_text.is_empty() {
-                return Err(format!("API error: {}", status));
-            }
-            return Err(format!("API error: {} - {}", status, body_text));
-        }
+    async fn put_no_body(&self, path: &str) -> Result<(), MinifluxError> {
+        self.send_with_retry(|| self.build_put_request(path), path)
+            .await?;
 
         Ok(())
     }
 
     /// Execute PUT request without expecting a response body
-    async fn put_empty<B: serde::Serialize>(&self, path: &str, body: &B) -> Result<(), String> {
-        let request = self.build_put_request(path).json(body);
-
-        let response = request
-            .send()
-            .await
-            .map_err(|e| format!("Network error: {}", e))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            // Try to get error details from response body
-            let body_text = response.text().await.unwrap_or_default();
-            if body_text.is_empty() {
-                return Err(format!("API error: {}", status));
-            }
-            return Err(format!("API error: {} - {}", status, body_text));
-        }
+    async fn put_empty<B: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<(), MinifluxError> {
+        self.send_with_retry(|| self.build_put_request(path).json(body), path)
+            .await?;
 
         Ok(())
     }
 
     /// Execute DELETE request
-    async fn delete(&self, path: &str) -> Result<(), String> {
-        let response = self
-            .build_delete_request(path)
-            .send()
-            .await
-            .map_err(|e| format!("Network error: {}", e))?;
-
-        if !response.status().is_success() {
-            return Err(format!("API error: {}", response.status()));
-        }
+    async fn delete(&self, path: &str) -> Result<(), MinifluxError> {
+        let response = self.build_delete_request(path).send().await?;
+        Self::check_response(response, path).await?;
 
         Ok(())
     }
@@ -283,28 +358,23 @@ impl MinifluxClient {
     // ==================== Authentication ====================
 
     /// Test authentication
-    pub async fn authenticate(&self) -> Result<bool, String> {
+    pub async fn authenticate(&self) -> Result<bool, MinifluxError> {
         match self.get::<User>("me").await {
             Ok(_) => Ok(true),
-            Err(e) => {
-                if e.contains("401") || e.contains("403") {
-                    Ok(false)
-                } else {
-                    Err(e)
-                }
-            }
+            Err(MinifluxError::Unauthorized { .. }) => Ok(false),
+            Err(e) => Err(e),
         }
     }
 
     // ==================== Categories ====================
 
     /// Get all categories
-    pub async fn get_categories(&self) -> Result<Vec<Category>, String> {
+    pub async fn get_categories(&self) -> Result<Vec<Category>, MinifluxError> {
         self.get("categories").await
     }
 
     /// Create a new category
-    pub async fn create_category(&self, title: String) -> Result<Category, String> {
+    pub async fn create_category(&self, title: String) -> Result<Category, MinifluxError> {
         #[derive(serde::Serialize)]
         struct CreateCategory {
             title: String,
@@ -314,7 +384,7 @@ impl MinifluxClient {
     }
 
     /// Update a category
-    pub async fn update_category(&self, id: i64, title: String) -> Result<Category, String> {
+    pub async fn update_category(&self, id: i64, title: String) -> Result<Category, MinifluxError> {
         #[derive(serde::Serialize)]
         struct UpdateCategory {
             title: String,
@@ -328,24 +398,24 @@ impl MinifluxClient {
     }
 
     /// Delete a category
-    pub async fn delete_category(&self, id: i64) -> Result<(), String> {
+    pub async fn delete_category(&self, id: i64) -> Result<(), MinifluxError> {
         self.delete(&format!("categories/{}", id)).await
     }
 
     // ==================== Feeds ====================
 
     /// Get all feeds
-    pub async fn get_feeds(&self) -> Result<Vec<Feed>, String> {
+    pub async fn get_feeds(&self) -> Result<Vec<Feed>, MinifluxError> {
         self.get("feeds").await
     }
 
     /// Get feeds by category
-    pub async fn get_category_feeds(&self, category_id: i64) -> Result<Vec<Feed>, String> {
+    pub async fn get_category_feeds(&self, category_id: i64) -> Result<Vec<Feed>, MinifluxError> {
         self.get(&format!("categories/{}/feeds", category_id)).await
     }
 
     /// Get a single feed
-    pub async fn get_feed(&self, id: i64) -> Result<Feed, String> {
+    pub async fn get_feed(&self, id: i64) -> Result<Feed, MinifluxError> {
         self.get(&format!("feeds/{}", id)).await
     }
 
@@ -354,7 +424,7 @@ impl MinifluxClient {
         &self,
         feed_url: String,
         category_id: Option<i64>,
-    ) -> Result<i64, String> {
+    ) -> Result<i64, MinifluxError> {
         #[derive(serde::Serialize)]
         struct CreateFeed {
             feed_url: String,
@@ -381,116 +451,116 @@ impl MinifluxClient {
     }
 
     /// Update a feed
-    pub async fn update_feed(&self, id: i64, updates: FeedUpdate) -> Result<Feed, String> {
+    pub async fn update_feed(&self, id: i64, updates: FeedUpdate) -> Result<Feed, MinifluxError> {
         self.put(&format!("feeds/{}", id), Some(&updates)).await
     }
 
     /// Delete a feed
-    pub async fn delete_feed(&self, id: i64) -> Result<(), String> {
+    pub async fn delete_feed(&self, id: i64) -> Result<(), MinifluxError> {
         self.delete(&format!("feeds/{}", id)).await
     }
 
     /// Refresh a feed
-    pub async fn refresh_feed(&self, id: i64) -> Result<(), String> {
+    pub async fn refresh_feed(&self, id: i64) -> Result<(), MinifluxError> {
         self.put_no_body(&format!("feeds/{}/refresh", id)).await
     }
 
     /// Refresh all feeds
-    pub async fn refresh_all_feeds(&self) -> Result<(), String> {
+    pub async fn refresh_all_feeds(&self) -> Result<(), MinifluxError> {
         self.put_no_body("feeds/refresh").await
     }
 
     /// Get feed icon
-    pub async fn get_feed_icon(&self, feed_id: i64) -> Result<Icon, String> {
+    pub async fn get_feed_icon(&self, feed_id: i64) -> Result<Icon, MinifluxError> {
         self.get(&format!("feeds/{}/icon", feed_id)).await
     }
 
+    /// Get an icon by its own ID, as referenced by `Feed::icon`
+    pub async fn get_icon(&self, icon_id: i64) -> Result<Icon, MinifluxError> {
+        self.get(&format!("icons/{}", icon_id)).await
+    }
+
+    /// Get per-feed read and unread counts
+    pub async fn get_feed_counters(&self) -> Result<FeedCounters, MinifluxError> {
+        self.get("feeds/counters").await
+    }
+
+    /// Refresh every feed in a category
+    pub async fn refresh_category(&self, category_id: i64) -> Result<(), MinifluxError> {
+        self.put_no_body(&format!("categories/{}/refresh", category_id))
+            .await
+    }
+
     // ==================== Entries ====================
 
     /// Get entries with filters
-    pub async fn get_entries(&self, filters: &EntryFilters) -> Result<EntryResponse, String> {
-        let mut query_params = Vec::new();
-
+    pub async fn get_entries(
+        &self,
+        filters: &EntryFilters,
+    ) -> Result<EntryResponse, MinifluxError> {
         log::debug!("Fetching entries with filters: {:?}", filters);
 
-        if let Some(status) = &filters.status {
-            query_params.push(format!("status={}", status));
-        }
-        if let Some(offset) = filters.offset {
-            query_params.push(format!("offset={}", offset));
-        }
-        if let Some(limit) = filters.limit {
-            query_params.push(format!("limit={}", limit));
-        }
-        if let Some(order) = &filters.order {
-            query_params.push(format!("order={}", order));
-        }
-        if let Some(direction) = &filters.direction {
-            query_params.push(format!("direction={}", direction));
-        }
-        if let Some(before) = filters.before {
-            query_params.push(format!("before={}", before));
-        }
-        if let Some(after) = filters.after {
-            query_params.push(format!("after={}", after));
-        }
-        if let Some(published_before) = filters.published_before {
-            query_params.push(format!("published_before={}", published_before));
-        }
-        if let Some(published_after) = filters.published_after {
-            query_params.push(format!("published_after={}", published_after));
-        }
-        if let Some(changed_before) = filters.changed_before {
-            query_params.push(format!("changed_before={}", changed_before));
-        }
-        if let Some(changed_after) = filters.changed_after {
-            query_params.push(format!("changed_after={}", changed_after));
-        }
-        if let Some(before_entry_id) = filters.before_entry_id {
-            query_params.push(format!("before_entry_id={}", before_entry_id));
-        }
-        if let Some(after_entry_id) = filters.after_entry_id {
-            query_params.push(format!("after_entry_id={}", after_entry_id));
-        }
-        if let Some(starred) = filters.starred {
-            query_params.push(format!("starred={}", starred));
-        }
-        if let Some(search) = &filters.search {
-            query_params.push(format!("search={}", urlencoding::encode(search)));
-        }
-        if let Some(category_id) = filters.category_id {
-            query_params.push(format!("category_id={}", category_id));
-        }
-        if let Some(feed_id) = filters.feed_id {
-            query_params.push(format!("feed_id={}", feed_id));
-        }
-        if let Some(globally_visible) = filters.globally_visible {
-            query_params.push(format!("globally_visible={}", globally_visible));
-        }
-
-        let path = if query_params.is_empty() {
-            "entries".to_string()
-        } else {
-            format!("entries?{}", query_params.join("&"))
-        };
+        let path = format!("entries{}", entry_query_string(filters));
 
         log::debug!("Query path: {}", path);
 
         self.get(&path).await
     }
 
+    /// Same as [`Self::get_entries`], also returning the size of the response
+    /// body in bytes so callers can tune their page size
+    pub async fn get_entries_with_size(
+        &self,
+        filters: &EntryFilters,
+    ) -> Result<(EntryResponse, usize), MinifluxError> {
+        self.get_with_size(&format!("entries{}", entry_query_string(filters)))
+            .await
+    }
+
+    /// Get a feed's entries with filters
+    pub async fn get_feed_entries(
+        &self,
+        feed_id: i64,
+        filters: &EntryFilters,
+    ) -> Result<EntryResponse, MinifluxError> {
+        self.get(&format!(
+            "feeds/{}/entries{}",
+            feed_id,
+            entry_query_string(filters)
+        ))
+        .await
+    }
+
+    /// Get a category's entries with filters
+    pub async fn get_category_entries(
+        &self,
+        category_id: i64,
+        filters: &EntryFilters,
+    ) -> Result<EntryResponse, MinifluxError> {
+        self.get(&format!(
+            "categories/{}/entries{}",
+            category_id,
+            entry_query_string(filters)
+        ))
+        .await
+    }
+
     /// Get a single entry
-    pub async fn get_entry(&self, id: i64) -> Result<Entry, String> {
+    pub async fn get_entry(&self, id: i64) -> Result<Entry, MinifluxError> {
         self.get(&format!("entries/{}", id)).await
     }
 
     /// Update an entry
-    pub async fn update_entry(&self, id: i64, updates: EntryUpdate) -> Result<Entry, String> {
+    pub async fn update_entry(
+        &self,
+        id: i64,
+        updates: EntryUpdate,
+    ) -> Result<Entry, MinifluxError> {
         self.put(&format!("entries/{}", id), Some(&updates)).await
     }
 
     /// Update multiple entries status
-    pub async fn update_entries(&self, ids: Vec<i64>, status: String) -> Result<(), String> {
+    pub async fn update_entries(&self, ids: Vec<i64>, status: String) -> Result<(), MinifluxError> {
         #[derive(serde::Serialize)]
         struct UpdateEntries {
             status: String,
@@ -508,27 +578,40 @@ impl MinifluxClient {
     }
 
     /// Mark all entries in a feed as read
-    pub async fn mark_feed_as_read(&self, feed_id: i64) -> Result<(), String> {
+    pub async fn mark_feed_as_read(&self, feed_id: i64) -> Result<(), MinifluxError> {
         self.put_empty(&format!("feeds/{}/mark-all-as-read", feed_id), &())
             .await
     }
 
     /// Mark all entries in a category as read
-    pub async fn mark_category_as_read(&self, category_id: i64) -> Result<(), String> {
+    pub async fn mark_category_as_read(&self, category_id: i64) -> Result<(), MinifluxError> {
         self.put_empty(&format!("categories/{}/mark-all-as-read", category_id), &())
             .await
     }
 
+    /// Mark every entry of a user as read
+    pub async fn mark_user_entries_as_read(&self, user_id: i64) -> Result<(), MinifluxError> {
+        self.put_no_body(&format!("users/{}/mark-all-as-read", user_id))
+            .await
+    }
+
     /// Toggle entry bookmark
-    pub async fn toggle_bookmark(&self, id: i64) -> Result<(), String> {
+    pub async fn toggle_bookmark(&self, id: i64) -> Result<(), MinifluxError> {
         // Miniflux API requires PUT (not POST) for bookmark toggle
-        // Returns 204 No Content
-        self.put_empty(&format!("entries/{}/bookmark", id), &())
-            .await
+        // Returns 204 No Content. A toggle is not idempotent, so it is never retried.
+        let path = format!("entries/{}/bookmark", id);
+        let response = self.build_put_request(&path).json(&()).send().await?;
+        Self::check_response(response, &path).await?;
+
+        Ok(())
     }
 
     /// Fetch original article content
-    pub async fn fetch_content(&self, id: i64, update_content: bool) -> Result<String, String> {
+    pub async fn fetch_content(
+        &self,
+        id: i64,
+        update_content: bool,
+    ) -> Result<String, MinifluxError> {
         let path = format!(
             "entries/{}/fetch-content?update_content={}",
             id, update_content
@@ -539,21 +622,20 @@ impl MinifluxClient {
 
     // ==================== Enclosures ====================
 
-    /// Get enclosure
-    pub async fn get_enclosure(&self, entry_id: i64) -> Result<Enclosure, String> {
-        self.get(&format!("entries/{}/enclosure", entry_id)).await
+    /// Get an enclosure, including its media progression
+    pub async fn get_enclosure(&self, id: i64) -> Result<Enclosure, MinifluxError> {
+        self.get(&format!("enclosures/{}", id)).await
     }
 
-    /// Update enclosure status
-    pub async fn update_enclosure(&self, id: i64, status: String) -> Result<(), String> {
-        #[derive(serde::Serialize)]
-        struct UpdateEnclosure {
-            status: String,
-        }
-
-        self.put(
+    /// Save the playback position (in seconds) of an enclosure
+    pub async fn update_enclosure_progression(
+        &self,
+        id: i64,
+        media_progression: i64,
+    ) -> Result<(), MinifluxError> {
+        self.put_empty(
             &format!("enclosures/{}", id),
-            Some(&UpdateEnclosure { status }),
+            &EnclosureUpdate { media_progression },
         )
         .await
     }
@@ -561,74 +643,86 @@ impl MinifluxClient {
     // ==================== Users ====================
 
     /// Get current user
-    pub async fn get_current_user(&self) -> Result<User, String> {
+    pub async fn get_current_user(&self) -> Result<User, MinifluxError> {
         self.get("me").await
     }
 
     /// Get all users
-    pub async fn get_users(&self) -> Result<Vec<User>, String> {
+    pub async fn get_users(&self) -> Result<Vec<User>, MinifluxError> {
         self.get("users").await
     }
 
     /// Create a new user
-    pub async fn create_user(&self, user: UserCreate) -> Result<User, String> {
+    pub async fn create_user(&self, user: UserCreate) -> Result<User, MinifluxError> {
         self.post("users", &user).await
     }
 
     /// Update a user
-    pub async fn update_user(&self, id: i64, updates: UserUpdate) -> Result<User, String> {
+    pub async fn update_user(&self, id: i64, updates: UserUpdate) -> Result<User, MinifluxError> {
         self.put(&format!("users/{}", id), Some(&updates)).await
     }
 
+    /// Update the current user's settings, e.g. `entries_per_page` or
+    /// `entry_sorting_direction`. Miniflux only updates users by ID, so the
+    /// current user is looked up first.
+    pub async fn update_current_user(&self, updates: UserUpdate) -> Result<User, MinifluxError> {
+        let me = self.get_current_user().await?;
+        self.update_user(me.id, updates).await
+    }
+
     /// Delete a user
-    pub async fn delete_user(&self, id: i64) -> Result<(), String> {
+    pub async fn delete_user(&self, id: i64) -> Result<(), MinifluxError> {
         self.delete(&format!("users/{}", id)).await
     }
 
     // ==================== Counters ====================
 
     /// Get unread and read counters
-    pub async fn get_counters(&self) -> Result<Counters, String> {
+    pub async fn get_counters(&self) -> Result<Counters, MinifluxError> {
         self.get("counters").await
     }
 
     // ==================== OPML ====================
 
     /// Export OPML (returns raw XML text, not JSON)
-    pub async fn export_opml(&self) -> Result<String, String> {
+    pub async fn export_opml(&self) -> Result<String, MinifluxError> {
         let response = self
-            .build_request("export")
-            .send()
-            .await
-            .map_err(|e| format!("Network error: {e}"))?;
+            .send_with_retry(|| self.build_request("export"), "export")
+            .await?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let url = response.url().clone();
-            return Err(format!("API error: {status} - {url}"));
-        }
+        Ok(response.text().await?)
+    }
 
-        response
-            .text()
-            .await
-            .map_err(|e| format!("Failed to read response body: {e}"))
+    /// Export OPML as a byte stream, so large exports can go straight to disk
+    pub async fn export_opml_stream(
+        &self,
+    ) -> Result<impl Stream<Item = Result<bytes::Bytes, MinifluxError>>, MinifluxError> {
+        let response = self
+            .send_with_retry(|| self.build_request("export"), "export")
+            .await?;
+
+        Ok(response
+            .bytes_stream()
+            .map(|chunk| chunk.map_err(MinifluxError::from)))
     }
 
-    /// Import OPML
-    pub async fn import_opml(&self, opml_content: String) -> Result<(), String> {
-        #[derive(serde::Serialize)]
-        struct ImportOpml {
-            opml: String,
-        }
+    /// Import OPML. Miniflux expects the raw XML as the request body.
+    pub async fn import_opml(&self, opml: impl Into<reqwest::Body>) -> Result<(), MinifluxError> {
+        let response = self
+            .build_post_request("import")
+            .header(header::CONTENT_TYPE, "application/xml")
+            .body(opml)
+            .send()
+            .await?;
+        Self::check_response(response, "import").await?;
 
-        self.post("import", &ImportOpml { opml: opml_content })
-            .await
+        Ok(())
     }
 
     // ==================== Discover ====================
 
     /// Discover subscriptions from a URL
-    pub async fn discover(&self, url: String) -> Result<Vec<Subscription>, String> {
+    pub async fn discover(&self, url: String) -> Result<Vec<Subscription>, MinifluxError> {
         #[derive(serde::Serialize)]
         struct DiscoverUrl {
             url: String,
@@ -640,42 +734,34 @@ impl MinifluxClient {
     // ==================== Version ====================
 
     /// Get Miniflux version information
-    pub async fn get_version(&self) -> Result<MinifluxVersion, String> {
+    pub async fn get_version(&self) -> Result<MinifluxVersion, MinifluxError> {
         self.get("version").await
     }
 
     // ==================== API Keys ====================
 
     /// Get all API keys
-    pub async fn get_api_keys(&self) -> Result<Vec<ApiKey>, String> {
+    pub async fn get_api_keys(&self) -> Result<Vec<ApiKey>, MinifluxError> {
         self.get("api-keys").await
     }
 
     /// Create a new API key
-    pub async fn create_api_key(&self, request: ApiKeyCreate) -> Result<ApiKey, String> {
+    pub async fn create_api_key(&self, request: ApiKeyCreate) -> Result<ApiKey, MinifluxError> {
         self.post("api-keys", &request).await
     }
 
     /// Delete an API key
-    pub async fn delete_api_key(&self, id: i64) -> Result<(), String> {
+    pub async fn delete_api_key(&self, id: i64) -> Result<(), MinifluxError> {
         self.delete(&format!("api-keys/{id}")).await
     }
 
     // ==================== Save Entry ====================
 
     /// Save entry to third-party services
-    pub async fn save_entry(&self, id: i64) -> Result<(), String> {
-        let response = self
-            .build_post_request(&format!("entries/{id}/save"))
-            .send()
-            .await
-            .map_err(|e| format!("Network error: {e}"))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return Err(format!("API error: {status} - {body}"));
-        }
+    pub async fn save_entry(&self, id: i64) -> Result<(), MinifluxError> {
+        let path = format!("entries/{id}/save");
+        let response = self.build_post_request(&path).send().await?;
+        Self::check_response(response, &path).await?;
 
         Ok(())
     }
@@ -683,7 +769,7 @@ impl MinifluxClient {
     // ==================== Integrations ====================
 
     /// Get user integration settings
-    pub async fn get_integrations(&self) -> Result<Integration, String> {
+    pub async fn get_integrations(&self) -> Result<Integration, MinifluxError> {
         self.get("integrations").await
     }
 
@@ -691,12 +777,78 @@ impl MinifluxClient {
 
     /// Flush history (delete all read entries via API)
     /// Changes status of all read entries to "removed" (except bookmarks)
-    pub async fn flush_history(&self) -> Result<(), String> {
+    pub async fn flush_history(&self) -> Result<(), MinifluxError> {
         self.delete("flush-history").await
     }
 }
 
-fn parse_fetch_content_response(response: Value) -> Result<String, String> {
+/// Builds the `?key=value` suffix for the entry listing endpoints
+fn entry_query_string(filters: &EntryFilters) -> String {
+    let mut query_params = Vec::new();
+
+    if let Some(status) = &filters.status {
+        query_params.push(format!("status={}", status));
+    }
+    if let Some(offset) = filters.offset {
+        query_params.push(format!("offset={}", offset));
+    }
+    if let Some(limit) = filters.limit {
+        query_params.push(format!("limit={}", limit));
+    }
+    if let Some(order) = &filters.order {
+        query_params.push(format!("order={}", order));
+    }
+    if let Some(direction) = &filters.direction {
+        query_params.push(format!("direction={}", direction));
+    }
+    if let Some(before) = filters.before {
+        query_params.push(format!("before={}", before));
+    }
+    if let Some(after) = filters.after {
+        query_params.push(format!("after={}", after));
+    }
+    if let Some(published_before) = filters.published_before {
+        query_params.push(format!("published_before={}", published_before));
+    }
+    if let Some(published_after) = filters.published_after {
+        query_params.push(format!("published_after={}", published_after));
+    }
+    if let Some(changed_before) = filters.changed_before {
+        query_params.push(format!("changed_before={}", changed_before));
+    }
+    if let Some(changed_after) = filters.changed_after {
+        query_params.push(format!("changed_after={}", changed_after));
+    }
+    if let Some(before_entry_id) = filters.before_entry_id {
+        query_params.push(format!("before_entry_id={}", before_entry_id));
+    }
+    if let Some(after_entry_id) = filters.after_entry_id {
+        query_params.push(format!("after_entry_id={}", after_entry_id));
+    }
+    if let Some(starred) = filters.starred {
+        query_params.push(format!("starred={}", starred));
+    }
+    if let Some(search) = &filters.search {
+        query_params.push(format!("search={}", urlencoding::encode(search)));
+    }
+    if let Some(category_id) = filters.category_id {
+        query_params.push(format!("category_id={}", category_id));
+    }
+    if let Some(feed_id) = filters.feed_id {
+        query_params.push(format!("feed_id={}", feed_id));
+    }
+    if let Some(globally_visible) = filters.globally_visible {
+        query_params.push(format!("globally_visible={}", globally_visible));
+    }
+
+    if query_params.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", query_params.join("&"))
+    }
+}
+
+fn parse_fetch_content_response(response: Value) -> Result<String, MinifluxError> {
     match response {
         Value::String(content) => Ok(content),
         Value::Object(map) => {
@@ -713,12 +865,13 @@ fn parse_fetch_content_response(response: Value) -> Result<String, String> {
                 return Ok(content.to_string());
             }
 
-            Err("Parse error: fetch-content response missing content string".to_string())
+            Err(MinifluxError::Parse {
+                message: "fetch-content response missing content string".to_string(),
+            })
         }
-        other => Err(format!(
-            "Parse error: invalid fetch-content response type: {}",
-            other
-        )),
+        other => Err(MinifluxError::Parse {
+            message: format!("invalid fetch-content response type: {}", other),
+        }),
     }
 }
 
@@ -780,6 +933,220 @@ mod tests {
             "content": null
         }))
         .expect_err("invalid payload should fail");
-        assert!(error.contains("missing content string"));
+        assert!(matches!(
+            error,
+            MinifluxError::Parse { ref message } if message.contains("missing content string")
+        ));
+    }
+
+    #[test]
+    fn test_entry_query_string() {
+        assert_eq!(entry_query_string(&EntryFilters::default()), "");
+
+        let filters = EntryFilters {
+            status: Some("unread".to_string()),
+            limit: Some(50),
+            search: Some("rust lang".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            entry_query_string(&filters),
+            "?status=unread&limit=50&search=rust%20lang"
+        );
+    }
+
+    #[test]
+    fn test_feed_counters_deserialize() {
+        let counters: FeedCounters = serde_json::from_value(serde_json::json!({
+            "reads": { "1": 12, "2": 0 },
+            "unreads": { "1": 3 }
+        }))
+        .unwrap();
+
+        assert_eq!(counters.reads.get("1"), Some(&12));
+        assert_eq!(counters.unreads.get("1"), Some(&3));
+        assert_eq!(counters.unreads.get("2"), None);
+    }
+
+    #[test]
+    fn test_enclosure_media_progression_defaults_to_zero() {
+        let enclosure: Enclosure = serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "entry_id": 3,
+            "url": "https://example.com/episode.mp3",
+            "mime_type": "audio/mpeg"
+        }))
+        .unwrap();
+
+        assert_eq!(enclosure.media_progression, 0);
+    }
+
+    mod retry {
+        use super::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        /// Serves `responses` in order to successive requests, repeating the
+        /// last one, and counts how many requests arrived.
+        async fn spawn_server(responses: Vec<&'static str>) -> (String, Arc<AtomicUsize>) {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let address = listener.local_addr().unwrap();
+            let hits = Arc::new(AtomicUsize::new(0));
+            let counter = hits.clone();
+
+            tokio::spawn(async move {
+                loop {
+                    let Ok((mut socket, _)) = listener.accept().await else {
+                        return;
+                    };
+                    let index = counter.fetch_add(1, Ordering::SeqCst);
+                    let response = responses[index.min(responses.len() - 1)];
+
+                    read_request(&mut socket).await;
+                    let _ = socket.write_all(response.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                }
+            });
+
+            (format!("http://{address}"), hits)
+        }
+
+        async fn read_request(socket: &mut tokio::net::TcpStream) {
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            loop {
+                let Ok(read) = socket.read(&mut buffer).await else {
+                    return;
+                };
+                if read == 0 {
+                    return;
+                }
+                request.extend_from_slice(&buffer[..read]);
+
+                let text = String::from_utf8_lossy(&request);
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let content_length = text[..end]
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse::<usize>().ok())
+                                .flatten()
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= end + 4 + content_length {
+                        return;
+                    }
+                }
+            }
+        }
+
+        const OK_VERSION: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 19\r\nConnection: close\r\n\r\n{\"version\":\"2.2.0\"}";
+        const BAD_GATEWAY: &str =
+            "HTTP/1.1 502 Bad Gateway\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+        const NOT_FOUND: &str =
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+        const RATE_LIMITED: &str = "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 1\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+        const UNAVAILABLE_FOR_AN_HOUR: &str = "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 3600\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+        fn fast_policy() -> RetryPolicy {
+            RetryPolicy {
+                max_attempts: 4,
+                base_delay: Duration::from_millis(10),
+                max_delay: Duration::from_millis(50),
+                deadline: Duration::from_secs(10),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_get_retries_transient_bad_gateway() {
+            let (url, hits) = spawn_server(vec![BAD_GATEWAY, BAD_GATEWAY, OK_VERSION]).await;
+            let client = MinifluxClient::new(url).with_retry_policy(fast_policy());
+
+            let version = client.get_version().await.unwrap();
+
+            assert_eq!(version.version, "2.2.0");
+            assert_eq!(hits.load(Ordering::SeqCst), 3);
+        }
+
+        #[tokio::test]
+        async fn test_gives_up_after_max_attempts() {
+            let (url, hits) = spawn_server(vec![BAD_GATEWAY]).await;
+            let client = MinifluxClient::new(url).with_retry_policy(fast_policy());
+
+            let error = client.get_version().await.unwrap_err();
+
+            assert!(matches!(
+                error,
+                MinifluxError::ServerError { status: 502, .. }
+            ));
+            assert_eq!(hits.load(Ordering::SeqCst), 4);
+        }
+
+        #[tokio::test]
+        async fn test_does_not_retry_client_errors() {
+            let (url, hits) = spawn_server(vec![NOT_FOUND, OK_VERSION]).await;
+            let client = MinifluxClient::new(url).with_retry_policy(fast_policy());
+
+            let error = client.get_version().await.unwrap_err();
+
+            assert!(error.is_not_found());
+            assert_eq!(hits.load(Ordering::SeqCst), 1);
+        }
+
+        #[tokio::test]
+        async fn test_does_not_retry_post() {
+            let (url, hits) = spawn_server(vec![BAD_GATEWAY, OK_VERSION]).await;
+            let client = MinifluxClient::new(url).with_retry_policy(fast_policy());
+
+            let result = client.create_category("News".to_string()).await;
+
+            assert!(result.is_err());
+            assert_eq!(hits.load(Ordering::SeqCst), 1);
+        }
+
+        #[tokio::test]
+        async fn test_waits_for_retry_after() {
+            let (url, hits) = spawn_server(vec![RATE_LIMITED, OK_VERSION]).await;
+            let client = MinifluxClient::new(url).with_retry_policy(fast_policy());
+            let started = Instant::now();
+
+            client.get_version().await.unwrap();
+
+            assert!(started.elapsed() >= Duration::from_secs(1));
+            assert_eq!(hits.load(Ordering::SeqCst), 2);
+        }
+
+        #[tokio::test]
+        async fn test_retry_after_past_deadline_fails_fast() {
+            let (url, hits) = spawn_server(vec![UNAVAILABLE_FOR_AN_HOUR, OK_VERSION]).await;
+            let client = MinifluxClient::new(url).with_retry_policy(fast_policy());
+
+            let error = client.get_version().await.unwrap_err();
+
+            assert!(matches!(
+                error,
+                MinifluxError::ServerError { status: 503, .. }
+            ));
+            assert_eq!(hits.load(Ordering::SeqCst), 1);
+        }
+
+        #[tokio::test]
+        async fn test_retries_refused_connections() {
+            // Bind and drop a listener to get a port nothing listens on
+            let address = TcpListener::bind("127.0.0.1:0")
+                .await
+                .unwrap()
+                .local_addr()
+                .unwrap();
+            let client =
+                MinifluxClient::new(format!("http://{address}")).with_retry_policy(fast_policy());
+
+            let error = client.get_version().await.unwrap_err();
+
+            assert!(matches!(error, MinifluxError::Network { .. }));
+        }
     }
 }
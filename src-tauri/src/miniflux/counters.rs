@@ -27,11 +27,24 @@ pub struct FeedUnread {
     pub unread_count: i64,
 }
 
+/// Per-smart-folder unread count
+#[derive(Debug, Clone, Serialize, Deserialize, Type, FromRow)]
+pub struct SmartFolderUnread {
+    #[serde(
+        serialize_with = "serialize_i64_as_string",
+        deserialize_with = "deserialize_i64_from_string_or_number"
+    )]
+    #[specta(type = String)]
+    pub smart_folder_id: i64,
+    pub unread_count: i64,
+}
+
 /// Enhanced unread counts from local database
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct UnreadCounts {
     pub total: i64,
     pub by_category: Vec<CategoryUnread>,
     pub by_feed: Vec<FeedUnread>,
+    pub by_smart_folder: Vec<SmartFolderUnread>,
     pub today: i64,
 }
@@ -0,0 +1,237 @@
+//! Error types for Miniflux API calls
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use thiserror::Error;
+
+/// Error types for Miniflux API calls
+#[derive(Debug, Clone, PartialEq, Eq, Error, Serialize, Deserialize, Type)]
+#[serde(tag = "type", content = "data")]
+pub enum MinifluxError {
+    /// No client is configured for the active account
+    #[error("Not connected to Miniflux server")]
+    NotConnected,
+
+    /// Neither an API token nor a username and password were given
+    #[error("Either auth_token or username/password must be provided")]
+    MissingCredentials,
+
+    /// The request never got a response (DNS, refused connection, TLS, ...)
+    #[error("Network error: {message}")]
+    Network { message: String },
+
+    /// The server did not answer within the client timeout
+    #[error("Request timed out")]
+    Timeout,
+
+    /// Credentials were rejected (401) or lack permission (403)
+    #[error("Unauthorized: {status}")]
+    Unauthorized { status: u16 },
+
+    /// The requested resource does not exist on the server
+    #[error("Not found: {path}")]
+    NotFound { path: String },
+
+    /// Too many requests; `retry_after_secs` comes from the `Retry-After` header
+    #[error("Rate limited")]
+    RateLimited { retry_after_secs: Option<u32> },
+
+    /// Any other non-success status, with the response body
+    #[error("API error: {status} - {body}")]
+    ServerError { status: u16, body: String },
+
+    /// The response body did not match the expected shape
+    #[error("Parse error: {message}")]
+    Parse { message: String },
+
+    /// An argument was rejected before any request was made
+    #[error("{message}")]
+    InvalidInput { message: String },
+
+    /// A local step around the request failed, such as a database or file write
+    #[error("{message}")]
+    Local { message: String },
+}
+
+impl MinifluxError {
+    /// Maps a non-success response to the matching variant.
+    pub(crate) fn from_response(
+        status: StatusCode,
+        path: &str,
+        headers: &HeaderMap,
+        body: String,
+    ) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => MinifluxError::Unauthorized {
+                status: status.as_u16(),
+            },
+            StatusCode::NOT_FOUND => MinifluxError::NotFound {
+                path: path.to_string(),
+            },
+            StatusCode::TOO_MANY_REQUESTS => MinifluxError::RateLimited {
+                retry_after_secs: parse_retry_after(headers),
+            },
+            _ => MinifluxError::ServerError {
+                status: status.as_u16(),
+                body,
+            },
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, MinifluxError::NotFound { .. })
+    }
+}
+
+impl From<reqwest::Error> for MinifluxError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            MinifluxError::Timeout
+        } else if err.is_decode() {
+            MinifluxError::Parse {
+                message: err.to_string(),
+            }
+        } else {
+            MinifluxError::Network {
+                message: err.to_string(),
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for MinifluxError {
+    fn from(err: serde_json::Error) -> Self {
+        MinifluxError::Parse {
+            message: err.to_string(),
+        }
+    }
+}
+
+/// Lets commands use `?` on the database helpers, which report plain strings.
+impl From<String> for MinifluxError {
+    fn from(message: String) -> Self {
+        MinifluxError::Local { message }
+    }
+}
+
+impl From<&str> for MinifluxError {
+    fn from(message: &str) -> Self {
+        MinifluxError::Local {
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Lets commands that still report plain strings use `?` on client calls.
+impl From<MinifluxError> for String {
+    fn from(err: MinifluxError) -> Self {
+        err.to_string()
+    }
+}
+
+/// Reads `Retry-After` as either delay-seconds or an HTTP date.
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<u32> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u32>() {
+        return Some(seconds);
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let seconds = (date.with_timezone(&chrono::Utc) - chrono::Utc::now()).num_seconds();
+    Some(seconds.clamp(0, i64::from(u32::MAX)) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_from_response_maps_statuses() {
+        let headers = HeaderMap::new();
+
+        assert_eq!(
+            MinifluxError::from_response(StatusCode::UNAUTHORIZED, "me", &headers, String::new()),
+            MinifluxError::Unauthorized { status: 401 }
+        );
+        assert_eq!(
+            MinifluxError::from_response(StatusCode::FORBIDDEN, "users", &headers, String::new()),
+            MinifluxError::Unauthorized { status: 403 }
+        );
+        assert!(MinifluxError::from_response(
+            StatusCode::NOT_FOUND,
+            "entries/1",
+            &headers,
+            String::new()
+        )
+        .is_not_found());
+        assert_eq!(
+            MinifluxError::from_response(
+                StatusCode::BAD_GATEWAY,
+                "feeds",
+                &headers,
+                "upstream down".to_string()
+            ),
+            MinifluxError::ServerError {
+                status: 502,
+                body: "upstream down".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_rate_limited_reads_retry_after_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+
+        assert_eq!(
+            MinifluxError::from_response(
+                StatusCode::TOO_MANY_REQUESTS,
+                "entries",
+                &headers,
+                String::new()
+            ),
+            MinifluxError::RateLimited {
+                retry_after_secs: Some(120)
+            }
+        );
+    }
+
+    #[test]
+    fn test_retry_after_accepts_http_date() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        // A date in the past means "retry now"
+        assert_eq!(parse_retry_after(&headers), Some(0));
+    }
+
+    #[test]
+    fn test_string_errors_become_local() {
+        let err: MinifluxError = "Database not initialized".into();
+
+        assert_eq!(
+            err,
+            MinifluxError::Local {
+                message: "Database not initialized".to_string()
+            }
+        );
+        assert_eq!(err.to_string(), "Database not initialized");
+    }
+
+    #[test]
+    fn test_serializes_with_type_tag() {
+        let json = serde_json::to_value(MinifluxError::Unauthorized { status: 401 }).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({ "type": "Unauthorized", "data": { "status": 401 } })
+        );
+    }
+}
@@ -1,6 +1,12 @@
 pub mod client;
 pub mod counters;
+pub mod error;
+pub mod retry;
+pub mod stats;
 pub mod types;
 
 pub use client::MinifluxClient;
+pub use error::MinifluxError;
+pub use retry::RetryPolicy;
+pub use stats::TransferStats;
 pub use types::*;
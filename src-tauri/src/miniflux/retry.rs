@@ -0,0 +1,148 @@
+//! Retry policy for idempotent Miniflux API calls
+//!
+//! Transient failures (connection errors, timeouts, 429 and 502/503/504) are
+//! retried with exponential backoff and jitter. A `Retry-After` header on 429
+//! and 503 replaces the computed delay, and no retry is scheduled past the
+//! call's overall deadline.
+
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+/// How [`MinifluxClient`](super::MinifluxClient) retries idempotent requests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first; `1` disables retries
+    pub max_attempts: u32,
+    /// Backoff before the second attempt, doubled for each one after
+    pub base_delay: Duration,
+    /// Upper bound for the computed backoff
+    pub max_delay: Duration,
+    /// Time budget for the whole call, retries and waits included
+    pub deadline: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            deadline: Duration::from_secs(90),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that makes exactly one attempt
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a response with this status is worth retrying
+    pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    /// Whether the server's `Retry-After` should be honored for this status
+    pub(crate) fn honors_retry_after(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+        )
+    }
+
+    /// Whether a request that never got a response is worth retrying
+    pub(crate) fn is_retryable_error(error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout()
+    }
+
+    /// Delay before the attempt after `attempt` (1-based), or `None` when the
+    /// attempts are used up. `retry_after` wins over the computed backoff.
+    pub(crate) fn delay_after(
+        &self,
+        attempt: u32,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        if let Some(retry_after) = retry_after {
+            return Some(retry_after);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(16);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        // Equal jitter: half fixed, half random, so retries from several
+        // clients spread out without ever collapsing to zero
+        let half = backoff / 2;
+        Some(half + half.mul_f64(jitter()))
+    }
+}
+
+/// A number in `[0, 1)` that differs between calls
+fn jitter() -> f64 {
+    let bits = RandomState::new().hash_one(std::time::Instant::now());
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(400),
+            deadline: Duration::from_secs(60),
+        };
+
+        let first = policy.delay_after(1, None).unwrap();
+        assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+
+        let second = policy.delay_after(2, None).unwrap();
+        assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+
+        let capped = policy.delay_after(8, None).unwrap();
+        assert!(capped >= Duration::from_millis(200) && capped <= Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_retry_after_overrides_backoff() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(
+            policy.delay_after(1, Some(Duration::from_secs(20))),
+            Some(Duration::from_secs(20))
+        );
+    }
+
+    #[test]
+    fn test_no_delay_once_attempts_are_used_up() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            ..RetryPolicy::default()
+        };
+
+        assert!(policy.delay_after(2, None).is_some());
+        assert_eq!(policy.delay_after(3, None), None);
+        assert_eq!(RetryPolicy::none().delay_after(1, None), None);
+    }
+}
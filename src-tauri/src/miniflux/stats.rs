@@ -0,0 +1,84 @@
+//! Transfer counters for Miniflux API calls
+//!
+//! A [`TransferStats`] attached to a client is shared by all of its clones,
+//! so one sync run can add up the requests, bytes and failed attempts of
+//! every page it fetched concurrently.
+
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Failed attempts whose messages are kept; the rest are only counted
+const MAX_RECORDED_ERRORS: usize = 20;
+
+#[derive(Debug, Default)]
+pub struct TransferStats {
+    requests: AtomicU32,
+    failed_attempts: AtomicU32,
+    bytes_received: AtomicU64,
+    errors: Mutex<Vec<String>>,
+}
+
+impl TransferStats {
+    /// Attempts sent, retries included
+    pub fn requests(&self) -> u32 {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    /// Attempts that failed, whether or not they were retried
+    pub fn failed_attempts(&self) -> u32 {
+        self.failed_attempts.load(Ordering::Relaxed)
+    }
+
+    /// Response bytes read
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received.load(Ordering::Relaxed)
+    }
+
+    /// Messages of the first failed attempts, oldest first
+    pub fn errors(&self) -> Vec<String> {
+        self.errors
+            .lock()
+            .map(|errors| errors.clone())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn record_request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_failure(&self, path: &str, error: &impl std::fmt::Display) {
+        self.failed_attempts.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut errors) = self.errors.lock() {
+            if errors.len() < MAX_RECORDED_ERRORS {
+                errors.push(format!("{path}: {error}"));
+            }
+        }
+    }
+
+    pub(crate) fn record_bytes(&self, bytes: usize) {
+        self.bytes_received
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_counting_past_recorded_errors() {
+        let stats = TransferStats::default();
+        for attempt in 0..25 {
+            stats.record_request();
+            stats.record_failure("entries", &format!("timeout {attempt}"));
+        }
+        stats.record_bytes(1024);
+
+        assert_eq!(stats.requests(), 25);
+        assert_eq!(stats.failed_attempts(), 25);
+        assert_eq!(stats.bytes_received(), 1024);
+        let errors = stats.errors();
+        assert_eq!(errors.len(), MAX_RECORDED_ERRORS);
+        assert_eq!(errors[0], "entries: timeout 0");
+    }
+}
@@ -1,9 +1,10 @@
 use crate::utils::serde_helpers::{
     deserialize_i64_from_string_or_number, deserialize_option_i64_from_string_or_number,
-    serialize_i64_as_string,
+    serialize_i64_as_string, serialize_option_i64_as_string,
 };
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::collections::HashMap;
 
 /// Miniflux Category
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -176,6 +177,15 @@ pub struct Entry {
     pub feed: Feed,
     #[serde(default)]
     pub tags: Option<Vec<String>>,
+    /// Local account the entry was synced from, for the all-accounts view.
+    /// Not part of the Miniflux API.
+    #[serde(
+        default,
+        serialize_with = "serialize_option_i64_as_string",
+        deserialize_with = "deserialize_option_i64_from_string_or_number"
+    )]
+    #[specta(type = Option<String>)]
+    pub account_id: Option<i64>,
 }
 
 /// Entry Response (with pagination)
@@ -207,6 +217,15 @@ pub struct Enclosure {
     pub length: Option<i64>,
     #[serde(default)]
     pub position: i32,
+    /// Playback position in seconds, as stored by Miniflux
+    #[serde(default)]
+    pub media_progression: i64,
+}
+
+/// Enclosure Update
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct EnclosureUpdate {
+    pub media_progression: i64,
 }
 
 /// User
@@ -263,6 +282,15 @@ pub struct Counters {
     pub unread_count: i64,
 }
 
+/// Per-feed counters, keyed by feed ID
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct FeedCounters {
+    #[serde(default)]
+    pub reads: HashMap<String, i64>,
+    #[serde(default)]
+    pub unreads: HashMap<String, i64>,
+}
+
 /// Subscription (from discover)
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct Subscription {
@@ -279,11 +307,13 @@ pub struct EntryFilters {
     pub status: Option<String>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub offset: Option<i64>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub limit: Option<i64>,
@@ -293,41 +323,49 @@ pub struct EntryFilters {
     pub direction: Option<String>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub before: Option<i64>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub after: Option<i64>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub published_before: Option<i64>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub published_after: Option<i64>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub changed_before: Option<i64>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub changed_after: Option<i64>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub before_entry_id: Option<i64>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub after_entry_id: Option<i64>,
@@ -337,16 +375,24 @@ pub struct EntryFilters {
     pub search: Option<String>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub category_id: Option<i64>,
     #[serde(
         default,
+        serialize_with = "serialize_option_i64_as_string",
         deserialize_with = "deserialize_option_i64_from_string_or_number"
     )]
     pub feed_id: Option<i64>,
     #[serde(default)]
     pub globally_visible: Option<bool>,
+    /// Local tags; matches entries carrying all of them. Not sent to Miniflux.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// Spans every configured account instead of the active one. Local only.
+    #[serde(default)]
+    pub all_accounts: Option<bool>,
 }
 
 /// Feed Update
@@ -421,6 +467,8 @@ pub struct UserUpdate {
     #[serde(default)]
     pub entry_sorting_direction: Option<String>,
     #[serde(default)]
+    pub entry_sorting_order: Option<String>,
+    #[serde(default)]
     pub entries_per_page: Option<i32>,
     #[serde(default)]
     pub keyboard_shortcuts: Option<bool>,
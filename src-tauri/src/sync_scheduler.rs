@@ -0,0 +1,232 @@
+//! Background Miniflux sync scheduler.
+//!
+//! Runs `sync_all_accounts` every `AppPreferences.sync_interval` minutes from
+//! the Rust side, so syncing keeps going while the main window is hidden to
+//! tray, and accounts other than the active one don't go stale.
+//! Failed runs are retried with jittered exponential backoff, runs are skipped
+//! while another sync is in progress, and scheduling pauses while the
+//! frontend reports the network as offline or metered.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use tauri::{AppHandle, Manager};
+use tokio::sync::Notify;
+use tokio::time::{sleep_until, Instant};
+
+use crate::AppState;
+
+/// First retry delay after a failed scheduled sync.
+const BACKOFF_BASE: Duration = Duration::from_secs(30);
+
+/// Shared scheduler state, held in `AppState`.
+#[derive(Clone)]
+pub struct SyncSchedulerState {
+    /// Wakes the scheduler to re-read preferences or network status.
+    pub notify: Arc<Notify>,
+    /// Set while any Miniflux sync (manual or scheduled) is running.
+    pub running: Arc<AtomicBool>,
+    /// Last network status reported by the frontend.
+    pub online: Arc<AtomicBool>,
+    pub metered: Arc<AtomicBool>,
+}
+
+impl Default for SyncSchedulerState {
+    fn default() -> Self {
+        Self {
+            notify: Arc::new(Notify::new()),
+            running: Arc::new(AtomicBool::new(false)),
+            online: Arc::new(AtomicBool::new(true)),
+            metered: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl SyncSchedulerState {
+    /// Marks a sync as running. Returns `None` if one is already in progress.
+    pub fn try_begin_sync(&self) -> Option<SyncRunGuard> {
+        self.running
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| SyncRunGuard {
+                running: self.running.clone(),
+            })
+    }
+
+    pub fn is_sync_running(&self) -> bool {
+        self.running.load(Ordering::Acquire)
+    }
+
+    fn can_sync_on_network(&self) -> bool {
+        self.online.load(Ordering::Acquire) && !self.metered.load(Ordering::Acquire)
+    }
+}
+
+/// Clears the running flag when the sync finishes, including on early return.
+pub struct SyncRunGuard {
+    running: Arc<AtomicBool>,
+}
+
+impl Drop for SyncRunGuard {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Release);
+    }
+}
+
+/// Reads the configured interval. `None` means manual sync only.
+fn configured_interval(app: &AppHandle) -> Option<Duration> {
+    let minutes = crate::commands::preferences::load_preferences_sync(app)
+        .unwrap_or_default()
+        .sync_interval?;
+    (minutes > 0).then(|| Duration::from_secs(u64::from(minutes) * 60))
+}
+
+/// Random factor in `[0.8, 1.2)` so many clients don't retry in lockstep.
+fn jitter_factor() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    0.8 + (random % 10_000) as f64 / 10_000.0 * 0.4
+}
+
+/// Delay before the next run: the interval after a success, otherwise
+/// exponential backoff capped at the interval.
+fn next_delay(interval: Duration, consecutive_failures: u32) -> Duration {
+    if consecutive_failures == 0 {
+        return interval;
+    }
+
+    let exponent = consecutive_failures.saturating_sub(1).min(10);
+    let backoff = BACKOFF_BASE.saturating_mul(2_u32.pow(exponent));
+    backoff.min(interval).mul_f64(jitter_factor())
+}
+
+/// Background task: sleeps until the next scheduled run, then syncs.
+pub async fn run_sync_scheduler(app: AppHandle) {
+    let state: tauri::State<'_, AppState> = app.state();
+    let scheduler = state.sync_scheduler.clone();
+
+    let mut consecutive_failures = 0_u32;
+    let mut interval = configured_interval(&app);
+    let mut deadline = interval.map(|interval| Instant::now() + interval);
+    // Set when a run was skipped for network reasons, so it happens as soon
+    // as the network is usable again instead of a full interval later.
+    let mut missed_run = false;
+
+    loop {
+        match deadline {
+            Some(at) => {
+                tokio::select! {
+                    () = scheduler.notify.notified() => {
+                        let updated = configured_interval(&app);
+                        if updated != interval {
+                            info!("Sync scheduler: interval changed to {updated:?}, rescheduling");
+                            interval = updated;
+                            deadline = interval.map(|interval| Instant::now() + interval);
+                        } else if missed_run && scheduler.can_sync_on_network() {
+                            debug!("Sync scheduler: network available again, running missed sync");
+                            missed_run = false;
+                            deadline = Some(Instant::now());
+                        }
+                        continue;
+                    }
+                    () = sleep_until(at) => {}
+                }
+            }
+            None => {
+                // Manual sync only; wait for a preference change
+                scheduler.notify.notified().await;
+                interval = configured_interval(&app);
+                deadline = interval.map(|interval| Instant::now() + interval);
+                continue;
+            }
+        }
+
+        let Some(current_interval) = interval else {
+            deadline = None;
+            continue;
+        };
+
+        if !scheduler.can_sync_on_network() {
+            debug!("Sync scheduler: offline or metered network, skipping run");
+            missed_run = true;
+            deadline = Some(Instant::now() + current_interval);
+            continue;
+        }
+
+        if scheduler.is_sync_running() {
+            debug!("Sync scheduler: sync already in progress, skipping run");
+            deadline = Some(Instant::now() + current_interval);
+            continue;
+        }
+
+        // Every saved account is synced with a client built from its stored
+        // credentials, so a disconnected active account doesn't hold back the
+        // others; it reports its own error instead
+        debug!("Sync scheduler: starting scheduled sync");
+        let outcome = crate::commands::sync::sync_all_accounts(app.clone(), app.state())
+            .await
+            .and_then(|results| {
+                // Back off only when no account got through, e.g. offline;
+                // one broken account shouldn't slow down the others
+                match results.iter().find_map(|result| result.error.clone()) {
+                    Some(error) if results.iter().all(|result| result.error.is_some()) => {
+                        Err(error)
+                    }
+                    _ => Ok(()),
+                }
+            });
+        match outcome {
+            Ok(()) => consecutive_failures = 0,
+            Err(e) => {
+                consecutive_failures = consecutive_failures.saturating_add(1);
+                warn!("Scheduled sync failed ({consecutive_failures} in a row): {e}");
+            }
+        }
+        // Entries pulled by this run may be the ones other devices' reading
+        // data is waiting for
+        crate::commands::library_sync::sync_library_in_background(&app).await;
+
+        deadline = Some(Instant::now() + next_delay(current_interval, consecutive_failures));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_delay_uses_interval_after_success() {
+        let interval = Duration::from_secs(15 * 60);
+        assert_eq!(next_delay(interval, 0), interval);
+    }
+
+    #[test]
+    fn next_delay_backs_off_with_jitter_and_caps_at_interval() {
+        let interval = Duration::from_secs(15 * 60);
+
+        let first = next_delay(interval, 1);
+        assert!(first >= Duration::from_secs(24) && first < Duration::from_secs(36));
+
+        let third = next_delay(interval, 3);
+        assert!(third >= Duration::from_secs(96) && third < Duration::from_secs(144));
+
+        let capped = next_delay(interval, 20);
+        assert!(capped <= interval.mul_f64(1.2));
+    }
+
+    #[test]
+    fn sync_run_guard_is_exclusive_and_released_on_drop() {
+        let scheduler = SyncSchedulerState::default();
+
+        let guard = scheduler.try_begin_sync().expect("first sync should start");
+        assert!(scheduler.is_sync_running());
+        assert!(scheduler.try_begin_sync().is_none());
+
+        drop(guard);
+        assert!(!scheduler.is_sync_running());
+        assert!(scheduler.try_begin_sync().is_some());
+    }
+}
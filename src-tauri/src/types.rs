@@ -120,6 +120,29 @@ pub enum PlayerDisplayMode {
     TrayPopover,
 }
 
+/// How sync resolves an entry that was changed locally while the server copy also changed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum SyncConflictRule {
+    /// Keep the local value and push it again on the next sync.
+    LocalWins,
+    /// Take the server value and drop the queued local change.
+    ServerWins,
+    /// Keep whichever side changed most recently.
+    #[default]
+    NewestWins,
+}
+
+impl SyncConflictRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyncConflictRule::LocalWins => "local-wins",
+            SyncConflictRule::ServerWins => "server-wins",
+            SyncConflictRule::NewestWins => "newest-wins",
+        }
+    }
+}
+
 const fn default_ai_summary_max_text_length() -> u32 {
     100_000
 }
@@ -269,6 +292,9 @@ pub struct AppPreferences {
     /// Sync interval in minutes. None or 0 means manual sync only.
     #[serde(default)]
     pub sync_interval: Option<u32>,
+    /// How sync resolves entries edited both locally and on the server.
+    #[serde(default)]
+    pub sync_conflict_rule: SyncConflictRule,
     /// Whether to automatically check for app updates.
     #[serde(default = "default_auto_check_updates")]
     pub auto_check_updates: bool,
@@ -296,7 +322,7 @@ pub struct AppPreferences {
     /// Whether cloud sync is enabled.
     #[serde(default)]
     pub cloud_sync_enabled: bool,
-    /// Cloud sync protocol: "s3" or "webdav".
+    /// Cloud sync protocol: "s3", "webdav" or "folder".
     #[serde(default = "default_cloud_sync_protocol")]
     pub cloud_sync_protocol: String,
     /// S3-compatible endpoint URL (e.g., "https://s3.amazonaws.com").
@@ -320,12 +346,24 @@ pub struct AppPreferences {
     /// WebDAV file path for the sync file.
     #[serde(default = "default_cloud_sync_webdav_path")]
     pub cloud_sync_webdav_path: String,
+    /// Local folder for the "folder" protocol, e.g. one kept in sync by Syncthing.
+    #[serde(default)]
+    pub cloud_sync_folder_path: Option<String>,
     /// Whether to auto-pull from cloud on app startup.
     #[serde(default)]
     pub cloud_sync_auto_pull: bool,
     /// ISO 8601 timestamp of the last successful cloud sync operation.
     #[serde(default)]
     pub cloud_sync_last_synced: Option<String>,
+    /// Whether to encrypt pushed sync data with the passphrase in the keyring.
+    #[serde(default)]
+    pub cloud_sync_encryption_enabled: bool,
+    /// Whether to also sync reading position, podcast progress, summaries and tags.
+    #[serde(default)]
+    pub cloud_sync_library_enabled: bool,
+    /// Snapshots of the sync file kept remotely; 0 turns snapshots off.
+    #[serde(default = "default_cloud_sync_snapshot_retention")]
+    pub cloud_sync_snapshot_retention: u32,
 }
 
 /// Fields that are local-only and should not be synced to cloud.
@@ -335,6 +373,7 @@ const LOCAL_ONLY_FIELDS: &[&str] = &[
     "image_download_path",
     "video_download_path",
     "cloud_sync_last_synced",
+    "cloud_sync_folder_path",
 ];
 
 impl AppPreferences {
@@ -356,6 +395,7 @@ impl AppPreferences {
         let local_img_dl = self.image_download_path.take();
         let local_vid_dl = self.video_download_path.take();
         let local_last_synced = self.cloud_sync_last_synced.take();
+        let local_folder = self.cloud_sync_folder_path.take();
 
         *self = cloud.clone();
 
@@ -363,6 +403,7 @@ impl AppPreferences {
         self.image_download_path = local_img_dl;
         self.video_download_path = local_vid_dl;
         self.cloud_sync_last_synced = local_last_synced;
+        self.cloud_sync_folder_path = local_folder;
     }
 }
 
@@ -382,6 +423,10 @@ fn default_cloud_sync_object_key() -> String {
     "minikyu/preferences-sync.json".to_string()
 }
 
+fn default_cloud_sync_snapshot_retention() -> u32 {
+    crate::cloud_sync::snapshots::DEFAULT_SNAPSHOT_RETENTION
+}
+
 fn default_reader_code_detection_mode() -> String {
     "auto".to_string()
 }
@@ -481,6 +526,7 @@ impl Default for AppPreferences {
             log_level: default_log_level(),
             time_format: default_time_format(),
             sync_interval: Some(15),
+            sync_conflict_rule: SyncConflictRule::default(),
             auto_check_updates: true,
             auto_download_updates: true,
             gesture_swipe_left_action: default_gesture_swipe_left_action(),
@@ -498,8 +544,12 @@ impl Default for AppPreferences {
             cloud_sync_webdav_url: None,
             cloud_sync_webdav_username: None,
             cloud_sync_webdav_path: default_cloud_sync_webdav_path(),
+            cloud_sync_folder_path: None,
             cloud_sync_auto_pull: false,
             cloud_sync_last_synced: None,
+            cloud_sync_encryption_enabled: false,
+            cloud_sync_library_enabled: false,
+            cloud_sync_snapshot_retention: default_cloud_sync_snapshot_retention(),
         }
     }
 }
@@ -18,6 +18,20 @@ where
     serializer.serialize_str(&value.to_string())
 }
 
+/// Serialize Option<i64> as a string or null for JavaScript compatibility.
+pub fn serialize_option_i64_as_string<S>(
+    value: &Option<i64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_str(&value.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// Deserialize i64 from either string or number.
 ///
 /// Accepts both formats for flexibility:
@@ -173,7 +173,7 @@ export function DownloadManagerDialog() {
       return;
     }
 
-    const result = await commands.getEntry(entryResult.data);
+    const result = await commands.getEntry(entryResult.data, null);
     if (result.status !== 'ok') {
       toast.error(_(msg`Could not load podcast entry`));
       return;
@@ -66,6 +66,7 @@ import {
 import { confirm, message as showMessage } from '@/lib/dialog';
 import { pickTextFile, saveTextFile } from '@/lib/file-transfer';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { queryClient } from '@/lib/query-client';
 import type { Category, Feed } from '@/lib/tauri-bindings';
 import { commands } from '@/lib/tauri-bindings';
@@ -474,7 +475,9 @@ function AppSidebarContent({ children, className }: AppSidebarProps) {
       const date = new Date().toISOString().split('T')[0];
       const result = await commands.exportOpml();
       if (result.status === 'error') {
-        toast.error(_(msg`Failed to export OPML`), { description: result.error });
+        toast.error(_(msg`Failed to export OPML`), {
+          description: formatMinifluxError(result.error),
+        });
         return;
       }
 
@@ -514,7 +517,9 @@ function AppSidebarContent({ children, className }: AppSidebarProps) {
 
       const result = await commands.importOpml(opmlContent);
       if (result.status === 'error') {
-        toast.error(_(msg`Failed to import OPML`), { description: result.error });
+        toast.error(_(msg`Failed to import OPML`), {
+          description: formatMinifluxError(result.error),
+        });
         return;
       }
 
@@ -31,6 +31,11 @@ interface CachedSummary {
 }
 const summaryCache = new Map<string, CachedSummary>();
 
+// Entry IDs are only unique per account
+function summaryCacheKey(accountId: string | null, entryId: string): string {
+  return `${accountId ?? ''}:${entryId}`;
+}
+
 export interface ArticleSummaryHookState {
   summary: string | null;
   loading: boolean;
@@ -44,6 +49,7 @@ export interface ArticleSummaryHookState {
 
 export function useArticleSummary(
   entryId: string,
+  accountId: string | null,
   articleText: string,
   language?: string,
   autoSummarize = false
@@ -58,10 +64,13 @@ export function useArticleSummary(
   // Always-current entryId ref — avoids stale closure in the stream listener
   const currentEntryIdRef = useRef(entryId);
   currentEntryIdRef.current = entryId;
+  const currentAccountIdRef = useRef(accountId);
+  currentAccountIdRef.current = accountId;
 
   // Restore from cache/DB or reset when entry changes
   useEffect(() => {
-    const cached = summaryCache.get(entryId);
+    const cacheKey = summaryCacheKey(accountId, entryId);
+    const cached = summaryCache.get(cacheKey);
     if (cached) {
       setSummary(cached.summary);
       setModelUsed(cached.modelUsed);
@@ -79,15 +88,17 @@ export function useArticleSummary(
       // Try loading from DB. Summaries are generated and cached by the Rust
       // backend, so there is nothing to load in the web build.
       if (!capabilities.summaries) return;
-      commands.getArticleSummary(entryId).then((result) => {
+      commands.getArticleSummary(entryId, accountId).then((result) => {
         if (result.status !== 'ok' || !result.data) return;
         // Guard: only apply if still on the same entry
-        if (currentEntryIdRef.current !== entryId) return;
+        if (currentEntryIdRef.current !== entryId || currentAccountIdRef.current !== accountId) {
+          return;
+        }
         const { summary: dbSummary, provider_used: dbProvider, model_used: dbModel } = result.data;
         setSummary(dbSummary);
         setModelUsed(dbModel ?? null);
         setProviderUsed(dbProvider ?? null);
-        summaryCache.set(entryId, {
+        summaryCache.set(cacheKey, {
           summary: dbSummary,
           modelUsed: dbModel ?? null,
           providerUsed: dbProvider ?? null,
@@ -95,7 +106,7 @@ export function useArticleSummary(
       });
     }
     activeStreamIdRef.current = null;
-  }, [entryId]);
+  }, [entryId, accountId]);
 
   // Listen for streaming events
   useEffect(() => {
@@ -109,17 +120,24 @@ export function useArticleSummary(
           break;
         case 'done': {
           const currentId = currentEntryIdRef.current;
+          const currentAccountId = currentAccountIdRef.current;
           setSummary(data.text);
           setProviderUsed(data.provider_used);
           setModelUsed(data.model_used);
           setLoading(false);
           activeStreamIdRef.current = null;
-          summaryCache.set(currentId, {
+          summaryCache.set(summaryCacheKey(currentAccountId, currentId), {
             summary: data.text,
             modelUsed: data.model_used,
             providerUsed: data.provider_used,
           });
-          commands.saveArticleSummary(currentId, data.text, data.provider_used, data.model_used);
+          commands.saveArticleSummary(
+            currentId,
+            data.text,
+            data.provider_used,
+            data.model_used,
+            currentAccountId
+          );
           break;
         }
         case 'error':
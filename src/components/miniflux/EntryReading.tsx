@@ -45,6 +45,7 @@ import { detectSourceLanguage, useReaderSettings } from '@/hooks/use-reader-sett
 import { useShortcutConfig } from '@/hooks/use-shortcut-config';
 import { getGestureAction } from '@/lib/gesture-actions';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { getPodcastEnclosure } from '@/lib/podcast-utils';
 import { getReaderFontStack } from '@/lib/reader-fonts';
 import {
@@ -184,6 +185,7 @@ export function EntryReading({
   translationAutoEnabledRef.current = translationAutoEnabled;
   const articleSummary = useArticleSummary(
     entryId,
+    entry?.account_id ?? null,
     entry?.content ?? '',
     translationTargetLanguage ?? undefined,
     aiSummaryAutoEnabled
@@ -697,7 +699,7 @@ export function EntryReading({
     try {
       const result = await commands.saveEntry(entry.id);
       if (result.status === 'error') {
-        showToast.error(_(msg`Failed to save entry`), result.error);
+        showToast.error(_(msg`Failed to save entry`), formatMinifluxError(result.error));
         return;
       }
       showToast.success(_(msg`Entry sent to services`), entry.title);
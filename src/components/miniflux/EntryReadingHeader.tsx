@@ -50,7 +50,7 @@ import { Tooltip, TooltipPanel, TooltipTrigger } from '@/components/ui/tooltip';
 import { useReaderSettings } from '@/hooks/use-reader-settings';
 import type { Entry } from '@/lib/bindings';
 import { convertChineseText, normalizeCustomConversionRules } from '@/lib/chinese-conversion';
-import { formatCompactDate, formatShortDate } from '@/lib/miniflux-utils';
+import { formatCompactDate, formatMinifluxError, formatShortDate } from '@/lib/miniflux-utils';
 import { getPodcastEnclosure } from '@/lib/podcast-utils';
 import { normalizeReaderTheme, type ReaderTheme, readerThemeOptions } from '@/lib/reader-theme';
 import { type ReaderCodeTheme, readerCodeThemeOptions } from '@/lib/shiki-highlight';
@@ -275,7 +275,9 @@ export function EntryReadingHeader({
     try {
       const result = await commands.saveEntry(entry.id);
       if (result.status === 'error') {
-        toast.error(_(msg`Failed to save entry`), { description: result.error });
+        toast.error(_(msg`Failed to save entry`), {
+          description: formatMinifluxError(result.error),
+        });
         return;
       }
       setSavedToServices(true);
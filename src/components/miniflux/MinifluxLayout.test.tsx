@@ -137,8 +137,8 @@ vi.mock('@/lib/account-reset', () => ({
   resetAccountState: vi.fn().mockResolvedValue(undefined),
 }));
 
-vi.mock('@/hooks/use-auto-sync', () => ({
-  useAutoSync: vi.fn(),
+vi.mock('@/hooks/use-network-status', () => ({
+  useNetworkStatus: vi.fn(),
 }));
 
 i18n.load('en', {
@@ -26,13 +26,14 @@ import {
 import { MainWindowContent } from '@/components/layout/MainWindowContent';
 import { Button } from '@/components/ui/button';
 import { useAudioEngine } from '@/hooks/use-audio-engine';
-import { useAutoSync } from '@/hooks/use-auto-sync';
+import { useNetworkStatus } from '@/hooks/use-network-status';
 import { usePlayerCommandListener } from '@/hooks/use-player-command-listener';
 import { useSyncProgressListener } from '@/hooks/use-sync-progress-listener';
 import { resetAccountState } from '@/lib/account-reset';
 import { confirm } from '@/lib/dialog';
 import { capabilities } from '@/lib/platform';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { queryClient } from '@/lib/query-client';
 import type { EntryFilters } from '@/lib/tauri-bindings';
 import { commands } from '@/lib/tauri-bindings';
@@ -70,7 +71,7 @@ const STATUS_SEGMENTS = [
 export function MinifluxLayout() {
   const { _, i18n } = useLingui();
   useAudioEngine();
-  useAutoSync();
+  useNetworkStatus();
   usePlayerCommandListener();
   const search = useSearch({ from: '/' });
   const navigate = useNavigate({ from: '/' });
@@ -425,7 +426,7 @@ export function MinifluxLayout() {
 
     if (result.status === 'error') {
       toast.error(_(msg`Failed to flush history`), {
-        description: result.error,
+        description: formatMinifluxError(result.error),
       });
       return;
     }
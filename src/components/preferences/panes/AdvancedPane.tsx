@@ -40,6 +40,7 @@ import { useClipboard } from '@/hooks/use-clipboard';
 import { getPlatform } from '@/hooks/use-platform';
 import { pickTextFile, saveTextFile } from '@/lib/file-transfer';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { capabilities, isTauri } from '@/lib/platform';
 import type { AppPreferences, LocalDataSize } from '@/lib/tauri-bindings';
 import { commands } from '@/lib/tauri-bindings';
@@ -332,7 +333,7 @@ export function AdvancedPane() {
       const date = new Date().toISOString().split('T')[0];
       const result = await commands.exportOpml();
       if (result.status === 'error') {
-        showToast.error(_(msg`Failed to export OPML`), result.error);
+        showToast.error(_(msg`Failed to export OPML`), formatMinifluxError(result.error));
         return;
       }
 
@@ -373,7 +374,7 @@ export function AdvancedPane() {
 
       const result = await commands.importOpml(opmlContent);
       if (result.status === 'error') {
-        showToast.error(_(msg`Failed to import OPML`), result.error);
+        showToast.error(_(msg`Failed to import OPML`), formatMinifluxError(result.error));
         return;
       }
 
@@ -16,6 +16,7 @@ import {
   DialogTitle,
 } from '@/components/ui/dialog';
 import { Input } from '@/components/ui/input';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { queryClient } from '@/lib/query-client';
 import type { ApiKey } from '@/lib/tauri-bindings';
 import { commands } from '@/lib/tauri-bindings';
@@ -53,7 +54,7 @@ export function ApiTokenPane() {
 
       if (result.status === 'error') {
         toast.error(_(msg`Failed to update API token`), {
-          description: result.error,
+          description: formatMinifluxError(result.error),
         });
         return;
       }
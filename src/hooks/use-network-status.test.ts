@@ -0,0 +1,78 @@
+import { renderHook } from '@testing-library/react';
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest';
+import { useNetworkStatus } from './use-network-status';
+
+const { setNetworkStatusMock } = vi.hoisted(() => ({
+  setNetworkStatusMock: vi.fn(),
+}));
+
+vi.mock('@/lib/tauri-bindings', () => ({
+  commands: { setNetworkStatus: setNetworkStatusMock },
+}));
+
+vi.mock('@/lib/platform', () => ({
+  capabilities: { offlineSync: true },
+}));
+
+vi.mock('@/lib/logger', () => ({
+  logger: { debug: vi.fn(), info: vi.fn(), warn: vi.fn(), error: vi.fn() },
+}));
+
+function setOnline(online: boolean) {
+  Object.defineProperty(navigator, 'onLine', { configurable: true, get: () => online });
+}
+
+function setConnection(connection: object | undefined) {
+  Object.defineProperty(navigator, 'connection', { configurable: true, value: connection });
+}
+
+describe('useNetworkStatus', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    setNetworkStatusMock.mockResolvedValue({ status: 'ok', data: null });
+    setOnline(true);
+    setConnection(undefined);
+  });
+
+  afterEach(() => {
+    setConnection(undefined);
+  });
+
+  it('reports the current status on mount', () => {
+    renderHook(() => useNetworkStatus());
+
+    expect(setNetworkStatusMock).toHaveBeenCalledWith(true, false);
+  });
+
+  it('reports going offline and back online', () => {
+    renderHook(() => useNetworkStatus());
+
+    setOnline(false);
+    window.dispatchEvent(new Event('offline'));
+    expect(setNetworkStatusMock).toHaveBeenLastCalledWith(false, false);
+
+    setOnline(true);
+    window.dispatchEvent(new Event('online'));
+    expect(setNetworkStatusMock).toHaveBeenLastCalledWith(true, false);
+  });
+
+  it('treats a cellular connection as metered', () => {
+    const connection = Object.assign(new EventTarget(), { type: 'wifi' });
+    setConnection(connection);
+    renderHook(() => useNetworkStatus());
+    expect(setNetworkStatusMock).toHaveBeenLastCalledWith(true, false);
+
+    connection.type = 'cellular';
+    connection.dispatchEvent(new Event('change'));
+    expect(setNetworkStatusMock).toHaveBeenLastCalledWith(true, true);
+  });
+
+  it('stops listening on unmount', () => {
+    const { unmount } = renderHook(() => useNetworkStatus());
+    unmount();
+    setNetworkStatusMock.mockClear();
+
+    window.dispatchEvent(new Event('offline'));
+    expect(setNetworkStatusMock).not.toHaveBeenCalled();
+  });
+});
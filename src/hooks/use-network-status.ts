@@ -0,0 +1,40 @@
+import { useEffect } from 'react';
+import { logger } from '@/lib/logger';
+import { capabilities } from '@/lib/platform';
+import { commands } from '@/lib/tauri-bindings';
+
+/** The parts of the Network Information API we read; not in every webview. */
+type NetworkInformation = EventTarget & { type?: string; saveData?: boolean };
+
+/**
+ * Reports the webview's network status to the Rust sync scheduler, which runs
+ * the periodic sync and pauses it while offline or on a metered connection.
+ * Re-reports on `online`/`offline` and on connection type changes.
+ */
+export function useNetworkStatus() {
+  useEffect(() => {
+    if (!capabilities.offlineSync) return;
+
+    const connection = (navigator as Navigator & { connection?: NetworkInformation }).connection;
+
+    const report = async () => {
+      const online = navigator.onLine;
+      const metered = connection?.type === 'cellular' || connection?.saveData === true;
+      const result = await commands.setNetworkStatus(online, metered);
+      if (result.status === 'error') {
+        logger.warn('Failed to report network status', { error: result.error });
+      }
+    };
+
+    report();
+    window.addEventListener('online', report);
+    window.addEventListener('offline', report);
+    connection?.addEventListener('change', report);
+
+    return () => {
+      window.removeEventListener('online', report);
+      window.removeEventListener('offline', report);
+      connection?.removeEventListener('change', report);
+    };
+  }, []);
+}
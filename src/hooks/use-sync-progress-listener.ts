@@ -99,6 +99,13 @@ export function useSyncProgressListener() {
               queryClient.invalidateQueries({ queryKey: counterQueryKeys.all });
               break;
 
+            case 'EntriesPruned':
+              if (payload.count > 0) {
+                queryClient.invalidateQueries({ queryKey: entryQueryKeys.lists() });
+                queryClient.invalidateQueries({ queryKey: counterQueryKeys.all });
+              }
+              break;
+
             default:
               logger.warn('Unknown sync progress event:', { payload });
               break;
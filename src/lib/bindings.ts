@@ -117,7 +117,7 @@ async resumeDownload(url: string, fileName: string | null, mediaType: string | n
  * Clears synced data for the current active account only.
  * 
  * Removes entries, feeds, categories, enclosures, and sync state for the
- * active user. Other accounts' data, preferences, and downloads are preserved.
+ * active account. Other accounts' data, preferences, and downloads are preserved.
  */
 async clearLocalData() : Promise<Result<null, string>> {
     try {
@@ -152,6 +152,20 @@ async factoryReset() : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Checks, cleans up and compacts the local database.
+ * 
+ * Runs the integrity and foreign key checks, removes orphaned rows and
+ * expired translations, then checkpoints the WAL and vacuums.
+ */
+async runDatabaseMaintenance() : Promise<Result<DatabaseMaintenanceReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("run_database_maintenance") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Simple greeting command for demonstration purposes.
  */
@@ -487,7 +501,7 @@ async autoReconnectMiniflux() : Promise<Result<null, AccountError>> {
 /**
  * Connect to Miniflux server
  */
-async minifluxConnect(config: AuthConfig) : Promise<Result<boolean, string>> {
+async minifluxConnect(config: AuthConfig) : Promise<Result<boolean, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("miniflux_connect", { config }) };
 } catch (e) {
@@ -531,7 +545,7 @@ async getCategories() : Promise<Result<Category[], string>> {
 /**
  * Create a new category
  */
-async createCategory(title: string) : Promise<Result<Category, string>> {
+async createCategory(title: string) : Promise<Result<Category, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("create_category", { title }) };
 } catch (e) {
@@ -542,7 +556,7 @@ async createCategory(title: string) : Promise<Result<Category, string>> {
 /**
  * Update a category
  */
-async updateCategory(id: string, title: string) : Promise<Result<Category, string>> {
+async updateCategory(id: string, title: string) : Promise<Result<Category, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_category", { id, title }) };
 } catch (e) {
@@ -553,7 +567,7 @@ async updateCategory(id: string, title: string) : Promise<Result<Category, strin
 /**
  * Delete a category
  */
-async deleteCategory(id: string) : Promise<Result<null, string>> {
+async deleteCategory(id: string) : Promise<Result<null, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("delete_category", { id }) };
 } catch (e) {
@@ -606,11 +620,34 @@ async getEntriesList(filters: EntryFilters) : Promise<Result<EntryResponse, stri
 }
 },
 /**
- * Get a single entry
+ * Get a single entry, of `account_id` in the all-accounts view or else of the
+ * active account
+ */
+async getEntry(entryId: string, accountId: string | null) : Promise<Result<Entry, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_entry", { entryId, accountId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Fetch a feed's entries straight from the Miniflux server
+ */
+async fetchFeedEntries(feedId: string, filters: EntryFilters) : Promise<Result<EntryResponse, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("fetch_feed_entries", { feedId, filters }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Fetch a category's entries straight from the Miniflux server
  */
-async getEntry(entryId: string) : Promise<Result<Entry, string>> {
+async fetchCategoryEntries(categoryId: string, filters: EntryFilters) : Promise<Result<EntryResponse, MinifluxError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_entry", { entryId }) };
+    return { status: "ok", data: await TAURI_INVOKE("fetch_category_entries", { categoryId, filters }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -618,30 +655,37 @@ async getEntry(entryId: string) : Promise<Result<Entry, string>> {
 },
 /**
  * Mark entry as read
+ * 
+ * Local-first: the DB is the source of truth for the entry list and History,
+ * so it is updated before the API call and the update survives an offline or
+ * failing server.
  */
-async markEntryRead(id: string) : Promise<Result<null, string>> {
+async markEntryRead(id: string, accountId: string | null) : Promise<Result<null, MinifluxError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("mark_entry_read", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("mark_entry_read", { id, accountId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Mark multiple entries as read
+ * Mark multiple entries of one account as read
  */
-async markEntriesRead(ids: string[]) : Promise<Result<null, string>> {
+async markEntriesRead(ids: string[], accountId: string | null) : Promise<Result<null, MinifluxError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("mark_entries_read", { ids }) };
+    return { status: "ok", data: await TAURI_INVOKE("mark_entries_read", { ids, accountId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Mark all entries in a feed as read
+ * Mark all entries in a feed as read.
+ * 
+ * Local-first like `mark_entries_read`: unread entries of the feed are marked
+ * read in SQLite and queued, then the feed-wide call is made to Miniflux.
  */
-async markFeedAsRead(id: string) : Promise<Result<null, string>> {
+async markFeedAsRead(id: string) : Promise<Result<null, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("mark_feed_as_read", { id }) };
 } catch (e) {
@@ -650,9 +694,11 @@ async markFeedAsRead(id: string) : Promise<Result<null, string>> {
 }
 },
 /**
- * Mark all entries in a category as read
+ * Mark all entries in a category as read.
+ * 
+ * Local-first like `mark_feed_as_read`.
  */
-async markCategoryAsRead(id: string) : Promise<Result<null, string>> {
+async markCategoryAsRead(id: string) : Promise<Result<null, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("mark_category_as_read", { id }) };
 } catch (e) {
@@ -661,22 +707,52 @@ async markCategoryAsRead(id: string) : Promise<Result<null, string>> {
 }
 },
 /**
- * Toggle entry read status between "read" and "unread"
+ * Mark every entry of the active account as read.
+ * 
+ * Local-first like `mark_feed_as_read`, using Miniflux's user-wide endpoint.
+ */
+async markAllAsRead() : Promise<Result<null, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("mark_all_as_read") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Mark entries published before `before` (RFC 3339) as read, optionally
+ * limited to one feed or category. Returns the number of entries marked.
+ * 
+ * Miniflux has no "before" variant of its mark-all endpoints, so the affected
+ * entry IDs are pushed explicitly.
+ */
+async markEntriesReadBefore(before: string, feedId: string | null, categoryId: string | null) : Promise<Result<number, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("mark_entries_read_before", { before, feedId, categoryId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Toggle entry read status between "read" and "unread", on `account_id` in the
+ * all-accounts view or else on the active account
  */
-async toggleEntryRead(id: string) : Promise<Result<string, string>> {
+async toggleEntryRead(id: string, accountId: string | null) : Promise<Result<string, MinifluxError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("toggle_entry_read", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("toggle_entry_read", { id, accountId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Toggle entry star
+ * Toggle entry star, on `account_id` in the all-accounts view or else on the
+ * active account
  */
-async toggleEntryStar(id: string) : Promise<Result<boolean, string>> {
+async toggleEntryStar(id: string, accountId: string | null) : Promise<Result<boolean, MinifluxError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("toggle_entry_star", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("toggle_entry_star", { id, accountId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -685,7 +761,7 @@ async toggleEntryStar(id: string) : Promise<Result<boolean, string>> {
 /**
  * Update entry
  */
-async updateEntry(id: string, updates: EntryUpdate) : Promise<Result<Entry, string>> {
+async updateEntry(id: string, updates: EntryUpdate) : Promise<Result<Entry, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_entry", { id, updates }) };
 } catch (e) {
@@ -696,7 +772,7 @@ async updateEntry(id: string, updates: EntryUpdate) : Promise<Result<Entry, stri
 /**
  * Refresh a feed
  */
-async refreshFeed(id: string) : Promise<Result<null, string>> {
+async refreshFeed(id: string) : Promise<Result<null, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("refresh_feed", { id }) };
 } catch (e) {
@@ -707,7 +783,7 @@ async refreshFeed(id: string) : Promise<Result<null, string>> {
 /**
  * Refresh all feeds
  */
-async refreshAllFeeds() : Promise<Result<null, string>> {
+async refreshAllFeeds() : Promise<Result<null, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("refresh_all_feeds") };
 } catch (e) {
@@ -715,10 +791,54 @@ async refreshAllFeeds() : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Refresh every feed in a category
+ */
+async refreshCategory(id: string) : Promise<Result<null, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("refresh_category", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Fetch a category's feeds straight from the Miniflux server
+ */
+async fetchCategoryFeeds(categoryId: string) : Promise<Result<Feed[], MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("fetch_category_feeds", { categoryId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get per-feed read and unread counts from the Miniflux server
+ */
+async getFeedCounters() : Promise<Result<FeedCounters, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_feed_counters") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get an icon by its ID
+ */
+async getIcon(iconId: string) : Promise<Result<Icon, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_icon", { iconId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Create a new feed
  */
-async createFeed(feedUrl: string, categoryId: string | null) : Promise<Result<string, string>> {
+async createFeed(feedUrl: string, categoryId: string | null) : Promise<Result<string, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("create_feed", { feedUrl, categoryId }) };
 } catch (e) {
@@ -729,7 +849,7 @@ async createFeed(feedUrl: string, categoryId: string | null) : Promise<Result<st
 /**
  * Update a feed
  */
-async updateFeed(id: string, updates: FeedUpdate) : Promise<Result<Feed, string>> {
+async updateFeed(id: string, updates: FeedUpdate) : Promise<Result<Feed, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_feed", { id, updates }) };
 } catch (e) {
@@ -740,7 +860,7 @@ async updateFeed(id: string, updates: FeedUpdate) : Promise<Result<Feed, string>
 /**
  * Delete a feed
  */
-async deleteFeed(id: string) : Promise<Result<null, string>> {
+async deleteFeed(id: string) : Promise<Result<null, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("delete_feed", { id }) };
 } catch (e) {
@@ -751,7 +871,7 @@ async deleteFeed(id: string) : Promise<Result<null, string>> {
 /**
  * Get current user
  */
-async getCurrentUser() : Promise<Result<User, string>> {
+async getCurrentUser() : Promise<Result<User, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_current_user") };
 } catch (e) {
@@ -762,7 +882,7 @@ async getCurrentUser() : Promise<Result<User, string>> {
 /**
  * Get all users
  */
-async getUsers() : Promise<Result<User[], string>> {
+async getUsers() : Promise<Result<User[], MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_users") };
 } catch (e) {
@@ -773,7 +893,7 @@ async getUsers() : Promise<Result<User[], string>> {
 /**
  * Create a new user
  */
-async createUser(user: UserCreate) : Promise<Result<User, string>> {
+async createUser(user: UserCreate) : Promise<Result<User, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("create_user", { user }) };
 } catch (e) {
@@ -784,7 +904,7 @@ async createUser(user: UserCreate) : Promise<Result<User, string>> {
 /**
  * Update a user
  */
-async updateUser(id: string, updates: UserUpdate) : Promise<Result<User, string>> {
+async updateUser(id: string, updates: UserUpdate) : Promise<Result<User, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_user", { id, updates }) };
 } catch (e) {
@@ -795,7 +915,7 @@ async updateUser(id: string, updates: UserUpdate) : Promise<Result<User, string>
 /**
  * Delete a user
  */
-async deleteUser(id: string) : Promise<Result<null, string>> {
+async deleteUser(id: string) : Promise<Result<null, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("delete_user", { id }) };
 } catch (e) {
@@ -803,10 +923,44 @@ async deleteUser(id: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Update the current user's reading settings, such as `entries_per_page`
+ * and `entry_sorting_direction`
+ */
+async updateUserPreferences(updates: UserUpdate) : Promise<Result<User, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_user_preferences", { updates }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get an enclosure with its media progression
+ */
+async getEnclosure(id: string) : Promise<Result<Enclosure, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_enclosure", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Save an enclosure's playback position (seconds) on the Miniflux server
+ */
+async updateEnclosureProgression(id: string, mediaProgression: number) : Promise<Result<null, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_enclosure_progression", { id, mediaProgression }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Get all API keys
  */
-async getApiKeys() : Promise<Result<ApiKey[], string>> {
+async getApiKeys() : Promise<Result<ApiKey[], MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_api_keys") };
 } catch (e) {
@@ -817,7 +971,7 @@ async getApiKeys() : Promise<Result<ApiKey[], string>> {
 /**
  * Create a new API key
  */
-async createApiKey(request: ApiKeyCreate) : Promise<Result<ApiKey, string>> {
+async createApiKey(request: ApiKeyCreate) : Promise<Result<ApiKey, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("create_api_key", { request }) };
 } catch (e) {
@@ -828,7 +982,7 @@ async createApiKey(request: ApiKeyCreate) : Promise<Result<ApiKey, string>> {
 /**
  * Delete an API key
  */
-async deleteApiKey(id: string) : Promise<Result<null, string>> {
+async deleteApiKey(id: string) : Promise<Result<null, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("delete_api_key", { id }) };
 } catch (e) {
@@ -839,7 +993,7 @@ async deleteApiKey(id: string) : Promise<Result<null, string>> {
 /**
  * Save entry to third-party services (Pocket, Wallabag, etc.)
  */
-async saveEntry(id: string) : Promise<Result<null, string>> {
+async saveEntry(id: string) : Promise<Result<null, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("save_entry", { id }) };
 } catch (e) {
@@ -850,7 +1004,7 @@ async saveEntry(id: string) : Promise<Result<null, string>> {
 /**
  * Get counters
  */
-async getCounters() : Promise<Result<Counters, string>> {
+async getCounters() : Promise<Result<Counters, MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_counters") };
 } catch (e) {
@@ -861,7 +1015,7 @@ async getCounters() : Promise<Result<Counters, string>> {
 /**
  * Discover subscriptions from URL
  */
-async discoverSubscriptions(url: string) : Promise<Result<Subscription[], string>> {
+async discoverSubscriptions(url: string) : Promise<Result<Subscription[], MinifluxError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("discover_subscriptions", { url }) };
 } catch (e) {
@@ -870,112 +1024,441 @@ async discoverSubscriptions(url: string) : Promise<Result<Subscription[], string
 }
 },
 /**
- * Export OPML — tries online API first, falls back to generating from local cache
+ * Export OPML — tries online API first, falls back to generating from local cache
+ */
+async exportOpml() : Promise<Result<string, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_opml") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Import OPML
+ */
+async importOpml(opmlContent: string) : Promise<Result<null, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_opml", { opmlContent }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Export OPML from the server straight into `path` without buffering it in
+ * memory. Returns the number of bytes written.
+ */
+async exportOpmlToFile(path: string) : Promise<Result<string, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_opml_to_file", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Import OPML by streaming the file at `path` to the server
+ */
+async importOpmlFromFile(path: string) : Promise<Result<null, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_opml_from_file", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get Miniflux version information
+ */
+async getMinifluxVersion() : Promise<Result<MinifluxVersion, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_miniflux_version") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get user integration settings
+ */
+async getIntegrations() : Promise<Result<Integration, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_integrations") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Fetch original article content
+ */
+async fetchEntryContent(id: string, updateContent: boolean) : Promise<Result<string, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("fetch_entry_content", { id, updateContent }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Flush history (delete all read entries from Miniflux server)
+ */
+async flushHistory() : Promise<Result<null, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("flush_history") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get feed icon as a data URL. Checks local cache first, then fetches from server.
+ */
+async getFeedIconData(feedId: string) : Promise<Result<string | null, MinifluxError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_feed_icon_data", { feedId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async syncMiniflux() : Promise<Result<SyncSummary, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("sync_miniflux") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async forceFullSync() : Promise<Result<SyncSummary, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("force_full_sync") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Syncs every saved account at once, each with its own client and
+ * `sync_state`. The active account reports on `sync-progress` and
+ * `sync-completed` as usual; the others on `account-sync-progress` and
+ * `account-sync-completed`.
+ */
+async syncAllAccounts() : Promise<Result<AccountSyncResult[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("sync_all_accounts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Re-crawls one feed on the server and pulls just its changed entries
+ */
+async syncFeed(feedId: string) : Promise<Result<SyncSummary, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("sync_feed", { feedId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Re-crawls a category's feeds on the server and pulls just their changed entries
+ */
+async syncCategory(categoryId: string) : Promise<Result<SyncSummary, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("sync_category", { categoryId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getSyncStatus() : Promise<Result<SyncStatus | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_sync_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Returns recently recorded sync conflicts for the active account, newest first
+ */
+async getSyncConflicts(limit: number | null) : Promise<Result<SyncConflict[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_sync_conflicts", { limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Clears the recorded sync conflicts for the active account
+ */
+async clearSyncConflicts() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_sync_conflicts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Returns recorded sync runs, newest first, for one account or all of them
+ */
+async getSyncHistory(accountId: string | null, limit: number | null) : Promise<Result<SyncRun[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_sync_history", { accountId, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reports the network status seen by the webview (`navigator.onLine` and the
+ * Network Information API) so the background scheduler can pause while
+ * offline or on a metered connection.
+ */
+async setNetworkStatus(online: boolean, metered: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_network_status", { online, metered }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get unread counts from local database, for the active account or with
+ * `all_accounts` summed over every configured one
+ */
+async getUnreadCounts(allAccounts: boolean | null) : Promise<Result<UnreadCounts, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_unread_counts", { allAccounts }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Full-text search over cached entries.
+ * 
+ * Supports `"exact phrases"`, `prefix*` and `feed:`/`author:`/`title:`
+ * qualifiers; see `build_fts_query`.
+ */
+async searchEntries(query: string, limit: number | null, offset: number | null) : Promise<Result<EntrySearchHit[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("search_entries", { query, limit, offset }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List the active account's tags with entry counts
+ */
+async listTags() : Promise<Result<TagSummary[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_tags") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Add tags to one or more entries
+ */
+async addEntryTags(entryIds: string[], tags: string[]) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_entry_tags", { entryIds, tags }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Remove tags from one or more entries
+ */
+async removeEntryTags(entryIds: string[], tags: string[]) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_entry_tags", { entryIds, tags }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Rename a tag across all entries
+ */
+async renameTag(from: string, to: string) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rename_tag", { from, to }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Remove a tag from all entries
+ */
+async deleteTag(tag: string) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_tag", { tag }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List the active account's smart folders
+ */
+async listSmartFolders() : Promise<Result<SmartFolder[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_smart_folders") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Save a named entry filter as a smart folder
+ */
+async createSmartFolder(name: string, filters: EntryFilters) : Promise<Result<SmartFolder, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_smart_folder", { name, filters }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Rename a smart folder or replace its filters
+ */
+async updateSmartFolder(id: string, name: string, filters: EntryFilters) : Promise<Result<SmartFolder, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_smart_folder", { id, name, filters }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Delete a smart folder
  */
-async exportOpml() : Promise<Result<string, string>> {
+async deleteSmartFolder(id: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("export_opml") };
+    return { status: "ok", data: await TAURI_INVOKE("delete_smart_folder", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Import OPML
+ * List the active account's filter rules
  */
-async importOpml(opmlContent: string) : Promise<Result<null, string>> {
+async listFilterRules() : Promise<Result<FilterRule[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("import_opml", { opmlContent }) };
+    return { status: "ok", data: await TAURI_INVOKE("list_filter_rules") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get Miniflux version information
+ * Create a filter rule. It applies to entries pulled by later syncs.
  */
-async getMinifluxVersion() : Promise<Result<MinifluxVersion, string>> {
+async createFilterRule(rule: FilterRuleInput) : Promise<Result<FilterRule, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_miniflux_version") };
+    return { status: "ok", data: await TAURI_INVOKE("create_filter_rule", { rule }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get user integration settings
+ * Replace a filter rule's settings
  */
-async getIntegrations() : Promise<Result<Integration, string>> {
+async updateFilterRule(id: string, rule: FilterRuleInput) : Promise<Result<FilterRule, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_integrations") };
+    return { status: "ok", data: await TAURI_INVOKE("update_filter_rule", { id, rule }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Fetch original article content
+ * Delete a filter rule and unhide the entries it hid
  */
-async fetchEntryContent(id: string, updateContent: boolean) : Promise<Result<string, string>> {
+async deleteFilterRule(id: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("fetch_entry_content", { id, updateContent }) };
+    return { status: "ok", data: await TAURI_INVOKE("delete_filter_rule", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Flush history (delete all read entries from Miniflux server)
+ * Report which cached entries a rule would match, without applying it
  */
-async flushHistory() : Promise<Result<null, string>> {
+async dryRunFilterRule(rule: FilterRuleInput, limit: number | null) : Promise<Result<FilterRuleDryRun, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("flush_history") };
+    return { status: "ok", data: await TAURI_INVOKE("dry_run_filter_rule", { rule, limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get feed icon as a data URL. Checks local cache first, then fetches from server.
+ * Show entries hidden by filter rules again; all of them if `rule_id` is omitted
  */
-async getFeedIconData(feedId: string) : Promise<Result<string | null, string>> {
+async unhideFilteredEntries(ruleId: string | null) : Promise<Result<number, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_feed_icon_data", { feedId }) };
+    return { status: "ok", data: await TAURI_INVOKE("unhide_filtered_entries", { ruleId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async syncMiniflux() : Promise<Result<SyncSummary, string>> {
+/**
+ * List the active account's retention policies, account default first
+ */
+async getRetentionPolicies() : Promise<Result<RetentionPolicy[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("sync_miniflux") };
+    return { status: "ok", data: await TAURI_INVOKE("get_retention_policies") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async forceFullSync() : Promise<Result<SyncSummary, string>> {
+/**
+ * Set the retention policy for a feed, or the account default when `feed_id` is omitted
+ */
+async setRetentionPolicy(feedId: string | null, policy: RetentionPolicyInput) : Promise<Result<RetentionPolicy, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("force_full_sync") };
+    return { status: "ok", data: await TAURI_INVOKE("set_retention_policy", { feedId, policy }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getSyncStatus() : Promise<Result<SyncStatus | null, string>> {
+/**
+ * Remove a feed's retention policy so it falls back to the account default
+ */
+async deleteRetentionPolicy(feedId: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_sync_status") };
+    return { status: "ok", data: await TAURI_INVOKE("delete_retention_policy", { feedId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get unread counts from local database
+ * Prune the active account's cached entries now instead of after the next sync
  */
-async getUnreadCounts() : Promise<Result<UnreadCounts, string>> {
+async applyRetentionPolicies() : Promise<Result<number, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_unread_counts") };
+    return { status: "ok", data: await TAURI_INVOKE("apply_retention_policies") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -1094,17 +1577,17 @@ async detectCodeLanguage(code: string) : Promise<Result<string, string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async getArticleSummary(entryId: string) : Promise<Result<ArticleSummaryRecord | null, string>> {
+async getArticleSummary(entryId: string, accountId: string | null) : Promise<Result<ArticleSummaryRecord | null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_article_summary", { entryId }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_article_summary", { entryId, accountId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async saveArticleSummary(entryId: string, summary: string, providerUsed: string | null, modelUsed: string | null) : Promise<Result<null, string>> {
+async saveArticleSummary(entryId: string, summary: string, providerUsed: string | null, modelUsed: string | null, accountId: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_article_summary", { entryId, summary, providerUsed, modelUsed }) };
+    return { status: "ok", data: await TAURI_INVOKE("save_article_summary", { entryId, summary, providerUsed, modelUsed, accountId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -1388,6 +1871,28 @@ async cloudSyncHasWebdavCredentials() : Promise<Result<boolean, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Save the passphrase sync data is encrypted with to keyring.
+ */
+async cloudSyncSaveEncryptionPassphrase(passphrase: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cloud_sync_save_encryption_passphrase", { passphrase }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Check if an encryption passphrase exists in keyring.
+ */
+async cloudSyncHasEncryptionPassphrase() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cloud_sync_has_encryption_passphrase") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Test S3 connection. Uses provided credentials, or falls back to keyring if empty.
  */
@@ -1411,7 +1916,11 @@ async cloudSyncTestWebdavConnection(url: string, username: string, password: str
 }
 },
 /**
- * Push current preferences + server URLs to remote storage.
+ * Merge current preferences + server URLs into the remote sync file.
+ * 
+ * The upload is conditional on the remote file being the version that was
+ * merged with, so a push racing another device's merges again instead of
+ * overwriting it. Remote changes the merge takes are applied locally too.
  */
 async cloudSyncPush() : Promise<Result<null, string>> {
     try {
@@ -1422,7 +1931,10 @@ async cloudSyncPush() : Promise<Result<null, string>> {
 }
 },
 /**
- * Pull preferences + server URLs from remote storage.
+ * Merge preferences + server URLs from remote storage into local ones.
+ * 
+ * Local changes the remote file doesn't have yet are kept and pushed on the
+ * next debounced push.
  */
 async cloudSyncPull() : Promise<Result<AppPreferences, string>> {
     try {
@@ -1431,6 +1943,76 @@ async cloudSyncPull() : Promise<Result<AppPreferences, string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+/**
+ * Preference conflicts settled by cloud sync merges, oldest first.
+ */
+async cloudSyncGetConflicts() : Promise<Result<PreferenceConflict[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cloud_sync_get_conflicts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Clear the recorded preference conflicts.
+ */
+async cloudSyncClearConflicts() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cloud_sync_clear_conflicts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List remote snapshots of the preferences sync file, newest first.
+ */
+async cloudSyncListSnapshots() : Promise<Result<SnapshotInfo[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cloud_sync_list_snapshots") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Preferences that would change if the snapshot were restored.
+ */
+async cloudSyncPreviewSnapshot(snapshotId: string) : Promise<Result<PreferenceDiff[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cloud_sync_preview_snapshot", { snapshotId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restore the synced preferences from a snapshot.
+ * 
+ * The restored values are saved as local changes, so the next push spreads
+ * them to other devices and wins over what they changed before.
+ */
+async cloudSyncRestoreSnapshot(snapshotId: string) : Promise<Result<AppPreferences, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cloud_sync_restore_snapshot", { snapshotId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Merge local reading data with the remote library file and apply what
+ * other devices changed.
+ */
+async cloudSyncLibrary() : Promise<Result<LibrarySyncSummary, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cloud_sync_library") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -1464,6 +2046,10 @@ export type AccountError =
  * Database operation failed
  */
 { type: "DatabaseError"; data: string }
+/**
+ * How one account's run in `sync_all_accounts` went
+ */
+export type AccountSyncResult = { account_id: string; summary: SyncSummary | null; error: string | null }
 /**
  * Per-account translation exclusion settings.
  * Keyed by `server_url|username` to isolate exclusions per Miniflux account.
@@ -1699,6 +2285,10 @@ time_format?: string;
  * Sync interval in minutes. None or 0 means manual sync only.
  */
 sync_interval?: number | null; 
+/**
+ * How sync resolves entries edited both locally and on the server.
+ */
+sync_conflict_rule?: SyncConflictRule; 
 /**
  * Whether to automatically check for app updates.
  */
@@ -1736,7 +2326,7 @@ layout_entry_list_width?: number | null;
  */
 cloud_sync_enabled?: boolean; 
 /**
- * Cloud sync protocol: "s3" or "webdav".
+ * Cloud sync protocol: "s3", "webdav" or "folder".
  */
 cloud_sync_protocol?: string; 
 /**
@@ -1767,6 +2357,10 @@ cloud_sync_webdav_username?: string | null;
  * WebDAV file path for the sync file.
  */
 cloud_sync_webdav_path?: string; 
+/**
+ * Local folder for the "folder" protocol, e.g. one kept in sync by Syncthing.
+ */
+cloud_sync_folder_path?: string | null; 
 /**
  * Whether to auto-pull from cloud on app startup.
  */
@@ -1774,7 +2368,19 @@ cloud_sync_auto_pull?: boolean;
 /**
  * ISO 8601 timestamp of the last successful cloud sync operation.
  */
-cloud_sync_last_synced?: string | null }
+cloud_sync_last_synced?: string | null; 
+/**
+ * Whether to encrypt pushed sync data with the passphrase in the keyring.
+ */
+cloud_sync_encryption_enabled?: boolean; 
+/**
+ * Whether to also sync reading position, podcast progress, summaries and tags.
+ */
+cloud_sync_library_enabled?: boolean; 
+/**
+ * Snapshots of the sync file kept remotely; 0 turns snapshots off.
+ */
+cloud_sync_snapshot_retention?: number }
 export type ArticleSummaryRecord = { entry_id: string; summary: string; provider_used: string | null; model_used: string | null }
 /**
  * Authentication Config
@@ -1856,6 +2462,38 @@ bytes: string;
  * Whether the file/directory exists
  */
 exists: boolean }
+/**
+ * Result of [`run_database_maintenance`].
+ */
+export type DatabaseMaintenanceReport = { 
+/**
+ * Whether `PRAGMA integrity_check` passed after any repair
+ */
+integrity_ok: boolean; 
+/**
+ * Problems reported by the first integrity check
+ */
+integrity_errors: string[]; 
+/**
+ * Whether a REINDEX was run to repair a failed integrity check
+ */
+reindexed: boolean; 
+/**
+ * `PRAGMA foreign_key_check` rows left after orphan cleanup, as `table(rowid) -> parent`
+ */
+foreign_key_violations: string[]; orphans_removed: OrphanedRows; expired_translations_removed: string; 
+/**
+ * Whether the database was vacuumed (skipped when integrity still fails)
+ */
+vacuumed: boolean; 
+/**
+ * Database plus WAL size before maintenance
+ */
+bytes_before: string; 
+/**
+ * Database plus WAL size after maintenance
+ */
+bytes_after: string; bytes_reclaimed: string }
 /**
  * Download state managed by download manager
  */
@@ -1883,19 +2521,52 @@ export type DownloadState =
 /**
  * Enclosure (for podcasts/videos)
  */
-export type Enclosure = { id: string; entry_id: string; url: string; mime_type: string; length?: string | null; position?: number }
+export type Enclosure = { id: string; entry_id: string; url: string; mime_type: string; length?: string | null; position?: number; 
+/**
+ * Playback position in seconds, as stored by Miniflux
+ */
+media_progression?: string }
 /**
  * Miniflux Entry
  */
-export type Entry = { id: string; user_id: string; feed_id: string; title: string; url: string; comments_url?: string | null; author?: string | null; content?: string | null; hash: string; published_at: string; created_at?: string | null; changed_at?: string | null; status: string; share_code?: string | null; starred?: boolean; reading_time?: number | null; enclosures?: Enclosure[] | null; feed: Feed; tags?: string[] | null }
+export type Entry = { id: string; user_id: string; feed_id: string; title: string; url: string; comments_url?: string | null; author?: string | null; content?: string | null; hash: string; published_at: string; created_at?: string | null; changed_at?: string | null; status: string; share_code?: string | null; starred?: boolean; reading_time?: number | null; enclosures?: Enclosure[] | null; feed: Feed; tags?: string[] | null; 
+/**
+ * Local account the entry was synced from, for the all-accounts view.
+ * Not part of the Miniflux API.
+ */
+account_id?: string | null }
 /**
  * Entry Filters
  */
-export type EntryFilters = { status?: string | null; offset?: string | null; limit?: string | null; order?: string | null; direction?: string | null; before?: string | null; after?: string | null; published_before?: string | null; published_after?: string | null; changed_before?: string | null; changed_after?: string | null; before_entry_id?: string | null; after_entry_id?: string | null; starred?: boolean | null; search?: string | null; category_id?: string | null; feed_id?: string | null; globally_visible?: boolean | null }
+export type EntryFilters = { status?: string | null; offset?: string | null; limit?: string | null; order?: string | null; direction?: string | null; before?: string | null; after?: string | null; published_before?: string | null; published_after?: string | null; changed_before?: string | null; changed_after?: string | null; before_entry_id?: string | null; after_entry_id?: string | null; starred?: boolean | null; search?: string | null; category_id?: string | null; feed_id?: string | null; globally_visible?: boolean | null; 
+/**
+ * Local tags; matches entries carrying all of them. Not sent to Miniflux.
+ */
+tags?: string[] | null; 
+/**
+ * Spans every configured account instead of the active one. Local only.
+ */
+all_accounts?: boolean | null }
 /**
  * Entry Response (with pagination)
  */
 export type EntryResponse = { total: string; entries?: Entry[] | null }
+/**
+ * A full-text search result, best match first.
+ */
+export type EntrySearchHit = { entry_id: string; feed_id: string; feed_title: string | null; title: string; 
+/**
+ * Title as HTML with matched terms wrapped in `<mark>`.
+ */
+title_highlight: string; author: string | null; url: string; published_at: string; status: string; starred: boolean; 
+/**
+ * Content excerpt around the best match, as HTML with `<mark>` tags.
+ */
+snippet: string; 
+/**
+ * Relevance score (negated BM25); higher is better.
+ */
+score: number }
 /**
  * Entry Update
  */
@@ -1904,6 +2575,10 @@ export type EntryUpdate = { title?: string | null; content?: string | null }
  * Miniflux Feed
  */
 export type Feed = { id: string; user_id: string; title: string; site_url: string; feed_url: string; category: Category | null; icon: FeedIcon | null; checked_at?: string | null; etag_header?: string | null; last_modified_header?: string | null; parsing_error_message?: string | null; parsing_error_count?: number; scraper_rules?: string | null; rewrite_rules?: string | null; crawler?: boolean; blocklist_rules?: string | null; keeplist_rules?: string | null; user_agent?: string | null; username?: string | null; password?: string | null; disabled?: boolean; ignore_http_cache?: boolean; fetch_via_proxy?: boolean; no_media_player?: boolean; allow_self_signed_certificates?: boolean; urlrewrite_rules?: string | null; cookie?: string | null; apprise_service_urls?: string | null; hide_globally?: boolean; created_at?: string | null; updated_at?: string | null }
+/**
+ * Per-feed counters, keyed by feed ID
+ */
+export type FeedCounters = { reads?: Partial<{ [key in string]: string }>; unreads?: Partial<{ [key in string]: string }> }
 /**
  * Feed Icon
  */
@@ -1916,12 +2591,43 @@ export type FeedUnread = { feed_id: string; unread_count: string }
  * Feed Update
  */
 export type FeedUpdate = { feed_url?: string | null; site_url?: string | null; title?: string | null; category_id?: string | null; scraper_rules?: string | null; rewrite_rules?: string | null; blocklist_rules?: string | null; keeplist_rules?: string | null; crawler?: boolean | null; user_agent?: string | null; username?: string | null; password?: string | null; disabled?: boolean | null; ignore_http_cache?: boolean | null; fetch_via_proxy?: boolean | null }
+export type FilterRule = { id: string; name: string; enabled: boolean; match_any: boolean; conditions: RuleCondition[]; actions: RuleAction[]; created_at: string; updated_at: string }
+export type FilterRuleDryRun = { scanned: number; matched: number; 
+/**
+ * First matches, newest first, up to the requested sample size
+ */
+entries: FilterRuleMatch[] }
+/**
+ * Rule fields as sent by the frontend for create, update and dry run
+ */
+export type FilterRuleInput = { name: string; enabled?: boolean; 
+/**
+ * Match when any condition holds instead of all of them
+ */
+match_any?: boolean; conditions: RuleCondition[]; actions: RuleAction[] }
+/**
+ * A cached entry matched by a dry run
+ */
+export type FilterRuleMatch = { entry_id: string; title: string; feed_title: string; published_at: string; status: string }
+/**
+ * Icon Data
+ */
+export type Icon = { id: string; data: string; mime_type: string }
 /**
  * Miniflux Integration Settings
  */
 export type Integration = { user_id: string; wallabag_enabled?: boolean; wallabag_url?: string | null; wallabag_client_id?: string | null; wallabag_client_secret?: string | null; wallabag_username?: string | null; wallabag_password?: string | null; shiori_enabled?: boolean; shiori_url?: string | null; shiori_username?: string | null; shiori_password?: string | null; pocket_enabled?: boolean; pocket_consumer_key?: string | null; pocket_access_token?: string | null; instapaper_enabled?: boolean; instapaper_url?: string | null; instapaper_username?: string | null; instapaper_password?: string | null; pinboard_enabled?: boolean; pinboard_token?: string | null; pinboard_tags?: string | null; shaarli_enabled?: boolean; shaarli_url?: string | null; shaarli_api_key?: string | null; rainloop_enabled?: boolean; rainloop_url?: string | null; rainloop_username?: string | null; rainloop_password?: string | null; raindrop_enabled?: boolean; raindrop_token?: string | null; discord_enabled?: boolean; discord_webhook_url?: string | null; discord_username?: string | null; discord_avatar_url?: string | null; telegram_bot_enabled?: boolean; telegram_bot_token?: string | null; telegram_bot_chat_id?: string | null; slack_enabled?: boolean; slack_webhook_url?: string | null; slack_username?: string | null; slack_icon_url?: string | null; slack_channel?: string | null; matrix_bot_enabled?: boolean; matrix_bot_url?: string | null; matrix_bot_username?: string | null; matrix_bot_password?: string | null; matrix_bot_chat_id?: string | null; ntfy_enabled?: boolean; ntfy_topic_url?: string | null; ntfy_username?: string | null; ntfy_password?: string | null; pushover_enabled?: boolean; pushover_app_id?: string | null; pushover_token?: string | null; pushover_user_key?: string | null; pushover_device?: string | null; apprise_enabled?: boolean; apprise_service_url?: string | null; apprise_script_url?: string | null; webhook_enabled?: boolean; webhook_url?: string | null; webhook_secret?: string | null; notion_enabled?: boolean; notion_page_id?: string | null; notion_token?: string | null; linkace_enabled?: boolean; linkace_url?: string | null; linkace_api_key?: string | null; linkding_enabled?: boolean; linkding_url?: string | null; linkding_api_key?: string | null; linkwarden_enabled?: boolean; linkwarden_url?: string | null; linkwarden_api_key?: string | null; linkwarden_username?: string | null; linkwarden_password?: string | null; betula_enabled?: boolean; betula_url?: string | null; betula_token?: string | null; cubox_enabled?: boolean; cubox_api_key?: string | null; omnivore_enabled?: boolean; omnivore_api_key?: string | null; readeck_enabled?: boolean; readeck_url?: string | null; readeck_api_key?: string | null; readeck_username?: string | null; readeck_password?: string | null; readwise_reader_enabled?: boolean; readwise_reader_api_key?: string | null; nunux_keeper_enabled?: boolean; nunux_keeper_url?: string | null; nunux_keeper_api_key?: string | null; espial_enabled?: boolean; espial_url?: string | null; espial_api_key?: string | null; rss_bridge_enabled?: boolean; rss_bridge_url?: string | null }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type LastReadingEntry = { entry_id: string; timestamp: string }
+export type LibrarySyncSummary = { 
+/**
+ * Records from other devices written locally
+ */
+records_applied: number; 
+/**
+ * Whether the remote library file was updated
+ */
+uploaded: boolean }
 /**
  * Aggregate local data size information.
  */
@@ -1935,10 +2641,66 @@ files: DataFileSize[];
  */
 total_bytes: string }
 export type MinifluxConnection = { id: string; username: string; server_url: string; auth_method: string; is_active: boolean; is_admin: boolean; created_at: string; updated_at: string }
+/**
+ * Error types for Miniflux API calls
+ */
+export type MinifluxError = 
+/**
+ * No client is configured for the active account
+ */
+{ type: "NotConnected" } | 
+/**
+ * Neither an API token nor a username and password were given
+ */
+{ type: "MissingCredentials" } | 
+/**
+ * The request never got a response (DNS, refused connection, TLS, ...)
+ */
+{ type: "Network"; data: { message: string } } | 
+/**
+ * The server did not answer within the client timeout
+ */
+{ type: "Timeout" } | 
+/**
+ * Credentials were rejected (401) or lack permission (403)
+ */
+{ type: "Unauthorized"; data: { status: number } } | 
+/**
+ * The requested resource does not exist on the server
+ */
+{ type: "NotFound"; data: { path: string } } | 
+/**
+ * Too many requests; `retry_after_secs` comes from the `Retry-After` header
+ */
+{ type: "RateLimited"; data: { retry_after_secs: number | null } } | 
+/**
+ * Any other non-success status, with the response body
+ */
+{ type: "ServerError"; data: { status: number; body: string } } | 
+/**
+ * The response body did not match the expected shape
+ */
+{ type: "Parse"; data: { message: string } } | 
+/**
+ * An argument was rejected before any request was made
+ */
+{ type: "InvalidInput"; data: { message: string } } | 
+/**
+ * A local step around the request failed, such as a database or file write
+ */
+{ type: "Local"; data: { message: string } }
 /**
  * Miniflux Version Information
  */
 export type MinifluxVersion = { version: string; commit?: string | null; build_date?: string | null; go_version?: string | null; arch?: string | null; os?: string | null }
+/**
+ * Rows removed because the entry or feed they belong to no longer exists.
+ */
+export type OrphanedRows = { enclosures: string; icons: string; summaries: string; tags: string; podcast_progress: string; 
+/**
+ * Full-text search rows
+ */
+search_index: string }
 /**
  * Player display mode when clicking the tray icon.
  */
@@ -1959,6 +2721,26 @@ export type PodcastFeedSettings = { feed_id: string; auto_download_count: number
  * Podcast playback progress
  */
 export type PodcastProgress = { entry_id: string; current_time: number; total_time: number; completed: boolean; last_played_at: string }
+/**
+ * A preference both devices changed since they last synced
+ */
+export type PreferenceConflict = { key: string; local_value: JsonValue; remote_value: JsonValue; 
+/**
+ * "local" or "remote"
+ */
+resolution: string; detected_at: string }
+/**
+ * A preference that differs between a snapshot and the local preferences
+ */
+export type PreferenceDiff = { key: string; 
+/**
+ * `null` when the local preferences don't have the key
+ */
+local_value: JsonValue; 
+/**
+ * `null` when the snapshot doesn't have the key
+ */
+snapshot_value: JsonValue }
 /**
  * Reader translation rendering mode.
  */
@@ -2020,18 +2802,161 @@ export type RecoveryError =
  * JSON serialization/deserialization error
  */
 { type: "ParseError"; message: string }
+export type RetentionPolicy = { 
+/**
+ * `None` for the account default
+ */
+feed_id?: string | null; max_age_days: number | null; max_entries_per_feed: number | null; keep_unread: boolean; updated_at: string }
+/**
+ * Limits applied when pruning a feed's cached entries
+ */
+export type RetentionPolicyInput = { 
+/**
+ * Prune entries published more than this many days ago
+ */
+max_age_days: number | null; 
+/**
+ * Keep at most this many of the newest entries per feed
+ */
+max_entries_per_feed: number | null; 
+/**
+ * Never prune unread entries
+ */
+keep_unread?: boolean }
+export type RuleAction = 
+{ type: "mark_read" } | 
+{ type: "star" } | 
+{ type: "tag"; tag: string } | 
+/**
+ * Keep the entry out of entry lists and unread counts
+ */
+{ type: "hide" } | 
+/**
+ * Show a native notification
+ */
+{ type: "notify" }
+export type RuleCondition = { field: RuleField; operator: RuleOperator; value: string; 
+/**
+ * Inverts the result of the comparison
+ */
+negate?: boolean }
+/**
+ * Entry attribute a condition looks at
+ */
+export type RuleField = 
+"title" | 
+"author" | 
+/**
+ * Entry content with HTML tags stripped
+ */
+"content" | 
+"url" | 
+/**
+ * Feed title
+ */
+"feed" | 
+/**
+ * Category title
+ */
+"category" | 
+/**
+ * Estimated reading time in minutes
+ */
+"reading_time"
+/**
+ * How a condition compares its value.
+ * 
+ * `AtLeast`/`AtMost` compare the character length of text fields and the
+ * number of minutes for `ReadingTime`. Text comparisons ignore case; regexes
+ * are used as written (prefix with `(?i)` for case-insensitive).
+ */
+export type RuleOperator = "contains" | "equals" | "regex" | "at_least" | "at_most"
+/**
+ * A saved entry filter shown as a folder
+ */
+export type SmartFolder = { id: string; name: string; filters: EntryFilters; created_at: string; updated_at: string }
+/**
+ * Per-smart-folder unread count
+ */
+export type SmartFolderUnread = { smart_folder_id: string; unread_count: string }
+export type SnapshotInfo = { id: string; created_at: string; device_name: string }
 /**
  * Subscription (from discover)
  */
 export type Subscription = { url: string; title: string; type: string }
 export type SummarizeArticleRequest = { text: string; language: string | null }
 export type SummarizeArticleResponse = { summary: string; provider_used: string; model_used: string }
+/**
+ * A pull that disagreed with a pending local change, and how it was resolved
+ */
+export type SyncConflict = { id: string; entry_id: string; entry_title: string | null; 
+/**
+ * "status" or "starred"
+ */
+field: string; local_value: string; server_value: string; local_changed_at: string | null; server_changed_at: string | null; 
+/**
+ * Rule in effect when the conflict was resolved
+ */
+rule: string; 
+/**
+ * "local" or "server"
+ */
+resolution: string; created_at: string }
+/**
+ * How sync resolves an entry that was changed locally while the server copy also changed.
+ */
+export type SyncConflictRule = 
+/**
+ * Keep the local value and push it again on the next sync.
+ */
+"local-wins" | 
+/**
+ * Take the server value and drop the queued local change.
+ */
+"server-wins" | 
+/**
+ * Keep whichever side changed most recently.
+ */
+"newest-wins"
+export type SyncRun = { id: string; account_id?: string | null; 
+/**
+ * "full", "incremental" or "forced"
+ */
+sync_type: string; 
+/**
+ * "running", "succeeded", "failed" or "interrupted"
+ */
+status: string; started_at: string; finished_at: string | null; entries_pulled: number; entries_pushed: number; 
+/**
+ * Entries deleted locally: gone from the server or pruned by retention
+ */
+entries_deleted: number; feeds_pulled: number; categories_pulled: number; 
+/**
+ * Response bytes received from the server
+ */
+bytes_transferred: string; pages: number; 
+/**
+ * Requests sent, retries included
+ */
+requests: number; 
+/**
+ * Failed attempts, whether or not a retry then succeeded
+ */
+failures: number; 
+/**
+ * Failed attempts in order, then the error that ended the run
+ */
+error_chain: string[] }
 /**
  * Persisted sync status returned to the frontend for display
  */
 export type SyncStatus = { last_sync_at: string | null; categories_synced: number; feeds_synced: number; entries_synced: number }
 export type SyncSummary = { entries_pulled: number; entries_pushed: number; feeds_pulled: number; categories_pulled: number }
 export type SystemTime = { duration_since_epoch: string; duration_since_unix_epoch: number }
+/**
+ * A tag with the number of entries carrying it
+ */
+export type TagSummary = { tag: string; entry_count: string; unread_count: string }
 /**
  * Cached translation entry stored on disk.
  */
@@ -2057,7 +2982,7 @@ export type TrayIconState =
 /**
  * Enhanced unread counts from local database
  */
-export type UnreadCounts = { total: string; by_category: CategoryUnread[]; by_feed: FeedUnread[]; today: string }
+export type UnreadCounts = { total: string; by_category: CategoryUnread[]; by_feed: FeedUnread[]; by_smart_folder: SmartFolderUnread[]; today: string }
 /**
  * User
  */
@@ -2069,7 +2994,7 @@ export type UserCreate = { username: string; password: string; is_admin?: boolea
 /**
  * User Update
  */
-export type UserUpdate = { username?: string | null; password?: string | null; theme?: string | null; language?: string | null; timezone?: string | null; entry_sorting_direction?: string | null; entries_per_page?: number | null; keyboard_shortcuts?: boolean | null; display_mode?: string | null; show_reading_time?: boolean | null; entry_swipe?: boolean | null; stylesheet?: string | null }
+export type UserUpdate = { username?: string | null; password?: string | null; theme?: string | null; language?: string | null; timezone?: string | null; entry_sorting_direction?: string | null; entry_sorting_order?: string | null; entries_per_page?: number | null; keyboard_shortcuts?: boolean | null; display_mode?: string | null; show_reading_time?: boolean | null; entry_swipe?: boolean | null; stylesheet?: string | null }
 
 /** tauri-specta globals **/
 
@@ -17,7 +17,7 @@ export const articleCommands: AppCommand[] = [
       if (!entryId) return;
       const _ = i18n._.bind(i18n);
       try {
-        const result = await commands.toggleEntryRead(entryId);
+        const result = await commands.toggleEntryRead(entryId, null);
         if (result.status === 'error') {
           context.showToast(_(msg`Failed to toggle read status`), 'error');
         }
@@ -39,7 +39,7 @@ export const articleCommands: AppCommand[] = [
       if (!entryId) return;
       const _ = i18n._.bind(i18n);
       try {
-        const result = await commands.toggleEntryStar(entryId);
+        const result = await commands.toggleEntryStar(entryId, null);
         if (result.status === 'error') {
           context.showToast(_(msg`Failed to toggle star`), 'error');
         }
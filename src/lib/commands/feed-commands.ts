@@ -1,5 +1,6 @@
 import { msg } from '@lingui/core/macro';
 import i18n from '@/i18n/config';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { commands } from '@/lib/tauri-bindings';
 import { useUIStore } from '@/store/ui-store';
 import type { AppCommand } from './types';
@@ -63,7 +64,8 @@ export const feedCommands: AppCommand[] = [
         if (result.status === 'ok') {
           context.showToast(_(msg`All feeds refreshed`), 'success');
         } else {
-          context.showToast(_(msg`Refresh failed: ${result.error}`), 'error');
+          const message = formatMinifluxError(result.error);
+          context.showToast(_(msg`Refresh failed: ${message}`), 'error');
         }
       } catch (error) {
         const message = error instanceof Error ? error.message : 'Unknown error';
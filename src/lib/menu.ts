@@ -5,6 +5,7 @@ import { i18n } from '@/i18n';
 import { resetAccountState } from '@/lib/account-reset';
 import { confirm } from '@/lib/dialog';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { notifications } from '@/lib/notifications';
 import { commands } from '@/lib/tauri-bindings';
 import { checkForUpdate, downloadUpdate } from '@/lib/updater';
@@ -550,7 +551,7 @@ async function handleRefreshAllFeeds(): Promise<void> {
     if (result.status === 'ok') {
       notifications.success(_(msg`Refresh Complete`), _(msg`All feeds refreshed`));
     } else {
-      notifications.error(_(msg`Refresh Failed`), result.error);
+      notifications.error(_(msg`Refresh Failed`), formatMinifluxError(result.error));
     }
   } catch (error) {
     logger.error('Refresh all feeds failed', { error });
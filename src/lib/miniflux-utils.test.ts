@@ -1,6 +1,7 @@
 import { describe, expect, it } from 'vite-plus/test';
 import {
   formatEntryTime,
+  formatMinifluxError,
   getEntryDateSectionType,
   groupEntriesByCalendarDate,
   parseDateInput,
@@ -76,4 +77,19 @@ describe('miniflux-utils', () => {
     expect(groups).toHaveLength(1);
     expect(groups[0]?.key).toBe('2026-02-10');
   });
+
+  it('formats Miniflux errors for display', () => {
+    expect(formatMinifluxError({ type: 'Unauthorized', data: { status: 401 } })).toBe(
+      'The server rejected these credentials'
+    );
+    expect(formatMinifluxError({ type: 'RateLimited', data: { retry_after_secs: 30 } })).toBe(
+      'Too many requests, try again in 30s'
+    );
+    expect(formatMinifluxError({ type: 'ServerError', data: { status: 502, body: '' } })).toBe(
+      'Server error 502'
+    );
+    expect(
+      formatMinifluxError({ type: 'InvalidInput', data: { message: 'Invalid feed ID: x' } })
+    ).toBe('Invalid feed ID: x');
+  });
 });
@@ -6,7 +6,7 @@ import {
   parseISO,
 } from 'date-fns';
 import { enUS, ja, ko, zhCN, zhTW } from 'date-fns/locale';
-import type { Entry } from '@/lib/tauri-bindings';
+import type { Entry, MinifluxError } from '@/lib/tauri-bindings';
 
 const DATE_LOCALE_MAP: Record<string, Locale> = {
   en: enUS,
@@ -158,3 +158,32 @@ export function getFeedIconUrl(entry: Entry): string | null {
     return null;
   }
 }
+
+/** Convert MinifluxError to a human-readable message */
+export function formatMinifluxError(error: MinifluxError): string {
+  switch (error.type) {
+    case 'NotConnected':
+      return 'Not connected to Miniflux server';
+    case 'MissingCredentials':
+      return 'Enter an API token or a username and password';
+    case 'Network':
+      return `Could not reach the server: ${error.data.message}`;
+    case 'Timeout':
+      return 'The server took too long to respond';
+    case 'Unauthorized':
+      return 'The server rejected these credentials';
+    case 'NotFound':
+      return `Not found: ${error.data.path}`;
+    case 'RateLimited':
+      return error.data.retry_after_secs === null
+        ? 'Too many requests, try again later'
+        : `Too many requests, try again in ${error.data.retry_after_secs}s`;
+    case 'ServerError':
+      return `Server error ${error.data.status}${error.data.body ? `: ${error.data.body}` : ''}`;
+    case 'Parse':
+      return `Unexpected response from server: ${error.data.message}`;
+    case 'InvalidInput':
+    case 'Local':
+      return error.data.message;
+  }
+}
@@ -19,11 +19,14 @@ export const API_PREFIX = '/miniflux-api';
 /** Raised for any non-2xx response, carrying the HTTP status for callers. */
 export class MinifluxHttpError extends Error {
   readonly status: number;
+  /** The API path that answered, when a response was received. */
+  readonly path?: string;
 
-  constructor(status: number, message: string) {
+  constructor(status: number, message: string, path?: string) {
     super(message);
     this.name = 'MinifluxHttpError';
     this.status = status;
+    this.path = path;
   }
 }
 
@@ -137,7 +140,7 @@ export async function request<T>(path: string, options: RequestOptions = {}): Pr
     } catch {
       // Non-JSON error body; keep the status text.
     }
-    throw new MinifluxHttpError(response.status, message);
+    throw new MinifluxHttpError(response.status, message, path);
   }
 
   if (responseType === 'none' || response.status === 204) return undefined as T;
@@ -67,15 +67,22 @@ describe('web commands: disconnected state', () => {
   });
 
   it('returns zeroed unread counts instead of erroring', async () => {
-    await expect(commands.getUnreadCounts()).resolves.toEqual({
+    await expect(commands.getUnreadCounts(null)).resolves.toEqual({
       status: 'ok',
-      data: { total: '0', today: '0', by_feed: [], by_category: [] },
+      data: { total: '0', today: '0', by_feed: [], by_category: [], by_smart_folder: [] },
     });
   });
 
   it('reports that it is not connected', async () => {
     await expect(commands.minifluxIsConnected()).resolves.toEqual({ status: 'ok', data: false });
   });
+
+  it('rejects server commands with a typed NotConnected error', async () => {
+    await expect(commands.createCategory('Tech')).resolves.toEqual({
+      status: 'error',
+      error: { type: 'NotConnected' },
+    });
+  });
 });
 
 describe('web commands: connected state', () => {
@@ -113,6 +120,29 @@ describe('web commands: connected state', () => {
     expect(url).toContain('limit=50');
     expect(url).not.toContain('search');
   });
+
+  it('maps HTTP failures onto MinifluxError', async () => {
+    localStorage.clear();
+    storeAccount();
+    vi.stubGlobal(
+      'fetch',
+      vi.fn().mockResolvedValue({
+        ok: false,
+        status: 404,
+        statusText: 'Not Found',
+        json: async () => ({ error_message: 'Feed not found' }),
+      })
+    );
+
+    await expect(commands.deleteFeed('3')).resolves.toEqual({
+      status: 'error',
+      error: { type: 'NotFound', data: { path: 'feeds/3' } },
+    });
+    await expect(commands.deleteFeed('abc')).resolves.toEqual({
+      status: 'error',
+      error: { type: 'InvalidInput', data: { message: 'Invalid feed id: "abc" is not a number' } },
+    });
+  });
 });
 
 describe('web commands: unsupported surface', () => {
@@ -33,6 +33,7 @@ import type {
   Integration,
   LastReadingEntry,
   MinifluxConnection,
+  MinifluxError,
   MinifluxVersion,
   Subscription,
   SyncSummary,
@@ -43,7 +44,7 @@ import type {
 } from '../bindings';
 import { credentialsFor, MinifluxHttpError, request } from './client';
 import { unsupported, UnsupportedInWebError } from './errors';
-import { normalizeIds, toNumericId } from './normalize';
+import { InvalidIdError, normalizeIds, toNumericId } from './normalize';
 import { accountStorage, clearAll, lastReadingStorage, preferencesStorage } from './storage';
 
 export type * from '../bindings';
@@ -66,6 +67,44 @@ async function attempt<T>(fn: () => Promise<T>): Promise<Ok<T> | Err<string>> {
   }
 }
 
+/** Maps a thrown error onto the `MinifluxError` the desktop client reports. */
+function toMinifluxError(error: unknown): MinifluxError {
+  if (error instanceof InvalidIdError) {
+    return { type: 'InvalidInput', data: { message: error.message } };
+  }
+  if (error instanceof MinifluxHttpError) {
+    switch (error.status) {
+      case 401:
+      case 403:
+        return { type: 'Unauthorized', data: { status: error.status } };
+      case 404:
+        return { type: 'NotFound', data: { path: error.path ?? error.message } };
+      case 429:
+        return { type: 'RateLimited', data: { retry_after_secs: null } };
+      default:
+        return { type: 'ServerError', data: { status: error.status, body: error.message } };
+    }
+  }
+  return {
+    type: 'Network',
+    data: { message: error instanceof Error ? error.message : String(error) },
+  };
+}
+
+/**
+ * `attempt` for commands whose desktop counterpart talks to the Miniflux
+ * server, so failures carry the same typed `MinifluxError`.
+ */
+async function attemptMiniflux<T>(fn: () => Promise<T>): Promise<Ok<T> | Err<MinifluxError>> {
+  if (!isConnected()) return err({ type: 'NotConnected' });
+  try {
+    return ok(await fn());
+  } catch (error) {
+    if (error instanceof UnsupportedInWebError) throw error;
+    return err(toMinifluxError(error));
+  }
+}
+
 function connectionFromStorage(): MinifluxConnection | null {
   const account = accountStorage.get();
   if (!account) return null;
@@ -136,10 +175,12 @@ function startOfTodayUnix(): number {
 const webCommands = {
   // ---------------------------------------------------------------- connection
   async minifluxConnect(config: AuthConfig) {
-    return attempt(async () => {
+    try {
       await connect(config);
-      return true;
-    });
+      return ok(true);
+    } catch (error) {
+      return err(toMinifluxError(error));
+    }
   },
 
   async minifluxDisconnect() {
@@ -169,11 +210,13 @@ const webCommands = {
   },
 
   async getMinifluxVersion() {
-    return attempt(async () => normalizeIds<MinifluxVersion>(await request<unknown>('version')));
+    return attemptMiniflux(async () =>
+      normalizeIds<MinifluxVersion>(await request<unknown>('version'))
+    );
   },
 
   async getCurrentUser() {
-    return attempt(async () => normalizeIds<User>(await request<unknown>('me')));
+    return attemptMiniflux(async () => normalizeIds<User>(await request<unknown>('me')));
   },
 
   // ------------------------------------------------------------------ accounts
@@ -215,7 +258,7 @@ const webCommands = {
   },
 
   async createCategory(title: string) {
-    return attempt(async () =>
+    return attemptMiniflux(async () =>
       normalizeIds<Category>(
         await request<unknown>('categories', { method: 'POST', body: { title } })
       )
@@ -223,7 +266,7 @@ const webCommands = {
   },
 
   async updateCategory(id: string, title: string) {
-    return attempt(async () =>
+    return attemptMiniflux(async () =>
       normalizeIds<Category>(
         await request<unknown>(`categories/${toNumericId(id, 'category id')}`, {
           method: 'PUT',
@@ -234,7 +277,7 @@ const webCommands = {
   },
 
   async deleteCategory(id: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request(`categories/${toNumericId(id, 'category id')}`, {
         method: 'DELETE',
         responseType: 'none',
@@ -244,7 +287,7 @@ const webCommands = {
   },
 
   async markCategoryAsRead(id: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request(`categories/${toNumericId(id, 'category id')}/mark-all-as-read`, {
         method: 'PUT',
         responseType: 'none',
@@ -269,7 +312,7 @@ const webCommands = {
   },
 
   async createFeed(feedUrl: string, categoryId: string | null) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       const created = await request<{ feed_id: number | string }>('feeds', {
         method: 'POST',
         body: {
@@ -282,7 +325,7 @@ const webCommands = {
   },
 
   async updateFeed(id: string, updates: FeedUpdate) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       // `category_id` crosses the boundary as a string but Miniflux wants a number.
       const { category_id: categoryId, ...rest } = updates;
       return normalizeIds<Feed>(
@@ -298,7 +341,7 @@ const webCommands = {
   },
 
   async deleteFeed(id: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request(`feeds/${toNumericId(id, 'feed id')}`, {
         method: 'DELETE',
         responseType: 'none',
@@ -308,7 +351,7 @@ const webCommands = {
   },
 
   async refreshFeed(id: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request(`feeds/${toNumericId(id, 'feed id')}/refresh`, {
         method: 'PUT',
         responseType: 'none',
@@ -318,14 +361,14 @@ const webCommands = {
   },
 
   async refreshAllFeeds() {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request('feeds/refresh', { method: 'PUT', responseType: 'none' });
       return null;
     });
   },
 
   async markFeedAsRead(id: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request(`feeds/${toNumericId(id, 'feed id')}/mark-all-as-read`, {
         method: 'PUT',
         responseType: 'none',
@@ -335,7 +378,7 @@ const webCommands = {
   },
 
   async getFeedIconData(feedId: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       try {
         const icon = await request<{ data: string; mime_type: string }>(
           `feeds/${toNumericId(feedId, 'feed id')}/icon`
@@ -354,7 +397,7 @@ const webCommands = {
   },
 
   async discoverSubscriptions(url: string) {
-    return attempt(async () =>
+    return attemptMiniflux(async () =>
       normalizeIds<Subscription[]>(
         await request<unknown>('discover', { method: 'POST', body: { url } })
       )
@@ -362,11 +405,11 @@ const webCommands = {
   },
 
   async exportOpml() {
-    return attempt(async () => request<string>('export', { responseType: 'text' }));
+    return attemptMiniflux(async () => request<string>('export', { responseType: 'text' }));
   },
 
   async importOpml(opmlContent: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request('import', { method: 'POST', rawBody: opmlContent, responseType: 'none' });
       return null;
     });
@@ -383,14 +426,14 @@ const webCommands = {
     });
   },
 
-  async getEntry(entryId: string) {
+  async getEntry(entryId: string, _accountId: string | null) {
     return attempt(async () =>
       normalizeIds<Entry>(await request<unknown>(`entries/${toNumericId(entryId, 'entry id')}`))
     );
   },
 
-  async markEntryRead(id: string) {
-    return attempt(async () => {
+  async markEntryRead(id: string, _accountId: string | null) {
+    return attemptMiniflux(async () => {
       await request('entries', {
         method: 'PUT',
         body: { entry_ids: [toNumericId(id, 'entry id')], status: 'read' },
@@ -400,8 +443,8 @@ const webCommands = {
     });
   },
 
-  async markEntriesRead(ids: string[]) {
-    return attempt(async () => {
+  async markEntriesRead(ids: string[], _accountId: string | null) {
+    return attemptMiniflux(async () => {
       if (ids.length === 0) return null;
       await request('entries', {
         method: 'PUT',
@@ -412,8 +455,8 @@ const webCommands = {
     });
   },
 
-  async toggleEntryRead(id: string) {
-    return attempt(async () => {
+  async toggleEntryRead(id: string, _accountId: string | null) {
+    return attemptMiniflux(async () => {
       const entry = normalizeIds<Entry>(
         await request<unknown>(`entries/${toNumericId(id, 'entry id')}`)
       );
@@ -427,8 +470,8 @@ const webCommands = {
     });
   },
 
-  async toggleEntryStar(id: string) {
-    return attempt(async () => {
+  async toggleEntryStar(id: string, _accountId: string | null) {
+    return attemptMiniflux(async () => {
       const entry = normalizeIds<Entry>(
         await request<unknown>(`entries/${toNumericId(id, 'entry id')}`)
       );
@@ -441,7 +484,7 @@ const webCommands = {
   },
 
   async saveEntry(id: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request(`entries/${toNumericId(id, 'entry id')}/save`, {
         method: 'POST',
         responseType: 'none',
@@ -451,7 +494,7 @@ const webCommands = {
   },
 
   async fetchEntryContent(id: string, _updateContent: boolean) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       const fetched = await request<{ content: string }>(
         `entries/${toNumericId(id, 'entry id')}/fetch-content`
       );
@@ -460,7 +503,7 @@ const webCommands = {
   },
 
   async updateEntry(id: string, updates: EntryUpdate) {
-    return attempt(async () =>
+    return attemptMiniflux(async () =>
       normalizeIds<Entry>(
         await request<unknown>(`entries/${toNumericId(id, 'entry id')}`, {
           method: 'PUT',
@@ -471,9 +514,15 @@ const webCommands = {
   },
 
   // ------------------------------------------------------------------ counters
-  async getUnreadCounts() {
+  async getUnreadCounts(_allAccounts: boolean | null) {
     if (!isConnected()) {
-      return ok({ total: '0', today: '0', by_feed: [], by_category: [] } satisfies UnreadCounts);
+      return ok({
+        total: '0',
+        today: '0',
+        by_feed: [],
+        by_category: [],
+        by_smart_folder: [],
+      } satisfies UnreadCounts);
     }
     return attempt(async () => {
       // The desktop build reads these from its SQLite mirror. Online-only, the
@@ -513,6 +562,8 @@ const webCommands = {
           category_id: categoryId,
           unread_count: String(count),
         })),
+        // Smart folders live in the desktop database only
+        by_smart_folder: [],
       } satisfies UnreadCounts;
     });
   },
@@ -522,17 +573,17 @@ const webCommands = {
   // (users, API keys, integrations) work in the browser too.
   async getUsers() {
     if (!isConnected()) return ok([] as User[]);
-    return attempt(async () => normalizeIds<User[]>(await request<unknown>('users')));
+    return attemptMiniflux(async () => normalizeIds<User[]>(await request<unknown>('users')));
   },
 
   async createUser(user: UserCreate) {
-    return attempt(async () =>
+    return attemptMiniflux(async () =>
       normalizeIds<User>(await request<unknown>('users', { method: 'POST', body: user }))
     );
   },
 
   async updateUser(id: string, updates: UserUpdate) {
-    return attempt(async () =>
+    return attemptMiniflux(async () =>
       normalizeIds<User>(
         await request<unknown>(`users/${toNumericId(id, 'user id')}`, {
           method: 'PUT',
@@ -543,7 +594,7 @@ const webCommands = {
   },
 
   async deleteUser(id: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request(`users/${toNumericId(id, 'user id')}`, {
         method: 'DELETE',
         responseType: 'none',
@@ -554,17 +605,17 @@ const webCommands = {
 
   async getApiKeys() {
     if (!isConnected()) return ok([] as ApiKey[]);
-    return attempt(async () => normalizeIds<ApiKey[]>(await request<unknown>('api-keys')));
+    return attemptMiniflux(async () => normalizeIds<ApiKey[]>(await request<unknown>('api-keys')));
   },
 
   async createApiKey(request_: ApiKeyCreate) {
-    return attempt(async () =>
+    return attemptMiniflux(async () =>
       normalizeIds<ApiKey>(await request<unknown>('api-keys', { method: 'POST', body: request_ }))
     );
   },
 
   async deleteApiKey(id: string) {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request(`api-keys/${toNumericId(id, 'api key id')}`, {
         method: 'DELETE',
         responseType: 'none',
@@ -574,11 +625,13 @@ const webCommands = {
   },
 
   async getIntegrations() {
-    return attempt(async () => normalizeIds<Integration>(await request<unknown>('integrations')));
+    return attemptMiniflux(async () =>
+      normalizeIds<Integration>(await request<unknown>('integrations'))
+    );
   },
 
   async flushHistory() {
-    return attempt(async () => {
+    return attemptMiniflux(async () => {
       await request('flush-history', { method: 'DELETE', responseType: 'none' });
       return null;
     });
@@ -47,6 +47,14 @@ function walk(value: unknown): unknown {
   return out;
 }
 
+/** An ID argument that is not a number; reported as `InvalidInput`. */
+export class InvalidIdError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'InvalidIdError';
+  }
+}
+
 /**
  * Converts a binding-side string id back to the number the REST API expects.
  * Throws rather than silently sending `NaN` down the wire.
@@ -54,7 +62,7 @@ function walk(value: unknown): unknown {
 export function toNumericId(id: string, label = 'id'): number {
   const parsed = Number(id);
   if (!Number.isFinite(parsed)) {
-    throw new Error(`Invalid ${label}: "${id}" is not a number`);
+    throw new InvalidIdError(`Invalid ${label}: "${id}" is not a number`);
   }
   return parsed;
 }
@@ -354,7 +354,7 @@ export function TrayPopover() {
   const fetchData = useCallback(async () => {
     try {
       const [c, e] = await Promise.all([
-        commands.getUnreadCounts(),
+        commands.getUnreadCounts(null),
         commands.getEntriesList({
           status: 'unread',
           limit: '10',
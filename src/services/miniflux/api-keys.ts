@@ -3,6 +3,7 @@ import { msg } from '@lingui/core/macro';
 import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query';
 import { toast } from 'sonner';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { commands } from '@/lib/tauri-bindings';
 import { useIsConnected } from '@/services/miniflux/auth';
 
@@ -27,7 +28,7 @@ export function useApiKeys(enabled: boolean = true) {
 
       if (result.status === 'error') {
         logger.error('[useApiKeys] Failed to fetch API keys', { error: result.error });
-        throw new Error(result.error);
+        throw new Error(formatMinifluxError(result.error));
       }
 
       return result.data;
@@ -50,9 +51,10 @@ export function useCreateApiKey() {
       const result = await commands.createApiKey({ description });
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('[useCreateApiKey] Failed to create API key', { error: result.error });
-        toast.error(translate(msg`Failed to create API key`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to create API key`), { description: message });
+        throw new Error(message);
       }
 
       return result.data;
@@ -75,9 +77,10 @@ export function useDeleteApiKey() {
       const result = await commands.deleteApiKey(id);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('[useDeleteApiKey] Failed to delete API key', { error: result.error, id });
-        toast.error(translate(msg`Failed to delete API key`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to delete API key`), { description: message });
+        throw new Error(message);
       }
     },
     onSuccess: () => {
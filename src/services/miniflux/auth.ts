@@ -2,6 +2,7 @@ import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query';
 import { useState } from 'react';
 import { showToast } from '@/components/ui/sonner';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { type AuthConfig, commands } from '@/lib/tauri-bindings';
 
 // Query keys for authentication
@@ -54,8 +55,9 @@ export function useConnect() {
           error: result.error,
           serverUrl: config.server_url,
         });
-        showToast.error('Connection failed', result.error);
-        throw new Error(result.error);
+        const message = formatMinifluxError(result.error);
+        showToast.error('Connection failed', message);
+        throw new Error(message);
       }
 
       logger.info('Successfully connected to Miniflux', {
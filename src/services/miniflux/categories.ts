@@ -3,6 +3,7 @@ import { msg } from '@lingui/core/macro';
 import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query';
 import { toast } from 'sonner';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { type Category, commands } from '@/lib/tauri-bindings';
 import { counterQueryKeys } from './counters';
 
@@ -74,14 +75,15 @@ export function useMarkCategoryAsRead() {
       const result = await commands.markCategoryAsRead(id);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to mark category as read', {
           error: result.error,
           id,
         });
         toast.error(translate(msg`Failed to mark category as read`), {
-          description: result.error,
+          description: message,
         });
-        throw new Error(result.error);
+        throw new Error(message);
       }
 
       logger.info('Category marked as read', { id });
@@ -107,12 +109,13 @@ export function useCreateCategory() {
       const result = await commands.createCategory(title);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to create category', {
           error: result.error,
           title,
         });
-        toast.error(translate(msg`Failed to create category`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to create category`), { description: message });
+        throw new Error(message);
       }
 
       logger.info('Category created successfully', { id: result.data.id });
@@ -139,12 +142,13 @@ export function useUpdateCategory() {
       const result = await commands.updateCategory(id, title);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to update category', {
           error: result.error,
           id,
         });
-        toast.error(translate(msg`Failed to update category`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to update category`), { description: message });
+        throw new Error(message);
       }
 
       logger.info('Category updated successfully', { id });
@@ -171,12 +175,13 @@ export function useDeleteCategory() {
       const result = await commands.deleteCategory(id);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to delete category', {
           error: result.error,
           id,
         });
-        toast.error(translate(msg`Failed to delete category`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to delete category`), { description: message });
+        throw new Error(message);
       }
 
       logger.info('Category deleted successfully', { id });
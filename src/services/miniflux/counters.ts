@@ -13,7 +13,7 @@ export function useUnreadCounts() {
     queryKey: counterQueryKeys.all,
     queryFn: async () => {
       logger.debug('Fetching unread counts');
-      const result = await commands.getUnreadCounts();
+      const result = await commands.getUnreadCounts(null);
 
       if (result.status === 'error') {
         const isNotConnected = result.error === 'Not connected to Miniflux server';
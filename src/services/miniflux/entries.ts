@@ -9,6 +9,7 @@ import {
 } from '@tanstack/react-query';
 import { toast } from 'sonner';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import {
   commands,
   type Entry,
@@ -248,7 +249,7 @@ export function useEntry(id: string) {
     queryKey: entryQueryKeys.detail(id),
     queryFn: async (): Promise<Entry> => {
       logger.debug('Fetching entry', { id });
-      const result = await commands.getEntry(id);
+      const result = await commands.getEntry(id, null);
 
       if (result.status === 'error') {
         logger.error('Failed to fetch entry', {
@@ -290,7 +291,7 @@ export function usePrefetchEntry() {
       queryClient.prefetchQuery({
         queryKey: entryQueryKeys.detail(id),
         queryFn: async (): Promise<Entry> => {
-          const result = await commands.getEntry(id);
+          const result = await commands.getEntry(id, null);
 
           if (result.status === 'error') {
             logger.debug('Failed to prefetch entry', {
@@ -324,10 +325,11 @@ export function useToggleEntryRead() {
   return useMutation({
     mutationFn: async (id: string): Promise<string> => {
       logger.debug('Toggling entry read status', { id });
-      const result = await commands.toggleEntryRead(id);
+      const result = await commands.toggleEntryRead(id, null);
 
       if (result.status === 'error') {
-        const isNotConnected = result.error === 'Not connected to Miniflux server';
+        const message = formatMinifluxError(result.error);
+        const isNotConnected = result.error.type === 'NotConnected';
 
         if (!isNotConnected) {
           logger.error('Failed to toggle entry read status', {
@@ -335,10 +337,10 @@ export function useToggleEntryRead() {
             id,
           });
           toast.error('Failed to update read status', {
-            description: result.error,
+            description: message,
           });
         }
-        throw new Error(result.error);
+        throw new Error(message);
       }
 
       logger.info('Entry read status toggled', { id, newStatus: result.data });
@@ -386,10 +388,11 @@ export function useMarkEntryRead() {
   return useMutation({
     mutationFn: async (id: string) => {
       logger.debug('Marking entry as read', { id });
-      const result = await commands.markEntryRead(id);
+      const result = await commands.markEntryRead(id, null);
 
       if (result.status === 'error') {
-        const isNotConnected = result.error === 'Not connected to Miniflux server';
+        const message = formatMinifluxError(result.error);
+        const isNotConnected = result.error.type === 'NotConnected';
 
         if (!isNotConnected) {
           logger.error('Failed to mark entry as read', {
@@ -397,10 +400,10 @@ export function useMarkEntryRead() {
             id,
           });
           toast.error('Failed to mark as read', {
-            description: result.error,
+            description: message,
           });
         }
-        throw new Error(result.error);
+        throw new Error(message);
       }
 
       logger.info('Entry marked as read', { id });
@@ -438,17 +441,18 @@ export function useMarkEntriesRead() {
   return useMutation({
     mutationFn: async (ids: string[]) => {
       logger.debug('Marking entries as read', { count: ids.length });
-      const result = await commands.markEntriesRead(ids);
+      const result = await commands.markEntriesRead(ids, null);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to mark entries as read', {
           error: result.error,
           count: ids.length,
         });
         toast.error('Failed to mark as read', {
-          description: result.error,
+          description: message,
         });
-        throw new Error(result.error);
+        throw new Error(message);
       }
 
       logger.info('Entries marked as read', { count: ids.length });
@@ -472,10 +476,11 @@ export function useToggleEntryStar() {
   return useMutation({
     mutationFn: async (id: string): Promise<boolean> => {
       logger.debug('Toggling entry star', { id });
-      const result = await commands.toggleEntryStar(id);
+      const result = await commands.toggleEntryStar(id, null);
 
       if (result.status === 'error') {
-        const isNotConnected = result.error === 'Not connected to Miniflux server';
+        const message = formatMinifluxError(result.error);
+        const isNotConnected = result.error.type === 'NotConnected';
 
         if (!isNotConnected) {
           logger.error('Failed to toggle entry star', {
@@ -483,10 +488,10 @@ export function useToggleEntryStar() {
             id,
           });
           toast.error('Failed to toggle star', {
-            description: result.error,
+            description: message,
           });
         }
-        throw new Error(result.error);
+        throw new Error(message);
       }
 
       logger.info('Entry star toggled', { id, newStatus: result.data });
@@ -533,14 +538,15 @@ export function useUpdateEntry() {
       const result = await commands.updateEntry(id, updates);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to update entry', {
           error: result.error,
           id,
         });
         toast.error('Failed to update entry', {
-          description: result.error,
+          description: message,
         });
-        throw new Error(result.error);
+        throw new Error(message);
       }
 
       logger.info('Entry updated successfully', { id });
@@ -567,14 +573,15 @@ export function useFetchEntryContent() {
       const result = await commands.fetchEntryContent(id, updateContent);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to fetch entry content', {
           error: result.error,
           id,
         });
         toast.error(translate(msg`Failed to download original content`), {
-          description: result.error,
+          description: message,
         });
-        throw new Error(result.error);
+        throw new Error(message);
       }
 
       logger.info('Entry content fetched successfully', { id });
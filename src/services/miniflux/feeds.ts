@@ -3,6 +3,7 @@ import { msg } from '@lingui/core/macro';
 import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query';
 import { toast } from 'sonner';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { commands, type Feed, type FeedUpdate } from '@/lib/tauri-bindings';
 import { useSyncStore } from '@/store/sync-store';
 import { counterQueryKeys } from './counters';
@@ -18,15 +19,6 @@ export const feedQueryKeys = {
   detail: (id: string) => [...feedQueryKeys.all, 'detail', id] as const,
 };
 
-function isNotFoundApiError(errorMessage: string): boolean {
-  const normalized = errorMessage.toLowerCase();
-  return (
-    normalized.includes('404 not found') ||
-    normalized.includes('api error: 404') ||
-    normalized.includes('status code: 404')
-  );
-}
-
 async function syncMinifluxCache() {
   const syncResult = await commands.syncMiniflux();
   if (syncResult.status === 'error') {
@@ -124,12 +116,13 @@ export function useCreateFeed() {
       const result = await commands.createFeed(feedUrl, categoryId);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to create feed', {
           error: result.error,
           feedUrl,
         });
-        toast.error(translate(msg`Failed to create feed`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to create feed`), { description: message });
+        throw new Error(message);
       }
 
       if (updates && hasDefinedFeedUpdateValue(updates)) {
@@ -140,7 +133,7 @@ export function useCreateFeed() {
             error: updateResult.error,
           });
           toast.warning(translate(msg`Feed created with partial settings`), {
-            description: updateResult.error,
+            description: formatMinifluxError(updateResult.error),
           });
         }
       }
@@ -170,12 +163,13 @@ export function useMarkFeedAsRead() {
       const result = await commands.markFeedAsRead(id);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to mark feed as read', {
           error: result.error,
           id,
         });
-        toast.error(translate(msg`Failed to mark feed as read`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to mark feed as read`), { description: message });
+        throw new Error(message);
       }
 
       logger.info('Feed marked as read', { id });
@@ -201,12 +195,13 @@ export function useUpdateFeed() {
       const result = await commands.updateFeed(id, updates);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to update feed', {
           error: result.error,
           id,
         });
-        toast.error(translate(msg`Failed to update feed`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to update feed`), { description: message });
+        throw new Error(message);
       }
 
       logger.info('Feed updated successfully', { id });
@@ -235,7 +230,7 @@ export function useDeleteFeed() {
       const result = await commands.deleteFeed(id);
 
       if (result.status === 'error') {
-        if (isNotFoundApiError(result.error)) {
+        if (result.error.type === 'NotFound') {
           logger.warn('Feed not found during deletion, treating as already deleted', {
             id,
             error: result.error,
@@ -243,12 +238,13 @@ export function useDeleteFeed() {
           return { id, alreadyDeleted: true as const };
         }
 
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to delete feed', {
           error: result.error,
           id,
         });
-        toast.error(translate(msg`Failed to delete feed`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to delete feed`), { description: message });
+        throw new Error(message);
       }
 
       logger.info('Feed deleted successfully', { id });
@@ -280,12 +276,13 @@ export function useSearchSources() {
       const result = await commands.discoverSubscriptions(url);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to discover sources', {
           error: result.error,
           url,
         });
-        toast.error(translate(msg`Failed to search sources`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to search sources`), { description: message });
+        throw new Error(message);
       }
 
       logger.info('Sources discovered successfully', {
@@ -309,12 +306,13 @@ export function useRefreshFeed() {
       const result = await commands.refreshFeed(id);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to refresh feed', {
           error: result.error,
           id,
         });
-        toast.error(translate(msg`Failed to refresh feed`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to refresh feed`), { description: message });
+        throw new Error(message);
       }
 
       logger.info('Feed refreshed successfully', { id });
@@ -355,7 +353,7 @@ export function useRefreshCategoryFeeds() {
       const results = await Promise.all(feedIds.map((id) => commands.refreshFeed(id)));
       const failed = results.find((r) => r.status === 'error');
       if (failed && failed.status === 'error') {
-        throw new Error(failed.error);
+        throw new Error(formatMinifluxError(failed.error));
       }
     },
     onSuccess: () => {
@@ -380,11 +378,12 @@ export function useRefreshAllFeeds() {
       const result = await commands.refreshAllFeeds();
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('Failed to refresh all feeds', {
           error: result.error,
         });
-        toast.error(translate(msg`Failed to refresh feeds`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to refresh feeds`), { description: message });
+        throw new Error(message);
       }
 
       logger.info('All feeds refreshed successfully');
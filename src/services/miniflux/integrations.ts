@@ -1,5 +1,6 @@
 import { useQuery } from '@tanstack/react-query';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { commands } from '@/lib/tauri-bindings';
 
 // Query keys for integration data
@@ -23,7 +24,7 @@ export function useIntegrations(enabled = false) {
         logger.error('[useIntegrations] Failed to fetch integrations', {
           error: result.error,
         });
-        throw new Error(result.error);
+        throw new Error(formatMinifluxError(result.error));
       }
 
       // Count enabled integrations for logging
@@ -3,6 +3,7 @@ import { msg } from '@lingui/core/macro';
 import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query';
 import { toast } from 'sonner';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { commands } from '@/lib/tauri-bindings';
 import { useIsConnected } from '@/services/miniflux/auth';
 
@@ -32,7 +33,7 @@ export function useCurrentUser() {
         logger.error('[useCurrentUser] Failed to fetch current user', {
           error: result.error,
         });
-        throw new Error(result.error);
+        throw new Error(formatMinifluxError(result.error));
       }
 
       logger.info('[useCurrentUser] Current user data fetched successfully', {
@@ -66,7 +67,7 @@ export function useMinifluxUsers(enabled: boolean = true) {
         logger.error('[useMinifluxUsers] Failed to fetch users', {
           error: result.error,
         });
-        throw new Error(result.error);
+        throw new Error(formatMinifluxError(result.error));
       }
 
       logger.info('[useMinifluxUsers] Users fetched successfully', {
@@ -99,12 +100,13 @@ export function useCreateMinifluxUser() {
       });
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('[useCreateMinifluxUser] Failed to create user', {
           error: result.error,
           username: input.username,
         });
-        toast.error(translate(msg`Failed to create user`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to create user`), { description: message });
+        throw new Error(message);
       }
 
       return result.data;
@@ -141,12 +143,13 @@ export function useUpdateMinifluxUser() {
       const result = await commands.updateUser(id, updates);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('[useUpdateMinifluxUser] Failed to update user', {
           error: result.error,
           id,
         });
-        toast.error(translate(msg`Failed to update user`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to update user`), { description: message });
+        throw new Error(message);
       }
 
       return result.data;
@@ -170,12 +173,13 @@ export function useDeleteMinifluxUser() {
       const result = await commands.deleteUser(id);
 
       if (result.status === 'error') {
+        const message = formatMinifluxError(result.error);
         logger.error('[useDeleteMinifluxUser] Failed to delete user', {
           error: result.error,
           id,
         });
-        toast.error(translate(msg`Failed to delete user`), { description: result.error });
-        throw new Error(result.error);
+        toast.error(translate(msg`Failed to delete user`), { description: message });
+        throw new Error(message);
       }
     },
     onSuccess: () => {
@@ -1,5 +1,6 @@
 import { useQuery } from '@tanstack/react-query';
 import { logger } from '@/lib/logger';
+import { formatMinifluxError } from '@/lib/miniflux-utils';
 import { commands } from '@/lib/tauri-bindings';
 import { useIsConnected } from '@/services/miniflux/auth';
 
@@ -26,7 +27,7 @@ export function useMinifluxVersion() {
         logger.error('[useMinifluxVersion] Failed to fetch version', {
           error: result.error,
         });
-        throw new Error(result.error);
+        throw new Error(formatMinifluxError(result.error));
       }
 
       logger.info('[useMinifluxVersion] Version fetched successfully', {